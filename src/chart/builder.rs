@@ -1,10 +1,12 @@
-use super::context::ChartContext;
+use super::context::{ChartContext, ChartLayers};
+use super::layout::ChartRegion;
 
 use crate::coord::cartesian::{Cartesian2d, Cartesian3d};
 use crate::coord::ranged1d::AsRangedCoord;
 use crate::coord::Shift;
 
 use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
+use crate::style::text_anchor::{HPos, Pos, VPos};
 use crate::style::{IntoTextStyle, SizeDesc, TextStyle};
 
 use plotters_backend::DrawingBackend;
@@ -20,14 +22,173 @@ pub enum LabelAreaPosition {
     Right = 3,
 }
 
+/// The size of a label area, which is used when we configure the label area size with the API
+/// [ChartBuilder::set_label_area_size](struct ChartBuilder.html#method.set_label_area_size)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LabelAreaSize {
+    /// The label area has a fixed size in pixels. If the size is negative, the label area
+    /// overlaps with the plotting area.
+    Fixed(i32),
+    /// The label area is sized to fit the widest (or tallest) label and the axis description
+    /// with some padding. The labels are measured when the mesh is drawn, with the label style,
+    /// the formatter and the label count of the mesh, and the chart is laid out again to fit
+    /// them. Thus the mesh should be drawn before the series. The chart isn't laid out again
+    /// once it's converted into a dual coordinate chart.
+    Auto,
+}
+
+/// The type that can be used as the size of a label area
+pub trait IntoLabelAreaSize {
+    /// Convert the value into the label area size
+    /// - `parent_dim`: The dimension of the parent container of the label area in pixels
+    fn into_label_area_size(self, parent_dim: (u32, u32)) -> LabelAreaSize;
+}
+
+impl<S: SizeDesc> IntoLabelAreaSize for S {
+    fn into_label_area_size(self, parent_dim: (u32, u32)) -> LabelAreaSize {
+        LabelAreaSize::Fixed(self.in_pixels(&parent_dim))
+    }
+}
+
+impl IntoLabelAreaSize for LabelAreaSize {
+    fn into_label_area_size(self, _: (u32, u32)) -> LabelAreaSize {
+        self
+    }
+}
+
+/// The layout of the plotting area and the label areas of a 2D chart. The chart context keeps
+/// it when any label area is sized automatically, so the chart can be laid out again once the
+/// mesh knows the size of the labels.
+pub(crate) struct LabelAreaLayout<DB: DrawingBackend> {
+    /// The area shared by the plotting area and the label areas
+    area: DrawingArea<DB, Shift>,
+    /// The offset of the area relative to the chart, including the margin and the caption
+    offset: (i32, i32),
+    size: [u32; 4],
+    overlap: [bool; 4],
+    auto: [bool; 4],
+}
+
+impl<DB: DrawingBackend> LabelAreaLayout<DB> {
+    /// Set the size of an automatically sized label area, the fixed sizes aren't changed
+    pub(crate) fn set_auto_size(&mut self, pos: LabelAreaPosition, size: u32) {
+        if self.auto[pos as usize] {
+            self.size[pos as usize] = size;
+        }
+    }
+
+    /// Split the area into the plotting area and the label areas
+    /// - **returns**: The plotting area, the label areas in the order of top, bottom, left and
+    ///   right, and the position of the plotting area relative to the chart
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn split(
+        &self,
+    ) -> (
+        DrawingArea<DB, Shift>,
+        [Option<DrawingArea<DB, Shift>>; 4],
+        (i32, i32),
+    ) {
+        let mut label_areas = [None, None, None, None];
+        let mut drawing_area = self.area.clone();
+        let (w, h) = drawing_area.dim_in_pixel();
+
+        let mut actual_drawing_area_pos = [0, h as i32, 0, w as i32];
+
+        const DIR: [(i16, i16); 4] = [(0, -1), (0, 1), (-1, 0), (1, 0)];
+
+        for (idx, (dx, dy)) in (0..4).map(|idx| (idx, DIR[idx])) {
+            if self.overlap[idx] {
+                continue;
+            }
+
+            let size = self.size[idx] as i32;
+
+            let split_point = if dx + dy < 0 { size } else { -size };
+
+            actual_drawing_area_pos[idx] += split_point;
+        }
+
+        // Now the root drawing area is to be split into
+        //
+        // +----------+------------------------------+------+
+        // |    0     |    1 (Top Label Area)        |   2  |
+        // +----------+------------------------------+------+
+        // |    3     |                              |   5  |
+        // |  Left    |       4 (Plotting Area)      | Right|
+        // |  Labels  |                              | Label|
+        // +----------+------------------------------+------+
+        // |    6     |        7 (Bottom Labels)     |   8  |
+        // +----------+------------------------------+------+
+
+        let mut split: Vec<_> = drawing_area
+            .split_by_breakpoints(
+                &actual_drawing_area_pos[2..4],
+                &actual_drawing_area_pos[0..2],
+            )
+            .into_iter()
+            .map(Some)
+            .collect();
+
+        // Take out the plotting area
+        std::mem::swap(&mut drawing_area, split[4].as_mut().unwrap());
+
+        // Initialize the label areas - since the label area might be overlapping
+        // with the plotting area, in this case, we need handle them differently
+        for (src_idx, dst_idx) in [1, 7, 3, 5].iter().zip(0..4) {
+            if !self.overlap[dst_idx] {
+                let (h, w) = split[*src_idx].as_ref().unwrap().dim_in_pixel();
+                if h > 0 && w > 0 {
+                    std::mem::swap(&mut label_areas[dst_idx], &mut split[*src_idx]);
+                }
+            } else if self.size[dst_idx] != 0 {
+                let size = self.size[dst_idx] as i32;
+                let (dw, dh) = drawing_area.dim_in_pixel();
+                let x0 = if DIR[dst_idx].0 > 0 {
+                    dw as i32 - size
+                } else {
+                    0
+                };
+                let y0 = if DIR[dst_idx].1 > 0 {
+                    dh as i32 - size
+                } else {
+                    0
+                };
+                let x1 = if DIR[dst_idx].0 >= 0 { dw as i32 } else { size };
+                let y1 = if DIR[dst_idx].1 >= 0 { dh as i32 } else { size };
+
+                label_areas[dst_idx] = Some(
+                    drawing_area
+                        .clone()
+                        .shrink((x0, y0), ((x1 - x0), (y1 - y0))),
+                );
+            }
+        }
+
+        (
+            drawing_area,
+            label_areas,
+            (
+                actual_drawing_area_pos[2] + self.offset.0,
+                actual_drawing_area_pos[0] + self.offset.1,
+            ),
+        )
+    }
+}
+
 /// The helper object to create a chart context, which is used for the high-level figure drawing.
 /// With the help of this object, we can convert a basic drawing area into a chart context, which
 /// allows the high-level charting API being used on the drawing area.
 pub struct ChartBuilder<'a, 'b, DB: DrawingBackend> {
     label_area_size: [u32; 4], // [upper, lower, left, right]
     overlap_plotting_area: [bool; 4],
+    auto_label_area: [bool; 4],
     root_area: &'a DrawingArea<DB, Shift>,
     title: Option<(String, TextStyle<'b>)>,
+    subtitle: Option<(String, TextStyle<'b>)>,
+    footer: Option<(String, TextStyle<'b>)>,
+    caption_align: [HPos; 3],            // [title, subtitle, footer]
+    caption_area_size: [Option<u32>; 2], // [title, footer]
+    colorbar_area: Option<(LabelAreaPosition, u32)>,
     margin: [u32; 4],
 }
 
@@ -40,11 +201,22 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
             label_area_size: [0; 4],
             root_area: root,
             title: None,
+            subtitle: None,
+            footer: None,
+            caption_align: [HPos::Center; 3],
+            caption_area_size: [None; 2],
+            colorbar_area: None,
             margin: [0; 4],
             overlap_plotting_area: [false; 4],
+            auto_label_area: [false; 4],
         }
     }
 
+    /// Get the drawing area the chart is built on
+    pub(super) fn root_area(&self) -> &'a DrawingArea<DB, Shift> {
+        self.root_area
+    }
+
     /// Set the margin size of the chart (applied for top, bottom, left and right at the same time)
     /// - `size`: The size of the chart margin.
     pub fn margin<S: SizeDesc>(&mut self, size: S) -> &mut Self {
@@ -127,18 +299,77 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
 
     /// Set a label area size
     /// - `pos`: THe position where the label area located
-    /// - `size`: The size of the label area size
-    pub fn set_label_area_size<S: SizeDesc>(
+    /// - `size`: The size of the label area size, use `LabelAreaSize::Auto` to fit the labels
+    pub fn set_label_area_size<S: IntoLabelAreaSize>(
         &mut self,
         pos: LabelAreaPosition,
         size: S,
     ) -> &mut Self {
-        let size = size.in_pixels(self.root_area);
-        self.label_area_size[pos as usize] = size.abs() as u32;
-        self.overlap_plotting_area[pos as usize] = size < 0;
+        match size.into_label_area_size(self.root_area.dim_in_pixel()) {
+            LabelAreaSize::Fixed(size) => {
+                self.label_area_size[pos as usize] = size.abs() as u32;
+                self.overlap_plotting_area[pos as usize] = size < 0;
+                self.auto_label_area[pos as usize] = false;
+            }
+            LabelAreaSize::Auto => {
+                self.overlap_plotting_area[pos as usize] = false;
+                self.auto_label_area[pos as usize] = true;
+            }
+        }
         self
     }
 
+    /// Reserve a strip on the right side of the chart for a colorbar, see
+    /// [set_colorbar_area_size](#method.set_colorbar_area_size)
+    /// - `size`: The width of the colorbar area, if it's 0, the chart doesn't have the colorbar area
+    pub fn colorbar_area_size<S: SizeDesc>(&mut self, size: S) -> &mut Self {
+        self.set_colorbar_area_size(LabelAreaPosition::Right, size)
+    }
+
+    /// Reserve a strip for a colorbar beside the chart. The strip is taken out of the chart
+    /// after the caption and the footer, so the plotting area and the label areas shrink to fit
+    /// the rest, and the colorbar drawn onto
+    /// [ChartContext::colorbar_area](struct.ChartContext.html#method.colorbar_area) never
+    /// overlaps with the plot.
+    /// - `pos`: The side of the chart where the colorbar area located
+    /// - `size`: The width (or height) of the colorbar area, including the tick labels of the
+    ///   colorbar. If it's 0, the chart doesn't have the colorbar area
+    pub fn set_colorbar_area_size<S: SizeDesc>(
+        &mut self,
+        pos: LabelAreaPosition,
+        size: S,
+    ) -> &mut Self {
+        let size = size.in_pixels(self.root_area).max(0) as u32;
+        self.colorbar_area = if size > 0 { Some((pos, size)) } else { None };
+        self
+    }
+
+    /// Split the colorbar area from the drawing area, returns the remaining drawing area and
+    /// the colorbar area
+    fn split_colorbar_area(
+        &self,
+        drawing_area: DrawingArea<DB, Shift>,
+    ) -> (DrawingArea<DB, Shift>, Option<DrawingArea<DB, Shift>>) {
+        let (pos, size) = match self.colorbar_area {
+            Some((pos, size)) => (pos, size as i32),
+            None => return (drawing_area, None),
+        };
+        let (w, h) = drawing_area.dim_in_pixel();
+        let (remaining, colorbar_area) = match pos {
+            LabelAreaPosition::Top => {
+                let (colorbar_area, remaining) = drawing_area.split_vertically(size);
+                (remaining, colorbar_area)
+            }
+            LabelAreaPosition::Bottom => drawing_area.split_vertically(h as i32 - size),
+            LabelAreaPosition::Left => {
+                let (colorbar_area, remaining) = drawing_area.split_horizontally(size);
+                (remaining, colorbar_area)
+            }
+            LabelAreaPosition::Right => drawing_area.split_horizontally(w as i32 - size),
+        };
+        (remaining, Some(colorbar_area))
+    }
+
     /// Set the caption of the chart
     /// - `caption`: The caption of the chart
     /// - `style`: The text style
@@ -155,6 +386,180 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
         self
     }
 
+    /// Set the subtitle of the chart, which is drawn under the caption
+    /// - `subtitle`: The subtitle of the chart, it may contain multiple lines
+    /// - `style`: The text style
+    pub fn subtitle<S: AsRef<str>, Style: IntoTextStyle<'b>>(
+        &mut self,
+        subtitle: S,
+        style: Style,
+    ) -> &mut Self {
+        self.subtitle = Some((
+            subtitle.as_ref().to_string(),
+            style.into_text_style(self.root_area),
+        ));
+        self
+    }
+
+    /// Set the footer of the chart, which is drawn at the bottom of the chart
+    /// - `footer`: The footer of the chart, it may contain multiple lines
+    /// - `style`: The text style
+    pub fn footer<S: AsRef<str>, Style: IntoTextStyle<'b>>(
+        &mut self,
+        footer: S,
+        style: Style,
+    ) -> &mut Self {
+        self.footer = Some((
+            footer.as_ref().to_string(),
+            style.into_text_style(self.root_area),
+        ));
+        self
+    }
+
+    /// Set the horizontal alignment of the caption, by default it's centered
+    pub fn caption_alignment(&mut self, align: HPos) -> &mut Self {
+        self.caption_align[0] = align;
+        self
+    }
+
+    /// Set the horizontal alignment of the subtitle, by default it's centered
+    pub fn subtitle_alignment(&mut self, align: HPos) -> &mut Self {
+        self.caption_align[1] = align;
+        self
+    }
+
+    /// Set the horizontal alignment of the footer, by default it's centered
+    pub fn footer_alignment(&mut self, align: HPos) -> &mut Self {
+        self.caption_align[2] = align;
+        self
+    }
+
+    /// Reserve a fixed height for the caption and subtitle, instead of fitting the text
+    /// - `size`: The height of the area on the top of the chart for the caption and subtitle
+    pub fn caption_area_size<S: SizeDesc>(&mut self, size: S) -> &mut Self {
+        self.caption_area_size[0] = Some(size.in_pixels(self.root_area).max(0) as u32);
+        self
+    }
+
+    /// Reserve a fixed height for the footer, instead of fitting the text
+    /// - `size`: The height of the area on the bottom of the chart for the footer
+    pub fn footer_area_size<S: SizeDesc>(&mut self, size: S) -> &mut Self {
+        self.caption_area_size[1] = Some(size.in_pixels(self.root_area).max(0) as u32);
+        self
+    }
+
+    fn estimate_caption_lines(
+        area: &DrawingArea<DB, Shift>,
+        text: &str,
+        style: &TextStyle,
+    ) -> Result<(Vec<u32>, i32), DrawingAreaErrorKind<DB::ErrorType>> {
+        let mut heights = vec![];
+        for line in text.lines() {
+            let (_, h) =
+                area.estimate_text_size(if line.is_empty() { " " } else { line }, style)?;
+            heights.push(h);
+        }
+        let padding = (heights.first().copied().unwrap_or(0) / 2).min(5) as i32;
+        Ok((heights, padding))
+    }
+
+    fn draw_caption_lines(
+        area: &DrawingArea<DB, Shift>,
+        text: &str,
+        style: &TextStyle,
+        align: HPos,
+        heights: &[u32],
+        padding: i32,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let (w, _) = area.dim_in_pixel();
+        let x = match align {
+            HPos::Left => padding,
+            HPos::Center => w as i32 / 2,
+            HPos::Right => w as i32 - padding,
+        };
+        let style = style.pos(Pos::new(align, VPos::Top));
+        let mut y = padding;
+        for (line, h) in text.lines().zip(heights.iter()) {
+            area.draw_text(line, &style, (x, y))?;
+            y += *h as i32;
+        }
+        Ok(())
+    }
+
+    /// Draw the caption, subtitle and footer, returns the remaining drawing area and the
+    /// regions of the caption and footer
+    #[allow(clippy::type_complexity)]
+    fn draw_captions(
+        &self,
+        mut drawing_area: DrawingArea<DB, Shift>,
+    ) -> Result<
+        (
+            DrawingArea<DB, Shift>,
+            Option<ChartRegion>,
+            Option<ChartRegion>,
+        ),
+        DrawingAreaErrorKind<DB::ErrorType>,
+    > {
+        let (x0, y0) = drawing_area.get_base_pixel();
+        let (w, _) = drawing_area.dim_in_pixel();
+
+        let mut blocks = vec![];
+        for (idx, caption) in [&self.title, &self.subtitle].iter().enumerate() {
+            if let Some((ref text, ref style)) = caption {
+                let (heights, padding) = Self::estimate_caption_lines(&drawing_area, text, style)?;
+                let height = heights.iter().sum::<u32>() as i32 + padding * 2;
+                blocks.push((
+                    text,
+                    style,
+                    self.caption_align[idx],
+                    heights,
+                    padding,
+                    height,
+                ));
+            }
+        }
+
+        let title_area = if !blocks.is_empty() {
+            let fitted_height: i32 = blocks.iter().map(|b| b.5).sum();
+            let height = self.caption_area_size[0].map_or(fitted_height, |h| h as i32);
+            let (title_area, remaining) = drawing_area.split_vertically(height);
+            let mut block_area = title_area;
+            for (text, style, align, heights, padding, height) in blocks {
+                Self::draw_caption_lines(&block_area, text, style, align, &heights, padding)?;
+                block_area = block_area.split_vertically(height).1;
+            }
+            drawing_area = remaining;
+            Some(ChartRegion {
+                upper_left: (x0, y0),
+                size: (w, height.max(0) as u32),
+            })
+        } else {
+            None
+        };
+
+        let footer_area = if let Some((ref text, ref style)) = self.footer {
+            let (heights, padding) = Self::estimate_caption_lines(&drawing_area, text, style)?;
+            let fitted_height = heights.iter().sum::<u32>() as i32 + padding * 2;
+            let height = self.caption_area_size[1].map_or(fitted_height, |h| h as i32);
+            let (_, h) = drawing_area.dim_in_pixel();
+            let (remaining, footer_area) = drawing_area.split_vertically(h as i32 - height);
+            Self::draw_caption_lines(
+                &footer_area,
+                text,
+                style,
+                self.caption_align[2],
+                &heights,
+                padding,
+            )?;
+            drawing_area = remaining;
+            Some(ChartRegion::of_area(&footer_area))
+        } else {
+            None
+        };
+
+        Ok((drawing_area, title_area, footer_area))
+    }
+
     #[allow(clippy::type_complexity)]
     #[deprecated(
         note = "`build_ranged` has been renamed to `build_cartesian_2d` and is to be removed in the future."
@@ -184,7 +589,8 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
         ChartContext<'a, DB, Cartesian2d<X::CoordDescType, Y::CoordDescType>>,
         DrawingAreaErrorKind<DB::ErrorType>,
     > {
-        let mut label_areas = [None, None, None, None];
+        let x_spec: X::CoordDescType = x_spec.into();
+        let y_spec: Y::CoordDescType = y_spec.into();
 
         let mut drawing_area = DrawingArea::clone(self.root_area);
 
@@ -197,88 +603,24 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
             );
         }
 
-        let (title_dx, title_dy) = if let Some((ref title, ref style)) = self.title {
-            let (origin_dx, origin_dy) = drawing_area.get_base_pixel();
-            drawing_area = drawing_area.titled(title, style.clone())?;
-            let (current_dx, current_dy) = drawing_area.get_base_pixel();
-            (current_dx - origin_dx, current_dy - origin_dy)
-        } else {
-            (0, 0)
-        };
-
-        let (w, h) = drawing_area.dim_in_pixel();
-
-        let mut actual_drawing_area_pos = [0, h as i32, 0, w as i32];
-
-        const DIR: [(i16, i16); 4] = [(0, -1), (0, 1), (-1, 0), (1, 0)];
-
-        for (idx, (dx, dy)) in (0..4).map(|idx| (idx, DIR[idx])) {
-            if self.overlap_plotting_area[idx] {
-                continue;
-            }
-
-            let size = self.label_area_size[idx] as i32;
-
-            let split_point = if dx + dy < 0 { size } else { -size };
+        let (origin_dx, origin_dy) = drawing_area.get_base_pixel();
+        let (drawing_area, title_area, footer_area) = self.draw_captions(drawing_area)?;
+        let (drawing_area, colorbar_area) = self.split_colorbar_area(drawing_area);
+        let (current_dx, current_dy) = drawing_area.get_base_pixel();
+        let (title_dx, title_dy) = (current_dx - origin_dx, current_dy - origin_dy);
 
-            actual_drawing_area_pos[idx] += split_point;
-        }
-
-        // Now the root drawing area is to be split into
-        //
-        // +----------+------------------------------+------+
-        // |    0     |    1 (Top Label Area)        |   2  |
-        // +----------+------------------------------+------+
-        // |    3     |                              |   5  |
-        // |  Left    |       4 (Plotting Area)      | Right|
-        // |  Labels  |                              | Label|
-        // +----------+------------------------------+------+
-        // |    6     |        7 (Bottom Labels)     |   8  |
-        // +----------+------------------------------+------+
-
-        let mut split: Vec<_> = drawing_area
-            .split_by_breakpoints(
-                &actual_drawing_area_pos[2..4],
-                &actual_drawing_area_pos[0..2],
-            )
-            .into_iter()
-            .map(Some)
-            .collect();
-
-        // Take out the plotting area
-        std::mem::swap(&mut drawing_area, split[4].as_mut().unwrap());
-
-        // Initialize the label areas - since the label area might be overlapping
-        // with the plotting area, in this case, we need handle them differently
-        for (src_idx, dst_idx) in [1, 7, 3, 5].iter().zip(0..4) {
-            if !self.overlap_plotting_area[dst_idx] {
-                let (h, w) = split[*src_idx].as_ref().unwrap().dim_in_pixel();
-                if h > 0 && w > 0 {
-                    std::mem::swap(&mut label_areas[dst_idx], &mut split[*src_idx]);
-                }
-            } else if self.label_area_size[dst_idx] != 0 {
-                let size = self.label_area_size[dst_idx] as i32;
-                let (dw, dh) = drawing_area.dim_in_pixel();
-                let x0 = if DIR[dst_idx].0 > 0 {
-                    dw as i32 - size
-                } else {
-                    0
-                };
-                let y0 = if DIR[dst_idx].1 > 0 {
-                    dh as i32 - size
-                } else {
-                    0
-                };
-                let x1 = if DIR[dst_idx].0 >= 0 { dw as i32 } else { size };
-                let y1 = if DIR[dst_idx].1 >= 0 { dh as i32 } else { size };
-
-                label_areas[dst_idx] = Some(
-                    drawing_area
-                        .clone()
-                        .shrink((x0, y0), ((x1 - x0), (y1 - y0))),
-                );
-            }
-        }
+        // The automatically sized label areas are empty until the mesh measures the labels
+        let layout = LabelAreaLayout {
+            area: drawing_area,
+            offset: (
+                title_dx + self.margin[2] as i32,
+                title_dy + self.margin[0] as i32,
+            ),
+            size: self.label_area_size,
+            overlap: self.overlap_plotting_area,
+            auto: self.auto_label_area,
+        };
+        let (drawing_area, mut label_areas, drawing_area_pos) = layout.split();
 
         let mut pixel_range = drawing_area.get_pixel_range();
         pixel_range.1 = (pixel_range.1.end - 1)..(pixel_range.1.start - 1);
@@ -300,10 +642,16 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
                 pixel_range,
             )),
             series_anno: vec![],
-            drawing_area_pos: (
-                actual_drawing_area_pos[2] + title_dx + self.margin[2] as i32,
-                actual_drawing_area_pos[0] + title_dy + self.margin[0] as i32,
-            ),
+            layers: ChartLayers::new(self.root_area),
+            title_area,
+            footer_area,
+            colorbar_area,
+            drawing_area_pos,
+            label_area_layout: if self.auto_label_area.iter().any(|&auto| auto) {
+                Some(layout)
+            } else {
+                None
+            },
         })
     }
 
@@ -334,14 +682,11 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
             );
         }
 
-        let (title_dx, title_dy) = if let Some((ref title, ref style)) = self.title {
-            let (origin_dx, origin_dy) = drawing_area.get_base_pixel();
-            drawing_area = drawing_area.titled(title, style.clone())?;
-            let (current_dx, current_dy) = drawing_area.get_base_pixel();
-            (current_dx - origin_dx, current_dy - origin_dy)
-        } else {
-            (0, 0)
-        };
+        let (origin_dx, origin_dy) = drawing_area.get_base_pixel();
+        let (drawing_area, title_area, footer_area) = self.draw_captions(drawing_area)?;
+        let (drawing_area, colorbar_area) = self.split_colorbar_area(drawing_area);
+        let (current_dx, current_dy) = drawing_area.get_base_pixel();
+        let (title_dx, title_dy) = (current_dx - origin_dx, current_dy - origin_dy);
 
         let pixel_range = drawing_area.get_pixel_range();
 
@@ -355,10 +700,15 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
                 pixel_range,
             )),
             series_anno: vec![],
+            layers: ChartLayers::new(self.root_area),
+            title_area,
+            footer_area,
+            colorbar_area,
             drawing_area_pos: (
                 title_dx + self.margin[2] as i32,
                 title_dy + self.margin[0] as i32,
             ),
+            label_area_layout: None,
         })
     }
 }
@@ -429,4 +779,123 @@ mod test {
         chart.caption("This is a test case", ("serif", 10));
         assert_eq!(chart.title.as_ref().unwrap().1.font.get_name(), "serif");
     }
+
+    #[test]
+    fn test_subtitle_and_footer() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "Title");
+                assert_eq!(pos.0, 5);
+            });
+            m.check_draw_text(|_, _, _, _, text| assert_eq!(text, "Line 1"));
+            m.check_draw_text(|_, _, _, _, text| assert_eq!(text, "Line 2"));
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "Footer");
+                assert!(pos.1 > 150);
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_text_call, 4));
+        });
+
+        let chart = ChartBuilder::on(&drawing_area)
+            .caption("Title", ("serif", 20))
+            .caption_alignment(HPos::Left)
+            .subtitle("Line 1\nLine 2", ("serif", 10))
+            .footer("Footer", ("serif", 10))
+            .footer_area_size(30)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+
+        let layout = chart.layout();
+        let title = layout.title_area.expect("No title area");
+        let footer = layout.footer_area.expect("No footer area");
+        assert_eq!(footer.upper_left, (0, 170));
+        assert_eq!(footer.size, (200, 30));
+        assert_eq!(layout.plotting_area.upper_left, (0, title.size.1 as i32));
+        assert_eq!(layout.plotting_area.size.1, 170 - title.size.1);
+    }
+
+    #[test]
+    fn test_auto_label_area_size() {
+        let drawing_area = create_mocked_drawing_area(400, 400, |_| {});
+
+        let mut narrow = ChartBuilder::on(&drawing_area)
+            .set_label_area_size(LabelAreaPosition::Left, LabelAreaSize::Auto)
+            .set_label_area_size(LabelAreaPosition::Bottom, LabelAreaSize::Auto)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+
+        // The label areas are empty until the mesh measures the labels
+        assert!(narrow.layout().left_label_area.is_none());
+        narrow.configure_mesh().draw().expect("Draw mesh");
+        let narrow = narrow.layout();
+
+        let mut wide = ChartBuilder::on(&drawing_area)
+            .set_label_area_size(LabelAreaPosition::Left, LabelAreaSize::Auto)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+        wide.configure_mesh()
+            .y_label_formatter(&|y| format!("{} thousand dollars", y))
+            .draw()
+            .expect("Draw mesh");
+        let wide = wide.layout();
+
+        let narrow_left = narrow.left_label_area.expect("No left label area");
+        let wide_left = wide.left_label_area.expect("No left label area");
+        assert!(narrow_left.size.0 > 0);
+        assert!(wide_left.size.0 > narrow_left.size.0);
+        assert_eq!(narrow.plotting_area.upper_left.0, narrow_left.size.0 as i32);
+        assert_eq!(narrow.plotting_area.size.0, 400 - narrow_left.size.0);
+        assert!(
+            narrow
+                .bottom_label_area
+                .expect("No bottom label area")
+                .size
+                .1
+                > 0
+        );
+        assert!(wide.bottom_label_area.is_none());
+    }
+
+    #[test]
+    fn test_colorbar_area_size() {
+        let drawing_area = create_mocked_drawing_area(400, 300, |_| {});
+
+        let chart = ChartBuilder::on(&drawing_area)
+            .margin(10)
+            .y_label_area_size(30)
+            .right_y_label_area_size(20)
+            .colorbar_area_size(60)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+
+        let layout = chart.layout();
+        let colorbar = layout.colorbar_area.expect("No colorbar area");
+        assert_eq!(colorbar.upper_left, (330, 10));
+        assert_eq!(colorbar.size, (60, 280));
+        assert_eq!(layout.plotting_area.upper_left, (40, 10));
+        assert_eq!(layout.plotting_area.size, (270, 280));
+        assert_eq!(
+            layout
+                .right_label_area
+                .expect("No right label area")
+                .upper_left,
+            (310, 10)
+        );
+
+        let chart = ChartBuilder::on(&drawing_area)
+            .set_colorbar_area_size(LabelAreaPosition::Top, 50)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+
+        let layout = chart.layout();
+        assert_eq!(
+            layout.colorbar_area,
+            Some(ChartRegion {
+                upper_left: (0, 0),
+                size: (400, 50),
+            })
+        );
+        assert_eq!(layout.plotting_area.upper_left, (0, 50));
+        assert_eq!(layout.plotting_area.size, (400, 250));
+    }
 }
@@ -1,17 +1,67 @@
 use std::borrow::Borrow;
 
-use plotters_backend::{BackendCoord, DrawingBackend};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 
-use crate::chart::{SeriesAnno, SeriesLabelStyle};
+use crate::chart::builder::LabelAreaLayout;
+use crate::chart::{ChartRegion, LegendMarker, SeriesAnno, SeriesLabelInfo, SeriesLabelStyle};
 use crate::coord::{CoordTranslate, ReverseCoordTranslate, Shift};
 use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
-use crate::element::{CoordMapper, Drawable, PointCollection};
+use crate::element::{CoordMapper, Drawable, PathElement, PointCollection};
+use crate::style::{CategoryColors, Color, Palette, Palette99, RGBAColor, SizeDesc};
 
 pub(super) mod cartesian2d;
 pub(super) mod cartesian3d;
 
 pub(super) use cartesian3d::Coord3D;
 
+type LayerDrawFn<'a, DB> =
+    dyn FnOnce(&mut DB) -> Result<(), DrawingErrorKind<<DB as DrawingBackend>::ErrorType>> + 'a;
+
+/// The drawing operations buffered on the layers of a chart. The points are already mapped to
+/// the backend coordinate when an operation is buffered, and the operations are drawn in the
+/// order of their z-index when the layers are flushed or dropped.
+pub(crate) struct ChartLayers<'a, DB: DrawingBackend> {
+    area: DrawingArea<DB, Shift>,
+    layers: Vec<(i32, Box<LayerDrawFn<'a, DB>>)>,
+}
+
+impl<'a, DB: DrawingBackend> ChartLayers<'a, DB> {
+    pub(crate) fn new<CT: CoordTranslate>(area: &DrawingArea<DB, CT>) -> Self {
+        Self {
+            area: area.strip_coord_spec(),
+            layers: vec![],
+        }
+    }
+
+    pub(crate) fn push<F>(&mut self, z: i32, draw: F)
+    where
+        F: FnOnce(&mut DB) -> Result<(), DrawingErrorKind<DB::ErrorType>> + 'a,
+    {
+        self.layers.push((z, Box::new(draw)));
+    }
+
+    pub(crate) fn flush(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let mut layers = std::mem::replace(&mut self.layers, vec![]);
+        if layers.is_empty() {
+            return Ok(());
+        }
+        layers.sort_by_key(|(z, _)| *z);
+        self.area.backend_ops(move |b| {
+            for (_, draw) in layers {
+                draw(b)?;
+            }
+            Ok(())
+        })
+    }
+}
+
+impl<'a, DB: DrawingBackend> Drop for ChartLayers<'a, DB> {
+    fn drop(&mut self) {
+        // Drop can't fail, use ChartContext::flush_layers to get the error
+        let _ = self.flush();
+    }
+}
+
 /// The context of the chart. This is the core object of Plotters.
 /// Any plot/chart is abstracted as this type, and any data series can be placed to the chart
 /// context.
@@ -24,7 +74,12 @@ pub struct ChartContext<'a, DB: DrawingBackend, CT: CoordTranslate> {
     pub(crate) y_label_area: [Option<DrawingArea<DB, Shift>>; 2],
     pub(crate) drawing_area: DrawingArea<DB, CT>,
     pub(crate) series_anno: Vec<SeriesAnno<'a, DB>>,
+    pub(crate) layers: ChartLayers<'a, DB>,
+    pub(crate) title_area: Option<ChartRegion>,
+    pub(crate) footer_area: Option<ChartRegion>,
+    pub(crate) colorbar_area: Option<DrawingArea<DB, Shift>>,
     pub(crate) drawing_area_pos: (i32, i32),
+    pub(crate) label_area_layout: Option<LabelAreaLayout<DB>>,
 }
 
 impl<'a, DB: DrawingBackend, CT: ReverseCoordTranslate> ChartContext<'a, DB, CT> {
@@ -44,11 +99,76 @@ impl<'a, DB: DrawingBackend, CT: CoordTranslate> ChartContext<'a, DB, CT> {
         SeriesLabelStyle::new(self)
     }
 
+    /// Get the labels and the legend markers of the series drawn on the chart, in the order the
+    /// series have been drawn. The series without a label or a legend element are skipped, the
+    /// same as the series label area drawn by
+    /// [configure_series_labels](#method.configure_series_labels).
+    pub fn series_labels(&self) -> Vec<SeriesLabelInfo> {
+        self.series_anno
+            .iter()
+            .enumerate()
+            .map(|(idx, anno)| anno.get_info(idx))
+            .filter(|info| !info.label.is_empty() || info.has_legend)
+            .collect()
+    }
+
     /// Get a reference of underlying plotting area
     pub fn plotting_area(&self) -> &DrawingArea<DB, CT> {
         &self.drawing_area
     }
 
+    /// Get the area reserved for the colorbar by
+    /// [ChartBuilder::set_colorbar_area_size](struct.ChartBuilder.html#method.set_colorbar_area_size),
+    /// which is in the pixel coordinate relative to the upper left corner of the area
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let root = BitMapBackend::new("plotters-doc-data/colorbar-area.png", (640, 480))
+    ///     .into_drawing_area();
+    /// let chart = ChartBuilder::on(&root)
+    ///     .margin(10)
+    ///     .x_label_area_size(30)
+    ///     .y_label_area_size(30)
+    ///     .colorbar_area_size(70)
+    ///     .build_cartesian_2d(0.0..1.0, 0.0..1.0)
+    ///     .unwrap();
+    ///
+    /// let colorbar_area = chart.colorbar_area().unwrap();
+    /// let (_, h) = colorbar_area.dim_in_pixel();
+    /// colorbar_area
+    ///     .draw(&Colorbar::new((15, 0), (15, h - 30), 0.0..1.0, |v| {
+    ///         HSLColor(0.7 * (1.0 - v), 1.0, 0.5)
+    ///     }))
+    ///     .unwrap();
+    /// ```
+    pub fn colorbar_area(&self) -> Option<&DrawingArea<DB, Shift>> {
+        self.colorbar_area.as_ref()
+    }
+
+    /// Get a pixel-based sub-area of the plotting area, which can be used to build an independent
+    /// chart inside the current one, such as a zoomed inset.
+    /// - `left_upper`: The offset of the upper left corner relative to the plotting area
+    /// - `dimension`: The size of the sub-area
+    /// - **returns**: The drawing area, which is clamped to the plotting area
+    pub fn inset_area<A: SizeDesc, B: SizeDesc, C: SizeDesc, D: SizeDesc>(
+        &self,
+        left_upper: (A, B),
+        dimension: (C, D),
+    ) -> DrawingArea<DB, Shift> {
+        let area = self.drawing_area.strip_coord_spec();
+        let (w, h) = area.dim_in_pixel();
+        let (x, y) = (
+            left_upper.0.in_pixels(&area).max(0).min(w as i32),
+            left_upper.1.in_pixels(&area).max(0).min(h as i32),
+        );
+        let (dw, dh) = (
+            dimension.0.in_pixels(&area).min(w as i32 - x),
+            dimension.1.in_pixels(&area).min(h as i32 - y),
+        );
+        area.shrink((x, y), (dw, dh))
+    }
+
     /// Cast the reference to a chart context to a reference to underlying coordinate specification.
     pub fn as_coord_spec(&self) -> &CT {
         self.drawing_area.as_coord_spec()
@@ -97,12 +217,440 @@ impl<'a, DB: DrawingBackend, CT: CoordTranslate> ChartContext<'a, DB, CT> {
         self.draw_series_impl(series)?;
         Ok(self.alloc_series_anno())
     }
+
+    /// Draw a data series with the painter's algorithm: the elements are sorted by their
+    /// average depth under the current coordinate transform and the farthest element is drawn
+    /// first, so the nearer elements cover the farther ones. This is useful for the 3D surfaces,
+    /// since the sort order follows the view angle set by
+    /// [with_projection](#method.with_projection). For the coordinate without depth, the
+    /// elements are drawn in their original order.
+    pub fn draw_series_depth_sorted<B, E, R, S>(
+        &mut self,
+        series: S,
+    ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        B: CoordMapper,
+        for<'b> &'b E: PointCollection<'b, CT::From, B>,
+        E: Drawable<DB, B>,
+        R: Borrow<E>,
+        S: IntoIterator<Item = R>,
+    {
+        let coord = self.drawing_area.as_coord_spec();
+        let mut elements: Vec<(f64, R)> = series
+            .into_iter()
+            .map(|element| {
+                let (sum, count) = element
+                    .borrow()
+                    .point_iter()
+                    .into_iter()
+                    .fold((0.0, 0), |(sum, count), p| {
+                        (sum + f64::from(coord.depth(p.borrow())), count + 1)
+                    });
+                (sum / f64::from(count.max(1)), element)
+            })
+            .collect();
+        elements.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+        self.draw_series_impl(elements.into_iter().map(|(_, element)| element))?;
+        Ok(self.alloc_series_anno())
+    }
+
+    /// Draw a series on a layer. Different from `draw_series`, the series is buffered and
+    /// composited when the chart is presented with [present](#method.present) or dropped, then
+    /// all the buffered series are drawn from the lowest layer to the highest one. The series on
+    /// the same layer are drawn in the order they are added. Anything drawn without a layer is
+    /// drawn immediately, thus it's under all the layers.
+    ///
+    /// Note that presenting the root drawing area doesn't know about the chart, so either
+    /// present the chart itself or drop it before presenting the root area.
+    /// - `z`: The z-index of the layer
+    /// - `series`: The series to draw
+    /// - **returns**: The series annotation, the legend is configured as usual
+    pub fn draw_series_on_layer<B, E, R, S>(&mut self, z: i32, series: S) -> &mut SeriesAnno<'a, DB>
+    where
+        B: CoordMapper,
+        B::Output: 'a,
+        for<'b> &'b E: PointCollection<'b, CT::From, B>,
+        E: Drawable<DB, B>,
+        R: Borrow<E> + 'a,
+        S: IntoIterator<Item = R>,
+    {
+        let elements: Vec<_> = series
+            .into_iter()
+            .map(|element| {
+                let points = self.drawing_area.map_points(element.borrow());
+                (element, points)
+            })
+            .collect();
+        let dim = self.drawing_area.dim_in_pixel();
+        self.layers.push(z, move |b| {
+            for (element, points) in elements {
+                element.borrow().draw(points.into_iter(), b, dim)?;
+            }
+            Ok(())
+        });
+        self.alloc_series_anno()
+    }
+
+    /// Draw all the series buffered by [draw_series_on_layer](#method.draw_series_on_layer) in
+    /// the order of their z-index. This happens automatically when the chart is presented or
+    /// dropped, call this to get the drawing error, which is ignored when the chart is dropped.
+    pub fn flush_layers(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        self.layers.flush()
+    }
+
+    /// Draw the buffered layers and present all the pending changes to the backend
+    pub fn present(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        self.flush_layers()?;
+        self.drawing_area.present()
+    }
+
+    /// Draw multiple data series at once. Each series is assigned a distinct color picked from
+    /// `Palette99` and registered as a legend entry with its label.
+    /// - `series`: The iterator of `(label, data)` pairs, one pair for each series
+    /// - `make_series`: The function that creates a series from the data and the assigned color
+    pub fn draw_series_auto<L, D, I, F, B, E, R, S>(
+        &mut self,
+        series: I,
+        make_series: F,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        DB: 'a,
+        L: Into<String>,
+        I: IntoIterator<Item = (L, D)>,
+        F: FnMut(D, RGBAColor) -> S,
+        B: CoordMapper,
+        for<'b> &'b E: PointCollection<'b, CT::From, B>,
+        E: Drawable<DB, B>,
+        R: Borrow<E>,
+        S: IntoIterator<Item = R>,
+    {
+        self.draw_series_auto_impl(series, make_series, |idx| Palette99::pick(idx).to_rgba())
+    }
+
+    /// Draw multiple data series at once, just like `draw_series_auto`, but the colors are
+    /// picked from the palette `P`.
+    pub fn draw_series_auto_with_palette<P, L, D, I, F, B, E, R, S>(
+        &mut self,
+        series: I,
+        make_series: F,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        DB: 'a,
+        P: Palette,
+        L: Into<String>,
+        I: IntoIterator<Item = (L, D)>,
+        F: FnMut(D, RGBAColor) -> S,
+        B: CoordMapper,
+        for<'b> &'b E: PointCollection<'b, CT::From, B>,
+        E: Drawable<DB, B>,
+        R: Borrow<E>,
+        S: IntoIterator<Item = R>,
+    {
+        self.draw_series_auto_impl(series, make_series, |idx| P::pick(idx).to_rgba())
+    }
+
+    /// Add a legend entry for each category of a categorical coloring, so the legend drawn by
+    /// [configure_series_labels](#method.configure_series_labels) always matches the colors of
+    /// the data, e.g. of a choropleth or a scatter plot colored by class. The entries don't
+    /// draw anything on the plotting area and they are listed in the order of the categories.
+    /// - `colors`: The mapping from the categories to their colors, the categories are
+    ///   formatted as the labels
+    /// - `marker`: The shape of the legend markers, the markers other than
+    ///   `LegendMarker::Line` are filled
+    pub fn add_category_legend<T: PartialEq + std::fmt::Display>(
+        &mut self,
+        colors: &CategoryColors<T>,
+        marker: LegendMarker,
+    ) {
+        for (category, color) in colors.iter() {
+            let style = if marker == LegendMarker::Line {
+                color.stroke_width(1)
+            } else {
+                color.filled()
+            };
+            self.alloc_series_anno()
+                .label(category.to_string())
+                .legend_marker(marker, style);
+        }
+    }
+
+    fn draw_series_auto_impl<L, D, I, F, B, E, R, S>(
+        &mut self,
+        series: I,
+        mut make_series: F,
+        pick_color: impl Fn(usize) -> RGBAColor,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        DB: 'a,
+        L: Into<String>,
+        I: IntoIterator<Item = (L, D)>,
+        F: FnMut(D, RGBAColor) -> S,
+        B: CoordMapper,
+        for<'b> &'b E: PointCollection<'b, CT::From, B>,
+        E: Drawable<DB, B>,
+        R: Borrow<E>,
+        S: IntoIterator<Item = R>,
+    {
+        for (idx, (label, data)) in series.into_iter().enumerate() {
+            let color = pick_color(idx);
+            self.draw_series(make_series(data, color))?
+                .label(label)
+                .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color));
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod test {
     use crate::prelude::*;
 
+    #[test]
+    fn test_set_axis_range() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..100.0, 0.0..100.0)
+            .expect("Create chart");
+
+        assert_eq!(chart.backend_coord(&(50.0, 50.0)), (100, 99));
+
+        chart.set_x_range(0.0..50.0).set_y_range(50.0..100.0);
+
+        assert_eq!(chart.x_range(), 0.0..50.0);
+        assert_eq!(chart.y_range(), 50.0..100.0);
+        assert_eq!(chart.backend_coord(&(50.0, 50.0)), (200, 199));
+    }
+
+    #[test]
+    fn test_draw_series_on_layer() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_circle(|c, _, _, _, _| assert_eq!(c, GREEN.to_rgba()));
+            m.check_draw_circle(|c, _, _, _, _| assert_eq!(c, BLUE.to_rgba()));
+            m.check_draw_circle(|c, _, _, _, _| assert_eq!(c, RED.to_rgba()));
+            m.check_draw_circle(|c, _, _, _, _| assert_eq!(c, YELLOW.to_rgba()));
+            m.drop_check(|b| assert_eq!(b.num_draw_circle_call, 4));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+
+        chart.draw_series_on_layer(10, vec![Circle::new((5, 5), 3, &YELLOW)]);
+        chart
+            .draw_series_on_layer(-1, vec![Circle::new((5, 5), 3, &BLUE)])
+            .label("blue");
+        chart.draw_series_on_layer(0, vec![Circle::new((5, 5), 3, &RED)]);
+        chart
+            .draw_series(vec![Circle::new((5, 5), 3, &GREEN)])
+            .expect("Drawing error");
+
+        assert_eq!(chart.series_anno[1].get_label(), "blue");
+        chart.flush_layers().expect("Drawing error");
+        assert!(chart.layers.layers.is_empty());
+    }
+
+    #[test]
+    fn test_layers_drawn_when_chart_dropped() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_circle(|c, _, _, _, _| assert_eq!(c, BLUE.to_rgba()));
+            m.check_draw_circle(|c, _, _, (x, _), _| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(x, 100);
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_circle_call, 2));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+
+        chart.draw_series_on_layer(1, vec![Circle::new((5, 5), 3, &RED)]);
+        chart.draw_series_on_layer(0, vec![Circle::new((5, 5), 3, &BLUE)]);
+        // The points are mapped when the series is buffered
+        chart.set_x_range(0..100);
+        drop(chart);
+    }
+
+    #[test]
+    fn test_draw_grid_on_top() {
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        let circle_drawn = Rc::new(Cell::new(false));
+        let (line_check, circle_check) = (circle_drawn.clone(), circle_drawn.clone());
+        let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+            m.check_draw_line(move |_, _, _, _| assert!(line_check.get()));
+            m.check_draw_circle(move |_, _, _, _, _| circle_check.set(true));
+            m.drop_check(|b| {
+                assert!(b.num_draw_line_call > 0);
+                assert_eq!(b.num_draw_circle_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+
+        chart
+            .configure_mesh()
+            .disable_axes()
+            .draw_grid_on_top(true)
+            .draw()
+            .expect("Drawing error");
+        chart
+            .draw_series(vec![Circle::new((5, 5), 3, &RED)])
+            .expect("Drawing error");
+        drop(chart);
+        assert!(circle_drawn.get());
+    }
+
+    #[test]
+    fn test_decade_bands() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_rect(|c, _, fill, u, d| {
+                assert_eq!(c, RED.mix(0.1).to_rgba());
+                assert!(fill);
+                assert_eq!(u, (0, 0));
+                assert!((d.0 - 50).abs() <= 1 && d.1 == 199);
+            });
+            m.check_draw_rect(|_, _, _, u, d| {
+                assert!((u.0 - 100).abs() <= 1 && u.1 == 0);
+                assert!((d.0 - 150).abs() <= 1 && d.1 == 199);
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_rect_call, 2));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d((1.0..10000.0).log_scale(), 0..10)
+            .expect("Create chart");
+
+        chart
+            .configure_mesh()
+            .disable_mesh()
+            .disable_axes()
+            .x_decade_bands(RED.mix(0.1).filled())
+            .draw()
+            .expect("Drawing error");
+    }
+
+    #[test]
+    fn test_span_order() {
+        for &above in [false, true].iter() {
+            let events = std::rc::Rc::new(std::cell::RefCell::new(vec![]));
+            let (span_events, grid_events) = (events.clone(), events.clone());
+            let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+                m.check_draw_rect(move |_, _, fill, u, d| {
+                    assert!(fill);
+                    assert_eq!((u, d), ((40, 0), (80, 199)));
+                    span_events.borrow_mut().push("span");
+                });
+                m.check_draw_line(move |_, _, _, _| grid_events.borrow_mut().push("grid"));
+            });
+
+            let mut chart = ChartBuilder::on(&drawing_area)
+                .build_cartesian_2d(0..10, 0..10)
+                .expect("Create chart");
+
+            chart
+                .configure_mesh()
+                .disable_axes()
+                .x_span(2..4, RED.mix(0.2).filled())
+                .spans_above_grid(above)
+                .draw()
+                .expect("Drawing error");
+
+            let events = events.borrow();
+            assert_eq!(events.iter().filter(|e| **e == "span").count(), 1);
+            if above {
+                assert_eq!(events.last(), Some(&"span"));
+            } else {
+                assert_eq!(events.first(), Some(&"span"));
+            }
+        }
+    }
+
+    #[cfg(feature = "line_series")]
+    #[test]
+    fn test_draw_series_checked() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 1);
+                assert_eq!(b.num_draw_circle_call, 4);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+
+        let (out_of_range, _) = chart
+            .draw_series_checked(LineSeries::new(
+                vec![(0, 0), (10, 10), (11, 5), (5, -1)],
+                &RED,
+            ))
+            .expect("Drawing error");
+        assert_eq!(out_of_range, 2);
+
+        let (out_of_range, _) = chart
+            .draw_series_checked(
+                vec![(0, 0), (5, 5), (3, 20), (-2, -2)]
+                    .into_iter()
+                    .map(|p| Circle::new(p, 2, BLUE.filled())),
+            )
+            .expect("Drawing error");
+        assert_eq!(out_of_range, 2);
+    }
+
+    #[test]
+    fn test_inset_area() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
+
+        let chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(20)
+            .y_label_area_size(20)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+
+        let inset = chart.inset_area((100, 10), (50, 300));
+        assert_eq!(inset.get_base_pixel(), (120, 10));
+        assert_eq!(inset.dim_in_pixel(), (50, 170));
+
+        let inset_chart = ChartBuilder::on(&inset)
+            .x_label_area_size(10)
+            .build_cartesian_2d(0.0..1.0, 0.0..1.0)
+            .expect("Create inset chart");
+        let layout = inset_chart.layout();
+        assert_eq!(layout.plotting_area.upper_left, (120, 10));
+        assert_eq!(layout.plotting_area.size, (50, 160));
+    }
+
+    #[cfg(feature = "line_series")]
+    #[test]
+    fn test_draw_series_auto() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_path(|c, _, _| assert_eq!(c, Palette99::pick(0).to_rgba()));
+            m.check_draw_path(|c, _, _| assert_eq!(c, Palette99::pick(1).to_rgba()));
+            m.drop_check(|b| assert_eq!(b.num_draw_path_call, 2));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+
+        chart
+            .draw_series_auto(
+                vec![("a", vec![(0, 0), (5, 5)]), ("b", vec![(0, 5), (5, 0)])],
+                |data, color| LineSeries::new(data, color),
+            )
+            .expect("Drawing error");
+
+        assert_eq!(chart.series_anno.len(), 2);
+        assert_eq!(chart.series_anno[0].get_label(), "a");
+        assert_eq!(chart.series_anno[1].get_label(), "b");
+        assert!(chart.series_anno[1].get_draw_func().is_some());
+    }
+
     #[test]
     fn test_chart_context() {
         let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
@@ -148,6 +696,130 @@ mod test {
             .expect("Drawing error");
     }
 
+    #[test]
+    fn test_axis_desc_multiline() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let texts = Rc::new(RefCell::new(vec![]));
+        {
+            let texts = texts.clone();
+            let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+                m.check_draw_text(move |_, _, size, pos, text| {
+                    texts.borrow_mut().push((text.to_string(), size, pos))
+                });
+            });
+
+            let mut chart = ChartBuilder::on(&drawing_area)
+                .x_label_area_size(40)
+                .build_cartesian_2d(0..10, 0..10)
+                .expect("Create chart");
+
+            chart
+                .configure_mesh()
+                .x_desc("Time\n(s)")
+                .x_desc_style(("sans-serif", 10))
+                .x_desc_offset((5, 0))
+                .draw()
+                .expect("Draw mesh");
+        }
+
+        let texts = texts.borrow();
+        let find = |name: &str| texts.iter().find(|(text, ..)| text == name).cloned();
+        let (_, size, first) = find("Time").expect("First line");
+        let (_, _, second) = find("(s)").expect("Second line");
+        assert_eq!(size, 10.0);
+        assert_eq!(second.0, first.0);
+        assert_eq!(second.1 - first.1, 10);
+    }
+
+    #[test]
+    fn test_tick_style() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let texts = Rc::new(RefCell::new(vec![]));
+        let lines = Rc::new(RefCell::new(vec![]));
+        {
+            let (texts, lines) = (texts.clone(), lines.clone());
+            let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+                m.check_draw_text(move |c, _, _, _, text| {
+                    texts.borrow_mut().push((text.to_string(), c))
+                });
+                m.check_draw_line(move |c, _, from, to| lines.borrow_mut().push((c, from, to)));
+            });
+
+            let mut chart = ChartBuilder::on(&drawing_area)
+                .x_label_area_size(20)
+                .build_cartesian_2d(0..10, 0..10)
+                .expect("Create chart");
+
+            let highlight = |v: &i32, _: usize| {
+                if *v == 5 {
+                    Some(
+                        TickStyle::new()
+                            .line_style(&RED)
+                            .label_style(("sans-serif", 10).into_font().color(&RED)),
+                    )
+                } else {
+                    None
+                }
+            };
+            chart
+                .configure_mesh()
+                .x_tick_style(&highlight)
+                .draw()
+                .expect("Draw mesh");
+        }
+
+        let texts = texts.borrow();
+        for (text, c) in texts.iter() {
+            let expected = if text == "5" { RED } else { BLACK };
+            assert_eq!(*c, expected.to_rgba());
+        }
+        let red_lines: Vec<_> = lines
+            .borrow()
+            .iter()
+            .filter(|(c, ..)| *c == RED.to_rgba())
+            .cloned()
+            .collect();
+        assert_eq!(red_lines.len(), 1);
+        assert_eq!((red_lines[0].1).0, (red_lines[0].2).0);
+    }
+
+    #[test]
+    fn test_target_tick_spacing() {
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        let count_labels = |size: u32| {
+            let texts = Rc::new(Cell::new(0));
+            {
+                let texts = texts.clone();
+                let drawing_area = create_mocked_drawing_area(size, size, move |m| {
+                    m.check_draw_text(move |_, _, _, _, _| texts.set(texts.get() + 1));
+                });
+                let mut chart = ChartBuilder::on(&drawing_area)
+                    .x_label_area_size(20)
+                    .y_label_area_size(20)
+                    .build_cartesian_2d(0.0..100.0, 0.0..100.0)
+                    .expect("Create chart");
+                chart
+                    .configure_mesh()
+                    .target_tick_spacing(50)
+                    .draw()
+                    .expect("Draw mesh");
+            }
+            texts.get()
+        };
+
+        // At most 3 labels on each axis of 180 pixels, and at most 15 labels of 780 pixels
+        let (small, large) = (count_labels(200), count_labels(800));
+        assert!(small <= 6);
+        assert!(large > small * 2);
+        assert!(large <= 30);
+    }
+
     #[test]
     fn test_chart_context_3d() {
         let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
@@ -176,4 +848,105 @@ mod test {
             .draw_series(std::iter::once(Circle::new((5, 5, 5), 5, &RED)))
             .expect("Drawing error");
     }
+
+    #[cfg(feature = "surface_series")]
+    #[test]
+    fn test_draw_series_depth_sorted() {
+        let colors = std::rc::Rc::new(std::cell::RefCell::new(vec![]));
+        let recorded = colors.clone();
+        let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+            m.check_fill_polygon(move |c, _| recorded.borrow_mut().push(c));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_3d(0.0..2.0, 0.0..1.0, 0.0..2.0)
+            .expect("Create chart");
+
+        for &yaw in [0.5, 0.5 + std::f64::consts::PI].iter() {
+            chart.with_projection(|mut pb| {
+                pb.yaw = yaw;
+                pb.into_matrix()
+            });
+            colors.borrow_mut().clear();
+            chart
+                .draw_series_depth_sorted(
+                    SurfaceSeries::xoz(
+                        [0.0, 1.0, 2.0].iter().copied(),
+                        [0.0, 2.0].iter().copied(),
+                        |x, _| x,
+                    )
+                    .color_map(|&y| if y < 1.0 { RED } else { BLUE }),
+                )
+                .expect("Drawing error");
+
+            let coord = chart.as_coord_spec();
+            let near = if coord.depth(&(0.5, 0.0, 1.0)) < coord.depth(&(1.5, 1.0, 1.0)) {
+                RED
+            } else {
+                BLUE
+            };
+            assert_eq!(colors.borrow().len(), 2);
+            assert_eq!(colors.borrow()[1], near.to_rgba());
+        }
+    }
+
+    #[test]
+    fn test_3d_view_angle() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_3d(0..10, 0..10, 0..10)
+            .expect("Create chart");
+
+        assert_eq!(chart.view_angle(), (0.5, 0.15));
+        assert_eq!(chart.scale(), 1.0);
+        let default = chart.as_coord_spec().translate(&(10, 10, 10));
+
+        chart.set_view_angle(1.0, 0.3).set_scale(0.5);
+        assert_eq!(chart.view_angle(), (1.0, 0.3));
+        assert_ne!(chart.as_coord_spec().translate(&(10, 10, 10)), default);
+
+        chart.rotate_view(-0.5, -0.15).set_scale(1.0);
+        assert_eq!(chart.view_angle(), (0.5, 0.15));
+        assert_eq!(chart.as_coord_spec().translate(&(10, 10, 10)), default);
+        assert_eq!(
+            chart.projection_matrix().as_ref(),
+            chart.as_coord_spec().projection().as_ref()
+        );
+    }
+
+    #[test]
+    fn test_category_legend() {
+        let drawn = std::rc::Rc::new(std::cell::RefCell::new((vec![], vec![])));
+        let (texts_ref, rects_ref) = (drawn.clone(), drawn.clone());
+        let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+            m.check_draw_text(move |_, _, _, _, text| {
+                texts_ref.borrow_mut().0.push(text.to_string());
+            });
+            m.check_draw_rect(move |c, _, fill, _, _| {
+                if fill {
+                    rects_ref.borrow_mut().1.push(c);
+                }
+            });
+        });
+
+        let colors = CategoryColors::from_palette::<Palette99, _>(vec!["north", "south", "east"]);
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+        chart.add_category_legend(&colors, LegendMarker::Rectangle);
+        assert_eq!(chart.series_labels().len(), 3);
+        chart
+            .configure_series_labels()
+            .draw()
+            .expect("Drawing error");
+        drop(chart);
+        drop(drawing_area);
+
+        let (texts, rects) = &*drawn.borrow();
+        assert_eq!(texts, &vec!["north", "south", "east"]);
+        // The markers are drawn after the background of the legend
+        let expected: Vec<_> = colors.iter().map(|(_, c)| c).collect();
+        assert_eq!(rects[rects.len() - 3..], expected[..]);
+    }
 }
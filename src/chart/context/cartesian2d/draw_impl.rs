@@ -2,7 +2,7 @@ use std::ops::Range;
 
 use plotters_backend::DrawingBackend;
 
-use crate::chart::ChartContext;
+use crate::chart::{ChartContext, TickStyle};
 use crate::coord::{
     cartesian::{Cartesian2d, MeshLine},
     ranged1d::{KeyPointHint, Ranged},
@@ -15,43 +15,62 @@ use crate::style::{
     FontTransform, ShapeStyle, TextStyle,
 };
 
+/// The label of a tick, which is the position, the text and the style override of the label
+type TickLabel<'s> = (i32, String, Option<&'s TextStyle<'s>>);
+
 impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesian2d<X, Y>> {
     /// The actual function that draws the mesh lines.
     /// It also returns the label that suppose to be there.
     #[allow(clippy::type_complexity)]
-    fn draw_mesh_lines<FmtLabel, YH: KeyPointHint, XH: KeyPointHint>(
+    fn draw_mesh_lines<'s, FmtLabel, YH: KeyPointHint, XH: KeyPointHint>(
         &mut self,
         (r, c): (YH, XH),
         (x_mesh, y_mesh): (bool, bool),
         mesh_line_style: &ShapeStyle,
+        (x_ticks, y_ticks): (&'s [Option<TickStyle<'s>>], &'s [Option<TickStyle<'s>>]),
         mut fmt_label: FmtLabel,
-    ) -> Result<(Vec<(i32, String)>, Vec<(i32, String)>), DrawingAreaErrorKind<DB::ErrorType>>
+    ) -> Result<(Vec<TickLabel<'s>>, Vec<TickLabel<'s>>), DrawingAreaErrorKind<DB::ErrorType>>
     where
         FmtLabel: FnMut(&X, &Y, &MeshLine<X, Y>) -> Option<String>,
     {
         let mut x_labels = vec![];
         let mut y_labels = vec![];
+        let (mut x_idx, mut y_idx) = (0, 0);
         let xr = self.drawing_area.as_coord_spec().x_spec();
         let yr = self.drawing_area.as_coord_spec().y_spec();
         self.drawing_area.draw_mesh(
             |b, l| {
                 let draw;
+                let tick;
                 match l {
                     MeshLine::XMesh((x, _), _, _) => {
+                        tick = x_ticks.get(x_idx).and_then(Option::as_ref);
+                        x_idx += 1;
                         if let Some(label_text) = fmt_label(xr, yr, &l) {
-                            x_labels.push((x, label_text));
+                            x_labels.push((
+                                x,
+                                label_text,
+                                tick.and_then(|t| t.label_style.as_ref()),
+                            ));
                         }
                         draw = x_mesh;
                     }
                     MeshLine::YMesh((_, y), _, _) => {
+                        tick = y_ticks.get(y_idx).and_then(Option::as_ref);
+                        y_idx += 1;
                         if let Some(label_text) = fmt_label(xr, yr, &l) {
-                            y_labels.push((y, label_text));
+                            y_labels.push((
+                                y,
+                                label_text,
+                                tick.and_then(|t| t.label_style.as_ref()),
+                            ));
                         }
                         draw = y_mesh;
                     }
                 };
                 if draw {
-                    l.draw(b, mesh_line_style)
+                    let style = tick.and_then(|t| t.line_style.as_ref());
+                    l.draw(b, style.unwrap_or(mesh_line_style))
                 } else {
                     Ok(())
                 }
@@ -137,11 +156,11 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
         &self,
         area: Option<&DrawingArea<DB, Shift>>,
         axis_style: Option<&ShapeStyle>,
-        labels: &[(i32, String)],
+        labels: &[TickLabel],
         label_style: &TextStyle,
         label_offset: i32,
         orientation: (i16, i16),
-        axis_desc: Option<(&str, &TextStyle)>,
+        axis_desc: Option<(&str, &TextStyle, (i32, i32))>,
         tick_size: i32,
     ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
         let area = if let Some(target) = area {
@@ -166,10 +185,10 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
          * So that we are able decide if we should apply right alignment for the text. */
         let label_width: Vec<_> = labels
             .iter()
-            .map(|(_, text)| {
+            .map(|(_, text, style)| {
                 if orientation.0 > 0 && orientation.1 == 0 && tick_size >= 0 {
                     self.drawing_area
-                        .estimate_text_size(text, &label_style)
+                        .estimate_text_size(text, style.unwrap_or(label_style))
                         .map(|(w, _)| w)
                         .unwrap_or(0) as i32
                 } else {
@@ -189,7 +208,7 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
         let right_align_width = (min_width * 2).min(max_width);
 
         /* Then we need to draw the tick mark and the label */
-        for ((p, t), w) in labels.iter().zip(label_width.into_iter()) {
+        for ((p, t, style), w) in labels.iter().zip(label_width.into_iter()) {
             /* Make sure we are actually in the visible range */
             let rp = if orientation.0 == 0 { *p - x0 } else { *p - y0 };
 
@@ -252,7 +271,7 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
                 (cx, cy + label_offset)
             };
 
-            let label_style = &label_style.pos(Pos::new(h_pos, v_pos));
+            let label_style = &style.unwrap_or(label_style).pos(Pos::new(h_pos, v_pos));
             area.draw_text(&t, label_style, (text_x, text_y))?;
 
             if tick_size != 0 {
@@ -290,7 +309,7 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
             }
         }
 
-        if let Some((text, style)) = axis_desc {
+        if let Some((text, style, (dx, dy))) = axis_desc {
             let actual_style = if orientation.0 == 0 {
                 style.clone()
             } else if orientation.0 == -1 {
@@ -311,8 +330,34 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
                 _ => panic!("Bug: Invalid orientation specification"),
             };
 
+            // The direction from the top to the bottom of the text, in which the lines of a
+            // multi-line description are stacked
+            let down = match orientation {
+                (dx, dy) if dx > 0 && dy == 0 => (-1, 0),
+                (dx, dy) if dx < 0 && dy == 0 => (1, 0),
+                _ => (0, 1),
+            };
+            let line_height = style.font.get_size().round() as i32;
+            let lines: Vec<_> = text.lines().collect();
+            // The bottom description is aligned to the bottom, so the lines grow upwards
+            let first_line = if orientation.0 == 0 && orientation.1 > 0 {
+                1 - lines.len() as i32
+            } else {
+                0
+            };
+
             let actual_style = &actual_style.pos(Pos::new(h_pos, v_pos));
-            area.draw_text(&text, &actual_style, (x0 as i32, y0 as i32))?;
+            for (idx, line) in lines.into_iter().enumerate() {
+                let shift = (first_line + idx as i32) * line_height;
+                area.draw_text(
+                    line,
+                    &actual_style,
+                    (
+                        x0 as i32 + dx + down.0 * shift,
+                        y0 as i32 + dy + down.1 * shift,
+                    ),
+                )?;
+            }
         }
 
         Ok(())
@@ -325,6 +370,7 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
         mesh_line_style: &ShapeStyle,
         x_label_style: &TextStyle,
         y_label_style: &TextStyle,
+        ticks: (&[Option<TickStyle>], &[Option<TickStyle>]),
         fmt_label: FmtLabel,
         x_mesh: bool,
         y_mesh: bool,
@@ -333,9 +379,8 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
         x_axis: bool,
         y_axis: bool,
         axis_style: &ShapeStyle,
-        axis_desc_style: &TextStyle,
-        x_desc: Option<String>,
-        y_desc: Option<String>,
+        x_desc: Option<(&str, &TextStyle, (i32, i32))>,
+        y_desc: Option<(&str, &TextStyle, (i32, i32))>,
         x_tick_size: [i32; 2],
         y_tick_size: [i32; 2],
     ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
@@ -343,7 +388,7 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
         FmtLabel: FnMut(&X, &Y, &MeshLine<X, Y>) -> Option<String>,
     {
         let (x_labels, y_labels) =
-            self.draw_mesh_lines((r, c), (x_mesh, y_mesh), mesh_line_style, fmt_label)?;
+            self.draw_mesh_lines((r, c), (x_mesh, y_mesh), mesh_line_style, ticks, fmt_label)?;
 
         for idx in 0..2 {
             self.draw_axis_and_labels(
@@ -353,7 +398,7 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
                 x_label_style,
                 x_label_offset,
                 (0, -1 + idx as i16 * 2),
-                x_desc.as_ref().map(|desc| (&desc[..], axis_desc_style)),
+                x_desc,
                 x_tick_size[idx],
             )?;
 
@@ -364,7 +409,7 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
                 y_label_style,
                 y_label_offset,
                 (-1 + idx as i16 * 2, 0),
-                y_desc.as_ref().map(|desc| (&desc[..], axis_desc_style)),
+                y_desc,
                 y_tick_size[idx],
             )?;
         }
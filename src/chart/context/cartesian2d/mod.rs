@@ -1,14 +1,19 @@
+use std::borrow::Borrow;
 use std::ops::Range;
 
 use plotters_backend::{BackendCoord, DrawingBackend};
 
-use crate::chart::{ChartContext, DualCoordChartContext, MeshStyle};
+use crate::chart::{ChartContext, DualCoordChartContext, LabelAreaPosition, MeshStyle, SeriesAnno};
 use crate::coord::{
     cartesian::Cartesian2d,
     ranged1d::{AsRangedCoord, Ranged, ValueFormatter},
-    Shift,
+    CoordTranslate, Shift,
 };
-use crate::drawing::DrawingArea;
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
+use crate::element::{CoordMapper, Drawable, PointCollection};
+use crate::style::DataStrokeWidth;
+
+use num_traits::ToPrimitive;
 
 mod draw_impl;
 
@@ -47,6 +52,37 @@ where
 }
 
 impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesian2d<X, Y>> {
+    /// Lay out the plotting area and the label areas again with the sizes of the automatically
+    /// sized label areas, it does nothing if none of the label areas is sized automatically
+    /// - `sizes`: The sizes of the label areas in the order of top, bottom, left and right
+    pub(crate) fn fit_label_areas(&mut self, sizes: [u32; 4]) {
+        let layout = match self.label_area_layout.as_mut() {
+            Some(layout) => layout,
+            None => return,
+        };
+        let positions = [
+            LabelAreaPosition::Top,
+            LabelAreaPosition::Bottom,
+            LabelAreaPosition::Left,
+            LabelAreaPosition::Right,
+        ];
+        for (pos, size) in positions.iter().zip(sizes.iter()) {
+            layout.set_auto_size(*pos, *size);
+        }
+
+        let (plotting_area, mut label_areas, pos) = layout.split();
+        let mut pixel_range = plotting_area.get_pixel_range();
+        pixel_range.1 = (pixel_range.1.end - 1)..(pixel_range.1.start - 1);
+
+        self.drawing_area.move_to(&plotting_area);
+        self.drawing_area
+            .as_coord_spec_mut()
+            .set_pixel_range(pixel_range);
+        self.x_label_area = [label_areas[0].take(), label_areas[1].take()];
+        self.y_label_area = [label_areas[2].take(), label_areas[3].take()];
+        self.drawing_area_pos = pos;
+    }
+
     /// Get the range of X axis
     pub fn x_range(&self) -> Range<X::ValueType> {
         self.drawing_area.get_x_range()
@@ -62,6 +98,142 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
     pub fn backend_coord(&self, coord: &(X::ValueType, Y::ValueType)) -> BackendCoord {
         self.drawing_area.map_coordinate(coord)
     }
+
+    /// Draw a data series like [draw_series](struct.ChartContext.html#method.draw_series), and
+    /// count the points of the elements which fall outside the axis ranges. Those points are
+    /// clipped when drawn, so a nonzero count usually means the ranges of the chart don't match
+    /// the data. This is meant for debugging the chart setup, e.g. with `debug_assert_eq!`.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut buffer = vec![0; 640 * 480 * 3];
+    /// let root = BitMapBackend::with_buffer(&mut buffer, (640, 480)).into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&root)
+    ///     .build_cartesian_2d(0..10, 0..10)
+    ///     .unwrap();
+    ///
+    /// let (out_of_range, _) = chart
+    ///     .draw_series_checked(LineSeries::new((0..10).map(|x| (x, x * 2)), &RED))
+    ///     .unwrap();
+    /// assert_eq!(out_of_range, 4);
+    /// ```
+    /// - `series`: The series to draw
+    /// - **returns**: The number of points out of the axis ranges and the series annotation
+    pub fn draw_series_checked<B, E, R, S>(
+        &mut self,
+        series: S,
+    ) -> Result<(usize, &mut SeriesAnno<'a, DB>), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        B: CoordMapper,
+        for<'b> &'b E: PointCollection<'b, (X::ValueType, Y::ValueType), B>,
+        E: Drawable<DB, B>,
+        R: Borrow<E>,
+        S: IntoIterator<Item = R>,
+    {
+        let mut out_of_range = 0;
+        for element in series {
+            let coord = self.drawing_area.as_coord_spec();
+            out_of_range += element
+                .borrow()
+                .point_iter()
+                .into_iter()
+                .filter(|p| !coord.is_in_pixel_range(coord.translate(p.borrow())))
+                .count();
+            self.drawing_area.draw(element.borrow())?;
+        }
+        Ok((out_of_range, self.alloc_series_anno()))
+    }
+
+    /// Create a drawing area for a horizontal band of the plotting area, which shares the X
+    /// coordinate with the chart but maps its own Y range into the band. The elements drawn on
+    /// the band are clipped to it, so it's useful for stacking multiple series in one chart,
+    /// for example the leads of an ECG.
+    /// - `band`: The vertical range of the band relative to the height of the plotting area,
+    ///   `0.0` is the top and `1.0` is the bottom
+    /// - `y_spec`: The Y range mapped to the band
+    /// - **returns**: The drawing area of the band
+    pub fn y_band<YS: AsRangedCoord>(
+        &self,
+        band: Range<f64>,
+        y_spec: YS,
+    ) -> DrawingArea<DB, Cartesian2d<X, YS::CoordDescType>>
+    where
+        X: Clone,
+    {
+        let (w, h) = self.drawing_area.dim_in_pixel();
+        let top = (f64::from(h) * band.start.max(0.0).min(1.0)).round() as i32;
+        let bottom = (f64::from(h) * band.end.max(0.0).min(1.0)).round() as i32;
+        let area = self
+            .drawing_area
+            .strip_coord_spec()
+            .shrink((0, top), (w, (bottom - top).max(0)));
+
+        let mut pixel_range = area.get_pixel_range();
+        pixel_range.1 = (pixel_range.1.end - 1)..(pixel_range.1.start - 1);
+        area.apply_coord_spec(Cartesian2d::new(
+            self.as_coord_spec().x_spec().clone(),
+            y_spec,
+            pixel_range,
+        ))
+    }
+
+    /// Split the plotting area into `count` horizontal lanes of the same height and create the
+    /// drawing area for one of them, see [y_band](#method.y_band) for details.
+    /// - `index`: The index of the lane, the lane 0 is the topmost one
+    /// - `count`: The number of the lanes
+    /// - `y_spec`: The Y range mapped to the lane
+    /// - **returns**: The drawing area of the lane
+    pub fn y_lane<YS: AsRangedCoord>(
+        &self,
+        index: usize,
+        count: usize,
+        y_spec: YS,
+    ) -> DrawingArea<DB, Cartesian2d<X, YS::CoordDescType>>
+    where
+        X: Clone,
+    {
+        let count = count.max(1) as f64;
+        self.y_band(index as f64 / count..(index + 1) as f64 / count, y_spec)
+    }
+
+    /// Update the range of X axis without rebuilding the chart, this is typically used
+    /// to zoom or pan an interactive chart.
+    /// The already drawn content isn't changed, so the plotting area should be cleared and
+    /// the mesh and series should be drawn again after the range is updated.
+    ///
+    /// - `range`: The new range of X axis
+    pub fn set_x_range<R: Into<X>>(&mut self, range: R) -> &mut Self {
+        self.drawing_area.as_coord_spec_mut().set_x_spec(range);
+        self
+    }
+
+    /// Update the range of Y axis without rebuilding the chart, see `set_x_range` for details.
+    ///
+    /// - `range`: The new range of Y axis
+    pub fn set_y_range<R: Into<Y>>(&mut self, range: R) -> &mut Self {
+        self.drawing_area.as_coord_spec_mut().set_y_spec(range);
+        self
+    }
+}
+
+impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesian2d<X, Y>>
+where
+    X::ValueType: ToPrimitive,
+    Y::ValueType: ToPrimitive,
+{
+    /// Convert a stroke width given in data units into pixels with the current axis ranges, e.g.
+    /// `style.stroke_width(chart.pixel_stroke_width(DataStrokeWidth::Y(0.5)))` draws the stroke
+    /// half a unit of Y wide.
+    ///
+    /// Since the conversion depends on the axis ranges, the width should be converted again
+    /// after the ranges are updated.
+    ///
+    /// - `width`: The stroke width in data units
+    /// - **returns**: The stroke width in pixels
+    pub fn pixel_stroke_width(&self, width: DataStrokeWidth) -> u32 {
+        width.to_pixels(self.drawing_area.as_coord_spec().pixels_per_unit())
+    }
 }
 
 impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesian2d<X, Y>> {
@@ -88,3 +260,46 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
         DualCoordChartContext::new(self, Cartesian2d::new(x_coord, y_coord, pixel_range))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_y_lane() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_path(|_, _, path| {
+                assert_eq!(path, vec![(0, 99), (100, 50), (200, 50)]);
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_path_call, 1));
+        });
+
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..100, 0..100)
+            .expect("Create chart");
+
+        let lane = chart.y_lane(1, 4, 0.0..1.0);
+        assert_eq!(lane.get_pixel_range(), (0..200, 50..100));
+        assert_eq!(lane.map_coordinate(&(50, 0.0)), (100, 99));
+
+        // The point out of the lane is clipped to the lane
+        lane.draw(&PathElement::new(
+            vec![(0, 0.0), (50, 1.0), (100, 2.0)],
+            &RED,
+        ))
+        .expect("Drawing error");
+    }
+    #[test]
+    fn test_pixel_stroke_width() {
+        let drawing_area = create_mocked_drawing_area(200, 100, |_| {});
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..100.0)
+            .expect("Create chart");
+
+        assert_eq!(chart.pixel_stroke_width(DataStrokeWidth::X(0.5)), 10);
+        assert_eq!(chart.pixel_stroke_width(DataStrokeWidth::Y(10.0)), 10);
+
+        chart.set_x_range(0.0..20.0);
+        assert_eq!(chart.pixel_stroke_width(DataStrokeWidth::X(0.5)), 5);
+    }
+}
@@ -1,5 +1,3 @@
-use std::cmp::Ordering;
-
 use plotters_backend::DrawingBackend;
 
 use crate::chart::ChartContext;
@@ -17,6 +15,77 @@ use crate::style::{
 
 use super::Coord3D;
 
+const CUBE_CORNERS: [(bool, bool, bool); 8] = [
+    (false, false, false),
+    (false, false, true),
+    (false, true, false),
+    (false, true, true),
+    (true, false, false),
+    (true, false, true),
+    (true, true, false),
+    (true, true, true),
+];
+
+/// Compute the direction of the ticks of an axis, which is perpendicular to the axis on the
+/// screen and points away from the center of the projected cube, so that the labels are never
+/// drawn over the plot
+fn tick_direction(
+    begin: (i32, i32),
+    end: (i32, i32),
+    center: (i32, i32),
+    tick_size: i32,
+) -> (i32, i32) {
+    let axis = (f64::from(end.0 - begin.0), f64::from(end.1 - begin.1));
+    let mid = (
+        f64::from(begin.0 + end.0) / 2.0,
+        f64::from(begin.1 + end.1) / 2.0,
+    );
+    let out = (mid.0 - f64::from(center.0), mid.1 - f64::from(center.1));
+    let axis_len2 = axis.0 * axis.0 + axis.1 * axis.1;
+    let mut normal = if axis_len2 > 0.0 {
+        let t = (out.0 * axis.0 + out.1 * axis.1) / axis_len2;
+        (out.0 - t * axis.0, out.1 - t * axis.1)
+    } else {
+        out
+    };
+    let len = normal.0.hypot(normal.1);
+    if len < 1e-6 {
+        // The axis passes through the center, any side of the axis is fine
+        normal = if axis_len2 > 0.0 {
+            (axis.1, -axis.0)
+        } else {
+            (0.0, 1.0)
+        };
+    }
+    let len = normal.0.hypot(normal.1);
+    let size = f64::from(tick_size);
+    (
+        (normal.0 / len * size).round() as i32,
+        (normal.1 / len * size).round() as i32,
+    )
+}
+
+/// Choose the anchor of the tick labels, so the text extends along the tick direction
+fn tick_label_anchor((dx, dy): (i32, i32)) -> Pos {
+    let (dx, dy) = (f64::from(dx), f64::from(dy));
+    let len = dx.hypot(dy).max(1e-6);
+    let h_pos = if dx > len * 0.38 {
+        HPos::Left
+    } else if dx < -len * 0.38 {
+        HPos::Right
+    } else {
+        HPos::Center
+    };
+    let v_pos = if dy > len * 0.38 {
+        VPos::Top
+    } else if dy < -len * 0.38 {
+        VPos::Bottom
+    } else {
+        VPos::Center
+    };
+    Pos::new(h_pos, v_pos)
+}
+
 pub(crate) struct KeyPoints3d<X: Ranged, Y: Ranged, Z: Ranged> {
     pub(crate) x_points: Vec<X::ValueType>,
     pub(crate) y_points: Vec<Y::ValueType>,
@@ -69,48 +138,57 @@ where
             &axis[1][1],
             &axis[1][2],
         ]));
-        let axis_dir = (end.0 - begin.0, end.1 - begin.1);
-        let (x_range, y_range) = self.plotting_area().get_pixel_range();
-        let x_mid = (x_range.start + x_range.end) / 2;
-        let y_mid = (y_range.start + y_range.end) / 2;
-
-        let x_dir = if begin.0 < x_mid {
-            (-tick_size, 0)
-        } else {
-            (tick_size, 0)
-        };
-
-        let y_dir = if begin.1 < y_mid {
-            (0, -tick_size)
-        } else {
-            (0, tick_size)
+        let center = {
+            let (x, y, z) = (
+                coord.logic_x.range(),
+                coord.logic_y.range(),
+                coord.logic_z.range(),
+            );
+            let mut sum = (0, 0);
+            for &(a, b, c) in CUBE_CORNERS.iter() {
+                let p = coord.translate(&(
+                    if a { x.end.clone() } else { x.start.clone() },
+                    if b { y.end.clone() } else { y.start.clone() },
+                    if c { z.end.clone() } else { z.start.clone() },
+                ));
+                sum = (sum.0 + p.0, sum.1 + p.1);
+            }
+            (sum.0 / 8, sum.1 / 8)
         };
 
-        let x_score = (x_dir.0 * axis_dir.0 + x_dir.1 * axis_dir.1).abs();
-        let y_score = (y_dir.0 * axis_dir.0 + y_dir.1 * axis_dir.1).abs();
-
-        let dir = if x_score < y_score { x_dir } else { y_dir };
+        let dir = tick_direction(begin, end, center, tick_size);
+        let mut font = font.clone();
+        font.pos = tick_label_anchor(dir);
+        let min_distance = font.font.get_size();
 
+        let mut last_label: Option<(i32, i32)> = None;
         for (pos, text) in labels {
             let logic_pos = Coord3D::build_coord([&pos[0], &pos[1], &pos[2]]);
-            let mut font = font.clone();
-
-            match dir.0.cmp(&0) {
-                Ordering::Less => font.pos = Pos::new(HPos::Right, VPos::Center),
-                Ordering::Greater => font.pos = Pos::new(HPos::Left, VPos::Center),
-                _ => (),
-            }
-
-            match dir.1.cmp(&0) {
-                Ordering::Less => font.pos = Pos::new(HPos::Center, VPos::Bottom),
-                Ordering::Greater => font.pos = Pos::new(HPos::Center, VPos::Top),
-                _ => (),
-            }
+            let anchor = coord.translate(&logic_pos);
+            // Skip the labels that would overlap with the previous one, this happens when the
+            // axis is foreshortened by the view angle
+            let crowded = match last_label {
+                Some(last) => {
+                    f64::from(anchor.0 - last.0).hypot(f64::from(anchor.1 - last.1)) < min_distance
+                }
+                None => false,
+            };
 
             let element = EmptyElement::at(logic_pos)
                 + PathElement::new(vec![(0, 0), dir], style.clone())
-                + Text::new(text.to_string(), (dir.0 * 2, dir.1 * 2), font.clone());
+                + Text::new(
+                    if crowded {
+                        String::new()
+                    } else {
+                        text.to_string()
+                    },
+                    (dir.0 * 2, dir.1 * 2),
+                    font.clone(),
+                );
             self.plotting_area().draw(&element)?;
+            if !crowded {
+                last_label = Some(anchor);
+            }
         }
         Ok(())
     }
@@ -307,3 +385,34 @@ where
         ])
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_tick_direction() {
+        assert_eq!(tick_direction((0, 100), (100, 100), (50, 50), 5), (0, 5));
+        assert_eq!(tick_direction((0, 0), (0, 100), (50, 50), 5), (-5, 0));
+        let (dx, dy) = tick_direction((0, 100), (100, 0), (100, 100), 10);
+        assert!(dx < 0 && dy < 0);
+        assert_eq!(tick_direction((0, 0), (100, 100), (50, 50), 5).0.abs(), 4);
+
+        let anchor = |dir| {
+            let pos = tick_label_anchor(dir);
+            (pos.h_pos, pos.v_pos)
+        };
+        match anchor((0, 5)) {
+            (HPos::Center, VPos::Top) => {}
+            _ => panic!("Expect the label below the tick"),
+        }
+        match anchor((-5, 0)) {
+            (HPos::Right, VPos::Center) => {}
+            _ => panic!("Expect the label on the left of the tick"),
+        }
+        match anchor((-4, -4)) {
+            (HPos::Right, VPos::Bottom) => {}
+            _ => panic!("Expect the label on the upper-left of the tick"),
+        }
+    }
+}
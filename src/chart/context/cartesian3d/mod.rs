@@ -78,6 +78,55 @@ where
         self
     }
 
+    /// Set the view angle of the 3D chart
+    /// - `yaw`: The rotation around the vertical axis, in radians. The default is 0.5
+    /// - `pitch`: The rotation around the horizontal axis, in radians. The default is 0.15
+    pub fn set_view_angle(&mut self, yaw: f64, pitch: f64) -> &mut Self {
+        let (_, _, scale) = self.drawing_area.as_coord_spec().view();
+        self.update_view((yaw, pitch, scale))
+    }
+
+    /// Rotate the view by the given angles, for example, when the chart is dragged
+    /// - `yaw`: The change of the yaw angle, in radians
+    /// - `pitch`: The change of the pitch angle, in radians
+    pub fn rotate_view(&mut self, yaw: f64, pitch: f64) -> &mut Self {
+        let (old_yaw, old_pitch, scale) = self.drawing_area.as_coord_spec().view();
+        self.update_view((old_yaw + yaw, old_pitch + pitch, scale))
+    }
+
+    /// Set the scale factor of the 3D chart, the default is 1.0
+    pub fn set_scale(&mut self, scale: f64) -> &mut Self {
+        let (yaw, pitch, _) = self.drawing_area.as_coord_spec().view();
+        self.update_view((yaw, pitch, scale))
+    }
+
+    /// Get the current view angle in `(yaw, pitch)`
+    ///
+    /// Note: The angle set by a customized projection through
+    /// [with_projection](#method.with_projection) isn't tracked.
+    pub fn view_angle(&self) -> (f64, f64) {
+        let (yaw, pitch, _) = self.drawing_area.as_coord_spec().view();
+        (yaw, pitch)
+    }
+
+    /// Get the current scale factor of the 3D chart
+    pub fn scale(&self) -> f64 {
+        self.drawing_area.as_coord_spec().view().2
+    }
+
+    /// Get the current projection matrix
+    pub fn projection_matrix(&self) -> ProjectionMatrix {
+        *self.drawing_area.as_coord_spec().projection()
+    }
+
+    fn update_view(&mut self, view: (f64, f64, f64)) -> &mut Self {
+        let (actual_x, actual_y) = self.drawing_area.get_pixel_range();
+        self.drawing_area
+            .as_coord_spec_mut()
+            .set_view(actual_x, actual_y, view);
+        self
+    }
+
     pub fn set_3d_pixel_range(&mut self, size: (i32, i32, i32)) -> &mut Self {
         let (actual_x, actual_y) = self.drawing_area.get_pixel_range();
         self.drawing_area
@@ -3,6 +3,7 @@ use std::borrow::{Borrow, BorrowMut};
 use std::ops::{Deref, DerefMut};
 use std::sync::Arc;
 
+use super::context::ChartLayers;
 use super::mesh::SecondaryMeshStyle;
 use super::{ChartContext, ChartState, SeriesAnno};
 
@@ -107,6 +108,11 @@ impl<'a, DB: DrawingBackend, CT1: CoordTranslate, CT2: CoordTranslate>
             .drawing_area
             .strip_coord_spec()
             .apply_coord_spec(secondary_coord);
+        let secondary_layers = ChartLayers::new(&secondary_drawing_area);
+        // The label areas are shared with the secondary coordinate from now on, so the chart
+        // can't be laid out again to fit the labels
+        primary.label_area_layout = None;
+
         let mut secondary_x_label_area = [None, None];
         let mut secondary_y_label_area = [None, None];
 
@@ -120,7 +126,12 @@ impl<'a, DB: DrawingBackend, CT1: CoordTranslate, CT2: CoordTranslate>
                 y_label_area: secondary_y_label_area,
                 drawing_area: secondary_drawing_area,
                 series_anno: vec![],
+                layers: secondary_layers,
+                title_area: None,
+                footer_area: None,
+                colorbar_area: None,
                 drawing_area_pos: (0, 0),
+                label_area_layout: None,
             },
         }
     }
@@ -0,0 +1,109 @@
+use plotters_backend::{BackendCoord, DrawingBackend};
+
+use super::ChartContext;
+use crate::coord::{CoordTranslate, Shift};
+use crate::drawing::DrawingArea;
+
+/// The pixel geometry of a region of the chart, in the backend coordinate
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ChartRegion {
+    /// The upper left corner of the region
+    pub upper_left: BackendCoord,
+    /// The width and height of the region
+    pub size: (u32, u32),
+}
+
+impl ChartRegion {
+    pub(crate) fn of_area<DB: DrawingBackend, CT: CoordTranslate>(
+        area: &DrawingArea<DB, CT>,
+    ) -> Self {
+        Self {
+            upper_left: area.get_base_pixel(),
+            size: area.dim_in_pixel(),
+        }
+    }
+}
+
+/// The computed layout of a chart, which describes how much space is reserved for each part
+/// of the chart. This is useful for debugging the layout issues, such as clipped labels.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChartLayout {
+    /// The region of the chart caption and subtitle
+    pub title_area: Option<ChartRegion>,
+    /// The region of the chart footer
+    pub footer_area: Option<ChartRegion>,
+    /// The region of the label area on the top of the plotting area
+    pub top_label_area: Option<ChartRegion>,
+    /// The region of the label area under the plotting area
+    pub bottom_label_area: Option<ChartRegion>,
+    /// The region of the label area on the left of the plotting area
+    pub left_label_area: Option<ChartRegion>,
+    /// The region of the label area on the right of the plotting area
+    pub right_label_area: Option<ChartRegion>,
+    /// The region reserved for the colorbar
+    pub colorbar_area: Option<ChartRegion>,
+    /// The region where the data is plotted
+    pub plotting_area: ChartRegion,
+}
+
+impl<'a, DB: DrawingBackend, CT: CoordTranslate> ChartContext<'a, DB, CT> {
+    /// Get the computed layout of the chart, all the regions are in the pixels of the backend
+    pub fn layout(&self) -> ChartLayout {
+        let region =
+            |area: &Option<DrawingArea<DB, Shift>>| area.as_ref().map(ChartRegion::of_area);
+        ChartLayout {
+            title_area: self.title_area,
+            footer_area: self.footer_area,
+            top_label_area: region(&self.x_label_area[0]),
+            bottom_label_area: region(&self.x_label_area[1]),
+            left_label_area: region(&self.y_label_area[0]),
+            right_label_area: region(&self.y_label_area[1]),
+            colorbar_area: region(&self.colorbar_area),
+            plotting_area: ChartRegion::of_area(&self.drawing_area),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_chart_layout() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
+
+        let chart = ChartBuilder::on(&drawing_area)
+            .caption("Title", ("serif", 20))
+            .x_label_area_size(20)
+            .y_label_area_size(30)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+
+        let layout = chart.layout();
+        let title = layout.title_area.expect("No title area");
+        assert_eq!(title.upper_left, (0, 0));
+        assert_eq!(title.size.0, 200);
+
+        let plotting = layout.plotting_area;
+        assert_eq!(plotting.upper_left, (30, title.size.1 as i32));
+        assert_eq!(plotting.size, (170, 200 - title.size.1 - 20));
+
+        assert_eq!(
+            layout.left_label_area,
+            Some(ChartRegion {
+                upper_left: (0, title.size.1 as i32),
+                size: (30, plotting.size.1),
+            })
+        );
+        assert_eq!(
+            layout.bottom_label_area,
+            Some(ChartRegion {
+                upper_left: (30, 180),
+                size: (170, 20),
+            })
+        );
+        assert_eq!(layout.top_label_area, None);
+        assert_eq!(layout.right_label_area, None);
+    }
+}
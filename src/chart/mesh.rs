@@ -1,10 +1,14 @@
 use std::marker::PhantomData;
+use std::ops::Range;
 
 use super::builder::LabelAreaPosition;
 use super::context::ChartContext;
 use crate::coord::cartesian::{Cartesian2d, MeshLine};
+use crate::coord::combinators::{LogCoord, LogScalable};
 use crate::coord::ranged1d::{BoldPoints, LightPoints, Ranged, ValueFormatter};
+use crate::data::float::NumberFormat;
 use crate::drawing::DrawingAreaErrorKind;
+use crate::element::Rectangle;
 use crate::style::{
     AsRelative, Color, FontDesc, FontFamily, FontStyle, IntoTextStyle, RGBColor, ShapeStyle,
     SizeDesc, TextStyle,
@@ -12,6 +16,42 @@ use crate::style::{
 
 use plotters_backend::DrawingBackend;
 
+/// The padding beyond the labels in an automatically sized label area
+const AUTO_LABEL_AREA_PADDING: u32 = 5;
+
+/// The style override of a single tick of the mesh, which is returned by the tick style
+/// callbacks, see [MeshStyle::x_tick_style](struct.MeshStyle.html#method.x_tick_style)
+#[derive(Clone, Default)]
+pub struct TickStyle<'b> {
+    /// The style of the grid line of the tick, the bold line style is used if it's not given
+    pub line_style: Option<ShapeStyle>,
+    /// The style of the label of the tick, the label style is used if it's not given
+    pub label_style: Option<TextStyle<'b>>,
+}
+
+impl<'b> TickStyle<'b> {
+    /// Create a tick style which overrides nothing
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Override the style of the grid line of the tick
+    pub fn line_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.line_style = Some(style.into());
+        self
+    }
+
+    /// Override the style of the label of the tick
+    pub fn label_style<S: Into<TextStyle<'b>>>(mut self, style: S) -> Self {
+        self.label_style = Some(style.into());
+        self
+    }
+}
+
+/// The callback which returns the style override of a tick from the tick value and the index of
+/// the tick on the axis
+pub type TickStyleFn<'b, T> = dyn Fn(&T, usize) -> Option<TickStyle<'b>> + 'b;
+
 /// The style used to describe the mesh and axis for a secondary coordinate system.
 pub struct SecondaryMeshStyle<'a, 'b, X: Ranged, Y: Ranged, DB: DrawingBackend> {
     style: MeshStyle<'a, 'b, X, Y, DB>,
@@ -69,6 +109,14 @@ where
         self
     }
 
+    /// Set the desired distance between the labels, so the number of the labels scales with the
+    /// size of the plotting area
+    /// - `value`: The desired spacing between the adjacent labels
+    pub fn target_tick_spacing<S: SizeDesc>(&mut self, value: S) -> &mut Self {
+        self.style.target_tick_spacing(value);
+        self
+    }
+
     /// Set the formatter function for the X label text
     /// - `fmt`: The formatter function
     pub fn x_label_formatter(&mut self, fmt: &'b dyn Fn(&X::ValueType) -> String) -> &mut Self {
@@ -83,6 +131,27 @@ where
         self
     }
 
+    /// Set the separators of the numeric labels printed by the default formatters, see
+    /// [MeshStyle::number_format](struct.MeshStyle.html#method.number_format)
+    pub fn number_format(&mut self, format: NumberFormat) -> &mut Self {
+        self.style.number_format(format);
+        self
+    }
+
+    /// Set the callback which overrides the style of the labels of the X axis, see
+    /// [MeshStyle::x_tick_style](struct.MeshStyle.html#method.x_tick_style)
+    pub fn x_tick_style(&mut self, func: &'b TickStyleFn<'b, X::ValueType>) -> &mut Self {
+        self.style.x_tick_style(func);
+        self
+    }
+
+    /// Set the callback which overrides the style of the labels of the Y axis, see
+    /// [MeshStyle::y_tick_style](struct.MeshStyle.html#method.y_tick_style)
+    pub fn y_tick_style(&mut self, func: &'b TickStyleFn<'b, Y::ValueType>) -> &mut Self {
+        self.style.y_tick_style(func);
+        self
+    }
+
     /// Set the axis description's style. If not given, use label style instead.
     /// - `style`: The text style that would be applied to descriptions
     pub fn axis_desc_style<T: IntoTextStyle<'b>>(&mut self, style: T) -> &mut Self {
@@ -105,6 +174,34 @@ where
         self
     }
 
+    /// Set the style of the X axis's description, which overrides `axis_desc_style`
+    /// - `style`: The text style of the description
+    pub fn x_desc_style<T: IntoTextStyle<'b>>(&mut self, style: T) -> &mut Self {
+        self.style.x_desc_style(style);
+        self
+    }
+
+    /// Set the style of the Y axis's description, which overrides `axis_desc_style`
+    /// - `style`: The text style of the description
+    pub fn y_desc_style<T: IntoTextStyle<'b>>(&mut self, style: T) -> &mut Self {
+        self.style.y_desc_style(style);
+        self
+    }
+
+    /// Move the X axis's description from its default position
+    /// - `offset`: The offset in pixels
+    pub fn x_desc_offset(&mut self, offset: (i32, i32)) -> &mut Self {
+        self.style.x_desc_offset(offset);
+        self
+    }
+
+    /// Move the Y axis's description from its default position
+    /// - `offset`: The offset in pixels
+    pub fn y_desc_offset(&mut self, offset: (i32, i32)) -> &mut Self {
+        self.style.y_desc_offset(offset);
+        self
+    }
+
     /// Draw the axes for the secondary coordinate system
     pub fn draw(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
         self.style.draw()
@@ -149,11 +246,17 @@ pub struct MeshStyle<'a, 'b, X: Ranged, Y: Ranged, DB: DrawingBackend> {
     pub(super) draw_y_axis: bool,
     pub(super) x_label_offset: i32,
     pub(super) y_label_offset: i32,
-    pub(super) n_x_labels: usize,
-    pub(super) n_y_labels: usize,
+    pub(super) n_x_labels: Option<usize>,
+    pub(super) n_y_labels: Option<usize>,
+    pub(super) plotting_size: (u32, u32),
+    pub(super) tick_spacing: Option<i32>,
     pub(super) axis_desc_style: Option<TextStyle<'b>>,
     pub(super) x_desc: Option<String>,
     pub(super) y_desc: Option<String>,
+    pub(super) x_desc_style: Option<TextStyle<'b>>,
+    pub(super) y_desc_style: Option<TextStyle<'b>>,
+    pub(super) x_desc_offset: (i32, i32),
+    pub(super) y_desc_offset: (i32, i32),
     pub(super) bold_line_style: Option<ShapeStyle>,
     pub(super) light_line_style: Option<ShapeStyle>,
     pub(super) axis_style: Option<ShapeStyle>,
@@ -161,10 +264,16 @@ pub struct MeshStyle<'a, 'b, X: Ranged, Y: Ranged, DB: DrawingBackend> {
     pub(super) y_label_style: Option<TextStyle<'b>>,
     pub(super) format_x: Option<&'b dyn Fn(&X::ValueType) -> String>,
     pub(super) format_y: Option<&'b dyn Fn(&Y::ValueType) -> String>,
+    pub(super) x_tick_style: Option<&'b TickStyleFn<'b, X::ValueType>>,
+    pub(super) y_tick_style: Option<&'b TickStyleFn<'b, Y::ValueType>>,
     pub(super) target: Option<&'b mut ChartContext<'a, DB, Cartesian2d<X, Y>>>,
     pub(super) _phantom_data: PhantomData<(X, Y)>,
     pub(super) x_tick_size: [i32; 2],
     pub(super) y_tick_size: [i32; 2],
+    pub(super) grid_on_top: bool,
+    pub(super) number_format: Option<NumberFormat>,
+    pub(super) spans: Vec<([(i32, i32); 2], ShapeStyle)>,
+    pub(super) spans_above_grid: bool,
 }
 
 impl<'a, 'b, X, Y, XT, YT, DB> MeshStyle<'a, 'b, X, Y, DB>
@@ -197,21 +306,33 @@ where
             draw_y_mesh: true,
             draw_x_axis: true,
             draw_y_axis: true,
-            n_x_labels: 10,
-            n_y_labels: 10,
+            n_x_labels: None,
+            n_y_labels: None,
+            plotting_size: chart.plotting_area().dim_in_pixel(),
+            tick_spacing: None,
             bold_line_style: None,
             light_line_style: None,
             x_label_style: None,
             y_label_style: None,
             format_x: None,
             format_y: None,
+            x_tick_style: None,
+            y_tick_style: None,
             target: Some(chart),
             _phantom_data: PhantomData,
             x_desc: None,
             y_desc: None,
+            x_desc_style: None,
+            y_desc_style: None,
+            x_desc_offset: (0, 0),
+            y_desc_offset: (0, 0),
             axis_desc_style: None,
             x_tick_size,
             y_tick_size,
+            grid_on_top: false,
+            number_format: None,
+            spans: vec![],
+            spans_above_grid: false,
         }
     }
 }
@@ -292,6 +413,68 @@ where
         self
     }
 
+    /// Set if the mesh lines are drawn on top of the series. By default, the mesh lines are
+    /// drawn immediately, thus they are under the series drawn afterwards. If this is enabled,
+    /// the mesh lines are buffered on the topmost layer and drawn after all the series when the
+    /// chart is presented or dropped, see
+    /// [draw_series_on_layer](struct.ChartContext.html#method.draw_series_on_layer). The axes
+    /// and labels are always drawn immediately.
+    pub fn draw_grid_on_top(&mut self, value: bool) -> &mut Self {
+        self.grid_on_top = value;
+        self
+    }
+
+    /// Shade a vertical span of the plotting area behind the series, e.g. to highlight a period
+    /// of time. The span covers the whole height of the plotting area.
+    /// - `range`: The range of X covered by the span
+    /// - `style`: The style used to fill the span, usually a faint color
+    pub fn x_span<S: Into<ShapeStyle>>(
+        &mut self,
+        range: Range<X::ValueType>,
+        style: S,
+    ) -> &mut Self {
+        if let Some(target) = self.target.as_ref() {
+            let area = target.plotting_area();
+            let y = area.get_y_range().start;
+            let a = area.map_coordinate(&(range.start, y)).0;
+            let y = area.get_y_range().start;
+            let b = area.map_coordinate(&(range.end, y)).0;
+            self.spans
+                .push((span_rect(a, b, area.get_pixel_range(), false), style.into()));
+        }
+        self
+    }
+
+    /// Shade a horizontal span of the plotting area behind the series, e.g. to highlight a
+    /// target range. The span covers the whole width of the plotting area.
+    /// - `range`: The range of Y covered by the span
+    /// - `style`: The style used to fill the span, usually a faint color
+    pub fn y_span<S: Into<ShapeStyle>>(
+        &mut self,
+        range: Range<Y::ValueType>,
+        style: S,
+    ) -> &mut Self {
+        if let Some(target) = self.target.as_ref() {
+            let area = target.plotting_area();
+            let x = area.get_x_range().start;
+            let a = area.map_coordinate(&(x, range.start)).1;
+            let x = area.get_x_range().start;
+            let b = area.map_coordinate(&(x, range.end)).1;
+            self.spans
+                .push((span_rect(a, b, area.get_pixel_range(), true), style.into()));
+        }
+        self
+    }
+
+    /// Set if the shaded spans, including the decade bands, are drawn above the mesh lines. By
+    /// default the spans are drawn first, so the mesh lines are visible on the top of them.
+    /// Either way the spans are under the series. If the grid is drawn on top with
+    /// [draw_grid_on_top](#method.draw_grid_on_top), the spans are always under the grid.
+    pub fn spans_above_grid(&mut self, value: bool) -> &mut Self {
+        self.spans_above_grid = value;
+        self
+    }
+
     /// Disable drawing all meshes
     pub fn disable_mesh(&mut self) -> &mut Self {
         self.disable_x_mesh().disable_y_mesh()
@@ -311,17 +494,41 @@ where
     /// Set how many labels for the X axis at most
     /// - `value`: The maximum desired number of labels in the X axis
     pub fn x_labels(&mut self, value: usize) -> &mut Self {
-        self.n_x_labels = value;
+        self.n_x_labels = Some(value);
         self
     }
 
     /// Set how many label for the Y axis at most
     /// - `value`: The maximum desired number of labels in the Y axis
     pub fn y_labels(&mut self, value: usize) -> &mut Self {
-        self.n_y_labels = value;
+        self.n_y_labels = Some(value);
+        self
+    }
+
+    /// Set the desired distance between the labels, so the number of the labels scales with the
+    /// size of the plotting area, e.g. a chart twice as wide gets twice as many X labels. The
+    /// number of labels set by `x_labels` or `y_labels` overrides this for the axis. By default,
+    /// there are at most 10 labels on each axis regardless of the size.
+    /// - `value`: The desired spacing between the adjacent labels
+    pub fn target_tick_spacing<S: SizeDesc>(&mut self, value: S) -> &mut Self {
+        self.tick_spacing = Some(value.in_pixels(&self.parent_size));
         self
     }
 
+    /// Get the max number of labels on the X and Y axes
+    fn label_counts(&self) -> (usize, usize) {
+        let count = |n: Option<usize>, len: u32| {
+            n.unwrap_or_else(|| match self.tick_spacing {
+                Some(spacing) if spacing > 0 => (len as usize / spacing as usize).max(2),
+                _ => 10,
+            })
+        };
+        (
+            count(self.n_x_labels, self.plotting_size.0),
+            count(self.n_y_labels, self.plotting_size.1),
+        )
+    }
+
     /// Set the style for the coarse grind grid
     /// - `style`: This is the coarse grind grid style
     pub fn bold_line_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
@@ -373,6 +580,33 @@ where
         self
     }
 
+    /// Set the decimal separator and the grouping of the numeric labels printed by the default
+    /// formatters, e.g. to print `1234567.5` as `1.234.567,5`. The labels printed by the
+    /// formatter functions and the non-numeric labels, such as dates, are kept as they are.
+    /// - `format`: The number format
+    pub fn number_format(&mut self, format: NumberFormat) -> &mut Self {
+        self.number_format = Some(format);
+        self
+    }
+
+    /// Set the callback which overrides the style of the grid lines and the labels of
+    /// individual ticks on the X axis, e.g. to emphasize the weekends on a date axis. The
+    /// callback receives the value and the index of each labeled tick, and the tick is drawn
+    /// with the normal style if it returns `None`
+    /// - `func`: The tick style callback
+    pub fn x_tick_style(&mut self, func: &'b TickStyleFn<'b, X::ValueType>) -> &mut Self {
+        self.x_tick_style = Some(func);
+        self
+    }
+
+    /// Set the callback which overrides the style of the grid lines and the labels of
+    /// individual ticks on the Y axis, see [x_tick_style](#method.x_tick_style)
+    /// - `func`: The tick style callback
+    pub fn y_tick_style(&mut self, func: &'b TickStyleFn<'b, Y::ValueType>) -> &mut Self {
+        self.y_tick_style = Some(func);
+        self
+    }
+
     /// Set the axis description's style. If not given, use label style instead.
     /// - `style`: The text style that would be applied to descriptions
     pub fn axis_desc_style<T: IntoTextStyle<'b>>(&mut self, style: T) -> &mut Self {
@@ -380,27 +614,190 @@ where
         self
     }
 
-    /// Set the X axis's description
+    /// Set the X axis's description, which may contain multiple lines separated by `'\n'`
     /// - `desc`: The description of the X axis
     pub fn x_desc<T: Into<String>>(&mut self, desc: T) -> &mut Self {
         self.x_desc = Some(desc.into());
         self
     }
 
-    /// Set the Y axis's description
+    /// Set the Y axis's description, which may contain multiple lines separated by `'\n'`
     /// - `desc`: The description of the Y axis
     pub fn y_desc<T: Into<String>>(&mut self, desc: T) -> &mut Self {
         self.y_desc = Some(desc.into());
         self
     }
 
-    /// Draw the configured mesh on the target plot
+    /// Set the style of the X axis's description, which overrides `axis_desc_style`
+    /// - `style`: The text style of the description
+    pub fn x_desc_style<T: IntoTextStyle<'b>>(&mut self, style: T) -> &mut Self {
+        self.x_desc_style = Some(style.into_text_style(&self.parent_size));
+        self
+    }
+
+    /// Set the style of the Y axis's description, which overrides `axis_desc_style`
+    /// - `style`: The text style of the description
+    pub fn y_desc_style<T: IntoTextStyle<'b>>(&mut self, style: T) -> &mut Self {
+        self.y_desc_style = Some(style.into_text_style(&self.parent_size));
+        self
+    }
+
+    /// Move the X axis's description from its default position
+    /// - `offset`: The offset in pixels
+    pub fn x_desc_offset(&mut self, offset: (i32, i32)) -> &mut Self {
+        self.x_desc_offset = offset;
+        self
+    }
+
+    /// Move the Y axis's description from its default position
+    /// - `offset`: The offset in pixels
+    pub fn y_desc_offset(&mut self, offset: (i32, i32)) -> &mut Self {
+        self.y_desc_offset = offset;
+        self
+    }
+
+    /// Format the label of a value on the X axis
+    fn format_x_label(&self, xr: &X, v: &X::ValueType) -> String
+    where
+        X: ValueFormatter<<X as Ranged>::ValueType>,
+    {
+        match self.format_x {
+            Some(fmt_func) => fmt_func(v),
+            None => self.localize(xr.format_ext(v)),
+        }
+    }
+
+    /// Format the label of a value on the Y axis
+    fn format_y_label(&self, yr: &Y, v: &Y::ValueType) -> String
+    where
+        Y: ValueFormatter<<Y as Ranged>::ValueType>,
+    {
+        match self.format_y {
+            Some(fmt_func) => fmt_func(v),
+            None => self.localize(yr.format_ext(v)),
+        }
+    }
+
+    /// Apply the number format to a label printed by the default formatter
+    fn localize(&self, label: String) -> String {
+        match self.number_format.as_ref() {
+            Some(format) => format.localize(&label),
+            None => label,
+        }
+    }
+
+    /// Measure the labels and the axis descriptions, and lay out the chart again to fit them if
+    /// any of the label areas is sized automatically
+    fn fit_label_areas(
+        &self,
+        target: &mut ChartContext<'a, DB, Cartesian2d<X, Y>>,
+        (n_x_labels, n_y_labels): (usize, usize),
+        (x_label_style, y_label_style): (&TextStyle, &TextStyle),
+        (x_desc_style, y_desc_style): (&TextStyle, &TextStyle),
+        (x_ticks, y_ticks): (&[Option<TickStyle>], &[Option<TickStyle>]),
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        X: ValueFormatter<<X as Ranged>::ValueType>,
+        Y: ValueFormatter<<Y as Ranged>::ValueType>,
+    {
+        if target.label_area_layout.is_none() {
+            return Ok(());
+        }
+
+        let area = target.plotting_area();
+        let (xr, yr) = (area.as_coord_spec().x_spec(), area.as_coord_spec().y_spec());
+        let x_labels: Vec<_> = if self.draw_x_axis {
+            xr.key_points(BoldPoints(n_x_labels))
+                .iter()
+                .map(|v| self.format_x_label(xr, v))
+                .collect()
+        } else {
+            vec![]
+        };
+        let y_labels: Vec<_> = if self.draw_y_axis {
+            yr.key_points(BoldPoints(n_y_labels))
+                .iter()
+                .map(|v| self.format_y_label(yr, v))
+                .collect()
+        } else {
+            vec![]
+        };
+
+        let measure = |labels: &[String],
+                       ticks: &[Option<TickStyle>],
+                       label_style: &TextStyle,
+                       desc: Option<(&String, &TextStyle)>,
+                       tick_size: i32,
+                       vertical_axis: bool|
+         -> Result<u32, DrawingAreaErrorKind<DB::ErrorType>> {
+            let mut size = 0;
+            for (idx, text) in labels.iter().enumerate() {
+                let style = ticks
+                    .get(idx)
+                    .and_then(|t| t.as_ref())
+                    .and_then(|t| t.label_style.as_ref())
+                    .unwrap_or(label_style);
+                let (w, h) = area.estimate_text_size(text, style)?;
+                size = size.max(if vertical_axis { w } else { h });
+            }
+            if let Some((text, style)) = desc {
+                let line_height = style.font.get_size().round() as u32;
+                size += line_height * text.lines().count() as u32;
+            }
+            // The labels are placed twice the tick size away from the axis
+            Ok(size + tick_size.abs() as u32 * 2 + AUTO_LABEL_AREA_PADDING)
+        };
+
+        let x_desc = self.x_desc.as_ref().map(|desc| (desc, x_desc_style));
+        let y_desc = self.y_desc.as_ref().map(|desc| (desc, y_desc_style));
+        let sizes = [
+            measure(
+                &x_labels,
+                x_ticks,
+                x_label_style,
+                x_desc,
+                self.x_tick_size[0],
+                false,
+            )?,
+            measure(
+                &x_labels,
+                x_ticks,
+                x_label_style,
+                x_desc,
+                self.x_tick_size[1],
+                false,
+            )?,
+            measure(
+                &y_labels,
+                y_ticks,
+                y_label_style,
+                y_desc,
+                self.y_tick_size[0],
+                true,
+            )?,
+            measure(
+                &y_labels,
+                y_ticks,
+                y_label_style,
+                y_desc,
+                self.y_tick_size[1],
+                true,
+            )?,
+        ];
+        target.fit_label_areas(sizes);
+        Ok(())
+    }
+
+    /// Draw the configured mesh on the target plot. If any of the label areas is sized
+    /// automatically, the chart is laid out again to fit the labels before the mesh is drawn,
+    /// see [LabelAreaSize::Auto](enum.LabelAreaSize.html#variant.Auto)
     pub fn draw(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
     where
         X: ValueFormatter<<X as Ranged>::ValueType>,
         Y: ValueFormatter<<Y as Ranged>::ValueType>,
     {
         let target = self.target.take().unwrap();
+        let (n_x_labels, n_y_labels) = self.label_counts();
 
         let default_mesh_color_1 = RGBColor(0, 0, 0).mix(0.2);
         let default_mesh_color_2 = RGBColor(0, 0, 0).mix(0.1);
@@ -438,71 +835,306 @@ where
             .axis_desc_style
             .clone()
             .unwrap_or_else(|| x_label_style.clone());
+        let x_desc_style = self
+            .x_desc_style
+            .clone()
+            .unwrap_or_else(|| axis_desc_style.clone());
+        let y_desc_style = self
+            .y_desc_style
+            .clone()
+            .unwrap_or_else(|| axis_desc_style.clone());
+
+        let x_ticks: Vec<_> = match self.x_tick_style {
+            Some(func) => target
+                .as_coord_spec()
+                .x_spec()
+                .key_points(BoldPoints(n_x_labels))
+                .iter()
+                .enumerate()
+                .map(|(idx, v)| func(v, idx))
+                .collect(),
+            None => vec![],
+        };
+        let y_ticks: Vec<_> = match self.y_tick_style {
+            Some(func) => target
+                .as_coord_spec()
+                .y_spec()
+                .key_points(BoldPoints(n_y_labels))
+                .iter()
+                .enumerate()
+                .map(|(idx, v)| func(v, idx))
+                .collect(),
+            None => vec![],
+        };
+
+        self.fit_label_areas(
+            target,
+            (n_x_labels, n_y_labels),
+            (&x_label_style, &y_label_style),
+            (&x_desc_style, &y_desc_style),
+            (&x_ticks, &y_ticks),
+        )?;
+
+        // The spans are located when they are configured, so they are scaled to the plotting
+        // area if the chart has been laid out again
+        let (w, h) = target.plotting_area().dim_in_pixel();
+        let scale = |v: i32, from: u32, to: u32| {
+            if from > 1 && from != to {
+                (f64::from(v) * f64::from(to - 1) / f64::from(from - 1)).round() as i32
+            } else {
+                v
+            }
+        };
+        let (w0, h0) = self.plotting_size;
+        for (rect, _) in self.spans.iter_mut() {
+            for (x, y) in rect.iter_mut() {
+                *x = scale(*x, w0, w);
+                *y = scale(*y, h0, h);
+            }
+        }
+        self.plotting_size = (w, h);
+
+        let spans_area = target.plotting_area().strip_coord_spec();
+        let draw_spans = || -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+            for (rect, style) in self.spans.iter() {
+                spans_area.draw(&Rectangle::new(*rect, style.clone()))?;
+            }
+            Ok(())
+        };
+        // The spans above the grid are drawn after the mesh lines, unless the grid is moved to
+        // the topmost layer, which is above anything drawn immediately
+        let spans_above_grid = self.spans_above_grid && !self.grid_on_top;
+        if !spans_above_grid {
+            draw_spans()?;
+        }
+
+        // When the grid is on top, only the axes and labels are drawn here
+        let (draw_x_mesh, draw_y_mesh) = if self.grid_on_top {
+            (false, false)
+        } else {
+            (self.draw_x_mesh, self.draw_y_mesh)
+        };
 
         target.draw_mesh(
             (
-                LightPoints::new(self.n_y_labels, self.n_y_labels * 10),
-                LightPoints::new(self.n_x_labels, self.n_x_labels * 10),
+                LightPoints::new(n_y_labels, n_y_labels * 10),
+                LightPoints::new(n_x_labels, n_x_labels * 10),
             ),
             &light_style,
             &x_label_style,
             &y_label_style,
+            (&[], &[]),
             |_, _, _| None,
-            self.draw_x_mesh,
-            self.draw_y_mesh,
+            draw_x_mesh,
+            draw_y_mesh,
             self.x_label_offset,
             self.y_label_offset,
             false,
             false,
             &axis_style,
-            &axis_desc_style,
-            self.x_desc.clone(),
-            self.y_desc.clone(),
+            self.x_desc
+                .as_ref()
+                .map(|desc| (desc.as_str(), &x_desc_style, self.x_desc_offset)),
+            self.y_desc
+                .as_ref()
+                .map(|desc| (desc.as_str(), &y_desc_style, self.y_desc_offset)),
             self.x_tick_size,
             self.y_tick_size,
         )?;
 
         target.draw_mesh(
-            (BoldPoints(self.n_y_labels), BoldPoints(self.n_x_labels)),
+            (BoldPoints(n_y_labels), BoldPoints(n_x_labels)),
             &bold_style,
             &x_label_style,
             &y_label_style,
+            (&x_ticks, &y_ticks),
             |xr, yr, m| match m {
                 MeshLine::XMesh(_, _, v) => {
                     if self.draw_x_axis {
-                        if let Some(fmt_func) = self.format_x {
-                            Some(fmt_func(v))
-                        } else {
-                            Some(xr.format_ext(v))
-                        }
+                        Some(self.format_x_label(xr, v))
                     } else {
                         None
                     }
                 }
                 MeshLine::YMesh(_, _, v) => {
                     if self.draw_y_axis {
-                        if let Some(fmt_func) = self.format_y {
-                            Some(fmt_func(v))
-                        } else {
-                            Some(yr.format_ext(v))
-                        }
+                        Some(self.format_y_label(yr, v))
                     } else {
                         None
                     }
                 }
             },
-            self.draw_x_mesh,
-            self.draw_y_mesh,
+            draw_x_mesh,
+            draw_y_mesh,
             self.x_label_offset,
             self.y_label_offset,
             self.draw_x_axis,
             self.draw_y_axis,
             &axis_style,
-            &axis_desc_style,
             None,
             None,
             self.x_tick_size,
             self.y_tick_size,
-        )
+        )?;
+
+        if spans_above_grid {
+            draw_spans()?;
+        }
+
+        if self.grid_on_top && (self.draw_x_mesh || self.draw_y_mesh) {
+            let (draw_x, draw_y) = (self.draw_x_mesh, self.draw_y_mesh);
+            let line_styles = |ticks: &[Option<TickStyle>]| -> Vec<_> {
+                ticks
+                    .iter()
+                    .map(|tick| tick.as_ref().and_then(|t| t.line_style.clone()))
+                    .collect()
+            };
+            let (x_lines, y_lines) = (line_styles(&x_ticks), line_styles(&y_ticks));
+            let mesh_line = move |style: ShapeStyle,
+                                  x_lines: Vec<Option<ShapeStyle>>,
+                                  y_lines: Vec<Option<ShapeStyle>>| {
+                let (mut x_idx, mut y_idx) = (0, 0);
+                move |line: MeshLine<X, Y>| {
+                    let (draw, over, from, to) = match line {
+                        MeshLine::XMesh(from, to, _) => {
+                            x_idx += 1;
+                            (draw_x, x_lines.get(x_idx - 1), from, to)
+                        }
+                        MeshLine::YMesh(from, to, _) => {
+                            y_idx += 1;
+                            (draw_y, y_lines.get(y_idx - 1), from, to)
+                        }
+                    };
+                    if !draw {
+                        return None;
+                    }
+                    let style = over.and_then(Option::as_ref).unwrap_or(&style);
+                    Some((from, to, style.clone()))
+                }
+            };
+            // The mesh lines are mapped now, and drawn when the layers are flushed
+            let coord = target.drawing_area.as_coord_spec();
+            let mut lines = vec![];
+            let mut light_line = mesh_line(light_style, vec![], vec![]);
+            coord.draw_mesh(
+                LightPoints::new(n_y_labels, n_y_labels * 10),
+                LightPoints::new(n_x_labels, n_x_labels * 10),
+                |line| -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+                    lines.extend(light_line(line));
+                    Ok(())
+                },
+            )?;
+            let mut bold_line = mesh_line(bold_style, x_lines, y_lines);
+            coord.draw_mesh(
+                BoldPoints(n_y_labels),
+                BoldPoints(n_x_labels),
+                |line| -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+                    lines.extend(bold_line(line));
+                    Ok(())
+                },
+            )?;
+            target.layers.push(std::i32::MAX, move |b| {
+                for (from, to, style) in lines {
+                    style.draw_line(b, from, to)?;
+                }
+                Ok(())
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Get the rectangle of a span in the pixels of the plotting area
+/// - `a`, `b`: The pixel range of the span on the axis
+/// - `area`: The pixel range of the plotting area
+/// - `vertical`: If the span is on the Y axis, i.e. the span is horizontal
+fn span_rect(a: i32, b: i32, area: (Range<i32>, Range<i32>), vertical: bool) -> [(i32, i32); 2] {
+    let (x_range, y_range) = area;
+    let (a, b) = (a.min(b), a.max(b));
+    if vertical {
+        [
+            (0, a - y_range.start),
+            (x_range.end - x_range.start - 1, b - y_range.start),
+        ]
+    } else {
+        [
+            (a - x_range.start, 0),
+            (b - x_range.start, y_range.end - y_range.start - 1),
+        ]
+    }
+}
+
+/// Get the rectangles of every other decade of a log axis in the pixels of the plotting area,
+/// the decades with the even exponents are shaded.
+/// - `decades`: The pixel range of each decade on the axis, with the exponent of its lower bound
+/// - `area`: The pixel range of the plotting area
+/// - `vertical`: If the axis is the Y axis
+fn decade_band_rects<I: IntoIterator<Item = (i32, i32, i32)>>(
+    decades: I,
+    area: (Range<i32>, Range<i32>),
+    vertical: bool,
+) -> Vec<[(i32, i32); 2]> {
+    decades
+        .into_iter()
+        .filter(|(exp, _, _)| exp % 2 == 0)
+        .map(|(_, a, b)| span_rect(a, b, area.clone(), vertical))
+        .collect()
+}
+
+impl<'a, 'b, V, Y, DB> MeshStyle<'a, 'b, LogCoord<V>, Y, DB>
+where
+    V: LogScalable,
+    Y: Ranged,
+    DB: DrawingBackend,
+{
+    /// Shade every other decade of the logarithmic X axis behind the plot, which makes it easier
+    /// to read the values across the orders of magnitude. The bands are vertical and span the
+    /// whole height of the plotting area, the decades from the even powers of the base are
+    /// shaded, e.g. 1 to 10 and 100 to 1000.
+    /// - `style`: The style used to fill the bands, usually a faint color
+    pub fn x_decade_bands<S: Into<ShapeStyle>>(&mut self, style: S) -> &mut Self {
+        let style = style.into();
+        if let Some(target) = self.target.as_ref() {
+            let area = target.plotting_area();
+            let spec = area.as_coord_spec();
+            let decades = spec.x_spec().decades().into_iter().map(|(exp, a, b)| {
+                let a = area.map_coordinate(&(a, spec.get_y_range().start)).0;
+                let b = area.map_coordinate(&(b, spec.get_y_range().start)).0;
+                (exp, a, b)
+            });
+            let rects = decade_band_rects(decades, area.get_pixel_range(), false);
+            self.spans
+                .extend(rects.into_iter().map(|rect| (rect, style.clone())));
+        }
+        self
+    }
+}
+
+impl<'a, 'b, X, V, DB> MeshStyle<'a, 'b, X, LogCoord<V>, DB>
+where
+    X: Ranged,
+    V: LogScalable,
+    DB: DrawingBackend,
+{
+    /// Shade every other decade of the logarithmic Y axis behind the plot. The bands are
+    /// horizontal and span the whole width of the plotting area, see
+    /// [x_decade_bands](#method.x_decade_bands)
+    /// - `style`: The style used to fill the bands, usually a faint color
+    pub fn y_decade_bands<S: Into<ShapeStyle>>(&mut self, style: S) -> &mut Self {
+        let style = style.into();
+        if let Some(target) = self.target.as_ref() {
+            let area = target.plotting_area();
+            let spec = area.as_coord_spec();
+            let decades = spec.y_spec().decades().into_iter().map(|(exp, a, b)| {
+                let a = area.map_coordinate(&(spec.get_x_range().start, a)).1;
+                let b = area.map_coordinate(&(spec.get_x_range().start, b)).1;
+                (exp, a, b)
+            });
+            let rects = decade_band_rects(decades, area.get_pixel_range(), true);
+            self.spans
+                .extend(rects.into_iter().map(|rect| (rect, style.clone())));
+        }
+        self
     }
 }
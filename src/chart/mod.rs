@@ -16,15 +16,35 @@ mod axes3d;
 mod builder;
 mod context;
 mod dual_coord;
+mod layout;
 mod mesh;
+mod nested_axis;
+mod price_volume;
+mod scatter_matrix;
 mod series;
+#[cfg(feature = "line_series")]
+mod sparkline;
 mod state;
+#[cfg(feature = "line_series")]
+mod template;
+mod theme;
 
-pub use builder::{ChartBuilder, LabelAreaPosition};
+pub use builder::{ChartBuilder, IntoLabelAreaSize, LabelAreaPosition, LabelAreaSize};
 pub use context::ChartContext;
 pub use dual_coord::{DualCoordChartContext, DualCoordChartState};
-pub use mesh::{MeshStyle, SecondaryMeshStyle};
-pub use series::{SeriesAnno, SeriesLabelPosition, SeriesLabelStyle};
+pub use layout::{ChartLayout, ChartRegion};
+pub use mesh::{MeshStyle, SecondaryMeshStyle, TickStyle, TickStyleFn};
+pub use nested_axis::NestedAxisStyle;
+pub use price_volume::{PriceVolumeChart, PriceVolumePanel};
+pub use scatter_matrix::{ScatterMatrix, ScatterMatrixCell};
+pub use series::{
+    LegendMarker, SeriesAnno, SeriesLabelInfo, SeriesLabelPosition, SeriesLabelStyle,
+};
+#[cfg(feature = "line_series")]
+pub use sparkline::{sparkline, Sparkline};
 pub use state::ChartState;
+#[cfg(feature = "line_series")]
+pub use template::ChartTemplate;
+pub use theme::Theme;
 
 use context::Coord3D;
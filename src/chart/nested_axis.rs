@@ -0,0 +1,235 @@
+use plotters_backend::DrawingBackend;
+
+use super::ChartContext;
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::combinators::{NestedRange, NestedValue};
+use crate::coord::ranged1d::{DiscreteRanged, Ranged, ValueFormatter};
+use crate::drawing::DrawingAreaErrorKind;
+use crate::element::PathElement;
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{
+    FontDesc, FontFamily, FontStyle, IntoTextStyle, ShapeStyle, SizeDesc, TextStyle, BLACK,
+};
+
+/// The struct used to draw the two-level labels of a nested X axis, which is created by
+/// [ChartContext::configure_nested_x_labels](struct.ChartContext.html#method.configure_nested_x_labels).
+///
+/// The labels are drawn in the bottom label area: the labels of the nested values are in the
+/// first row, and the label of each category is centered below its nested values in the second
+/// row. The categories are separated by the vertical lines, which are joined by a line along the
+/// top of the label area, so each category looks like a bracket. The mesh is expected to be
+/// configured with `disable_x_axis`, so the labels aren't drawn twice.
+///
+/// ```rust
+/// use plotters::prelude::*;
+/// use plotters::coord::types::RangedCategory;
+///
+/// let mut buffer = vec![0; 640 * 480 * 3];
+/// let root = BitMapBackend::with_buffer(&mut buffer, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .x_label_area_size(50)
+///     .y_label_area_size(40)
+///     .build_cartesian_2d(
+///         RangedCategory::new(vec!["Q1", "Q2", "Q3"])
+///             .nested_coord(|_| RangedCategory::new(vec![2023, 2024]).into_segmented()),
+///         0.0..10.0,
+///     )
+///     .unwrap();
+///
+/// chart.configure_mesh().disable_x_axis().draw().unwrap();
+/// chart.configure_nested_x_labels().draw().unwrap();
+/// ```
+pub struct NestedAxisStyle<'a, 'b, DB: DrawingBackend, P: DiscreteRanged, S: Ranged, Y: Ranged> {
+    target: &'b ChartContext<'a, DB, Cartesian2d<NestedRange<P, S>, Y>>,
+    inner_label_style: Option<TextStyle<'b>>,
+    group_label_style: Option<TextStyle<'b>>,
+    separator_style: ShapeStyle,
+    n_inner_labels: usize,
+    row_gap: u32,
+}
+
+impl<'a, 'b, DB, P, S, Y> NestedAxisStyle<'a, 'b, DB, P, S, Y>
+where
+    DB: DrawingBackend,
+    P: DiscreteRanged + ValueFormatter<P::ValueType>,
+    S: Ranged + ValueFormatter<S::ValueType>,
+    Y: Ranged,
+{
+    pub(super) fn new(target: &'b ChartContext<'a, DB, Cartesian2d<NestedRange<P, S>, Y>>) -> Self {
+        Self {
+            target,
+            inner_label_style: None,
+            group_label_style: None,
+            separator_style: (&BLACK).into(),
+            n_inner_labels: 10,
+            row_gap: 5,
+        }
+    }
+
+    /// Set the style of the labels of the nested values
+    pub fn inner_label_style<T: IntoTextStyle<'b>>(&mut self, style: T) -> &mut Self {
+        self.inner_label_style =
+            Some(style.into_text_style(&self.target.plotting_area().dim_in_pixel()));
+        self
+    }
+
+    /// Set the style of the labels of the categories, the style of the inner labels is used if
+    /// it's not set
+    pub fn group_label_style<T: IntoTextStyle<'b>>(&mut self, style: T) -> &mut Self {
+        self.group_label_style =
+            Some(style.into_text_style(&self.target.plotting_area().dim_in_pixel()));
+        self
+    }
+
+    /// Set the style of the separator lines between the categories
+    pub fn separator_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.separator_style = style.into();
+        self
+    }
+
+    /// Set how many labels of the nested values in each category at most
+    pub fn inner_labels(&mut self, n: usize) -> &mut Self {
+        self.n_inner_labels = n;
+        self
+    }
+
+    /// Set the gap above each row of labels
+    pub fn row_gap<T: SizeDesc>(&mut self, gap: T) -> &mut Self {
+        self.row_gap = gap
+            .in_pixels(&self.target.plotting_area().dim_in_pixel())
+            .max(0) as u32;
+        self
+    }
+
+    /// Draw the labels, nothing is drawn if the chart doesn't have the bottom label area
+    pub fn draw(&self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let area = match self.target.x_label_area[1].as_ref() {
+            Some(area) => area,
+            None => return Ok(()),
+        };
+        let coord = self.target.as_coord_spec().x_spec();
+        let pixel_range = self.target.drawing_area.get_x_axis_pixel_range();
+        let limit = (pixel_range.start, pixel_range.end);
+        let x0 = area.get_base_pixel().0;
+
+        let inner_style = self.inner_label_style.clone().unwrap_or_else(|| {
+            FontDesc::new(FontFamily::SansSerif, 12.0, FontStyle::Normal).into()
+        });
+        let group_style = self
+            .group_label_style
+            .clone()
+            .unwrap_or_else(|| inner_style.clone());
+        let gap = self.row_gap as i32;
+        let inner_y = gap;
+        let group_y = inner_y + inner_style.font.get_size().round() as i32 + gap;
+        let bottom = group_y + group_style.font.get_size().round() as i32;
+
+        let inner_style = inner_style.pos(Pos::new(HPos::Center, VPos::Top));
+        let hint = coord.primary().size() * (self.n_inner_labels + 1);
+        for value in coord.key_points(hint) {
+            if let NestedValue::Value(_, ref inner) = value {
+                let x = coord.map(&value, limit) - x0;
+                area.draw_text(&S::format(inner), &inner_style, (x, inner_y))?;
+            }
+        }
+
+        let group_style = group_style.pos(Pos::new(HPos::Center, VPos::Top));
+        let mut span = None;
+        for category in coord.primary().values() {
+            let (left, right) = match coord.category_span(&category, limit) {
+                Some(span) => span,
+                None => continue,
+            };
+            let (left, right) = (left - x0, right - x0);
+            area.draw_text(
+                &P::format(&category),
+                &group_style,
+                ((left + right) / 2, group_y),
+            )?;
+            area.draw(&PathElement::new(
+                vec![(left, 0), (left, bottom)],
+                self.separator_style.clone(),
+            ))?;
+            span = Some(span.map_or((left, right), |(l, _)| (l, right)));
+        }
+
+        if let Some((left, right)) = span {
+            area.draw(&PathElement::new(
+                vec![(right, 0), (right, bottom)],
+                self.separator_style.clone(),
+            ))?;
+            area.draw(&PathElement::new(
+                vec![(left, 0), (right, 0)],
+                self.separator_style.clone(),
+            ))?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, DB, P, S, Y> ChartContext<'a, DB, Cartesian2d<NestedRange<P, S>, Y>>
+where
+    DB: DrawingBackend,
+    P: DiscreteRanged + ValueFormatter<P::ValueType>,
+    S: Ranged + ValueFormatter<S::ValueType>,
+    Y: Ranged,
+{
+    /// Configure the two-level labels of the nested X axis, which shows the nested values in
+    /// the first row and the categories in the second row, see
+    /// [NestedAxisStyle](struct.NestedAxisStyle.html)
+    pub fn configure_nested_x_labels<'b>(&'b self) -> NestedAxisStyle<'a, 'b, DB, P, S, Y> {
+        NestedAxisStyle::new(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::coord::types::RangedCategory;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_nested_x_labels() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let texts = Rc::new(RefCell::new(vec![]));
+        {
+            let texts = texts.clone();
+            let drawing_area = create_mocked_drawing_area(300, 200, move |m| {
+                m.check_draw_text(move |_, _, _, pos, text| {
+                    texts.borrow_mut().push((text.to_string(), pos))
+                });
+                m.drop_check(|b| assert_eq!(b.num_draw_path_call, 4));
+            });
+
+            let chart = ChartBuilder::on(&drawing_area)
+                .x_label_area_size(50)
+                .build_cartesian_2d(
+                    RangedCategory::new(vec!["Q1", "Q2"])
+                        .nested_coord(|_| RangedCategory::new(vec![2023, 2024]).into_segmented()),
+                    0..10,
+                )
+                .expect("Create chart");
+
+            chart
+                .configure_nested_x_labels()
+                .draw()
+                .expect("Drawing error");
+        }
+
+        let texts = texts.borrow();
+        assert_eq!(texts.len(), 6);
+        let find = |name: &str| {
+            texts
+                .iter()
+                .filter(|(text, _)| text == name)
+                .map(|(_, pos)| *pos)
+                .collect::<Vec<_>>()
+        };
+        let (q1, q2) = (find("Q1")[0], find("Q2")[0]);
+        let inner = find("2023");
+        assert_eq!(inner.len(), 2);
+        assert!(inner[0].0 < q1.0 && q1.0 < inner[1].0 && inner[1].0 < q2.0);
+        assert!(q1.1 > inner[0].1);
+    }
+}
@@ -0,0 +1,150 @@
+use std::ops::Range;
+
+use plotters_backend::DrawingBackend;
+
+use super::{ChartBuilder, ChartContext};
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::{AsRangedCoord, ValueFormatter};
+use crate::coord::types::RangedCoordf64;
+use crate::coord::Shift;
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
+
+/// The chart context of a panel in the price-volume chart
+pub type PriceVolumePanel<'a, DB, X> = ChartContext<'a, DB, Cartesian2d<X, RangedCoordf64>>;
+
+type PanelResult<'a, DB, X> = Result<
+    (PriceVolumePanel<'a, DB, X>, PriceVolumePanel<'a, DB, X>),
+    DrawingAreaErrorKind<<DB as DrawingBackend>::ErrorType>,
+>;
+
+/// The helper that builds the common financial chart layout: a price chart, usually with
+/// candlesticks, on the top and a volume chart below it. Both charts share the same X range and
+/// their plotting areas are aligned, and only the volume chart has the X labels.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let root = BitMapBackend::new("plotters-doc-data/price-volume.png", (640, 480))
+///     .into_drawing_area();
+/// root.fill(&WHITE).unwrap();
+///
+/// let data = [(1, 10.0, 12.0, 9.0, 11.0, 300.0), (2, 11.0, 11.5, 10.0, 10.2, 500.0)];
+/// let mut layout = PriceVolumeChart::new(&root);
+/// let (mut price, mut volume) = layout.volume_ratio(0.3).build(0..3, 8.0..13.0, 0.0..600.0).unwrap();
+///
+/// price.configure_mesh().draw().unwrap();
+/// price
+///     .draw_series(data.iter().map(|&(x, o, h, l, c, _)| CandleStick::new(x, o, h, l, c, &GREEN, &RED, 10)))
+///     .unwrap();
+///
+/// volume.configure_mesh().y_labels(3).draw().unwrap();
+/// volume
+///     .draw_series(data.iter().map(|&(x, _, _, _, _, v)| Rectangle::new([(x, 0.0), (x + 1, v)], BLUE.filled())))
+///     .unwrap();
+/// ```
+pub struct PriceVolumeChart<'a, DB: DrawingBackend> {
+    root: &'a DrawingArea<DB, Shift>,
+    areas: Option<(DrawingArea<DB, Shift>, DrawingArea<DB, Shift>)>,
+    volume_ratio: f64,
+    gap: u32,
+    x_label_area_size: u32,
+    y_label_area_size: u32,
+}
+
+impl<'a, DB: DrawingBackend> PriceVolumeChart<'a, DB> {
+    /// Create a new price-volume chart layout
+    /// - `root`: The drawing area the charts are drawn on
+    pub fn new(root: &'a DrawingArea<DB, Shift>) -> Self {
+        Self {
+            root,
+            areas: None,
+            volume_ratio: 0.25,
+            gap: 5,
+            x_label_area_size: 30,
+            y_label_area_size: 50,
+        }
+    }
+
+    /// Set the ratio of the height of the volume chart to the height of the drawing area
+    pub fn volume_ratio(&mut self, ratio: f64) -> &mut Self {
+        self.volume_ratio = ratio.max(0.0).min(1.0);
+        self
+    }
+
+    /// Set the gap between the price chart and the volume chart in pixels
+    pub fn gap(&mut self, gap: u32) -> &mut Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Set the size of the X label area under the volume chart
+    pub fn x_label_area_size(&mut self, size: u32) -> &mut Self {
+        self.x_label_area_size = size;
+        self
+    }
+
+    /// Set the size of the Y label areas of both charts, which keeps the plotting areas aligned
+    pub fn y_label_area_size(&mut self, size: u32) -> &mut Self {
+        self.y_label_area_size = size;
+        self
+    }
+
+    /// Build the price chart and the volume chart
+    /// - `x_spec`: The X range shared by both charts
+    /// - `price`: The Y range of the price chart
+    /// - `volume`: The Y range of the volume chart
+    /// - **returns**: The price chart and the volume chart
+    pub fn build<X: AsRangedCoord + Clone>(
+        &'a mut self,
+        x_spec: X,
+        price: Range<f64>,
+        volume: Range<f64>,
+    ) -> PanelResult<'a, DB, X::CoordDescType>
+    where
+        X::CoordDescType: ValueFormatter<X::Value>,
+    {
+        let (_, h) = self.root.dim_in_pixel();
+        let volume_height = (f64::from(h) * self.volume_ratio).round() as i32;
+        let (upper, lower) = self.root.split_vertically(h as i32 - volume_height);
+        self.areas = Some((upper.margin(0, self.gap, 0, 0), lower));
+        let this: &'a Self = self;
+        let (upper, lower) = this.areas.as_ref().unwrap();
+
+        let price_chart = ChartBuilder::on(upper)
+            .y_label_area_size(this.y_label_area_size)
+            .build_cartesian_2d(x_spec.clone(), price)?;
+        let volume_chart = ChartBuilder::on(lower)
+            .x_label_area_size(this.x_label_area_size)
+            .y_label_area_size(this.y_label_area_size)
+            .build_cartesian_2d(x_spec, volume)?;
+        Ok((price_chart, volume_chart))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_price_volume_chart() {
+        let drawing_area = create_mocked_drawing_area(400, 400, |_| {});
+        let mut layout = PriceVolumeChart::new(&drawing_area);
+        let (price, volume) = layout
+            .volume_ratio(0.25)
+            .gap(10)
+            .build(0..10, 0.0..100.0, 0.0..10.0)
+            .expect("Create charts");
+
+        let (price_x, price_y) = price.plotting_area().get_pixel_range();
+        let (volume_x, volume_y) = volume.plotting_area().get_pixel_range();
+        assert_eq!(price_x, volume_x);
+        assert_eq!(price_x, 50..400);
+        assert_eq!(price_y, 0..290);
+        assert_eq!(volume_y, 300..370);
+        assert_eq!(
+            price.as_coord_spec().translate(&(5, 0.0)).0,
+            volume.as_coord_spec().translate(&(5, 0.0)).0
+        );
+    }
+}
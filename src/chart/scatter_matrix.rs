@@ -0,0 +1,285 @@
+use std::ops::Range;
+
+use plotters_backend::DrawingBackend;
+
+use super::{ChartBuilder, ChartContext};
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::types::RangedCoordf64;
+use crate::coord::Shift;
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
+use crate::element::{Circle, Rectangle};
+use crate::style::{Color, BLUE};
+
+/// The chart context of a single cell in a scatter matrix
+pub type ScatterMatrixCell<'a, DB> =
+    ChartContext<'a, DB, Cartesian2d<RangedCoordf64, RangedCoordf64>>;
+
+type DiagonalFn<'b, DB> = dyn for<'a> FnMut(
+        &mut ScatterMatrixCell<'a, DB>,
+        usize,
+        &[f64],
+    ) -> Result<(), DrawingAreaErrorKind<<DB as DrawingBackend>::ErrorType>>
+    + 'b;
+
+type OffDiagonalFn<'b, DB> = dyn for<'a> FnMut(
+        &mut ScatterMatrixCell<'a, DB>,
+        (usize, usize),
+        &[f64],
+        &[f64],
+    ) -> Result<(), DrawingAreaErrorKind<<DB as DrawingBackend>::ErrorType>>
+    + 'b;
+
+/// The helper that draws a scatter matrix, a.k.a. pairs plot, of a table with N numeric
+/// columns. The drawing area is split into a N x N grid, the cell on row `i` and column `j`
+/// plots column `j` against column `i`, and the diagonal cells show the histogram of each
+/// column. All the cells in the same column share the X range, and all the off-diagonal cells
+/// in the same row share the Y range.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let root = BitMapBackend::new("plotters-doc-data/scatter-matrix.png", (600, 600))
+///     .into_drawing_area();
+/// root.fill(&WHITE).unwrap();
+///
+/// ScatterMatrix::new(
+///     &root,
+///     vec![
+///         ("a", vec![1.0, 2.0, 3.0, 4.0]),
+///         ("b", vec![2.0, 1.0, 4.0, 3.0]),
+///         ("c", vec![0.5, 0.7, 0.1, 0.9]),
+///     ],
+/// )
+/// .draw()
+/// .unwrap();
+/// ```
+pub struct ScatterMatrix<'a, 'b, DB: DrawingBackend> {
+    area: &'a DrawingArea<DB, Shift>,
+    names: Vec<String>,
+    columns: Vec<Vec<f64>>,
+    label_area_size: u32,
+    bins: usize,
+    diagonal: Option<Box<DiagonalFn<'b, DB>>>,
+    off_diagonal: Option<Box<OffDiagonalFn<'b, DB>>>,
+}
+
+fn column_range(data: &[f64]) -> Range<f64> {
+    let (min, max) = data.iter().filter(|v| v.is_finite()).fold(
+        (std::f64::INFINITY, std::f64::NEG_INFINITY),
+        |(min, max), v| (min.min(*v), max.max(*v)),
+    );
+    if min > max {
+        return 0.0..1.0;
+    }
+    let pad = if max > min { (max - min) * 0.05 } else { 0.5 };
+    (min - pad)..(max + pad)
+}
+
+fn histogram(data: &[f64], range: &Range<f64>, bins: usize) -> Vec<usize> {
+    let mut counts = vec![0; bins];
+    let width = (range.end - range.start) / bins as f64;
+    for v in data.iter().filter(|v| v.is_finite()) {
+        let idx = ((v - range.start) / width).floor().max(0.0) as usize;
+        counts[idx.min(bins - 1)] += 1;
+    }
+    counts
+}
+
+impl<'a, 'b, DB: DrawingBackend> ScatterMatrix<'a, 'b, DB> {
+    /// Create a new scatter matrix
+    /// - `area`: The drawing area the scatter matrix is drawn on
+    /// - `columns`: The table, which is an iterator of the name and the values of each column
+    pub fn new<N: Into<String>, C: IntoIterator<Item = f64>, I: IntoIterator<Item = (N, C)>>(
+        area: &'a DrawingArea<DB, Shift>,
+        columns: I,
+    ) -> Self {
+        let (names, columns) = columns
+            .into_iter()
+            .map(|(name, data)| (name.into(), data.into_iter().collect()))
+            .unzip();
+        Self {
+            area,
+            names,
+            columns,
+            label_area_size: 40,
+            bins: 10,
+            diagonal: None,
+            off_diagonal: None,
+        }
+    }
+
+    /// Set the size of the label areas on the left and the bottom of the matrix
+    pub fn label_area_size(&mut self, size: u32) -> &mut Self {
+        self.label_area_size = size;
+        self
+    }
+
+    /// Set the number of the bins of the default histograms on the diagonal
+    pub fn bins(&mut self, bins: usize) -> &mut Self {
+        self.bins = bins.max(1);
+        self
+    }
+
+    /// Set the function that draws the diagonal cells
+    /// - `func`: The function takes the cell chart, the index of the column and its values. The
+    ///   Y range of the cell is `0..1` unless changed by the function
+    pub fn diagonal<
+        F: for<'c> FnMut(
+                &mut ScatterMatrixCell<'c, DB>,
+                usize,
+                &[f64],
+            ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+            + 'b,
+    >(
+        &mut self,
+        func: F,
+    ) -> &mut Self {
+        self.diagonal = Some(Box::new(func));
+        self
+    }
+
+    /// Set the function that draws the off-diagonal cells
+    /// - `func`: The function takes the cell chart, the `(row, column)` index of the cell, the
+    ///   values of the X column and the values of the Y column
+    pub fn off_diagonal<
+        F: for<'c> FnMut(
+                &mut ScatterMatrixCell<'c, DB>,
+                (usize, usize),
+                &[f64],
+                &[f64],
+            ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+            + 'b,
+    >(
+        &mut self,
+        func: F,
+    ) -> &mut Self {
+        self.off_diagonal = Some(Box::new(func));
+        self
+    }
+
+    /// Draw the scatter matrix
+    pub fn draw(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let n = self.columns.len();
+        if n == 0 {
+            return Ok(());
+        }
+        let ranges: Vec<_> = self.columns.iter().map(|c| column_range(c)).collect();
+
+        let (w, h) = self.area.dim_in_pixel();
+        let label = self.label_area_size as i32;
+        let cell_w = (w as i32 - label).max(0) / n as i32;
+        let cell_h = (h as i32 - label).max(0) / n as i32;
+
+        for row in 0..n {
+            for col in 0..n {
+                let (mut x, y) = (label + col as i32 * cell_w, row as i32 * cell_h);
+                let (mut cw, mut ch) = (cell_w, cell_h);
+                if col == 0 {
+                    x -= label;
+                    cw += label;
+                }
+                if row == n - 1 {
+                    ch += label;
+                }
+                let cell_area = self.area.strip_coord_spec().shrink((x, y), (cw, ch));
+
+                let mut builder = ChartBuilder::on(&cell_area);
+                if col == 0 {
+                    builder.y_label_area_size(label);
+                }
+                if row == n - 1 {
+                    builder.x_label_area_size(label);
+                }
+                let y_range = if row == col {
+                    0.0..1.0
+                } else {
+                    ranges[row].clone()
+                };
+                let mut chart = builder.build_cartesian_2d(ranges[col].clone(), y_range)?;
+
+                if row == col {
+                    match self.diagonal.as_mut() {
+                        Some(func) => func(&mut chart, col, &self.columns[col])?,
+                        None => {
+                            let counts = histogram(&self.columns[col], &ranges[col], self.bins);
+                            let max = counts.iter().copied().max().unwrap_or(0).max(1) as f64;
+                            chart.set_y_range(0.0..max * 1.1);
+                            let range = &ranges[col];
+                            let width = (range.end - range.start) / self.bins as f64;
+                            chart.draw_series(counts.into_iter().enumerate().map(|(i, c)| {
+                                let x0 = range.start + width * i as f64;
+                                Rectangle::new([(x0, 0.0), (x0 + width, c as f64)], BLUE.filled())
+                            }))?;
+                        }
+                    }
+                } else {
+                    let (xs, ys) = (&self.columns[col], &self.columns[row]);
+                    match self.off_diagonal.as_mut() {
+                        Some(func) => func(&mut chart, (row, col), xs, ys)?,
+                        None => {
+                            chart.draw_series(
+                                xs.iter()
+                                    .zip(ys.iter())
+                                    .map(|(x, y)| Circle::new((*x, *y), 2, BLUE.filled())),
+                            )?;
+                        }
+                    }
+                }
+
+                let mut mesh = chart.configure_mesh();
+                mesh.x_labels(4).y_labels(4);
+                if row == n - 1 {
+                    mesh.x_desc(self.names[col].as_str());
+                }
+                if col == 0 {
+                    mesh.y_desc(self.names[row].as_str());
+                }
+                mesh.draw()?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_scatter_matrix() {
+        let drawing_area = create_mocked_drawing_area(340, 340, |m| {
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_circle_call, 3 * 2 * 2);
+                assert!(b.num_draw_rect_call >= 10 * 2);
+            });
+        });
+
+        let mut diagonal_calls = vec![];
+        ScatterMatrix::new(
+            &drawing_area,
+            vec![("a", vec![1.0, 2.0, 3.0]), ("b", vec![3.0, 2.0, 1.0])],
+        )
+        .label_area_size(40)
+        .diagonal(|_, idx, data| {
+            diagonal_calls.push((idx, data.len()));
+            Ok(())
+        })
+        .draw()
+        .expect("Drawing error");
+        assert_eq!(diagonal_calls, vec![(0, 3), (1, 3)]);
+
+        ScatterMatrix::new(
+            &drawing_area,
+            vec![("a", vec![1.0, 2.0, 3.0]), ("b", vec![3.0, 2.0, 1.0])],
+        )
+        .draw()
+        .expect("Drawing error");
+    }
+
+    #[test]
+    fn test_histogram_bins() {
+        assert_eq!(histogram(&[0.0, 0.5, 1.0, 9.9], &(0.0..10.0), 10)[0], 2);
+        assert_eq!(histogram(&[10.0], &(0.0..10.0), 10)[9], 1);
+    }
+}
@@ -1,12 +1,48 @@
 use super::ChartContext;
 use crate::coord::CoordTranslate;
 use crate::drawing::DrawingAreaErrorKind;
-use crate::element::{DynElement, EmptyElement, IntoDynElement, MultiLineText, Rectangle};
+use crate::element::{
+    Circle, Cross, DynElement, EmptyElement, IntoDynElement, MultiLineText, PathElement, Rectangle,
+    TriangleMarker,
+};
 use crate::style::{IntoFont, IntoTextStyle, ShapeStyle, SizeDesc, TextStyle, TRANSPARENT};
 
 use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 
 type SeriesAnnoDrawFn<'a, DB> = dyn Fn(BackendCoord) -> DynElement<'a, DB, BackendCoord> + 'a;
+type SeriesLabelFilterFn<'b> = dyn Fn(usize, &str) -> bool + 'b;
+
+/// The shape of a predefined marker, e.g. the one drawn at the vertices of a line series or the
+/// legend marker set by
+/// [SeriesAnno::legend_marker](struct.SeriesAnno.html#method.legend_marker)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LegendMarker {
+    /// A horizontal line segment
+    Line,
+    /// A square
+    Rectangle,
+    /// A circle
+    Circle,
+    /// A cross
+    Cross,
+    /// A triangle
+    Triangle,
+}
+
+/// The description of a series label, which allows a legend to be drawn outside of the chart,
+/// e.g. by a GUI toolkit, and stay in sync with the series
+#[derive(Clone)]
+pub struct SeriesLabelInfo {
+    /// The index of the series, in the order they have been drawn
+    pub index: usize,
+    /// The label of the series
+    pub label: String,
+    /// The shape and the style of the legend marker, it's `None` when the legend is drawn by a
+    /// custom function set by [SeriesAnno::legend](struct.SeriesAnno.html#method.legend)
+    pub marker: Option<(LegendMarker, ShapeStyle)>,
+    /// If the series has a legend element
+    pub has_legend: bool,
+}
 
 /// The annotations (such as the label of the series, the legend element, etc)
 /// When a series is drawn onto a drawing area, an series annotation object
@@ -14,6 +50,7 @@ type SeriesAnnoDrawFn<'a, DB> = dyn Fn(BackendCoord) -> DynElement<'a, DB, Backe
 pub struct SeriesAnno<'a, DB: DrawingBackend> {
     label: Option<String>,
     draw_func: Option<Box<SeriesAnnoDrawFn<'a, DB>>>,
+    marker: Option<(LegendMarker, ShapeStyle)>,
 }
 
 impl<'a, DB: DrawingBackend> SeriesAnno<'a, DB> {
@@ -31,6 +68,16 @@ impl<'a, DB: DrawingBackend> SeriesAnno<'a, DB> {
         Self {
             label: None,
             draw_func: None,
+            marker: None,
+        }
+    }
+
+    pub(crate) fn get_info(&self, index: usize) -> SeriesLabelInfo {
+        SeriesLabelInfo {
+            index,
+            label: self.get_label().to_string(),
+            marker: self.marker.clone(),
+            has_legend: self.draw_func.is_some(),
         }
     }
 
@@ -50,6 +97,33 @@ impl<'a, DB: DrawingBackend> SeriesAnno<'a, DB> {
         func: T,
     ) -> &mut Self {
         self.draw_func = Some(Box::new(move |p| func(p).into_dyn()));
+        self.marker = None;
+        self
+    }
+
+    /// Set the legend element to a predefined marker. Different from
+    /// [legend](#method.legend), the shape and the style of the marker are reported by
+    /// [ChartContext::series_labels](struct.ChartContext.html#method.series_labels)
+    /// - `marker`: The shape of the marker
+    /// - `style`: The style of the marker
+    pub fn legend_marker<S: Into<ShapeStyle>>(
+        &mut self,
+        marker: LegendMarker,
+        style: S,
+    ) -> &mut Self {
+        let style = style.into();
+        self.marker = Some((marker, style.clone()));
+        self.draw_func = Some(Box::new(move |(x, y)| match marker {
+            LegendMarker::Line => {
+                PathElement::new(vec![(x, y), (x + 20, y)], style.clone()).into_dyn()
+            }
+            LegendMarker::Rectangle => {
+                Rectangle::new([(x, y - 5), (x + 10, y + 5)], style.clone()).into_dyn()
+            }
+            LegendMarker::Circle => Circle::new((x + 5, y), 5, style.clone()).into_dyn(),
+            LegendMarker::Cross => Cross::new((x + 5, y), 5, style.clone()).into_dyn(),
+            LegendMarker::Triangle => TriangleMarker::new((x + 5, y), 5, style.clone()).into_dyn(),
+        }));
         self
     }
 }
@@ -70,23 +144,32 @@ pub enum SeriesLabelPosition {
 }
 
 impl SeriesLabelPosition {
-    fn layout_label_area(&self, label_dim: (i32, i32), area_dim: (u32, u32)) -> (i32, i32) {
+    fn layout_label_area(
+        &self,
+        label_dim: (i32, i32),
+        area_dim: (u32, u32),
+        offset: i32,
+    ) -> (i32, i32) {
         use SeriesLabelPosition::*;
         (
             match self {
-                UpperLeft | MiddleLeft | LowerLeft => 5,
+                UpperLeft | MiddleLeft | LowerLeft => offset,
                 UpperMiddle | MiddleMiddle | LowerMiddle => {
                     (area_dim.0 as i32 - label_dim.0 as i32) / 2
                 }
-                UpperRight | MiddleRight | LowerRight => area_dim.0 as i32 - label_dim.0 as i32 - 5,
+                UpperRight | MiddleRight | LowerRight => {
+                    area_dim.0 as i32 - label_dim.0 as i32 - offset
+                }
                 Coordinate(x, _) => *x,
             },
             match self {
-                UpperLeft | UpperMiddle | UpperRight => 5,
+                UpperLeft | UpperMiddle | UpperRight => offset,
                 MiddleLeft | MiddleMiddle | MiddleRight => {
                     (area_dim.1 as i32 - label_dim.1 as i32) / 2
                 }
-                LowerLeft | LowerMiddle | LowerRight => area_dim.1 as i32 - label_dim.1 as i32 - 5,
+                LowerLeft | LowerMiddle | LowerRight => {
+                    area_dim.1 as i32 - label_dim.1 as i32 - offset
+                }
                 Coordinate(_, y) => *y,
             },
         )
@@ -102,6 +185,10 @@ pub struct SeriesLabelStyle<'a, 'b, DB: DrawingBackend, CT: CoordTranslate> {
     background: ShapeStyle,
     label_font: Option<TextStyle<'b>>,
     margin: u32,
+    offset: u32,
+    order: Option<Vec<usize>>,
+    filter: Option<Box<SeriesLabelFilterFn<'b>>>,
+    sections: Vec<(usize, String)>,
 }
 
 impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, 'b, DB, CT> {
@@ -114,6 +201,10 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
             background: (&TRANSPARENT).into(),
             label_font: None,
             margin: 10,
+            offset: 5,
+            order: None,
+            filter: None,
+            sections: vec![],
         }
     }
 
@@ -134,6 +225,18 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
         self
     }
 
+    /// Set the offset of the series label drawing area, which is the distance between the
+    /// border of the label area and the edge of the plotting area. It doesn't apply to the
+    /// `SeriesLabelPosition::Coordinate` position
+    ///
+    /// - `value`: The size specification
+    pub fn offset<S: SizeDesc>(&mut self, value: S) -> &mut Self {
+        self.offset = value
+            .in_pixels(&self.target.plotting_area().dim_in_pixel())
+            .max(0) as u32;
+        self
+    }
+
     /// Set the size of legend area
     /// `size` - The size of legend area in pixel
     pub fn legend_area_size<S: SizeDesc>(&mut self, size: S) -> &mut Self {
@@ -158,6 +261,14 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
         self
     }
 
+    /// Make both the background and the border of the series label area transparent, so only
+    /// the legend entries are drawn
+    pub fn transparent(&mut self) -> &mut Self {
+        self.border_style = (&TRANSPARENT).into();
+        self.background = (&TRANSPARENT).into();
+        self
+    }
+
     /// Set the series label font
     /// `font` - The font
     pub fn label_font<F: IntoTextStyle<'b>>(&mut self, font: F) -> &mut Self {
@@ -165,6 +276,60 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
         self
     }
 
+    /// Set the order of the legend entries
+    /// - `order`: The indices of the series, in the order they have been drawn, that should
+    ///   be listed first. The series not mentioned are listed afterwards in the drawing order
+    pub fn order<I: IntoIterator<Item = usize>>(&mut self, order: I) -> &mut Self {
+        self.order = Some(order.into_iter().collect());
+        self
+    }
+
+    /// Set the predicate that decides which series are included in the legend
+    /// - `filter`: The function takes the index of the series and its label, and returns if
+    ///   the series should be listed
+    pub fn filter<F: Fn(usize, &str) -> bool + 'b>(&mut self, filter: F) -> &mut Self {
+        self.filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Add a section title to the legend
+    /// - `series_idx`: The index of the series that starts the section, the title is put right
+    ///   before the legend entry of this series
+    /// - `title`: The section title
+    pub fn section<T: Into<String>>(&mut self, series_idx: usize, title: T) -> &mut Self {
+        self.sections.push((series_idx, title.into()));
+        self
+    }
+
+    fn legend_entries(&self) -> Vec<usize> {
+        let count = self.target.series_anno.len();
+        let mut entries: Vec<usize> = vec![];
+        for idx in self
+            .order
+            .iter()
+            .flatten()
+            .copied()
+            .chain(0..count)
+            .filter(|&idx| idx < count)
+        {
+            if !entries.contains(&idx) {
+                entries.push(idx);
+            }
+        }
+        entries.retain(|&idx| {
+            let anno = &self.target.series_anno[idx];
+            let label_text = anno.get_label();
+            if label_text.is_empty() && anno.get_draw_func().is_none() {
+                return false;
+            }
+            match self.filter.as_ref() {
+                Some(filter) => filter(idx, label_text),
+                None => true,
+            }
+        });
+        entries
+    }
+
     /// Draw the series label area
     pub fn draw(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
         let drawing_area = self.target.plotting_area().strip_coord_spec();
@@ -181,16 +346,18 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
         };
 
         let mut label_element = MultiLineText::<_, &str>::new((0, 0), &font);
-        let mut funcs = vec![];
+        let mut funcs: Vec<&SeriesAnnoDrawFn<'a, DB>> = vec![];
 
-        for anno in self.target.series_anno.iter() {
+        for idx in self.legend_entries() {
+            for (_, title) in self.sections.iter().filter(|(i, _)| *i == idx) {
+                funcs.push(&|p: BackendCoord| EmptyElement::at(p).into_dyn());
+                label_element.push_line(title.as_str());
+            }
+
+            let anno = &self.target.series_anno[idx];
             let label_text = anno.get_label();
             let draw_func = anno.get_draw_func();
 
-            if label_text.is_empty() && draw_func.is_none() {
-                continue;
-            }
-
             funcs.push(
                 draw_func.unwrap_or_else(|| &|p: BackendCoord| EmptyElement::at(p).into_dyn()),
             );
@@ -208,7 +375,9 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
 
         let (area_w, area_h) = drawing_area.dim_in_pixel();
 
-        let (label_x, label_y) = self.position.layout_label_area((w, h), (area_w, area_h));
+        let (label_x, label_y) =
+            self.position
+                .layout_label_area((w, h), (area_w, area_h), self.offset as i32);
 
         label_element.relocate((
             label_x + self.legend_area_size as i32 + margin,
@@ -240,3 +409,119 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_series_label_order_and_filter() {
+        let drawing_area = create_mocked_drawing_area(400, 400, |m| {
+            let mut expected = vec!["Group", "c", "a"].into_iter();
+            m.check_draw_text(move |_, _, _, _, text| {
+                assert_eq!(Some(text), expected.next());
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_text_call, 3));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+
+        for label in ["a", "b", "c"].iter() {
+            chart
+                .draw_series(std::iter::empty::<Circle<(i32, i32), u32>>())
+                .expect("Drawing error")
+                .label(*label);
+        }
+
+        chart
+            .configure_series_labels()
+            .order(vec![2])
+            .filter(|_, label| label != "b")
+            .section(2, "Group")
+            .draw()
+            .expect("Drawing error");
+    }
+
+    #[test]
+    fn test_series_label_info() {
+        let drawing_area = create_mocked_drawing_area(400, 400, |m| {
+            m.check_draw_circle(|c, _, f, _, r| {
+                assert_eq!(c, RED.to_rgba());
+                assert!(f);
+                assert_eq!(r, 5);
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_circle_call, 1));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+
+        chart
+            .draw_series(std::iter::empty::<Circle<(i32, i32), u32>>())
+            .expect("Drawing error")
+            .label("a")
+            .legend_marker(LegendMarker::Circle, RED.filled());
+        chart
+            .draw_series(std::iter::empty::<Circle<(i32, i32), u32>>())
+            .expect("Drawing error");
+        chart
+            .draw_series(std::iter::empty::<Circle<(i32, i32), u32>>())
+            .expect("Drawing error")
+            .label("c")
+            .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &BLUE));
+
+        let labels = chart.series_labels();
+        assert_eq!(labels.len(), 2);
+        assert_eq!((labels[0].index, labels[0].label.as_str()), (0, "a"));
+        let (marker, style) = labels[0].marker.clone().unwrap();
+        assert_eq!(marker, LegendMarker::Circle);
+        assert_eq!(style.color, RED.to_rgba());
+        assert_eq!((labels[1].index, labels[1].label.as_str()), (2, "c"));
+        assert!(labels[1].marker.is_none() && labels[1].has_legend);
+
+        chart
+            .configure_series_labels()
+            .filter(|idx, _| idx == 0)
+            .draw()
+            .expect("Drawing error");
+    }
+
+    #[test]
+    fn test_series_label_margin_and_offset() {
+        let drawing_area = create_mocked_drawing_area(400, 400, |m| {
+            m.check_draw_rect(|c, _, f, u, _| {
+                assert_eq!(c, RED.to_rgba());
+                assert!(f);
+                assert_eq!(u, (20, 20));
+            });
+            m.check_draw_rect(|c, _, f, u, _| {
+                assert_eq!(c, BLUE.to_rgba());
+                assert!(!f);
+                assert_eq!(u, (20, 20));
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_rect_call, 2));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+
+        chart
+            .draw_series(std::iter::empty::<Circle<(i32, i32), u32>>())
+            .expect("Drawing error")
+            .label("a");
+
+        chart
+            .configure_series_labels()
+            .position(SeriesLabelPosition::UpperLeft)
+            .margin(4)
+            .offset(20)
+            .background_style(&RED)
+            .border_style(&BLUE)
+            .draw()
+            .expect("Drawing error");
+    }
+}
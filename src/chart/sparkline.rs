@@ -0,0 +1,177 @@
+use plotters_backend::DrawingBackend;
+
+use super::ChartBuilder;
+use crate::coord::Shift;
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
+use crate::element::Circle;
+use crate::series::LineSeries;
+use crate::style::{Color, RGBAColor, ShapeStyle, BLACK};
+
+/// The helper that draws a sparkline, a tiny line chart without axes or labels, which fills the
+/// whole drawing area. It's useful for the inline charts in tables and dense dashboards.
+///
+/// The X coordinate of each value is its index, and the Y range is fitted to the values. The
+/// non-finite values are skipped.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let root = BitMapBackend::new("plotters-doc-data/sparkline.png", (100, 20))
+///     .into_drawing_area();
+/// root.fill(&WHITE).unwrap();
+///
+/// Sparkline::new(&root, vec![3.0, 5.0, 2.0, 8.0, 6.0, 7.0])
+///     .mark_extremes(&BLUE, &RED)
+///     .mark_last(&BLACK)
+///     .draw()
+///     .unwrap();
+/// ```
+pub struct Sparkline<'a, DB: DrawingBackend> {
+    area: &'a DrawingArea<DB, Shift>,
+    data: Vec<f64>,
+    style: ShapeStyle,
+    extremes: Option<(RGBAColor, RGBAColor)>,
+    last: Option<RGBAColor>,
+    marker_size: u32,
+}
+
+impl<'a, DB: DrawingBackend> Sparkline<'a, DB> {
+    /// Create a new sparkline
+    /// - `area`: The drawing area the sparkline fills
+    /// - `data`: The values to plot
+    pub fn new<I: IntoIterator<Item = f64>>(area: &'a DrawingArea<DB, Shift>, data: I) -> Self {
+        Self {
+            area,
+            data: data.into_iter().collect(),
+            style: (&BLACK).into(),
+            extremes: None,
+            last: None,
+            marker_size: 2,
+        }
+    }
+
+    /// Set the style of the line
+    pub fn style<S: Into<ShapeStyle>>(&mut self, style: S) -> &mut Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Mark the minimum and the maximum values with dots
+    /// - `min_color`: The color of the dot on the minimum value
+    /// - `max_color`: The color of the dot on the maximum value
+    pub fn mark_extremes<C: Color>(&mut self, min_color: &C, max_color: &C) -> &mut Self {
+        self.extremes = Some((min_color.to_rgba(), max_color.to_rgba()));
+        self
+    }
+
+    /// Mark the last value with a dot
+    pub fn mark_last<C: Color>(&mut self, color: &C) -> &mut Self {
+        self.last = Some(color.to_rgba());
+        self
+    }
+
+    /// Set the radius of the dots in pixels
+    pub fn marker_size(&mut self, size: u32) -> &mut Self {
+        self.marker_size = size;
+        self
+    }
+
+    /// Draw the sparkline
+    pub fn draw(&self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = self
+            .data
+            .iter()
+            .enumerate()
+            .filter(|(_, v)| v.is_finite())
+            .map(|(idx, v)| (idx as f64, *v))
+            .collect();
+        let (min, max) = match (
+            points.iter().min_by(|a, b| a.1.partial_cmp(&b.1).unwrap()),
+            points.iter().max_by(|a, b| a.1.partial_cmp(&b.1).unwrap()),
+        ) {
+            (Some(min), Some(max)) => (*min, *max),
+            _ => return Ok(()),
+        };
+
+        let y_range = if max.1 > min.1 {
+            min.1..max.1
+        } else {
+            (min.1 - 0.5)..(max.1 + 0.5)
+        };
+        let x_range = 0.0..(self.data.len().max(2) - 1) as f64;
+        let has_marker = self.extremes.is_some() || self.last.is_some();
+        let margin = if has_marker {
+            self.marker_size + 1
+        } else {
+            self.style.stroke_width / 2 + 1
+        };
+
+        let mut chart = ChartBuilder::on(self.area)
+            .margin(margin)
+            .build_cartesian_2d(x_range, y_range)?;
+        chart.draw_series(LineSeries::new(points.iter().copied(), self.style.clone()))?;
+
+        let mut markers = vec![];
+        if let Some((min_color, max_color)) = self.extremes {
+            markers.push((min, min_color));
+            markers.push((max, max_color));
+        }
+        if let (Some(color), Some(last)) = (self.last, points.last()) {
+            markers.push((*last, color));
+        }
+        chart.draw_series(
+            markers
+                .into_iter()
+                .map(|(point, color)| Circle::new(point, self.marker_size, color.filled())),
+        )?;
+        Ok(())
+    }
+}
+
+/// Draw a sparkline with the default style, see [Sparkline](struct.Sparkline.html) for the
+/// details and the options
+/// - `area`: The drawing area the sparkline fills
+/// - `data`: The values to plot
+pub fn sparkline<DB: DrawingBackend, I: IntoIterator<Item = f64>>(
+    area: &DrawingArea<DB, Shift>,
+    data: I,
+) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+    Sparkline::new(area, data).draw()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_sparkline() {
+        let drawing_area = create_mocked_drawing_area(100, 20, |m| {
+            m.check_draw_circle(|c, _, _, pos, _| {
+                assert_eq!(c, BLUE.to_rgba());
+                assert!(pos.1 > 10);
+            });
+            m.check_draw_circle(|c, _, _, pos, _| {
+                assert_eq!(c, RED.to_rgba());
+                assert!(pos.1 < 10);
+            });
+            m.check_draw_circle(|c, _, _, pos, _| {
+                assert_eq!(c, BLACK.to_rgba());
+                assert!(pos.0 > 90);
+                assert!(pos.1 < 10);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 1);
+                assert_eq!(b.num_draw_circle_call, 3);
+                assert_eq!(b.num_draw_text_call, 0);
+                assert_eq!(b.num_draw_line_call, 0);
+            });
+        });
+
+        Sparkline::new(&drawing_area, vec![1.0, 3.0, std::f64::NAN, 5.0, 4.0])
+            .mark_extremes(&BLUE, &RED)
+            .mark_last(&BLACK)
+            .draw()
+            .expect("Drawing error");
+    }
+}
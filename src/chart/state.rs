@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use super::context::ChartLayers;
 use super::ChartContext;
 use crate::coord::{CoordTranslate, Shift};
 use crate::drawing::DrawingArea;
@@ -101,12 +102,18 @@ impl<CT: CoordTranslate> ChartState<CT> {
         let area = area
             .clone()
             .shrink(self.drawing_area_pos, self.drawing_area_size);
+        let layers = ChartLayers::new(&area);
         ChartContext {
             x_label_area: [None, None],
             y_label_area: [None, None],
             drawing_area: area.apply_coord_spec(self.coord),
             series_anno: vec![],
+            layers,
+            title_area: None,
+            footer_area: None,
+            colorbar_area: None,
             drawing_area_pos: self.drawing_area_pos,
+            label_area_layout: None,
         }
     }
 }
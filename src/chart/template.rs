@@ -0,0 +1,191 @@
+use std::ops::Range;
+
+use plotters_backend::DrawingBackend;
+
+use super::{ChartBuilder, LegendMarker, Theme};
+use crate::coord::Shift;
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
+use crate::series::LineSeries;
+
+type RangeFunc<'a, D> = dyn Fn(&D) -> (Range<f64>, Range<f64>) + 'a;
+type SeriesFunc<'a, D> = dyn Fn(&D) -> Vec<(f64, f64)> + 'a;
+
+/// The reusable layout of a line chart, which is rendered with different data on any drawing
+/// backend.
+///
+/// The template holds the configuration of the chart, i.e. the theme, the caption and the axis
+/// descriptions, and the closures computing the axis ranges and the series from the data. So
+/// the dashboards regenerating the same charts with fresh data don't repeat the builder setup.
+/// The series are colored by the palette of the theme and labeled in the legend.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// struct Sales {
+///     online: Vec<f64>,
+///     retail: Vec<f64>,
+/// }
+///
+/// let by_month = |values: &Vec<f64>| {
+///     values
+///         .iter()
+///         .enumerate()
+///         .map(|(month, value)| (month as f64, *value))
+///         .collect::<Vec<_>>()
+/// };
+/// let template = ChartTemplate::new(|sales: &Sales| {
+///     let max = sales.online.iter().chain(&sales.retail).fold(0.0, |a, b| b.max(a));
+///     (0.0..11.0, 0.0..max * 1.1)
+/// })
+/// .caption("Monthly sales")
+/// .x_desc("Month")
+/// .series("Online", move |sales: &Sales| by_month(&sales.online))
+/// .series("Retail", move |sales: &Sales| by_month(&sales.retail));
+///
+/// let mut buffer = vec![0; 640 * 480 * 3];
+/// let root = BitMapBackend::with_buffer(&mut buffer, (640, 480)).into_drawing_area();
+/// let (left, right) = root.split_horizontally(320);
+/// template
+///     .render(&left, &Sales { online: vec![3.0; 12], retail: vec![5.0; 12] })
+///     .unwrap();
+/// template
+///     .render(&right, &Sales { online: vec![4.0; 12], retail: vec![2.0; 12] })
+///     .unwrap();
+/// ```
+pub struct ChartTemplate<'a, D> {
+    theme: Theme<'a>,
+    caption: Option<String>,
+    x_desc: Option<String>,
+    y_desc: Option<String>,
+    ranges: Box<RangeFunc<'a, D>>,
+    series: Vec<(String, Box<SeriesFunc<'a, D>>)>,
+}
+
+impl<'a, D> ChartTemplate<'a, D> {
+    /// Create a new chart template
+    /// - `ranges`: The function computes the X and Y ranges of the chart from the data
+    pub fn new<F: Fn(&D) -> (Range<f64>, Range<f64>) + 'a>(ranges: F) -> Self {
+        Self {
+            theme: Theme::default(),
+            caption: None,
+            x_desc: None,
+            y_desc: None,
+            ranges: Box::new(ranges),
+            series: vec![],
+        }
+    }
+
+    /// Set the theme of the chart, by default the light theme is used
+    pub fn theme(mut self, theme: Theme<'a>) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Set the caption of the chart
+    pub fn caption<S: Into<String>>(mut self, caption: S) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Set the description of the X axis
+    pub fn x_desc<S: Into<String>>(mut self, desc: S) -> Self {
+        self.x_desc = Some(desc.into());
+        self
+    }
+
+    /// Set the description of the Y axis
+    pub fn y_desc<S: Into<String>>(mut self, desc: S) -> Self {
+        self.y_desc = Some(desc.into());
+        self
+    }
+
+    /// Add a line series to the chart
+    /// - `label`: The label of the series in the legend
+    /// - `points`: The function computes the points of the series from the data
+    pub fn series<S: Into<String>, F: Fn(&D) -> Vec<(f64, f64)> + 'a>(
+        mut self,
+        label: S,
+        points: F,
+    ) -> Self {
+        self.series.push((label.into(), Box::new(points)));
+        self
+    }
+
+    /// Render the chart with the data
+    /// - `area`: The drawing area the chart is rendered on
+    /// - `data`: The data of the chart
+    pub fn render<DB: DrawingBackend>(
+        &self,
+        area: &DrawingArea<DB, Shift>,
+        data: &D,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let (x_range, y_range) = (self.ranges)(data);
+
+        let mut builder = ChartBuilder::on(area);
+        self.theme.apply_to(&mut builder)?;
+        if let Some(caption) = self.caption.as_ref() {
+            builder.caption(caption, self.theme.caption_style());
+        }
+        let mut chart = builder.build_cartesian_2d(x_range, y_range)?;
+
+        let mut mesh = chart.configure_mesh();
+        self.theme.apply_to_mesh(&mut mesh);
+        if let Some(desc) = self.x_desc.as_ref() {
+            mesh.x_desc(desc.as_str());
+        }
+        if let Some(desc) = self.y_desc.as_ref() {
+            mesh.y_desc(desc.as_str());
+        }
+        mesh.draw()?;
+
+        for (idx, (label, points)) in self.series.iter().enumerate() {
+            let color = self.theme.pick_color(idx);
+            chart
+                .draw_series(LineSeries::new(points(data), color))?
+                .label(label.as_str())
+                .legend_marker(LegendMarker::Line, color);
+        }
+
+        if !self.series.is_empty() {
+            let mut labels = chart.configure_series_labels();
+            self.theme.apply_to_series_labels(&mut labels);
+            labels.draw()?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_render_template() {
+        let template = ChartTemplate::new(|data: &Vec<(f64, f64)>| {
+            let max = data.iter().fold(1.0, |max: f64, p| max.max(p.1));
+            (0.0..10.0, 0.0..max)
+        })
+        .caption("Template")
+        .series("Data", |data: &Vec<(f64, f64)>| data.clone());
+
+        for &points in [3, 4].iter() {
+            let drawn = std::rc::Rc::new(std::cell::RefCell::new((vec![], vec![])));
+            let (texts_ref, paths_ref) = (drawn.clone(), drawn.clone());
+            let drawing_area = create_mocked_drawing_area(300, 200, move |m| {
+                m.check_draw_text(move |_, _, _, _, text| {
+                    texts_ref.borrow_mut().0.push(text.to_string());
+                });
+                m.check_draw_path(move |_, _, path| paths_ref.borrow_mut().1.push(path.len()));
+            });
+            let data: Vec<_> = (0..points).map(|x| (x as f64, 5.0 * x as f64)).collect();
+            template.render(&drawing_area, &data).expect("Render error");
+            drop(drawing_area);
+
+            let (texts, paths) = &*drawn.borrow();
+            assert!(texts.iter().any(|t| t == "Template"));
+            assert!(texts.iter().any(|t| t == "Data"));
+            assert!(paths.contains(&points));
+        }
+    }
+}
@@ -0,0 +1,245 @@
+use plotters_backend::DrawingBackend;
+
+use super::builder::ChartBuilder;
+use super::mesh::MeshStyle;
+use super::series::SeriesLabelStyle;
+use crate::coord::ranged1d::Ranged;
+use crate::coord::CoordTranslate;
+use crate::drawing::DrawingAreaErrorKind;
+use crate::style::{
+    Color, IntoFont, Palette, Palette99, RGBAColor, RGBColor, ShapeStyle, TextStyle, BLACK, WHITE,
+};
+
+/// The set of styles shared by multiple charts, so that they have a consistent look without
+/// repeating the same configuration for each chart.
+///
+/// A theme styles the chart builder, the mesh and the series labels. The built-in themes are
+/// [Theme::light](#method.light) and [Theme::dark](#method.dark), and all the fields are public,
+/// so a custom theme is usually derived from one of them.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let theme = Theme::dark();
+/// let root = BitMapBackend::new("plotters-doc-data/theme.png", (640, 480)).into_drawing_area();
+///
+/// let mut builder = ChartBuilder::on(&root);
+/// theme.apply_to(&mut builder).unwrap();
+/// let mut chart = builder
+///     .caption("Dark theme", theme.caption_style())
+///     .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+///     .unwrap();
+///
+/// let mut mesh = chart.configure_mesh();
+/// theme.apply_to_mesh(&mut mesh);
+/// mesh.draw().unwrap();
+///
+/// chart
+///     .draw_series(LineSeries::new(vec![(0.0, 1.0), (10.0, 9.0)], theme.pick_color(0)))
+///     .unwrap();
+/// ```
+#[derive(Clone)]
+pub struct Theme<'a> {
+    /// The font family of all the text
+    pub font_family: &'a str,
+    /// The font size of the caption
+    pub caption_size: f64,
+    /// The font size of the tick labels, the axis descriptions and the series labels
+    pub label_size: f64,
+    /// The background color of the chart
+    pub background: RGBAColor,
+    /// The color of the text
+    pub text_color: RGBAColor,
+    /// The style of the axis lines
+    pub axis_style: ShapeStyle,
+    /// The style of the bold grid lines
+    pub bold_line_style: ShapeStyle,
+    /// The style of the light grid lines
+    pub light_line_style: ShapeStyle,
+    /// The background color of the series labels
+    pub legend_background: RGBAColor,
+    /// The colors of the series, picked in order
+    pub palette: Vec<RGBAColor>,
+    /// The margin of the chart in pixels
+    pub margin: u32,
+    /// The size of the label areas on the left and the bottom in pixels
+    pub label_area_size: u32,
+}
+
+impl<'a> Theme<'a> {
+    /// The theme with dark text and lines on a white background
+    pub fn light() -> Self {
+        Self {
+            font_family: "sans-serif",
+            caption_size: 24.0,
+            label_size: 12.0,
+            background: WHITE.to_rgba(),
+            text_color: BLACK.to_rgba(),
+            axis_style: (&BLACK).into(),
+            bold_line_style: (&BLACK.mix(0.2)).into(),
+            light_line_style: (&BLACK.mix(0.1)).into(),
+            legend_background: WHITE.mix(0.8),
+            palette: Palette99::COLORS
+                .iter()
+                .map(|&(r, g, b)| RGBColor(r, g, b).to_rgba())
+                .collect(),
+            margin: 10,
+            label_area_size: 40,
+        }
+    }
+
+    /// The theme with light text and lines on a dark background. The series palette is
+    /// lightened, so the series stand out from the background
+    pub fn dark() -> Self {
+        let text = RGBColor(220, 220, 220);
+        Self {
+            background: RGBColor(32, 32, 36).to_rgba(),
+            text_color: text.to_rgba(),
+            axis_style: (&text).into(),
+            bold_line_style: (&WHITE.mix(0.2)).into(),
+            light_line_style: (&WHITE.mix(0.08)).into(),
+            legend_background: RGBColor(48, 48, 54).mix(0.8),
+            palette: Palette99::COLORS
+                .iter()
+                .map(|&(r, g, b)| {
+                    let lighten = |c: u8| c + ((255 - c) as f64 * 0.35) as u8;
+                    RGBColor(lighten(r), lighten(g), lighten(b)).to_rgba()
+                })
+                .collect(),
+            ..Self::light()
+        }
+    }
+
+    /// Pick the light or the dark theme, whichever fits the background color, and use the
+    /// color as the background of the theme
+    /// - `background`: The background color of the chart
+    pub fn for_background<C: Color>(background: &C) -> Self {
+        let (r, g, b) = background.to_backend_color().rgb;
+        let mut theme = if RGBColor(r, g, b).best_contrast_text() == RGBColor(0, 0, 0) {
+            Self::light()
+        } else {
+            Self::dark()
+        };
+        theme.background = background.to_rgba();
+        theme
+    }
+
+    /// Get the color of the `idx`-th series, the palette is repeated if there are more series
+    /// than colors
+    pub fn pick_color(&self, idx: usize) -> RGBAColor {
+        if self.palette.is_empty() {
+            return self.text_color;
+        }
+        self.palette[idx % self.palette.len()]
+    }
+
+    fn text_style(&self, size: f64) -> TextStyle<'a> {
+        let mut style: TextStyle = (self.font_family, size).into_font().into();
+        style.color = self.text_color.to_backend_color();
+        style
+    }
+
+    /// Get the text style of the caption
+    pub fn caption_style(&self) -> TextStyle<'a> {
+        self.text_style(self.caption_size)
+    }
+
+    /// Get the text style of the labels
+    pub fn label_style(&self) -> TextStyle<'a> {
+        self.text_style(self.label_size)
+    }
+
+    /// Fill the background of the chart, and set the margin and the label area sizes of the
+    /// chart builder
+    pub fn apply_to<DB: DrawingBackend>(
+        &self,
+        builder: &mut ChartBuilder<DB>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        builder.root_area().fill(&self.background)?;
+        builder
+            .margin(self.margin)
+            .set_left_and_bottom_label_area_size(self.label_area_size);
+        Ok(())
+    }
+
+    /// Set the line styles and the text styles of the mesh
+    pub fn apply_to_mesh<'b, X: Ranged, Y: Ranged, DB: DrawingBackend>(
+        &self,
+        mesh: &mut MeshStyle<'_, 'b, X, Y, DB>,
+    ) where
+        'a: 'b,
+    {
+        mesh.axis_style(self.axis_style.clone())
+            .bold_line_style(self.bold_line_style.clone())
+            .light_line_style(self.light_line_style.clone())
+            .label_style(self.label_style())
+            .axis_desc_style(self.label_style());
+    }
+
+    /// Set the background, the border and the font of the series labels
+    pub fn apply_to_series_labels<'b, 'c, DB: DrawingBackend + 'c, CT: CoordTranslate>(
+        &self,
+        labels: &mut SeriesLabelStyle<'c, 'b, DB, CT>,
+    ) where
+        'a: 'b,
+    {
+        labels
+            .background_style(self.legend_background)
+            .border_style(self.axis_style.clone())
+            .label_font(self.label_style());
+    }
+}
+
+impl<'a> Default for Theme<'a> {
+    fn default() -> Self {
+        Self::light()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_theme() {
+        let theme = Theme::dark();
+        let background = theme.background;
+        let text = theme.text_color;
+        let drawing_area = create_mocked_drawing_area(400, 300, move |m| {
+            m.check_draw_rect(move |c, _, f, u, d| {
+                assert_eq!(c, background);
+                assert!(f);
+                assert_eq!(u, (0, 0));
+                assert_eq!(d, (399, 299));
+            });
+            m.check_draw_text(move |c, _, _, _, _| {
+                assert_eq!(c, text);
+            });
+        });
+
+        let mut builder = ChartBuilder::on(&drawing_area);
+        theme.apply_to(&mut builder).unwrap();
+        let mut chart = builder.build_cartesian_2d(0..10, 0..10).unwrap();
+
+        let mut mesh = chart.configure_mesh();
+        theme.apply_to_mesh(&mut mesh);
+        mesh.disable_mesh().draw().unwrap();
+
+        assert_eq!(theme.pick_color(0), theme.pick_color(theme.palette.len()));
+    }
+
+    #[test]
+    fn test_theme_for_background() {
+        let light = Theme::for_background(&RGBColor(250, 250, 240));
+        assert_eq!(light.text_color, BLACK.to_rgba());
+        assert_eq!(light.background, RGBColor(250, 250, 240).to_rgba());
+
+        let dark = Theme::for_background(&BLACK);
+        assert_eq!(dark.background, BLACK.to_rgba());
+        assert_eq!(dark.text_color, Theme::dark().text_color);
+        for (l, d) in light.palette.iter().zip(dark.palette.iter()) {
+            assert!(d.0 >= l.0 && d.1 >= l.1 && d.2 >= l.2);
+        }
+    }
+}
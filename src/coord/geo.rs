@@ -0,0 +1,342 @@
+/*!
+ The map projections for geographic plotting.
+
+ Each projection is a coordinate specification which maps the `(longitude, latitude)` in degrees
+ to the pixel coordinate, so the geographic data can be drawn with any element and series by
+ applying the projection to a drawing area:
+
+ ```rust
+ use plotters::prelude::*;
+ use plotters::coord::geo::{graticule, Orthographic};
+
+ let root = BitMapBackend::new("plotters-doc-data/orthographic.png", (400, 400))
+     .into_drawing_area();
+ root.fill(&WHITE).unwrap();
+
+ let globe = root.apply_coord_spec(Orthographic::new((10.0, 45.0), root.get_pixel_range()));
+ for line in graticule(30.0, 2.0) {
+     for part in globe.as_coord_spec().clip_path(&line) {
+         root.draw(&PathElement::new(part, &BLACK.mix(0.3))).unwrap();
+     }
+ }
+ ```
+*/
+
+use std::f64::consts::PI;
+use std::ops::Range;
+
+use crate::coord::{snap_pixel, CoordTranslate, ReverseCoordTranslate};
+use plotters_backend::BackendCoord;
+
+/// The largest latitude the Web Mercator projection can represent, in degrees
+pub const MERCATOR_MAX_LATITUDE: f64 = 85.051_128_779_806_59;
+
+fn lerp_pixel(value: f64, from: &Range<f64>, to: (i32, i32)) -> i32 {
+    let ratio = (value - from.start) / (from.end - from.start);
+    snap_pixel(f64::from(to.0) + ratio * f64::from(to.1 - to.0)).round() as i32
+}
+
+fn unlerp_pixel(pixel: i32, from: (i32, i32), to: &Range<f64>) -> Option<f64> {
+    if from.0 == from.1 {
+        return None;
+    }
+    let ratio = f64::from(pixel - from.0) / f64::from(from.1 - from.0);
+    Some(to.start + ratio * (to.end - to.start))
+}
+
+/// The equirectangular projection, which maps the longitude and the latitude linearly to the
+/// X and Y axis
+#[derive(Clone, Debug)]
+pub struct Equirectangular {
+    lon: Range<f64>,
+    lat: Range<f64>,
+    back_x: (i32, i32),
+    back_y: (i32, i32),
+}
+
+impl Equirectangular {
+    /// Create a new equirectangular projection
+    /// - `lon`: The range of the longitude visible in the pixel range
+    /// - `lat`: The range of the latitude visible in the pixel range
+    /// - `actual`: The pixel range on the screen for this projection
+    pub fn new(lon: Range<f64>, lat: Range<f64>, actual: (Range<i32>, Range<i32>)) -> Self {
+        Self {
+            lon,
+            lat,
+            back_x: (actual.0.start, actual.0.end),
+            back_y: (actual.1.end, actual.1.start),
+        }
+    }
+}
+
+impl CoordTranslate for Equirectangular {
+    type From = (f64, f64);
+    fn translate(&self, &(lon, lat): &Self::From) -> BackendCoord {
+        (
+            lerp_pixel(lon, &self.lon, self.back_x),
+            lerp_pixel(lat, &self.lat, self.back_y),
+        )
+    }
+}
+
+impl ReverseCoordTranslate for Equirectangular {
+    fn reverse_translate(&self, input: BackendCoord) -> Option<Self::From> {
+        Some((
+            unlerp_pixel(input.0, self.back_x, &self.lon)?,
+            unlerp_pixel(input.1, self.back_y, &self.lat)?,
+        ))
+    }
+}
+
+/// The Web Mercator projection, which is used by most of the web map services. The latitudes
+/// beyond [MERCATOR_MAX_LATITUDE](constant.MERCATOR_MAX_LATITUDE.html) are clamped.
+#[derive(Clone, Debug)]
+pub struct WebMercator {
+    lon: Range<f64>,
+    y: Range<f64>,
+    back_x: (i32, i32),
+    back_y: (i32, i32),
+}
+
+fn mercator_y(lat: f64) -> f64 {
+    let lat = lat.max(-MERCATOR_MAX_LATITUDE).min(MERCATOR_MAX_LATITUDE);
+    (PI / 4.0 + lat.to_radians() / 2.0).tan().ln()
+}
+
+impl WebMercator {
+    /// Create a new Web Mercator projection
+    /// - `lon`: The range of the longitude visible in the pixel range
+    /// - `lat`: The range of the latitude visible in the pixel range
+    /// - `actual`: The pixel range on the screen for this projection
+    pub fn new(lon: Range<f64>, lat: Range<f64>, actual: (Range<i32>, Range<i32>)) -> Self {
+        Self {
+            lon,
+            y: mercator_y(lat.start)..mercator_y(lat.end),
+            back_x: (actual.0.start, actual.0.end),
+            back_y: (actual.1.end, actual.1.start),
+        }
+    }
+}
+
+impl CoordTranslate for WebMercator {
+    type From = (f64, f64);
+    fn translate(&self, &(lon, lat): &Self::From) -> BackendCoord {
+        (
+            lerp_pixel(lon, &self.lon, self.back_x),
+            lerp_pixel(mercator_y(lat), &self.y, self.back_y),
+        )
+    }
+}
+
+impl ReverseCoordTranslate for WebMercator {
+    fn reverse_translate(&self, input: BackendCoord) -> Option<Self::From> {
+        let y = unlerp_pixel(input.1, self.back_y, &self.y)?;
+        Some((
+            unlerp_pixel(input.0, self.back_x, &self.lon)?,
+            (2.0 * y.exp().atan() - PI / 2.0).to_degrees(),
+        ))
+    }
+}
+
+/// The orthographic projection, which shows the globe as seen from the infinite distance. The
+/// globe is the largest circle fits into the pixel range.
+///
+/// Only one hemisphere is visible, the points on the back hemisphere are clamped to the horizon,
+/// so that a shape crossing the horizon is flattened along the edge of the globe rather than
+/// folded onto the front side. Use [is_visible](#method.is_visible) or
+/// [clip_path](#method.clip_path) to drop the hidden points instead.
+#[derive(Clone, Debug)]
+pub struct Orthographic {
+    center: (f64, f64),
+    radius: f64,
+    origin: (f64, f64),
+}
+
+impl Orthographic {
+    /// Create a new orthographic projection
+    /// - `center`: The `(longitude, latitude)` that faces the viewer
+    /// - `actual`: The pixel range on the screen for this projection
+    pub fn new(center: (f64, f64), actual: (Range<i32>, Range<i32>)) -> Self {
+        let (w, h) = (actual.0.end - actual.0.start, actual.1.end - actual.1.start);
+        Self {
+            center: (center.0.to_radians(), center.1.to_radians()),
+            radius: f64::from(w.min(h).max(0)) / 2.0,
+            origin: (
+                f64::from(actual.0.start) + f64::from(w) / 2.0,
+                f64::from(actual.1.start) + f64::from(h) / 2.0,
+            ),
+        }
+    }
+
+    /// Get the radius of the globe in pixels
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    /// Project the point onto the unit globe, returns the offset from the globe center and the
+    /// cosine of the angular distance from the view center, which is negative for the points on
+    /// the back hemisphere
+    fn project(&self, (lon, lat): (f64, f64)) -> (f64, f64, f64) {
+        let (lon0, lat0) = self.center;
+        let (lon, lat) = (lon.to_radians() - lon0, lat.to_radians());
+        let x = lat.cos() * lon.sin();
+        let y = lat0.cos() * lat.sin() - lat0.sin() * lat.cos() * lon.cos();
+        let cos_c = lat0.sin() * lat.sin() + lat0.cos() * lat.cos() * lon.cos();
+        (x, y, cos_c)
+    }
+
+    /// Check if the point is on the visible hemisphere
+    pub fn is_visible(&self, coord: &(f64, f64)) -> bool {
+        self.project(*coord).2 >= 0.0
+    }
+
+    /// Split the path into the visible parts, the points on the back hemisphere are dropped
+    /// - **returns**: The list of the visible parts in pixels
+    pub fn clip_path(&self, path: &[(f64, f64)]) -> Vec<Vec<BackendCoord>> {
+        let mut parts = vec![];
+        let mut current = vec![];
+        for p in path {
+            if self.is_visible(p) {
+                current.push(self.translate(p));
+            } else if !current.is_empty() {
+                parts.push(std::mem::replace(&mut current, vec![]));
+            }
+        }
+        if !current.is_empty() {
+            parts.push(current);
+        }
+        parts
+    }
+}
+
+impl CoordTranslate for Orthographic {
+    type From = (f64, f64);
+    fn translate(&self, from: &Self::From) -> BackendCoord {
+        let (mut x, mut y, cos_c) = self.project(*from);
+        if cos_c < 0.0 {
+            let len = x.hypot(y);
+            if len > 0.0 {
+                x /= len;
+                y /= len;
+            } else {
+                x = 1.0;
+            }
+        }
+        (
+            snap_pixel(self.origin.0 + x * self.radius).round() as i32,
+            snap_pixel(self.origin.1 - y * self.radius).round() as i32,
+        )
+    }
+
+    fn depth(&self, from: &Self::From) -> i32 {
+        snap_pixel(self.project(*from).2 * self.radius).round() as i32
+    }
+}
+
+impl ReverseCoordTranslate for Orthographic {
+    fn reverse_translate(&self, input: BackendCoord) -> Option<Self::From> {
+        if self.radius <= 0.0 {
+            return None;
+        }
+        let x = (f64::from(input.0) - self.origin.0) / self.radius;
+        let y = (self.origin.1 - f64::from(input.1)) / self.radius;
+        let rho = x.hypot(y);
+        if rho > 1.0 {
+            return None;
+        }
+        let (lon0, lat0) = self.center;
+        if rho == 0.0 {
+            return Some((lon0.to_degrees(), lat0.to_degrees()));
+        }
+        let (sin_c, cos_c) = (rho, (1.0 - rho * rho).sqrt());
+        let lat = (cos_c * lat0.sin() + y * sin_c * lat0.cos() / rho).asin();
+        let lon = lon0 + (x * sin_c).atan2(rho * cos_c * lat0.cos() - y * sin_c * lat0.sin());
+        Some((lon.to_degrees(), lat.to_degrees()))
+    }
+}
+
+/// Generate the graticule, which is the meridians and the parallels of the globe
+/// - `step`: The distance between two adjacent lines, in degrees
+/// - `resolution`: The distance between two adjacent points on a line, in degrees
+/// - **returns**: The list of lines, each line is a list of `(longitude, latitude)`
+pub fn graticule(step: f64, resolution: f64) -> Vec<Vec<(f64, f64)>> {
+    let steps = |from: f64, to: f64, step: f64| {
+        let n = ((to - from) / step).floor() as i32;
+        (0..=n).map(move |i| from + f64::from(i) * step)
+    };
+    let mut lines = vec![];
+    if step <= 0.0 || resolution <= 0.0 {
+        return lines;
+    }
+    for lon in steps(-180.0, 180.0 - step / 2.0, step) {
+        lines.push(
+            steps(-90.0, 90.0, resolution)
+                .map(|lat| (lon, lat))
+                .collect(),
+        );
+    }
+    for lat in steps(-90.0 + step, 90.0 - step / 2.0, step) {
+        lines.push(
+            steps(-180.0, 180.0, resolution)
+                .map(|lon| (lon, lat))
+                .collect(),
+        );
+    }
+    lines
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_equirectangular() {
+        let proj = Equirectangular::new(-180.0..180.0, -90.0..90.0, (0..360, 0..180));
+        assert_eq!(proj.translate(&(0.0, 0.0)), (180, 90));
+        assert_eq!(proj.translate(&(-180.0, 90.0)), (0, 0));
+        assert_eq!(proj.reverse_translate((270, 45)), Some((90.0, 45.0)));
+    }
+
+    #[test]
+    fn test_web_mercator() {
+        let limit = MERCATOR_MAX_LATITUDE;
+        let proj = WebMercator::new(-180.0..180.0, -limit..limit, (0..256, 0..256));
+        assert_eq!(proj.translate(&(0.0, 0.0)), (128, 128));
+        assert_eq!(proj.translate(&(180.0, 90.0)), (256, 0));
+        assert_eq!(proj.translate(&(0.0, 60.0)), (128, 74));
+        let (lon, lat) = proj.reverse_translate((128, 64)).unwrap();
+        assert!(lon.abs() < 1e-10);
+        assert!((proj.translate(&(lon, lat)).1 - 64).abs() <= 1);
+    }
+
+    #[test]
+    fn test_orthographic() {
+        let proj = Orthographic::new((0.0, 0.0), (0..200, 0..100));
+        assert_eq!(proj.radius(), 50.0);
+        assert_eq!(proj.translate(&(0.0, 0.0)), (100, 50));
+        assert_eq!(proj.translate(&(90.0, 0.0)), (150, 50));
+        assert_eq!(proj.translate(&(0.0, 90.0)), (100, 0));
+
+        assert!(!proj.is_visible(&(150.0, 10.0)));
+        assert_eq!(proj.translate(&(135.0, 0.0)), (150, 50));
+        assert_eq!(
+            proj.clip_path(&[(0.0, 0.0), (180.0, 0.0), (0.0, 45.0)])
+                .len(),
+            2
+        );
+        assert!(proj.depth(&(0.0, 0.0)) > proj.depth(&(80.0, 0.0)));
+
+        let (lon, lat) = proj.reverse_translate((125, 25)).unwrap();
+        assert_eq!(proj.translate(&(lon, lat)), (125, 25));
+        assert_eq!(proj.reverse_translate((0, 0)), None);
+    }
+
+    #[test]
+    fn test_graticule() {
+        let lines = graticule(30.0, 10.0);
+        assert_eq!(lines.len(), 12 + 5);
+        assert_eq!(lines[0].len(), 19);
+        assert_eq!(lines[0][0], (-180.0, -90.0));
+        assert_eq!(lines[12][0], (-180.0, -60.0));
+    }
+}
@@ -21,6 +21,7 @@ See documentation for module [combinators](combinators/index.html)  for details.
 Currently we support the following 2D coordinate system:
 
 - 2-dimensional Cartesian Coordinate: This is done by the combinator [Cartesian2d](cartesian/struct.Cartesian2d.html).
+- Map projections: The equirectangular, Web Mercator and orthographic projections for geographic data. See module [geo](geo/index.html).
 
 */
 
@@ -42,17 +43,30 @@ pub mod types {
     pub use super::ranged1d::types::*;
 }
 
+pub mod geo;
 mod ranged2d;
 pub mod ranged3d;
 
 pub mod cartesian {
-    pub use super::ranged2d::cartesian::{Cartesian2d, MeshLine};
+    pub use super::ranged2d::cartesian::{Cartesian2d, Cartesian2dMapping, MeshLine};
     pub use super::ranged3d::Cartesian3d;
 }
 
 mod translate;
 pub use translate::{CoordTranslate, ReverseCoordTranslate};
 
+/// Prepare a pixel position computed in floating point for the conversion into an integer.
+/// With the `deterministic` feature, the position is snapped to 1/1024 pixel, thus the results
+/// of the math functions, whose last bits differ across platforms, round to the same pixel.
+#[inline(always)]
+pub(crate) fn snap_pixel(value: f64) -> f64 {
+    if cfg!(feature = "deterministic") {
+        (value * 1024.0).round() / 1024.0
+    } else {
+        value
+    }
+}
+
 /// The coordinate translation that only impose shift
 #[derive(Debug, Clone)]
 pub struct Shift(pub BackendCoord);
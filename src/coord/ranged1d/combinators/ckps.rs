@@ -74,6 +74,10 @@ where
     fn axis_pixel_range(&self, limit: (i32, i32)) -> Range<i32> {
         self.inner.axis_pixel_range(limit)
     }
+
+    fn value_floor(&self) -> Option<Self::ValueType> {
+        self.inner.value_floor()
+    }
 }
 
 impl<R: DiscreteRanged> DiscreteRanged for WithKeyPoints<R>
@@ -197,6 +201,10 @@ impl<R: Ranged> Ranged for WithKeyPointMethod<R> {
     fn axis_pixel_range(&self, limit: (i32, i32)) -> Range<i32> {
         self.inner.axis_pixel_range(limit)
     }
+
+    fn value_floor(&self) -> Option<Self::ValueType> {
+        self.inner.value_floor()
+    }
 }
 
 impl<R: DiscreteRanged> DiscreteRanged for WithKeyPointMethod<R> {
@@ -221,7 +229,10 @@ mod test {
         assert_eq!(range.map(&3, (0, 1000)), 30);
         assert_eq!(range.range(), 0..100);
         assert_eq!(range.key_points(BoldPoints(100)), vec![1, 2, 3]);
-        assert_eq!(range.key_points(LightPoints::new(100, 100)), vec![]);
+        assert_eq!(
+            range.key_points(LightPoints::new(100, 100)),
+            Vec::<i32>::new()
+        );
         let range = range.with_light_points(5..10);
         assert_eq!(range.key_points(BoldPoints(10)), vec![1, 2, 3]);
         assert_eq!(
@@ -249,7 +260,10 @@ mod test {
         assert_eq!(range.map(&3, (0, 1000)), 30);
         assert_eq!(range.range(), 0..100);
         assert_eq!(range.key_points(BoldPoints(100)), vec![1, 2, 3]);
-        assert_eq!(range.key_points(LightPoints::new(100, 100)), vec![]);
+        assert_eq!(
+            range.key_points(LightPoints::new(100, 100)),
+            Vec::<i32>::new()
+        );
         let range = range.with_light_point_func(|_| (5..10).collect());
         assert_eq!(range.key_points(BoldPoints(10)), vec![1, 2, 3]);
         assert_eq!(
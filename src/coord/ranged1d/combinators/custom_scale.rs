@@ -0,0 +1,190 @@
+use crate::coord::ranged1d::{
+    AsRangedCoord, DefaultFormatting, KeyPointHint, Ranged, ReversibleRanged,
+};
+use crate::coord::snap_pixel;
+use std::ops::Range;
+
+type ScaleFunc<V> = dyn Fn(&V) -> f64;
+type InverseScaleFunc<V> = dyn Fn(f64) -> V;
+type KeyPointFunc<V> = dyn Fn(usize) -> Vec<V>;
+
+/// The builder of a coordinate spec with a custom scale, which is defined by closures instead of
+/// a [Ranged](../trait.Ranged.html) implementation.
+///
+/// The scale function transforms a value into the linear space of the axis, e.g. `f64::sqrt`
+/// for a square root scale, and the axis maps the transformed range onto the pixels linearly.
+/// The pixel limits and the key point hints are handled by the builder.
+///
+/// - The inverse of the scale function makes the coordinate reversible, which is required for
+///   converting the pixels back to the values. It's also used to place the default key points
+///   evenly in the transformed space.
+/// - The key point function takes the maximum number of key points and returns the values of
+///   the ticks. Without it, the ticks are evenly spaced in the transformed space if the inverse
+///   function is given, otherwise only the ends of the range are labeled.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut buffer = vec![0; 640 * 480 * 3];
+/// let root = BitMapBackend::with_buffer(&mut buffer, (640, 480)).into_drawing_area();
+///
+/// let sqrt_scale = RangedBuilder::new(0.0..100.0, |v: &f64| v.sqrt())
+///     .unmap(|v| v * v)
+///     .key_points(|_| vec![0.0, 1.0, 4.0, 9.0, 16.0, 25.0, 49.0, 100.0]);
+///
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d(sqrt_scale, 0.0..1.0)
+///     .unwrap();
+/// chart.configure_mesh().draw().unwrap();
+/// ```
+pub struct RangedBuilder<V> {
+    range: Range<V>,
+    scale: Box<ScaleFunc<V>>,
+    inverse: Option<Box<InverseScaleFunc<V>>>,
+    key_points: Option<Box<KeyPointFunc<V>>>,
+}
+
+impl<V> RangedBuilder<V> {
+    /// Create a new custom scale
+    /// - `range`: The range of the values on the axis
+    /// - `scale`: The function transforms a value into the linear space of the axis, it should
+    ///   be monotonic within the range
+    pub fn new<F: Fn(&V) -> f64 + 'static>(range: Range<V>, scale: F) -> Self {
+        Self {
+            range,
+            scale: Box::new(scale),
+            inverse: None,
+            key_points: None,
+        }
+    }
+
+    /// Set the inverse of the scale function, which transforms a point in the linear space of
+    /// the axis back to the value
+    pub fn unmap<F: Fn(f64) -> V + 'static>(mut self, inverse: F) -> Self {
+        self.inverse = Some(Box::new(inverse));
+        self
+    }
+
+    /// Set the function which returns the key points, i.e. the values of the ticks, from the
+    /// maximum number of key points
+    pub fn key_points<F: Fn(usize) -> Vec<V> + 'static>(mut self, func: F) -> Self {
+        self.key_points = Some(Box::new(func));
+        self
+    }
+
+    /// Build the coordinate spec
+    pub fn build(self) -> CustomRanged<V> {
+        let linear = (self.scale)(&self.range.start)..(self.scale)(&self.range.end);
+        CustomRanged {
+            range: self.range,
+            linear,
+            scale: self.scale,
+            inverse: self.inverse,
+            key_points: self.key_points,
+        }
+    }
+}
+
+impl<V: Clone> From<RangedBuilder<V>> for CustomRanged<V> {
+    fn from(builder: RangedBuilder<V>) -> Self {
+        builder.build()
+    }
+}
+
+impl<V: Clone> AsRangedCoord for RangedBuilder<V> {
+    type CoordDescType = CustomRanged<V>;
+    type Value = V;
+}
+
+/// The coordinate spec with a custom scale, see [RangedBuilder](struct.RangedBuilder.html)
+pub struct CustomRanged<V> {
+    range: Range<V>,
+    linear: Range<f64>,
+    scale: Box<ScaleFunc<V>>,
+    inverse: Option<Box<InverseScaleFunc<V>>>,
+    key_points: Option<Box<KeyPointFunc<V>>>,
+}
+
+impl<V: Clone> Ranged for CustomRanged<V> {
+    type FormatOption = DefaultFormatting;
+    type ValueType = V;
+
+    fn map(&self, value: &V, limit: (i32, i32)) -> i32 {
+        let t = ((self.scale)(value) - self.linear.start) / (self.linear.end - self.linear.start);
+        if !t.is_finite() {
+            return limit.0 + (limit.1 - limit.0) / 2;
+        }
+        limit.0 + (snap_pixel(f64::from(limit.1 - limit.0) * t) + 1e-3).floor() as i32
+    }
+
+    fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<V> {
+        let max_points = hint.max_num_points();
+        if let Some(func) = self.key_points.as_ref() {
+            let mut points = func(max_points);
+            points.truncate(max_points);
+            return points;
+        }
+        match self.inverse.as_ref() {
+            Some(inverse) if max_points >= 2 => {
+                let n = max_points.min(11) - 1;
+                let Range { start, end } = self.linear;
+                (0..=n)
+                    .map(|idx| inverse(start + (end - start) * idx as f64 / n as f64))
+                    .collect()
+            }
+            _ if max_points >= 2 => vec![self.range.start.clone(), self.range.end.clone()],
+            _ => vec![],
+        }
+    }
+
+    fn range(&self) -> Range<V> {
+        self.range.clone()
+    }
+}
+
+impl<V: Clone> ReversibleRanged for CustomRanged<V> {
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<V> {
+        let inverse = self.inverse.as_ref()?;
+        if limit.0 == limit.1 {
+            return None;
+        }
+        let t = f64::from(input - limit.0) / f64::from(limit.1 - limit.0);
+        Some(inverse(
+            self.linear.start + (self.linear.end - self.linear.start) * t,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::coord::ranged1d::BoldPoints;
+
+    #[test]
+    fn test_custom_scale() {
+        let coord = RangedBuilder::new(0.0..100.0, |v: &f64| v.sqrt())
+            .unmap(|v| v * v)
+            .build();
+        assert_eq!(coord.map(&0.0, (0, 100)), 0);
+        assert_eq!(coord.map(&25.0, (0, 100)), 50);
+        assert_eq!(coord.map(&100.0, (0, 100)), 100);
+        assert_eq!(coord.unmap(50, (0, 100)), Some(25.0));
+        assert_eq!(coord.range(), 0.0..100.0);
+
+        let points = coord.key_points(BoldPoints(3));
+        assert_eq!(points, vec![0.0, 25.0, 100.0]);
+    }
+
+    #[test]
+    fn test_custom_key_points() {
+        let coord = RangedBuilder::new(1..1000, |v: &i32| f64::from(*v).ln())
+            .key_points(|_| vec![1, 10, 100, 1000])
+            .build();
+        assert_eq!(coord.key_points(BoldPoints(10)), vec![1, 10, 100, 1000]);
+        assert_eq!(coord.key_points(BoldPoints(2)), vec![1, 10]);
+        assert_eq!(coord.unmap(10, (0, 100)), None);
+
+        let coord = RangedBuilder::new(0..10, |v: &i32| f64::from(*v)).build();
+        assert_eq!(coord.key_points(BoldPoints(5)), vec![0, 10]);
+    }
+}
@@ -1,5 +1,8 @@
 use crate::coord::ranged1d::types::RangedCoordf64;
-use crate::coord::ranged1d::{AsRangedCoord, DefaultFormatting, KeyPointHint, Ranged};
+use crate::coord::ranged1d::{
+    AsRangedCoord, AxisMapping, AxisScale, DefaultFormatting, ExportableRanged, KeyPointHint,
+    Ranged,
+};
 use std::marker::PhantomData;
 use std::ops::Range;
 
@@ -180,6 +183,31 @@ impl<V: LogScalable> LogCoord<V> {
 
         (V::as_f64(&a) - V::as_f64(&b)).abs() < std::f64::EPSILON
     }
+
+    /// Get the decades covered by the range, i.e. the intervals from a power of the base to the
+    /// next power. The decades on the ends are clamped to the range.
+    /// - **returns**: The exponent of the lower power, the lower and the upper bound of each decade
+    pub(crate) fn decades(&self) -> Vec<(i32, V, V)> {
+        let Range { mut start, mut end } = self.normalized;
+        if start > end {
+            std::mem::swap(&mut start, &mut end);
+        }
+        if self.base <= 1.0 || start <= 0.0 || !end.is_finite() {
+            return vec![];
+        }
+
+        let mut ret = vec![];
+        let mut exp = (start.ln() / self.base.ln()).floor() as i32;
+        while self.base.powi(exp) < end {
+            let lower = self.base.powi(exp).max(start);
+            let upper = self.base.powi(exp + 1).min(end);
+            if lower < upper {
+                ret.push((exp, self.f64_to_value(lower), self.f64_to_value(upper)));
+            }
+            exp += 1;
+        }
+        ret
+    }
 }
 
 impl<V: LogScalable> Ranged for LogCoord<V> {
@@ -251,6 +279,18 @@ impl<V: LogScalable> Ranged for LogCoord<V> {
     fn range(&self) -> Range<V> {
         self.logic.clone()
     }
+
+    fn value_floor(&self) -> Option<V> {
+        if self.negative {
+            return None;
+        }
+        let Range { start, end } = &self.logic;
+        Some(if start.as_f64() <= end.as_f64() {
+            start.clone()
+        } else {
+            end.clone()
+        })
+    }
 }
 
 /// The logarithmic coodinate decorator.
@@ -272,6 +312,23 @@ impl<V: LogScalable> From<LogRange<V>> for LogCoord<V> {
     }
 }
 
+impl<V: LogScalable> ExportableRanged for LogCoord<V> {
+    fn export_mapping(&self, limit: (i32, i32)) -> AxisMapping {
+        let sign = if self.negative { -1.0 } else { 1.0 };
+        AxisMapping {
+            pixel_range: limit,
+            value_range: (
+                self.zero_point + sign * self.normalized.start,
+                self.zero_point + sign * self.normalized.end,
+            ),
+            scale: AxisScale::Log {
+                offset: self.zero_point,
+                negative: self.negative,
+            },
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -281,4 +338,23 @@ mod test {
 
         range.key_points(100);
     }
+
+    #[test]
+    fn test_value_floor() {
+        let range: LogCoord<f64> = (1000.0..2.0).log_scale().into();
+        assert_eq!(range.value_floor(), Some(2.0));
+        let range: RangedCoordf64 = (-10.0..10.0).into();
+        assert_eq!(range.value_floor(), None);
+    }
+
+    #[test]
+    fn test_decades() {
+        let range: LogCoord<f64> = (2.0..1000.0).log_scale().into();
+        let decades = range.decades();
+        assert_eq!(decades.len(), 3);
+        assert_eq!(decades[0].0, 0);
+        assert!((decades[0].1 - 2.0).abs() < 1e-9 && (decades[0].2 - 10.0).abs() < 1e-9);
+        assert_eq!(decades[2].0, 2);
+        assert!((decades[2].1 - 100.0).abs() < 1e-9 && (decades[2].2 - 1000.0).abs() < 1e-9);
+    }
 }
@@ -1,6 +1,9 @@
 mod ckps;
 pub use ckps::{BindKeyPointMethod, BindKeyPoints, WithKeyPointMethod, WithKeyPoints};
 
+mod custom_scale;
+pub use custom_scale::{CustomRanged, RangedBuilder};
+
 mod group_by;
 pub use group_by::{GroupBy, ToGroupByRange};
 
@@ -16,5 +19,8 @@ pub use logarithmic::LogRange;
 mod nested;
 pub use nested::{BuildNestedCoord, NestedRange, NestedValue};
 
+mod symlog;
+pub use symlog::{IntoSymLogRange, SymLogCoord, SymLogRangeExt};
+
 mod partial_axis;
 pub use partial_axis::{make_partial_axis, IntoPartialAxis};
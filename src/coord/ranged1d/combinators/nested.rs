@@ -50,6 +50,40 @@ pub struct NestedRange<Primary: DiscreteRanged, Secondary: Ranged> {
     secondary: Vec<Secondary>,
 }
 
+impl<P: DiscreteRanged, S: Ranged> NestedRange<P, S> {
+    /// Get the primary coordinate, whose values are the categories
+    pub fn primary(&self) -> &P {
+        &self.primary
+    }
+
+    /// Get the pixel range of a category
+    /// - `category`: The category
+    /// - `limit`: The pixel range of the whole coordinate
+    /// - **returns**: The left and the right ends of the category, or `None` if the category
+    ///   isn't in the primary coordinate
+    pub fn category_span(&self, category: &P::ValueType, limit: (i32, i32)) -> Option<(i32, i32)> {
+        self.primary
+            .index_of(category)
+            .filter(|idx| *idx < self.primary.size())
+            .map(|idx| self.bucket(idx, limit))
+    }
+
+    fn bucket(&self, idx: usize, limit: (i32, i32)) -> (i32, i32) {
+        let total = self.primary.size();
+
+        let bucket_size = (limit.1 - limit.0) / total as i32;
+        let mut residual = (limit.1 - limit.0) % total as i32;
+
+        if residual < 0 {
+            residual += total as i32;
+        }
+
+        let s_left = limit.0 + bucket_size * idx as i32 + residual.min(idx as i32);
+        let s_right = s_left + bucket_size + if (residual as usize) < idx { 1 } else { 0 };
+        (s_left, s_right)
+    }
+}
+
 impl<PT, ST, P, S> ValueFormatter<NestedValue<PT, ST>> for NestedRange<P, S>
 where
     P: Ranged<ValueType = PT> + DiscreteRanged,
@@ -81,17 +115,7 @@ impl<P: DiscreteRanged, S: Ranged> Ranged for NestedRange<P, S> {
 
     fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
         let idx = self.primary.index_of(value.category()).unwrap_or(0);
-        let total = self.primary.size();
-
-        let bucket_size = (limit.1 - limit.0) / total as i32;
-        let mut residual = (limit.1 - limit.0) % total as i32;
-
-        if residual < 0 {
-            residual += total as i32;
-        }
-
-        let s_left = limit.0 + bucket_size * idx as i32 + residual.min(idx as i32);
-        let s_right = s_left + bucket_size + if (residual as usize) < idx { 1 } else { 0 };
+        let (s_left, s_right) = self.bucket(idx, limit);
 
         if let Some(secondary_value) = value.nested_value() {
             self.secondary[idx].map(secondary_value, (s_left, s_right))
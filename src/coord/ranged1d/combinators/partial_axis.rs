@@ -50,6 +50,10 @@ where
 
         left.min(right)..left.max(right)
     }
+
+    fn value_floor(&self) -> Option<Self::ValueType> {
+        self.0.value_floor()
+    }
 }
 
 impl<R: DiscreteRanged> DiscreteRanged for PartialAxis<R>
@@ -0,0 +1,220 @@
+use crate::coord::ranged1d::types::RangedCoordf64;
+use crate::coord::ranged1d::{AsRangedCoord, DefaultFormatting, KeyPointHint, Ranged};
+use std::ops::Range;
+
+use super::LogScalable;
+
+/// Convert a range to a symmetric log scale coordinate spec
+pub trait IntoSymLogRange {
+    /// The type of the value
+    type ValueType: LogScalable;
+
+    /// Make the symmetric log scale coordinate
+    fn symlog_scale(self) -> SymLogRangeExt<Self::ValueType>;
+}
+
+impl<T: LogScalable> IntoSymLogRange for Range<T> {
+    type ValueType = T;
+    fn symlog_scale(self) -> SymLogRangeExt<T> {
+        SymLogRangeExt {
+            range: self,
+            threshold: 1.0,
+            base: 10.0,
+        }
+    }
+}
+
+/// The symmetric logarithmic coordinate decorator, which is linear within the threshold around
+/// zero and logarithmic outside of it, for both the positive and the negative values.
+/// This makes it possible to present the signed data spanning multiple orders of magnitude,
+/// e.g. the financial returns.
+#[derive(Clone)]
+pub struct SymLogRangeExt<V: LogScalable> {
+    range: Range<V>,
+    threshold: f64,
+    base: f64,
+}
+
+impl<V: LogScalable> SymLogRangeExt<V> {
+    /// Set the linear threshold, the axis is linear in `[-threshold, threshold]`. The default
+    /// threshold is 1
+    pub fn linear_threshold(mut self, threshold: f64) -> Self {
+        if threshold > 0.0 {
+            self.threshold = threshold;
+        }
+        self
+    }
+
+    /// Set the base of the logarithm. The default base is 10
+    pub fn base(mut self, base: f64) -> Self {
+        if base > 1.0 {
+            self.base = base;
+        }
+        self
+    }
+}
+
+impl<V: LogScalable> From<SymLogRangeExt<V>> for SymLogCoord<V> {
+    fn from(spec: SymLogRangeExt<V>) -> SymLogCoord<V> {
+        let mut ret = SymLogCoord {
+            linear: (0.0..1.0).into(),
+            logic: spec.range,
+            threshold: spec.threshold,
+            base: spec.base,
+        };
+        let start = ret.transform(ret.value_to_f64(&ret.logic.start));
+        let end = ret.transform(ret.value_to_f64(&ret.logic.end));
+        ret.linear = (start..end).into();
+        ret
+    }
+}
+
+impl<V: LogScalable> AsRangedCoord for SymLogRangeExt<V> {
+    type CoordDescType = SymLogCoord<V>;
+    type Value = V;
+}
+
+/// A symmetric log scaled coordinate axis
+pub struct SymLogCoord<V: LogScalable> {
+    linear: RangedCoordf64,
+    logic: Range<V>,
+    threshold: f64,
+    base: f64,
+}
+
+impl<V: LogScalable> SymLogCoord<V> {
+    fn value_to_f64(&self, value: &V) -> f64 {
+        // The integer types present zero as a small positive number for the log scale, since
+        // zero is valid in the symmetric log scale, it's mapped back to zero
+        let zero = V::from_f64(0.0);
+        if value.as_f64() == zero.as_f64() {
+            0.0
+        } else {
+            value.as_f64()
+        }
+    }
+
+    /// Map the value to the linear space: the values within the threshold are scaled into
+    /// `[-1, 1]`, and every power of the base beyond the threshold takes one unit
+    fn transform(&self, value: f64) -> f64 {
+        let scaled = value / self.threshold;
+        if scaled.abs() <= 1.0 {
+            scaled
+        } else {
+            scaled.signum() * (1.0 + scaled.abs().ln() / self.base.ln())
+        }
+    }
+
+    /// Get the powers of the base beyond the threshold, which are in the range, in the order
+    /// of their magnitude
+    fn magnitudes(&self) -> Vec<f64> {
+        let start = self.value_to_f64(&self.logic.start);
+        let end = self.value_to_f64(&self.logic.end);
+        let max = start.abs().max(end.abs());
+        let mut ret = vec![];
+        let mut val = self.threshold;
+        while val <= max * (1.0 + 1e-9) && ret.len() < 1024 {
+            ret.push(val);
+            val *= self.base;
+        }
+        ret
+    }
+
+    fn in_range(&self, value: f64) -> bool {
+        let start = self.value_to_f64(&self.logic.start);
+        let end = self.value_to_f64(&self.logic.end);
+        let tolerance = (end - start).abs() * 1e-9;
+        start.min(end) - tolerance <= value && value <= start.max(end) + tolerance
+    }
+
+    fn collect_points<I: IntoIterator<Item = f64>>(&self, magnitudes: I) -> Vec<f64> {
+        let mut ret = vec![];
+        for val in magnitudes {
+            ret.push(val);
+            ret.push(-val);
+        }
+        ret.push(0.0);
+        ret.retain(|v| self.in_range(*v));
+        ret.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        ret
+    }
+}
+
+impl<V: LogScalable> Ranged for SymLogCoord<V> {
+    type FormatOption = DefaultFormatting;
+    type ValueType = V;
+
+    fn map(&self, value: &V, limit: (i32, i32)) -> i32 {
+        self.linear
+            .map(&self.transform(self.value_to_f64(value)), limit)
+    }
+
+    fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<V> {
+        let max_points = hint.max_num_points();
+        if max_points == 0 {
+            return vec![];
+        }
+
+        let magnitudes = self.magnitudes();
+        let mut stride = 1;
+        let mut bold = self.collect_points(magnitudes.iter().cloned());
+        while bold.len() > max_points && stride < magnitudes.len() {
+            stride += 1;
+            bold = self.collect_points(magnitudes.iter().step_by(stride).cloned());
+        }
+        bold.truncate(max_points);
+
+        // Fill the multiples of the powers in between, if there's room for all of them
+        let multiples = self.base.floor() as usize;
+        if stride == 1 && hint.weight().allow_light_points() && multiples > 2 {
+            let light = self.collect_points(
+                magnitudes
+                    .iter()
+                    .flat_map(|m| (1..multiples).map(move |mul| m * mul as f64)),
+            );
+            if light.len() <= max_points {
+                bold = light;
+            }
+        }
+
+        bold.into_iter().map(V::from_f64).collect()
+    }
+
+    fn range(&self) -> Range<V> {
+        self.logic.clone()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_symlog_coord() {
+        let coord: SymLogCoord<f64> = (-1000.0..1000.0).symlog_scale().into();
+
+        assert_eq!(coord.map(&0.0, (0, 600)), 300);
+        assert_eq!(coord.map(&1.0, (0, 600)), 375);
+        assert_eq!(coord.map(&100.0, (0, 600)), 525);
+        assert_eq!(coord.map(&-100.0, (0, 600)), 75);
+        assert_eq!(
+            coord.key_points(9),
+            vec![-1000.0, -100.0, -10.0, -1.0, 0.0, 1.0, 10.0, 100.0, 1000.0]
+        );
+        assert_eq!(coord.key_points(5), vec![-100.0, -1.0, 0.0, 1.0, 100.0]);
+    }
+
+    #[test]
+    fn test_symlog_threshold() {
+        let coord: SymLogCoord<i32> = (-50..500)
+            .symlog_scale()
+            .linear_threshold(5.0)
+            .base(10.0)
+            .into();
+
+        assert_eq!(coord.map(&0, (0, 100)), 40);
+        assert_eq!(coord.map(&-5, (0, 100)), 20);
+        assert_eq!(coord.map(&50, (0, 100)), 80);
+        assert_eq!(coord.key_points(6), vec![-50, -5, 0, 5, 50, 500]);
+    }
+}
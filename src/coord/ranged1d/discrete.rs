@@ -137,23 +137,23 @@ impl<D: DiscreteRanged> Ranged for SegmentedCoord<D> {
     type ValueType = SegmentValue<D::ValueType>;
 
     fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
-        let margin = ((limit.1 - limit.0) as f32 / self.0.size() as f32).round() as i32;
+        // The segment edges are an integer partition of the pixel range, so the segments add up
+        // to the whole range and their widths differ by at most one pixel
+        let segments = self.0.size().max(1) as i64;
+        let span = i64::from(limit.1 - limit.0);
+        let edge = |idx: usize| limit.0 + (span * idx as i64 / segments) as i32;
+
+        let margin = (f64::from(limit.1 - limit.0) / self.0.size().max(1) as f64).round() as i32;
 
         match value {
-            SegmentValue::Exact(coord) => self.0.map(coord, (limit.0, limit.1 - margin)),
-            SegmentValue::CenterOf(coord) => {
-                let left = self.0.map(coord, (limit.0, limit.1 - margin));
-                if let Some(idx) = self.0.index_of(coord) {
-                    if idx + 1 < self.0.size() {
-                        let right = self.0.map(
-                            &self.0.from_index(idx + 1).unwrap(),
-                            (limit.0, limit.1 - margin),
-                        );
-                        return (left + right) / 2;
-                    }
-                }
-                left + margin / 2
-            }
+            SegmentValue::Exact(coord) => match self.0.index_of(coord) {
+                Some(idx) => edge(idx),
+                None => self.0.map(coord, (limit.0, limit.1 - margin)),
+            },
+            SegmentValue::CenterOf(coord) => match self.0.index_of(coord) {
+                Some(idx) => (edge(idx) + edge(idx + 1)) / 2,
+                None => self.0.map(coord, (limit.0, limit.1 - margin)) + margin / 2,
+            },
             SegmentValue::Last => limit.1,
         }
     }
@@ -267,4 +267,20 @@ mod test {
         assert_eq!(coord.map(&SegmentValue::Exact(0), (0, 24)), 0);
         assert_eq!(coord.map(&SegmentValue::Exact(1), (0, 24)), 2);
     }
+
+    #[test]
+    fn test_segment_widths_even() {
+        let coord = (0..36).into_segmented();
+        for &limit in [(0, 500), (13, 1000), (199, -1)].iter() {
+            let mut edges: Vec<_> = (0..37)
+                .map(|v| coord.map(&SegmentValue::Exact(v), limit))
+                .collect();
+            edges.push(coord.map(&SegmentValue::Last, limit));
+            let widths: Vec<_> = edges.windows(2).map(|w| (w[1] - w[0]).abs()).collect();
+
+            let (min, max) = (widths.iter().min().unwrap(), widths.iter().max().unwrap());
+            assert!(max - min <= 1);
+            assert_eq!(widths.iter().sum::<i32>(), (limit.1 - limit.0).abs());
+        }
+    }
 }
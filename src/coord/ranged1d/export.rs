@@ -0,0 +1,131 @@
+use super::Ranged;
+
+/// The scale of an exported axis mapping, see [AxisMapping](struct.AxisMapping.html)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AxisScale {
+    /// The pixel is linear to the value
+    Linear,
+    /// The pixel is linear to `ln(value - offset)`, or `ln(offset - value)` if the axis is
+    /// negative
+    Log {
+        /// The value which is treated as zero by the log scale
+        offset: f64,
+        /// If the values of the axis are below the offset
+        negative: bool,
+    },
+}
+
+/// The parameters of the mapping between the values and the pixels of an axis, which allows the
+/// mapping to be reproduced outside of Plotters, e.g. by the interactive overlays drawn in the
+/// browser over a server rendered chart.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AxisMapping {
+    /// The pixels the ends of the value range are mapped to
+    pub pixel_range: (i32, i32),
+    /// The value range of the axis
+    pub value_range: (f64, f64),
+    /// The scale of the axis
+    pub scale: AxisScale,
+}
+
+impl AxisMapping {
+    fn linear_of(&self, value: f64) -> f64 {
+        match self.scale {
+            AxisScale::Linear => value,
+            AxisScale::Log { offset, negative } => {
+                if negative {
+                    (offset - value).ln()
+                } else {
+                    (value - offset).ln()
+                }
+            }
+        }
+    }
+
+    fn value_of(&self, linear: f64) -> f64 {
+        match self.scale {
+            AxisScale::Linear => linear,
+            AxisScale::Log { offset, negative } => {
+                if negative {
+                    offset - linear.exp()
+                } else {
+                    offset + linear.exp()
+                }
+            }
+        }
+    }
+
+    /// Map a value to the pixel, without rounding to an integer
+    pub fn map(&self, value: f64) -> f64 {
+        let (l0, l1) = (
+            self.linear_of(self.value_range.0),
+            self.linear_of(self.value_range.1),
+        );
+        let (p0, p1) = (f64::from(self.pixel_range.0), f64::from(self.pixel_range.1));
+        p0 + (p1 - p0) * (self.linear_of(value) - l0) / (l1 - l0)
+    }
+
+    /// Map a pixel back to the value, which is what `ReversibleRanged::unmap` computes
+    pub fn unmap(&self, pixel: f64) -> f64 {
+        let (l0, l1) = (
+            self.linear_of(self.value_range.0),
+            self.linear_of(self.value_range.1),
+        );
+        let (p0, p1) = (f64::from(self.pixel_range.0), f64::from(self.pixel_range.1));
+        self.value_of(l0 + (l1 - l0) * (pixel - p0) / (p1 - p0))
+    }
+
+    /// Serialize the mapping as a JSON object, e.g.
+    /// `{"pixel_range":[0,640],"value_range":[1,1000],"scale":"log","offset":0,"negative":false}`
+    pub fn to_json(&self) -> String {
+        let scale = match self.scale {
+            AxisScale::Linear => r#""scale":"linear""#.to_string(),
+            AxisScale::Log { offset, negative } => format!(
+                r#""scale":"log","offset":{},"negative":{}"#,
+                offset, negative
+            ),
+        };
+        format!(
+            r#"{{"pixel_range":[{},{}],"value_range":[{},{}],{}}}"#,
+            self.pixel_range.0, self.pixel_range.1, self.value_range.0, self.value_range.1, scale
+        )
+    }
+}
+
+/// The coordinate spec whose mapping can be exported as an [AxisMapping](struct.AxisMapping.html)
+pub trait ExportableRanged: Ranged {
+    /// Get the parameters of the mapping
+    /// - `limit`: The pixel range the coordinate is mapped to
+    fn export_mapping(&self, limit: (i32, i32)) -> AxisMapping;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::coord::combinators::{IntoLogRange, LogCoord};
+    use crate::coord::ranged1d::ReversibleRanged;
+    use crate::coord::types::RangedCoordf64;
+
+    #[test]
+    fn test_export_mapping() {
+        let coord: RangedCoordf64 = (-5.0..15.0).into();
+        let mapping = coord.export_mapping((0, 200));
+        assert_eq!(mapping.scale, AxisScale::Linear);
+        assert_eq!(mapping.value_range, (-5.0, 15.0));
+        for &v in [-5.0, 0.0, 7.5, 15.0].iter() {
+            assert_eq!(mapping.map(v).round() as i32, coord.map(&v, (0, 200)));
+        }
+        assert_eq!(Some(mapping.unmap(150.0)), coord.unmap(150, (0, 200)));
+
+        let coord: LogCoord<f64> = (1.0..1000.0).log_scale().into();
+        let mapping = coord.export_mapping((199, -1));
+        assert_eq!(
+            mapping.to_json(),
+            r#"{"pixel_range":[199,-1],"value_range":[1,1000],"scale":"log","offset":0,"negative":false}"#
+        );
+        for &v in [1.0, 10.0, 100.0, 1000.0].iter() {
+            assert!((mapping.map(v) - f64::from(coord.map(&v, (199, -1)))).abs() <= 1.0);
+        }
+        assert!((mapping.unmap(mapping.map(42.0)) - 42.0).abs() < 1e-9);
+    }
+}
@@ -49,6 +49,9 @@ let chart = ChartBuilder::on(&root)
     .unwrap();
 
   ```
+
+  For a simple nonlinear scale, the [RangedBuilder](../combinators/struct.RangedBuilder.html)
+  builds a coordinate specification from the closures, without implementing the trait.
 */
 use std::fmt::Debug;
 use std::ops::Range;
@@ -59,6 +62,9 @@ pub(super) mod types;
 mod discrete;
 pub use discrete::{DiscreteRanged, IntoSegmentedCoord, SegmentValue, SegmentedCoord};
 
+mod export;
+pub use export::{AxisMapping, AxisScale, ExportableRanged};
+
 /// Since stable Rust doesn't have specialization, it's very hard to make our own trait that
 /// automatically implemented the value formatter. This trait uses as a marker indicates if we
 /// should automatically implement the default value formater based on it's `Debug` trait
@@ -216,6 +222,14 @@ pub trait Ranged {
             (limit.1 + 1)..(limit.0 + 1)
         }
     }
+
+    /// Get the lower limit of the values this coordinate can map, e.g. a log scale coordinate
+    /// can't map zero, thus the elements extending from zero, such as the bars of a histogram,
+    /// should start from the lower bound of the range instead. `None` means there's no such
+    /// limit.
+    fn value_floor(&self) -> Option<Self::ValueType> {
+        None
+    }
 }
 
 /// The trait indicates the ranged value can be map reversely, which means
@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+use std::ops::Range;
+
+use crate::coord::ranged1d::{
+    DiscreteRanged, KeyPointHint, NoDefaultFormatting, Ranged, ValueFormatter,
+};
+
+/// How the values which aren't in the categories are handled by
+/// [RangedCategory::select](struct.RangedCategory.html#method.select)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UnknownCategory {
+    /// Drop the values of the unknown categories
+    Drop,
+    /// Report the first unknown category as an error
+    Report,
+}
+
+/// The error reported when a value isn't in any category of the coordinate
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnknownCategoryError<T>(pub T);
+
+impl<T: fmt::Debug> fmt::Display for UnknownCategoryError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Unknown category: {:?}", self.0)
+    }
+}
+
+impl<T: fmt::Debug> std::error::Error for UnknownCategoryError<T> {}
+
+/// The categorical coordinate with an explicit order of the categories. Each category is mapped
+/// to a slot in the given order, whatever order the categories appear in the data, so the
+/// charts drawn from different datasets share the same layout.
+///
+/// Use [into_segmented](../trait.IntoSegmentedCoord.html#method.into_segmented) to put the
+/// categories in the center of their slots for bar charts and box plots.
+///
+/// Please note: the behavior of constructing an empty coordinate may cause panic
+#[derive(Clone)]
+pub struct RangedCategory<T: Eq + Hash + Clone> {
+    categories: Vec<T>,
+    index: HashMap<T, usize>,
+    unknown: UnknownCategory,
+}
+
+impl<T: Eq + Hash + Clone> RangedCategory<T> {
+    /// Create a categorical coordinate, the duplicated categories are ignored
+    /// - `categories`: The categories in the order of the slots
+    pub fn new<I: IntoIterator<Item = T>>(categories: I) -> Self {
+        let mut ret = Self {
+            categories: vec![],
+            index: HashMap::new(),
+            unknown: UnknownCategory::Drop,
+        };
+        for category in categories {
+            if !ret.index.contains_key(&category) {
+                ret.index.insert(category.clone(), ret.categories.len());
+                ret.categories.push(category);
+            }
+        }
+        ret
+    }
+
+    /// Set how the values of the unknown categories are handled by
+    /// [select](#method.select). The default is `UnknownCategory::Drop`
+    pub fn unknown(mut self, policy: UnknownCategory) -> Self {
+        self.unknown = policy;
+        self
+    }
+
+    /// Get the categories in the order of the slots
+    pub fn categories(&self) -> &[T] {
+        &self.categories
+    }
+
+    /// Select the data of the known categories and sort them in the order of the categories,
+    /// the data of the same category keep their original order
+    /// - `data`: The data to select
+    /// - `category_of`: The function which gets the category of a datum
+    /// - **returns**: The sorted data, or the error with the first unknown category if the
+    ///   policy is `UnknownCategory::Report`
+    pub fn select<V, I, F>(
+        &self,
+        data: I,
+        category_of: F,
+    ) -> Result<Vec<V>, UnknownCategoryError<T>>
+    where
+        I: IntoIterator<Item = V>,
+        F: Fn(&V) -> &T,
+    {
+        let mut ret = vec![];
+        for datum in data {
+            match self.index.get(category_of(&datum)) {
+                Some(&idx) => ret.push((idx, datum)),
+                None if self.unknown == UnknownCategory::Report => {
+                    return Err(UnknownCategoryError(category_of(&datum).clone()));
+                }
+                None => {}
+            }
+        }
+        ret.sort_by_key(|(idx, _)| *idx);
+        Ok(ret.into_iter().map(|(_, datum)| datum).collect())
+    }
+}
+
+impl<T: Eq + Hash + Clone> Ranged for RangedCategory<T> {
+    type FormatOption = NoDefaultFormatting;
+    type ValueType = T;
+
+    fn range(&self) -> Range<T> {
+        // If there's no category, we should always panic
+        self.categories[0].clone()..self.categories[self.categories.len() - 1].clone()
+    }
+
+    fn map(&self, value: &T, limit: (i32, i32)) -> i32 {
+        match self.index.get(value) {
+            Some(&pos) if self.categories.len() > 1 => {
+                let pixel_span = limit.1 - limit.0;
+                let value_span = self.categories.len() - 1;
+                (f64::from(limit.0) + f64::from(pixel_span) * (pos as f64 / value_span as f64))
+                    .round() as i32
+            }
+            Some(_) => (limit.0 + limit.1) / 2,
+            None => limit.0,
+        }
+    }
+
+    fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<T> {
+        let max_points = hint.max_num_points();
+        if max_points == 0 {
+            return vec![];
+        }
+        let intervals = self.categories.len().saturating_sub(1) as f64;
+        let step = (intervals / max_points as f64 + 1.0) as usize;
+        self.categories.iter().step_by(step).cloned().collect()
+    }
+}
+
+impl<T: Eq + Hash + Clone> DiscreteRanged for RangedCategory<T> {
+    fn size(&self) -> usize {
+        self.categories.len()
+    }
+
+    fn index_of(&self, value: &T) -> Option<usize> {
+        self.index.get(value).copied()
+    }
+
+    fn from_index(&self, index: usize) -> Option<T> {
+        self.categories.get(index).cloned()
+    }
+}
+
+impl<T: Eq + Hash + Clone + fmt::Display> ValueFormatter<T> for RangedCategory<T> {
+    fn format(value: &T) -> String {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_category_range() {
+        let coord = RangedCategory::new(vec!["low", "mid", "high", "mid"]);
+
+        assert_eq!(coord.categories(), &["low", "mid", "high"]);
+        assert_eq!(coord.range(), "low".."high");
+        assert_eq!(coord.map(&"mid", (0, 100)), 50);
+        assert_eq!(coord.map(&"high", (0, 100)), 100);
+        assert_eq!(coord.index_of(&"high"), Some(2));
+        assert_eq!(coord.from_index(0), Some("low"));
+        assert_eq!(RangedCategory::<&str>::format(&"mid"), "mid");
+    }
+
+    #[test]
+    fn test_category_select() {
+        let coord = RangedCategory::new(vec!["low", "mid", "high"]);
+        let data = vec![("high", 1), ("other", 2), ("low", 3), ("high", 4)];
+
+        assert_eq!(
+            coord.select(data.clone(), |d| &d.0),
+            Ok(vec![("low", 3), ("high", 1), ("high", 4)])
+        );
+        assert_eq!(
+            coord
+                .unknown(UnknownCategory::Report)
+                .select(data, |d| &d.0),
+            Err(UnknownCategoryError("other"))
+        );
+    }
+}
@@ -30,6 +30,11 @@ pub trait TimeValue: Eq {
         let total_span = end.subtract(begin);
         let value_span = value.subtract(begin);
 
+        // A range with a single instant maps everything to the center
+        if total_span == Duration::zero() {
+            return limit.0 + (limit.1 - limit.0) / 2;
+        }
+
         // First, lets try the nanoseconds precision
         if let Some(total_ns) = total_span.num_nanoseconds() {
             if let Some(value_ns) = value_span.num_nanoseconds() {
@@ -201,6 +206,14 @@ where
         }
 
         let week_per_point = ((total_weeks as f64) / (max_points as f64)).ceil() as usize;
+        // A range shorter than a week, or no points at all
+        if week_per_point == 0 {
+            return if max_points > 0 {
+                vec![self.0.clone()]
+            } else {
+                ret
+            };
+        }
 
         for idx in 0..=(total_weeks as usize / week_per_point) {
             ret.push(self.0.clone() + Duration::weeks((idx * week_per_point) as i64));
@@ -682,6 +695,10 @@ impl Ranged for RangedDuration {
         let total_span = self.1 - self.0;
         let value_span = *value - self.0;
 
+        if total_span == Duration::zero() {
+            return limit.0 + (limit.1 - limit.0) / 2;
+        }
+
         if let Some(total_ns) = total_span.num_nanoseconds() {
             if let Some(value_ns) = value_span.num_nanoseconds() {
                 return limit.0
@@ -868,6 +885,23 @@ mod test {
         assert_eq!(max % 7, 0);
     }
 
+    #[test]
+    fn test_zero_width_date_range() {
+        let day = Utc.ymd(2019, 1, 1);
+        let ranged_coord = Into::<RangedDate<_>>::into(day..day);
+        assert_eq!(ranged_coord.map(&day, (0, 100)), 50);
+        assert_eq!(ranged_coord.key_points(10), vec![day]);
+
+        let time = Utc.ymd(2019, 1, 1).and_hms(12, 0, 0);
+        let ranged_coord = Into::<RangedDateTime<_>>::into(time..time);
+        assert_eq!(ranged_coord.map(&time, (100, 200)), 150);
+        let _points = ranged_coord.key_points(10);
+
+        let ranged_coord = Into::<RangedDuration>::into(Duration::hours(1)..Duration::hours(1));
+        assert_eq!(ranged_coord.map(&Duration::hours(1), (0, 100)), 50);
+        let _points = ranged_coord.key_points(10);
+    }
+
     #[test]
     fn test_date_range_short() {
         let range = Utc.ymd(2019, 1, 1)..Utc.ymd(2019, 1, 21);
@@ -5,6 +5,9 @@ pub use datetime::{
     IntoMonthly, IntoYearly, Monthly, RangedDate, RangedDateTime, RangedDuration, Yearly,
 };
 
+mod category;
+pub use category::{RangedCategory, UnknownCategory, UnknownCategoryError};
+
 mod numeric;
 pub use numeric::{
     RangedCoordf32, RangedCoordf64, RangedCoordi128, RangedCoordi32, RangedCoordi64,
@@ -2,9 +2,10 @@ use std::convert::TryFrom;
 use std::ops::Range;
 
 use crate::coord::ranged1d::{
-    AsRangedCoord, DefaultFormatting, DiscreteRanged, KeyPointHint, NoDefaultFormatting, Ranged,
-    ReversibleRanged, ValueFormatter,
+    AsRangedCoord, AxisMapping, AxisScale, DefaultFormatting, DiscreteRanged, ExportableRanged,
+    KeyPointHint, NoDefaultFormatting, Ranged, ReversibleRanged, ValueFormatter,
 };
+use crate::coord::snap_pixel;
 
 macro_rules! impl_discrete_trait {
     ($name:ident) => {
@@ -58,14 +59,52 @@ macro_rules! impl_reverse_mapping_trait {
         }
     };
 }
+macro_rules! numeric_bounds {
+    (float, $range:expr) => {{
+        let range = $range;
+        // A zero-width range is widened to a small symmetric window around the value, so that
+        // the value is drawn at the center and the axis still has meaningful key points
+        if range.start == range.end && range.start.is_finite() {
+            let delta = if range.start == 0.0 {
+                0.5
+            } else {
+                range.start.abs() * 0.05
+            };
+            (range.start - delta, range.end + delta)
+        } else {
+            (range.start, range.end)
+        }
+    }};
+    (integer, $range:expr) => {{
+        let range = $range;
+        (range.start, range.end)
+    }};
+}
+
+macro_rules! numeric_offset {
+    (float, $value:expr, $base:expr) => {
+        $value as f64 - $base as f64
+    };
+    // The difference is computed in the integer type first, since large integers, e.g. the
+    // timestamps in nanoseconds, can't be distinguished after converted to f64
+    (integer, $value:expr, $base:expr) => {
+        match $value.checked_sub($base) {
+            Some(offset) => offset as f64,
+            None => $value as f64 - $base as f64,
+        }
+    };
+}
+
 macro_rules! make_numeric_coord {
-    ($type:ty, $name:ident, $key_points:ident, $doc: expr, $fmt: ident) => {
+    ($kind:ident, $type:ty, $name:ident, $key_points:ident, $doc: expr, $fmt: ident) => {
         #[doc = $doc]
         #[derive(Clone)]
         pub struct $name($type, $type);
         impl From<Range<$type>> for $name {
+            #[allow(clippy::float_cmp)]
             fn from(range: Range<$type>) -> Self {
-                return $name(range.start, range.end);
+                let (start, end) = numeric_bounds!($kind, range);
+                return $name(start, end);
             }
         }
         impl Ranged for $name {
@@ -74,12 +113,13 @@ macro_rules! make_numeric_coord {
             #[allow(clippy::float_cmp)]
             fn map(&self, v: &$type, limit: (i32, i32)) -> i32 {
                 // Corner case: If we have a range that have only one value,
-                // then we just assign everything to the only point
+                // then we just assign everything to the center of the pixel range
                 if self.1 == self.0 {
-                    return (limit.1 - limit.0) / 2;
+                    return limit.0 + (limit.1 - limit.0) / 2;
                 }
 
-                let logic_length = (*v as f64 - self.0 as f64) / (self.1 as f64 - self.0 as f64);
+                let logic_length =
+                    numeric_offset!($kind, *v, self.0) / numeric_offset!($kind, self.1, self.0);
 
                 let actual_length = limit.1 - limit.0;
 
@@ -87,7 +127,8 @@ macro_rules! make_numeric_coord {
                     return limit.1;
                 }
 
-                return limit.0 + (actual_length as f64 * logic_length + 1e-3).floor() as i32;
+                return limit.0
+                    + (snap_pixel(actual_length as f64 * logic_length) + 1e-3).floor() as i32;
             }
             fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<$type> {
                 $key_points((self.0, self.1), hint.max_num_points())
@@ -96,9 +137,19 @@ macro_rules! make_numeric_coord {
                 return self.0..self.1;
             }
         }
+
+        impl ExportableRanged for $name {
+            fn export_mapping(&self, limit: (i32, i32)) -> AxisMapping {
+                AxisMapping {
+                    pixel_range: limit,
+                    value_range: (self.0 as f64, self.1 as f64),
+                    scale: AxisScale::Linear,
+                }
+            }
+        }
     };
     ($type:ty, $name:ident, $key_points:ident, $doc: expr) => {
-        make_numeric_coord!($type, $name, $key_points, $doc, DefaultFormatting);
+        make_numeric_coord!(integer, $type, $name, $key_points, $doc, DefaultFormatting);
     };
 }
 
@@ -215,6 +266,7 @@ gen_key_points_comp!(integer, compute_isize_key_points, isize);
 gen_key_points_comp!(integer, compute_usize_key_points, usize);
 
 make_numeric_coord!(
+    float,
     f32,
     RangedCoordf32,
     compute_f32_key_points,
@@ -233,6 +285,7 @@ impl ValueFormatter<f32> for RangedCoordf32 {
     }
 }
 make_numeric_coord!(
+    float,
     f64,
     RangedCoordf64,
     compute_f64_key_points,
@@ -379,6 +432,40 @@ mod test {
         assert!(points.len() > 0);
     }
 
+    #[test]
+    fn test_zero_width_coord() {
+        let coord: RangedCoordf64 = (5.0..5.0).into();
+        assert_eq!(coord.range(), 4.75..5.25);
+        assert_eq!(coord.map(&5.0, (0, 100)), 50);
+        assert!(coord.key_points(10).len() > 1);
+
+        let coord: RangedCoordf32 = (0.0..0.0).into();
+        assert_eq!(coord.range(), -0.5..0.5);
+        assert_eq!(coord.map(&0.0, (100, 200)), 150);
+
+        let coord: RangedCoordi32 = (3..3).into();
+        assert_eq!(coord.range(), 3..3);
+        assert_eq!(coord.map(&3, (100, 200)), 150);
+        assert_eq!(coord.key_points(10), vec![3]);
+        assert_eq!(coord.unmap(150, (100, 200)), Some(3));
+    }
+
+    #[test]
+    fn test_large_magnitude_coord() {
+        let coord: RangedCoordf64 = (1e15..1e15 + 100.0).into();
+        assert_eq!(coord.map(&(1e15 + 50.0), (0, 100)), 50);
+        assert_eq!(coord.map(&(1e15 + 99.0), (0, 100)), 99);
+        assert_eq!(coord.unmap(25, (0, 100)), Some(1e15 + 25.0));
+
+        let start = 1_600_000_000_000_000_000i64;
+        let coord: RangedCoordi64 = (start..start + 100).into();
+        assert_eq!(coord.map(&(start + 50), (0, 100)), 50);
+        assert_eq!(coord.map(&(start - 100), (0, 100)), -100);
+
+        let coord: RangedCoordu64 = (std::u64::MAX - 100..std::u64::MAX).into();
+        assert_eq!(coord.map(&(std::u64::MAX - 50), (0, 100)), 50);
+    }
+
     #[test]
     fn regression_test_issue_255_reverse_f32_coord_no_hang() {
         let coord: RangedCoordf32 = (10.0..0.0).into();
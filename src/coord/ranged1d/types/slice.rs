@@ -20,6 +20,7 @@ impl<'a, T: PartialEq> Ranged for RangedSlice<'a, T> {
 
     fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
         match self.0.iter().position(|x| &x == value) {
+            Some(_) if self.0.len() == 1 => limit.0 + (limit.1 - limit.0) / 2,
             Some(pos) => {
                 let pixel_span = limit.1 - limit.0;
                 let value_span = self.0.len() - 1;
@@ -35,6 +36,9 @@ impl<'a, T: PartialEq> Ranged for RangedSlice<'a, T> {
     fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<Self::ValueType> {
         let max_points = hint.max_num_points();
         let mut ret = vec![];
+        if self.0.is_empty() {
+            return ret;
+        }
         let intervals = (self.0.len() - 1) as f64;
         let step = (intervals / max_points as f64 + 1.0) as usize;
         for idx in (0..self.0.len()).step_by(step) {
@@ -88,6 +92,15 @@ mod test {
         assert_eq!(slice_range.map(&&0, (0, 50)), 30);
     }
 
+    #[test]
+    fn test_single_element_slice_range() {
+        let my_slice = [42];
+        let slice_range: RangedSlice<i32> = my_slice[..].into();
+
+        assert_eq!(slice_range.map(&&42, (10, 50)), 30);
+        assert_eq!(slice_range.key_points(6), vec![&42]);
+    }
+
     #[test]
     fn test_slice_range_discrete() {
         let my_slice = [1, 2, 3, 0, -1, -2];
@@ -7,10 +7,13 @@
  This types of coordinate system is used by the chart constructed with [ChartBuilder::build_cartesian_2d](../../chart/ChartBuilder.html#method.build_cartesian_2d).
 */
 
-use crate::coord::ranged1d::{KeyPointHint, Ranged, ReversibleRanged};
+use crate::coord::ranged1d::{
+    AxisMapping, ExportableRanged, KeyPointHint, Ranged, ReversibleRanged,
+};
 use crate::coord::{CoordTranslate, ReverseCoordTranslate};
 
 use crate::style::ShapeStyle;
+use num_traits::ToPrimitive;
 use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 
 use std::ops::Range;
@@ -99,6 +102,13 @@ impl<X: Ranged, Y: Ranged> Cartesian2d<X, Y> {
         self.logic_y.axis_pixel_range(self.back_y)
     }
 
+    /// Check if a backend coordinate is in the pixel range of the coordinate system, the ends of
+    /// the ranges are included
+    pub(crate) fn is_in_pixel_range(&self, (x, y): BackendCoord) -> bool {
+        let within = |v: i32, (a, b): (i32, i32)| a.min(b) <= v && v <= a.max(b);
+        within(x, self.back_x) && within(y, self.back_y)
+    }
+
     /// Get the 1D coordinate spec for X axis
     pub fn x_spec(&self) -> &X {
         &self.logic_x
@@ -108,6 +118,98 @@ impl<X: Ranged, Y: Ranged> Cartesian2d<X, Y> {
     pub fn y_spec(&self) -> &Y {
         &self.logic_y
     }
+
+    /// Replace the 1D coordinate spec for X axis, the pixel range is kept unchanged
+    pub fn set_x_spec<IntoX: Into<X>>(&mut self, logic_x: IntoX) {
+        self.logic_x = logic_x.into();
+    }
+
+    /// Replace the 1D coordinate spec for Y axis, the pixel range is kept unchanged
+    pub fn set_y_spec<IntoY: Into<Y>>(&mut self, logic_y: IntoY) {
+        self.logic_y = logic_y.into();
+    }
+
+    /// Replace the pixel range on the screen, the 1D coordinate specs are kept unchanged
+    pub(crate) fn set_pixel_range(&mut self, actual: (Range<i32>, Range<i32>)) {
+        self.back_x = (actual.0.start, actual.0.end);
+        self.back_y = (actual.1.start, actual.1.end);
+    }
+}
+
+impl<X: Ranged, Y: Ranged> Cartesian2d<X, Y>
+where
+    X::ValueType: ToPrimitive,
+    Y::ValueType: ToPrimitive,
+{
+    /// Get the number of pixels per data unit along the X and Y axes. For the non-linear
+    /// coordinates, this is the average over the whole axis range.
+    pub fn pixels_per_unit(&self) -> (f64, f64) {
+        fn scale<R: Ranged>(spec: &R, limit: (i32, i32)) -> f64
+        where
+            R::ValueType: ToPrimitive,
+        {
+            let range = spec.range();
+            let pixels = f64::from(spec.map(&range.end, limit) - spec.map(&range.start, limit));
+            match (range.start.to_f64(), range.end.to_f64()) {
+                (Some(start), Some(end)) if start != end => (pixels / (end - start)).abs(),
+                _ => 0.0,
+            }
+        }
+        (
+            scale(&self.logic_x, self.back_x),
+            scale(&self.logic_y, self.back_y),
+        )
+    }
+}
+
+/// The exported mapping of a [Cartesian2d](struct.Cartesian2d.html) coordinate, which has
+/// everything needed to reproduce the coordinate translation and its reverse outside of
+/// Plotters, e.g. in the JavaScript drawing the interactive overlays on a rendered chart.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut buffer = vec![0; 640 * 480 * 3];
+/// let root = BitMapBackend::with_buffer(&mut buffer, (640, 480)).into_drawing_area();
+/// let chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d(0.0..10.0, (1.0..1000.0).log_scale())
+///     .unwrap();
+///
+/// // Sent to the browser along with the image
+/// let json = chart.as_coord_spec().export_mapping().to_json();
+/// assert!(json.starts_with(r#"{"x":{"pixel_range":[0,640],"value_range":[0,10]"#));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Cartesian2dMapping {
+    /// The mapping of the X axis
+    pub x: AxisMapping,
+    /// The mapping of the Y axis
+    pub y: AxisMapping,
+}
+
+impl Cartesian2dMapping {
+    /// Map a point in the pixel coordinate back to the values, the same as `reverse_translate`
+    /// but without rounding the pixel to an integer
+    pub fn reverse_translate(&self, (x, y): (f64, f64)) -> (f64, f64) {
+        (self.x.unmap(x), self.y.unmap(y))
+    }
+
+    /// Serialize the mapping as a JSON object with the mapping of each axis, see
+    /// [AxisMapping::to_json](../ranged1d/struct.AxisMapping.html#method.to_json)
+    pub fn to_json(&self) -> String {
+        format!(r#"{{"x":{},"y":{}}}"#, self.x.to_json(), self.y.to_json())
+    }
+}
+
+impl<X: ExportableRanged, Y: ExportableRanged> Cartesian2d<X, Y> {
+    /// Export the parameters of the coordinate translation, see
+    /// [Cartesian2dMapping](struct.Cartesian2dMapping.html)
+    pub fn export_mapping(&self) -> Cartesian2dMapping {
+        Cartesian2dMapping {
+            x: self.logic_x.export_mapping(self.back_x),
+            y: self.logic_y.export_mapping(self.back_y),
+        }
+    }
 }
 
 impl<X: Ranged, Y: Ranged> CoordTranslate for Cartesian2d<X, Y> {
@@ -147,6 +249,6 @@ impl<'a, X: Ranged, Y: Ranged> MeshLine<'a, X, Y> {
             MeshLine::XMesh(a, b, _) => (a, b),
             MeshLine::YMesh(a, b, _) => (a, b),
         };
-        backend.draw_line(left, right, style)
+        style.draw_line(backend, left, right)
     }
 }
@@ -12,6 +12,7 @@ pub struct Cartesian3d<X: Ranged, Y: Ranged, Z: Ranged> {
     pub(crate) logic_z: Z,
     coord_size: (i32, i32, i32),
     projection: ProjectionMatrix,
+    view: (f64, f64, f64),
 }
 
 impl<X: Ranged, Y: Ranged, Z: Ranged> Cartesian3d<X, Y, Z> {
@@ -58,9 +59,25 @@ impl<X: Ranged, Y: Ranged, Z: Ranged> Cartesian3d<X, Y, Z> {
                 coord_size,
                 build_projection_matrix,
             ),
+            view: Self::default_view(),
         }
     }
 
+    fn default_view() -> (f64, f64, f64) {
+        let pb = ProjectionMatrixBuilder::default();
+        (pb.yaw, pb.pitch, pb.scale)
+    }
+
+    fn view_projection(&self, actual_x: Range<i32>, actual_y: Range<i32>) -> ProjectionMatrix {
+        let (yaw, pitch, scale) = self.view;
+        Self::create_projection(actual_x, actual_y, self.coord_size, |mut pb| {
+            pb.yaw = yaw;
+            pb.pitch = pitch;
+            pb.scale = scale;
+            pb.into_matrix()
+        })
+    }
+
     pub fn set_coord_pixel_range(
         &mut self,
         actual_x: Range<i32>,
@@ -68,11 +85,30 @@ impl<X: Ranged, Y: Ranged, Z: Ranged> Cartesian3d<X, Y, Z> {
         coord_size: (i32, i32, i32),
     ) -> &mut Self {
         self.coord_size = coord_size;
-        self.projection =
-            Self::create_projection(actual_x, actual_y, coord_size, |pb| pb.into_matrix());
+        self.projection = self.view_projection(actual_x, actual_y);
         self
     }
 
+    /// Set the view of the default projection
+    /// - `yaw`: The rotation around the vertical axis, in radians
+    /// - `pitch`: The rotation around the horizontal axis, in radians
+    /// - `scale`: The scale factor of the projection
+    pub fn set_view(
+        &mut self,
+        actual_x: Range<i32>,
+        actual_y: Range<i32>,
+        (yaw, pitch, scale): (f64, f64, f64),
+    ) -> &mut Self {
+        self.view = (yaw, pitch, scale);
+        self.projection = self.view_projection(actual_x, actual_y);
+        self
+    }
+
+    /// Get the `(yaw, pitch, scale)` of the view set by [set_view](#method.set_view)
+    pub fn view(&self) -> (f64, f64, f64) {
+        self.view
+    }
+
     /// Set the projection matrix
     pub fn set_projection<F: FnOnce(ProjectionMatrixBuilder) -> ProjectionMatrix>(
         &mut self,
@@ -1,3 +1,4 @@
+use crate::coord::snap_pixel;
 use std::f64::consts::PI;
 use std::ops::Mul;
 
@@ -51,8 +52,8 @@ impl Mul<(i32, i32, i32)> for ProjectionMatrix {
         let (x, y, z) = (x as f64, y as f64, z as f64);
         let m = self.0;
         (
-            (x * m[0][0] + y * m[0][1] + z * m[0][2] + m[0][3]) as i32,
-            (x * m[1][0] + y * m[1][1] + z * m[1][2] + m[1][3]) as i32,
+            snap_pixel(x * m[0][0] + y * m[0][1] + z * m[0][2] + m[0][3]) as i32,
+            snap_pixel(x * m[1][0] + y * m[1][1] + z * m[1][2] + m[1][3]) as i32,
         )
     }
 }
@@ -62,8 +63,8 @@ impl Mul<(f64, f64, f64)> for ProjectionMatrix {
     fn mul(self, (x, y, z): (f64, f64, f64)) -> (i32, i32) {
         let m = self.0;
         (
-            (x * m[0][0] + y * m[0][1] + z * m[0][2] + m[0][3]) as i32,
-            (x * m[1][0] + y * m[1][1] + z * m[1][2] + m[1][3]) as i32,
+            snap_pixel(x * m[0][0] + y * m[0][1] + z * m[0][2] + m[0][3]) as i32,
+            snap_pixel(x * m[1][0] + y * m[1][1] + z * m[1][2] + m[1][3]) as i32,
         )
     }
 }
@@ -135,7 +136,7 @@ impl ProjectionMatrix {
     /// Get the distance of the point in guest coordinate from the screen in pixels
     pub fn projected_depth(&self, (x, y, z): (i32, i32, i32)) -> i32 {
         let r = &self.0[2];
-        (r[0] * x as f64 + r[1] * y as f64 + r[2] * z as f64 + r[3]) as i32
+        snap_pixel(r[0] * x as f64 + r[1] * y as f64 + r[2] * z as f64 + r[3]) as i32
     }
 }
 
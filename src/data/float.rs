@@ -118,6 +118,105 @@ pub fn pretty_print_float(n: f64, allow_sn: bool) -> String {
     .print(n)
 }
 
+/// The separators used to print the numbers, e.g. to print `1234567.89` as `1.234.567,89` for
+/// the European locales, or as `1,234,567.89` with the digits grouped.
+///
+/// ```rust
+/// use plotters::data::float::NumberFormat;
+///
+/// let format = NumberFormat::new()
+///     .decimal_separator(',')
+///     .thousands_separator('.')
+///     .grouping(true);
+/// assert_eq!(format.print(1234567.25), "1.234.567,25");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NumberFormat {
+    decimal_separator: char,
+    thousands_separator: char,
+    grouping: bool,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NumberFormat {
+    /// Create the default number format, which uses `.` as the decimal separator and doesn't
+    /// group the digits, the same as the default formatters
+    pub fn new() -> Self {
+        Self {
+            decimal_separator: '.',
+            thousands_separator: ',',
+            grouping: false,
+        }
+    }
+
+    /// Set the decimal separator, the default is `.`
+    pub fn decimal_separator(mut self, separator: char) -> Self {
+        self.decimal_separator = separator;
+        self
+    }
+
+    /// Set the separator between the groups of thousands, the default is `,`. It's only used
+    /// when the [grouping](#method.grouping) is enabled
+    pub fn thousands_separator(mut self, separator: char) -> Self {
+        self.thousands_separator = separator;
+        self
+    }
+
+    /// Set if the digits of the integer part are grouped by thousands
+    pub fn grouping(mut self, grouping: bool) -> Self {
+        self.grouping = grouping;
+        self
+    }
+
+    /// Pretty print the floating number with the separators, see
+    /// [pretty_print_float](fn.pretty_print_float.html)
+    pub fn print(&self, n: f64) -> String {
+        self.localize(&pretty_print_float(n, false))
+    }
+
+    /// Replace the separators of a number printed in the plain format, such as `-1234.5` or
+    /// `1.5e10`. Anything else, e.g. a date, is returned as it is.
+    pub fn localize(&self, repr: &str) -> String {
+        let is_digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+        let (sign, unsigned) = if repr.starts_with('-') {
+            ("-", &repr[1..])
+        } else {
+            ("", repr)
+        };
+        let (mantissa, exp) = match unsigned.find('e') {
+            Some(idx) => (&unsigned[..idx], &unsigned[idx..]),
+            None => (unsigned, ""),
+        };
+        let (int_part, dec_part) = match mantissa.find('.') {
+            Some(idx) => (&mantissa[..idx], Some(&mantissa[idx + 1..])),
+            None => (mantissa, None),
+        };
+        let exp_valid = exp.is_empty() || is_digits(exp[1..].trim_start_matches('-'));
+        if !is_digits(int_part) || !dec_part.map_or(true, is_digits) || !exp_valid {
+            return repr.to_string();
+        }
+
+        let mut result = sign.to_string();
+        for (idx, c) in int_part.chars().enumerate() {
+            if self.grouping && idx > 0 && (int_part.len() - idx) % 3 == 0 {
+                result.push(self.thousands_separator);
+            }
+            result.push(c);
+        }
+        if let Some(dec_part) = dec_part {
+            result.push(self.decimal_separator);
+            result.push_str(dec_part);
+        }
+        result.push_str(exp);
+        result
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -137,4 +236,22 @@ mod test {
         assert_eq!(pretty_print_float(1234567890f64, true), "1234567890");
         assert_eq!(pretty_print_float(1000000001f64, true), "1e9");
     }
+
+    #[test]
+    fn test_number_format() {
+        let european = NumberFormat::new()
+            .decimal_separator(',')
+            .thousands_separator('.')
+            .grouping(true);
+        assert_eq!(european.print(1234567.25), "1.234.567,25");
+        assert_eq!(european.print(-999.5), "-999,5");
+        assert_eq!(european.localize("-1000"), "-1.000");
+        assert_eq!(european.localize("1.5e-10"), "1,5e-10");
+        assert_eq!(european.localize("2021-01-01"), "2021-01-01");
+
+        let grouped = NumberFormat::new().grouping(true);
+        assert_eq!(grouped.print(1234567.25), "1,234,567.25");
+        assert_eq!(grouped.print(123456.0), "123,456");
+        assert_eq!(NumberFormat::new().print(1234567.25), "1234567.25");
+    }
 }
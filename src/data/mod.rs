@@ -9,4 +9,7 @@ pub use data_range::fitting_range;
 mod quartiles;
 pub use quartiles::Quartiles;
 
+mod rolling;
+pub use rolling::{exponential_moving_average, rolling_mean, RollingWindow, WindowEdge};
+
 pub mod float;
@@ -0,0 +1,172 @@
+/// How the rolling window handles the points at the start of the series, where there are fewer
+/// points than the window size
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WindowEdge {
+    /// Skip the points until the window is full, thus the result is `window - 1` points shorter
+    /// than the input
+    Skip,
+    /// Compute the statistic over the points available so far
+    Partial,
+}
+
+/// The rolling window, which computes a statistic over the last `size` points for each point of
+/// the series. The series is a sequence of `(x, y)` pairs and the statistic is computed over the
+/// `y` values, the `x` of each result is the `x` of the last point in the window.
+///
+/// ```rust
+/// use plotters::data::{RollingWindow, WindowEdge};
+///
+/// let data = vec![(0, 1.0), (1, 2.0), (2, 3.0), (3, 4.0)];
+/// let mean: Vec<_> = RollingWindow::new(2).mean(data.clone()).collect();
+/// assert_eq!(mean, vec![(1, 1.5), (2, 2.5), (3, 3.5)]);
+///
+/// let max: Vec<_> = RollingWindow::new(3).edge(WindowEdge::Partial).max(data).collect();
+/// assert_eq!(max, vec![(0, 1.0), (1, 2.0), (2, 3.0), (3, 4.0)]);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct RollingWindow {
+    size: usize,
+    edge: WindowEdge,
+}
+
+impl RollingWindow {
+    /// Create a new rolling window, which skips the points before the window is full
+    /// - `size`: The number of the points in the window
+    pub fn new(size: usize) -> Self {
+        Self {
+            size: size.max(1),
+            edge: WindowEdge::Skip,
+        }
+    }
+
+    /// Set how the points at the start of the series are handled
+    pub fn edge(mut self, edge: WindowEdge) -> Self {
+        self.edge = edge;
+        self
+    }
+
+    fn apply<X, I: IntoIterator<Item = (X, f64)>, F: Fn(&[f64]) -> f64>(
+        &self,
+        data: I,
+        stat: F,
+    ) -> std::vec::IntoIter<(X, f64)> {
+        let (xs, ys): (Vec<_>, Vec<_>) = data.into_iter().unzip();
+        let mut result = Vec::with_capacity(xs.len());
+        for (idx, x) in xs.into_iter().enumerate() {
+            if idx + 1 < self.size && self.edge == WindowEdge::Skip {
+                continue;
+            }
+            let window = &ys[(idx + 1).saturating_sub(self.size)..=idx];
+            result.push((x, stat(window)));
+        }
+        result.into_iter()
+    }
+
+    /// Compute the rolling mean, a.k.a. the simple moving average
+    pub fn mean<X, I: IntoIterator<Item = (X, f64)>>(
+        &self,
+        data: I,
+    ) -> std::vec::IntoIter<(X, f64)> {
+        self.apply(data, |w| w.iter().sum::<f64>() / w.len() as f64)
+    }
+
+    /// Compute the rolling minimum
+    pub fn min<X, I: IntoIterator<Item = (X, f64)>>(
+        &self,
+        data: I,
+    ) -> std::vec::IntoIter<(X, f64)> {
+        self.apply(data, |w| {
+            w.iter().copied().fold(std::f64::INFINITY, f64::min)
+        })
+    }
+
+    /// Compute the rolling maximum
+    pub fn max<X, I: IntoIterator<Item = (X, f64)>>(
+        &self,
+        data: I,
+    ) -> std::vec::IntoIter<(X, f64)> {
+        self.apply(data, |w| {
+            w.iter().copied().fold(std::f64::NEG_INFINITY, f64::max)
+        })
+    }
+
+    /// Compute the rolling sample standard deviation, the window with a single point has zero
+    /// standard deviation
+    pub fn std_dev<X, I: IntoIterator<Item = (X, f64)>>(
+        &self,
+        data: I,
+    ) -> std::vec::IntoIter<(X, f64)> {
+        self.apply(data, |w| {
+            if w.len() < 2 {
+                return 0.0;
+            }
+            let mean = w.iter().sum::<f64>() / w.len() as f64;
+            let sum_sq: f64 = w.iter().map(|v| (v - mean) * (v - mean)).sum();
+            (sum_sq / (w.len() - 1) as f64).sqrt()
+        })
+    }
+}
+
+/// Compute the simple moving average of the series, the points before the window is full are
+/// skipped. Use [RollingWindow](struct.RollingWindow.html) to keep them.
+/// - `data`: The series of `(x, y)` pairs
+/// - `window`: The number of the points in the window
+pub fn rolling_mean<X, I: IntoIterator<Item = (X, f64)>>(
+    data: I,
+    window: usize,
+) -> std::vec::IntoIter<(X, f64)> {
+    RollingWindow::new(window).mean(data)
+}
+
+/// Compute the exponential moving average of the series, which is
+/// `ema[i] = alpha * y[i] + (1 - alpha) * ema[i - 1]`, and starts with the first value of the
+/// series
+/// - `data`: The series of `(x, y)` pairs
+/// - `alpha`: The smoothing factor in `(0, 1]`, a larger factor follows the data more closely.
+///   For an N-period EMA, the usual choice is `2 / (N + 1)`
+pub fn exponential_moving_average<X, I: IntoIterator<Item = (X, f64)>>(
+    data: I,
+    alpha: f64,
+) -> impl Iterator<Item = (X, f64)> {
+    let alpha = alpha.max(0.0).min(1.0);
+    let mut ema: Option<f64> = None;
+    data.into_iter().map(move |(x, y)| {
+        let value = match ema {
+            Some(prev) => alpha * y + (1.0 - alpha) * prev,
+            None => y,
+        };
+        ema = Some(value);
+        (x, value)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rolling_window() {
+        let data = vec![(0, 4.0), (1, 2.0), (2, 6.0), (3, 4.0)];
+        let result: Vec<_> = rolling_mean(data.clone(), 2).collect();
+        assert_eq!(result, vec![(1, 3.0), (2, 4.0), (3, 5.0)]);
+
+        let window = RollingWindow::new(3).edge(WindowEdge::Partial);
+        let result: Vec<_> = window.min(data.clone()).map(|(_, y)| y).collect();
+        assert_eq!(result, vec![4.0, 2.0, 2.0, 2.0]);
+        let result: Vec<_> = window.max(data.clone()).map(|(_, y)| y).collect();
+        assert_eq!(result, vec![4.0, 4.0, 6.0, 6.0]);
+        let result: Vec<_> = window.std_dev(data.clone()).map(|(_, y)| y).collect();
+        assert_eq!(result[0], 0.0);
+        assert!((result[2] - 2.0).abs() < 1e-10);
+
+        assert_eq!(RollingWindow::new(5).mean(data).count(), 0);
+    }
+
+    #[test]
+    fn test_exponential_moving_average() {
+        let result: Vec<_> = exponential_moving_average(vec![(0, 1.0), (1, 3.0), (2, 3.0)], 0.5)
+            .map(|(_, y)| y)
+            .collect();
+        assert_eq!(result, vec![1.0, 2.0, 2.5]);
+    }
+}
@@ -1,7 +1,8 @@
 use crate::coord::cartesian::{Cartesian2d, MeshLine};
 use crate::coord::ranged1d::{KeyPointHint, Ranged};
 use crate::coord::{CoordTranslate, Shift};
-use crate::element::{CoordMapper, Drawable, PointCollection};
+use crate::drawing::ScaledBackend;
+use crate::element::{draw_text_with_background, CoordMapper, Drawable, PointCollection};
 use crate::style::text_anchor::{HPos, Pos, VPos};
 use crate::style::{Color, SizeDesc, TextStyle};
 
@@ -9,7 +10,7 @@ use crate::style::{Color, SizeDesc, TextStyle};
 use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 
 use std::borrow::Borrow;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::error::Error;
 use std::iter::{once, repeat};
 use std::ops::Range;
@@ -107,6 +108,11 @@ impl Rect {
     pub fn truncate(&self, p: (i32, i32)) -> (i32, i32) {
         (p.0.min(self.x1).max(self.x0), p.1.min(self.y1).max(self.y0))
     }
+
+    /// Get the upper-left and the bottom-right corners of the rectangle
+    pub(crate) fn corners(&self) -> ((i32, i32), (i32, i32)) {
+        ((self.x0, self.y0), (self.x1, self.y1))
+    }
 }
 
 /// The abstraction of a drawing area. Plotters uses drawing area as the fundamental abstraction for the
@@ -157,6 +163,59 @@ impl<E: Error + Send + Sync> std::fmt::Display for DrawingAreaErrorKind<E> {
 
 impl<E: Error + Send + Sync> Error for DrawingAreaErrorKind<E> {}
 
+impl<E: Error + Send + Sync> DrawingAreaErrorKind<E> {
+    /// Get the drawing operation which caused the error, if it's known. The context is
+    /// attached to the boxed backend errors, e.g. the font errors, raised while drawing an
+    /// element or a text on a drawing area. The errors of the backend's own error type are
+    /// returned unchanged, so they carry no context.
+    pub fn context(&self) -> Option<&DrawingErrorContext> {
+        match self {
+            DrawingAreaErrorKind::BackendError(DrawingErrorKind::FontError(e)) => {
+                e.downcast_ref::<ContextError>().map(|e| &e.context)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The drawing operation which caused a backend error, see
+/// [DrawingAreaErrorKind::context](enum.DrawingAreaErrorKind.html#method.context)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DrawingErrorContext {
+    /// The operation, e.g. `text` or the type name of the element drawn
+    pub operation: &'static str,
+    /// The backend coordinate involved, e.g. the first point of the element
+    pub position: Option<BackendCoord>,
+}
+
+impl std::fmt::Display for DrawingErrorContext {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        match self.position {
+            Some((x, y)) => write!(fmt, "while drawing {} at ({}, {})", self.operation, x, y),
+            None => write!(fmt, "while drawing {}", self.operation),
+        }
+    }
+}
+
+/// The boxed backend error with the drawing operation which caused it
+#[derive(Debug)]
+struct ContextError {
+    context: DrawingErrorContext,
+    source: Box<dyn Error + Send + Sync>,
+}
+
+impl std::fmt::Display for ContextError {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        write!(fmt, "{} {}", self.source, self.context)
+    }
+}
+
+impl Error for ContextError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&*self.source)
+    }
+}
+
 #[allow(type_alias_bounds)]
 type DrawingAreaError<T: DrawingBackend> = DrawingAreaErrorKind<T::ErrorType>;
 
@@ -226,6 +285,12 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
         (self.rect.x0, self.rect.y0)
     }
 
+    /// Move this area onto the rectangle of another area, the coordinate specification is kept
+    /// unchanged
+    pub(crate) fn move_to<CT2: CoordTranslate>(&mut self, area: &DrawingArea<DB, CT2>) {
+        self.rect = area.rect.clone();
+    }
+
     /// Strip the applied coordinate specification and returns a shift-based drawing area
     pub fn strip_coord_spec(&self) -> DrawingArea<DB, Shift> {
         DrawingArea {
@@ -243,6 +308,68 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
         }
     }
 
+    /// Get the drawing area covering the whole figure, i.e. the entire drawing backend, which
+    /// uses the pixel coordinate of the figure regardless of the layout and the coordinate
+    /// specification of this area
+    pub fn figure_area(&self) -> DrawingArea<DB, Shift> {
+        let (w, h) = RefCell::borrow(&self.backend).get_size();
+        DrawingArea {
+            rect: Rect {
+                x0: 0,
+                y0: 0,
+                x1: w as i32,
+                y1: h as i32,
+            },
+            backend: self.backend.clone(),
+            coord: Shift((0, 0)),
+        }
+    }
+
+    /// Get the pixel position of a point on the figure from its position relative to the
+    /// figure, `(0.0, 0.0)` is the upper-left corner and `(1.0, 1.0)` is the bottom-right corner
+    pub fn figure_relative(&self, (x, y): (f64, f64)) -> BackendCoord {
+        let (w, h) = RefCell::borrow(&self.backend).get_size();
+        (
+            (f64::from(w) * x).round() as i32,
+            (f64::from(h) * y).round() as i32,
+        )
+    }
+
+    /// Draw an element in the pixel coordinate of the whole figure, bypassing the coordinate
+    /// specification of this area, which is useful for the watermarks, logos and footnotes.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut buffer = vec![0; 640 * 480 * 3];
+    /// let root = BitMapBackend::with_buffer(&mut buffer, (640, 480)).into_drawing_area();
+    /// let chart = ChartBuilder::on(&root)
+    ///     .margin(20)
+    ///     .build_cartesian_2d(0.0..1e6, 0.0..1.0)
+    ///     .unwrap();
+    /// let plotting_area = chart.plotting_area();
+    ///
+    /// // The footnote stays at the bottom-right corner of the figure whatever the data range is
+    /// plotting_area
+    ///     .draw_on_figure(&Text::new(
+    ///         "Draft",
+    ///         plotting_area.figure_relative((0.9, 0.95)),
+    ///         ("sans-serif", 20),
+    ///     ))
+    ///     .unwrap();
+    /// plotting_area
+    ///     .draw_on_figure(&Circle::new((630, 10), 5, RED.filled()))
+    ///     .unwrap();
+    /// ```
+    pub fn draw_on_figure<'a, E, B>(&self, element: &'a E) -> Result<(), DrawingAreaError<DB>>
+    where
+        B: CoordMapper,
+        &'a E: PointCollection<'a, BackendCoord, B>,
+        E: Drawable<DB, B>,
+    {
+        self.figure_area().draw(element)
+    }
+
     /// Get the area dimension in pixel
     pub fn dim_in_pixel(&self) -> (u32, u32) {
         (
@@ -266,8 +393,36 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
         (self.rect.x0..self.rect.x1, self.rect.y0..self.rect.y1)
     }
 
+    /// Attach the operation which caused a boxed backend error to the error, see
+    /// [DrawingAreaErrorKind::context](enum.DrawingAreaErrorKind.html#method.context)
+    fn with_context(
+        error: DrawingAreaError<DB>,
+        operation: &'static str,
+        position: Option<BackendCoord>,
+    ) -> DrawingAreaError<DB> {
+        match error {
+            DrawingAreaErrorKind::BackendError(DrawingErrorKind::FontError(source))
+                if !source.is::<ContextError>() =>
+            {
+                DrawingAreaErrorKind::BackendError(DrawingErrorKind::FontError(Box::new(
+                    ContextError {
+                        context: DrawingErrorContext {
+                            operation,
+                            position,
+                        },
+                        source,
+                    },
+                )))
+            }
+            error => error,
+        }
+    }
+
     /// Perform operation on the drawing backend
-    fn backend_ops<R, O: FnOnce(&mut DB) -> Result<R, DrawingErrorKind<DB::ErrorType>>>(
+    pub(crate) fn backend_ops<
+        R,
+        O: FnOnce(&mut DB) -> Result<R, DrawingErrorKind<DB::ErrorType>>,
+    >(
         &self,
         ops: O,
     ) -> Result<R, DrawingAreaError<DB>> {
@@ -308,17 +463,40 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
     }
 
     /// Draw an high-level element
+    ///
+    /// If the backend fails with a boxed error, the type name of the element and its first point
+    /// in the backend coordinate are attached to the error, see
+    /// [DrawingAreaErrorKind::context](enum.DrawingAreaErrorKind.html#method.context).
     pub fn draw<'a, E, B>(&self, element: &'a E) -> Result<(), DrawingAreaError<DB>>
     where
         B: CoordMapper,
         &'a E: PointCollection<'a, CT::From, B>,
         E: Drawable<DB, B>,
     {
+        let first_point: Cell<Option<BackendCoord>> = Cell::new(None);
         let backend_coords = element.point_iter().into_iter().map(|p| {
             let b = p.borrow();
+            if first_point.get().is_none() {
+                first_point.set(Some(self.coord.translate(b)));
+            }
             B::map(&self.coord, b, &self.rect)
         });
         self.backend_ops(move |b| element.draw(backend_coords, b, self.dim_in_pixel()))
+            .map_err(|e| Self::with_context(e, std::any::type_name::<E>(), first_point.get()))
+    }
+
+    /// Map the points of an element to the backend coordinate, thus the element can be drawn
+    /// later even if the coordinate specification of this area has been changed
+    pub(crate) fn map_points<'a, E, B>(&self, element: &'a E) -> Vec<B::Output>
+    where
+        B: CoordMapper,
+        &'a E: PointCollection<'a, CT::From, B>,
+    {
+        element
+            .point_iter()
+            .into_iter()
+            .map(|p| B::map(&self.coord, p.borrow(), &self.rect))
+            .collect()
     }
 
     /// Map coordinate to the backend coordinate
@@ -358,6 +536,31 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
         }
     }
 
+    /// Create a drawing area on the same region of the same backend, in which all the pixel
+    /// quantities, such as coordinates, stroke widths, element sizes and font sizes are
+    /// multiplied by the scale factor. This allows a chart authored in logical pixels
+    /// renders sharp on HiDPI displays.
+    ///
+    /// - `scale`: The scale factor, which is the number of physical pixels per logical pixel
+    /// - **returns** The drawing area in the logical pixels
+    pub fn with_scale(&self, scale: f64) -> DrawingArea<ScaledBackend<DB>, Shift> {
+        let to_logical = |value: i32| (f64::from(value) / scale).round() as i32;
+        let rect = Rect {
+            x0: to_logical(self.rect.x0),
+            y0: to_logical(self.rect.y0),
+            x1: to_logical(self.rect.x1),
+            y1: to_logical(self.rect.y1),
+        };
+        DrawingArea {
+            backend: Rc::new(RefCell::new(ScaledBackend::with_shared(
+                self.backend.clone(),
+                scale,
+            ))),
+            coord: Shift((rect.x0, rect.y0)),
+            rect,
+        }
+    }
+
     /// Shrink the region, note all the locations are in guest coordinate
     pub fn shrink<A: SizeDesc, B: SizeDesc, C: SizeDesc, D: SizeDesc>(
         mut self,
@@ -488,7 +691,8 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
         let style = &style.pos(Pos::new(HPos::Center, VPos::Top));
 
         self.backend_ops(|b| {
-            b.draw_text(
+            draw_text_with_background(
+                b,
                 text,
                 style,
                 (self.rect.x0 + x_padding, self.rect.y0 + y_padding),
@@ -514,7 +718,9 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
         style: &TextStyle,
         pos: BackendCoord,
     ) -> Result<(), DrawingAreaError<DB>> {
-        self.backend_ops(|b| b.draw_text(text, style, (pos.0 + self.rect.x0, pos.1 + self.rect.y0)))
+        let pos = (pos.0 + self.rect.x0, pos.1 + self.rect.y0);
+        self.backend_ops(|b| draw_text_with_background(b, text, style, pos))
+            .map_err(|e| Self::with_context(e, "text", Some(pos)))
     }
 }
 
@@ -733,6 +939,56 @@ mod drawing_area_tests {
             .unwrap();
     }
 
+    #[test]
+    fn test_with_scale() {
+        let drawing_area = create_mocked_drawing_area(1024, 768, |m| {
+            m.check_draw_rect(|_, s, f, u, d| {
+                assert_eq!(s, 2);
+                assert_eq!(f, false);
+                assert_eq!(u, (200, 100));
+                assert_eq!(d, (220, 120));
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_rect_call, 1));
+        });
+
+        let (_, right) = drawing_area.split_horizontally(200);
+        let scaled = right.with_scale(2.0);
+        assert_eq!(scaled.dim_in_pixel(), (412, 384));
+        scaled
+            .draw(&Rectangle::new([(0, 50), (10, 60)], &RED))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_draw_text_with_background() {
+        let drawing_area = create_mocked_drawing_area(1024, 768, |m| {
+            m.check_draw_rect(|c, _, f, u, d| {
+                assert_eq!(c, WHITE.to_rgba());
+                assert_eq!(f, true);
+                assert!(u.0 <= 95 && u.1 <= 95);
+                assert!(d.0 > 105 && d.1 > 105);
+            });
+            m.check_draw_rect(|c, _, f, _, _| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(f, false);
+            });
+            m.check_draw_text(|c, _, _, pos, text| {
+                assert_eq!(c, BLACK.to_rgba());
+                assert_eq!(pos, (100, 100));
+                assert_eq!(text, "Label");
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 2);
+                assert_eq!(b.num_draw_text_call, 1);
+                assert_eq!(b.draw_count, 3);
+            });
+        });
+
+        let style = TextStyle::from(("serif", 20).into_font())
+            .background(TextBackground::new(&WHITE).padding(5).border(&RED));
+        drawing_area.draw_text("Label", &style, (100, 100)).unwrap();
+    }
+
     #[test]
     fn test_margin() {
         let drawing_area = create_mocked_drawing_area(1024, 768, |m| {
@@ -850,4 +1106,103 @@ mod drawing_area_tests {
 
         drawing_area.fill(&RED).unwrap();
     }
+
+    #[test]
+    fn test_error_context() {
+        use crate::drawing::DummyBackendError;
+        use plotters_backend::{BackendColor, BackendCoord, BackendTextStyle, DrawingErrorKind};
+
+        struct FailingBackend;
+        impl DrawingBackend for FailingBackend {
+            type ErrorType = DummyBackendError;
+            fn get_size(&self) -> (u32, u32) {
+                (100, 100)
+            }
+            fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+                Ok(())
+            }
+            fn present(&mut self) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+                Ok(())
+            }
+            fn draw_pixel(
+                &mut self,
+                _: BackendCoord,
+                _: BackendColor,
+            ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+                Err(DrawingErrorKind::DrawingError(DummyBackendError))
+            }
+            fn draw_text<S: BackendTextStyle>(
+                &mut self,
+                _: &str,
+                _: &S,
+                _: BackendCoord,
+            ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+                Err(DrawingErrorKind::FontError(Box::new(DummyBackendError)))
+            }
+        }
+
+        let root = FailingBackend.into_drawing_area();
+        let area = root.margin(10, 0, 20, 0);
+
+        let error = area
+            .draw(&Text::new("a", (30, 40), ("sans-serif", 10)))
+            .expect_err("Expect a font error");
+        match error {
+            DrawingAreaErrorKind::BackendError(DrawingErrorKind::FontError(ref e)) => {
+                assert!(e.source().is_some())
+            }
+            _ => panic!("Expect a font error"),
+        }
+        let context = error.context().expect("Expect the error context");
+        assert!(context.operation.contains("Text"));
+        assert_eq!(context.position, Some((50, 50)));
+        assert!(error.to_string().ends_with("at (50, 50)"));
+
+        let error = area
+            .draw_text("a", &("sans-serif", 10).into_text_style(&area), (5, 5))
+            .expect_err("Expect a font error");
+        assert_eq!(
+            error.context().unwrap().to_string(),
+            "while drawing text at (25, 15)"
+        );
+
+        // The errors of the backend error type are returned unchanged
+        match area.draw(&Circle::new((30, 40), 5, RED.filled())) {
+            Err(e @ DrawingAreaErrorKind::BackendError(DrawingErrorKind::DrawingError(_))) => {
+                assert!(e.context().is_none())
+            }
+            _ => panic!("Expect a backend error"),
+        }
+    }
+
+    #[test]
+    fn test_draw_on_figure() {
+        let drawing_area = create_mocked_drawing_area(200, 100, |m| {
+            m.check_draw_circle(|_, _, _, center, r| {
+                assert_eq!(center, (190, 90));
+                assert_eq!(r, 5);
+            });
+            m.check_draw_pixel(|_, pos| assert_eq!(pos, (20, 25)));
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_circle_call, 1);
+                assert_eq!(b.num_draw_pixel_call, 1);
+            });
+        });
+
+        let plotting_area = drawing_area
+            .margin(10, 10, 10, 10)
+            .apply_coord_spec(Cartesian2d::<
+                crate::coord::types::RangedCoordf64,
+                crate::coord::types::RangedCoordf64,
+            >::new(1e15..2e15, 0.0..1.0, (10..190, 10..90)));
+
+        assert_eq!(plotting_area.figure_relative((0.1, 0.25)), (20, 25));
+        plotting_area
+            .draw_on_figure(&Circle::new((190, 90), 5, RED.filled()))
+            .unwrap();
+        plotting_area
+            .figure_area()
+            .draw_pixel((20, 25), &BLUE)
+            .unwrap();
+    }
 }
@@ -0,0 +1,176 @@
+use plotters_backend::{BackendColor, BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// The way the color drawn is combined with the color already on the canvas
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BlendMode {
+    /// The normal alpha compositing, the color drawn covers the canvas by its alpha
+    SourceOver,
+    /// Multiply the colors, which always makes the canvas darker, like overlapping inks
+    Multiply,
+    /// Multiply the inverted colors, which always makes the canvas lighter, like overlapping
+    /// lights
+    Screen,
+    /// Add the colors, the overlapping areas get brighter until they saturate, which reveals
+    /// the density of the overplotted data
+    Add,
+}
+
+impl BlendMode {
+    /// Blend a color onto a pixel of the canvas
+    /// - `src`: The color drawn
+    /// - `dst`: The color on the canvas
+    /// - **returns**: The resulting color of the pixel
+    pub fn blend(&self, src: BackendColor, dst: (u8, u8, u8)) -> (u8, u8, u8) {
+        let alpha = src.alpha.max(0.0).min(1.0);
+        let channel = |s: u8, d: u8| {
+            let (s, d) = (f64::from(s) / 255.0, f64::from(d) / 255.0);
+            let value = match self {
+                BlendMode::SourceOver => s * alpha + d * (1.0 - alpha),
+                BlendMode::Multiply => s * d * alpha + d * (1.0 - alpha),
+                BlendMode::Screen => (1.0 - (1.0 - s) * (1.0 - d)) * alpha + d * (1.0 - alpha),
+                BlendMode::Add => d + s * alpha,
+            };
+            (value.max(0.0).min(1.0) * 255.0).round() as u8
+        };
+        (
+            channel(src.rgb.0, dst.0),
+            channel(src.rgb.1, dst.1),
+            channel(src.rgb.2, dst.2),
+        )
+    }
+}
+
+/// The drawing backend that blends everything drawn on it with a [BlendMode](enum.BlendMode.html).
+///
+/// The backends only support the normal alpha compositing, and they can't read the canvas
+/// back, so this backend keeps a copy of the canvas and draws everything pixel by pixel with
+/// the blended colors. The copy starts black, which is the initial content of the bitmap
+/// backends, and the drawing area should be filled with the background color first as usual.
+/// Since everything is rasterized, a vector backend, such as the SVG backend, receives the
+/// image as pixels.
+///
+/// The blend mode of the backend is used for everything, and the elements wrapped by
+/// [Blended](../element/struct.Blended.html) are drawn with their own blend modes.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut buffer = vec![0; 200 * 200 * 3];
+/// let root = BlendBackend::new(BitMapBackend::with_buffer(&mut buffer, (200, 200)))
+///     .into_drawing_area();
+/// root.fill(&BLACK).unwrap();
+/// for x in (40..160).step_by(20) {
+///     root.draw(&Blended::new(
+///         Circle::new((x, 100), 30, RED.mix(0.3).filled()),
+///         BlendMode::Add,
+///     ))
+///     .unwrap();
+/// }
+/// ```
+pub struct BlendBackend<DB: DrawingBackend> {
+    inner: DB,
+    canvas: Vec<(u8, u8, u8)>,
+    size: (u32, u32),
+    mode: BlendMode,
+}
+
+impl<DB: DrawingBackend> BlendBackend<DB> {
+    /// Create a new blending backend, which uses `BlendMode::SourceOver` by default
+    /// - `inner`: The backend that actually renders the image
+    pub fn new(inner: DB) -> Self {
+        let size = inner.get_size();
+        Self {
+            inner,
+            canvas: vec![(0, 0, 0); size.0 as usize * size.1 as usize],
+            size,
+            mode: BlendMode::SourceOver,
+        }
+    }
+
+    /// Set the blend mode used for everything drawn on the backend
+    pub fn blend_mode(mut self, mode: BlendMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Set the blend mode and get the previous one
+    pub(crate) fn replace_blend_mode(&mut self, mode: BlendMode) -> BlendMode {
+        std::mem::replace(&mut self.mode, mode)
+    }
+
+    /// Consume the blending backend and get the underlying backend
+    pub fn into_inner(self) -> DB {
+        self.inner
+    }
+}
+
+impl<DB: DrawingBackend> DrawingBackend for BlendBackend<DB> {
+    type ErrorType = DB::ErrorType;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.size
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.inner.ensure_prepared()
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.inner.present()
+    }
+
+    fn draw_pixel(
+        &mut self,
+        (x, y): BackendCoord,
+        color: BackendColor,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if x < 0 || y < 0 || x as u32 >= self.size.0 || y as u32 >= self.size.1 {
+            return Ok(());
+        }
+        if color.alpha <= 0.0 {
+            return Ok(());
+        }
+        let idx = y as usize * self.size.0 as usize + x as usize;
+        let rgb = self.mode.blend(color, self.canvas[idx]);
+        self.canvas[idx] = rgb;
+        self.inner
+            .draw_pixel((x, y), BackendColor { alpha: 1.0, rgb })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::drawing::MockedBackend;
+
+    #[test]
+    fn test_blend_modes() {
+        let red = BackendColor {
+            alpha: 0.4,
+            rgb: (255, 0, 0),
+        };
+        let gray = (100, 100, 100);
+        assert_eq!(BlendMode::SourceOver.blend(red, gray), (162, 60, 60));
+        assert_eq!(BlendMode::Multiply.blend(red, gray), (100, 60, 60));
+        assert_eq!(BlendMode::Screen.blend(red, gray), (162, 100, 100));
+        assert_eq!(BlendMode::Add.blend(red, gray), (202, 100, 100));
+        assert_eq!(BlendMode::Add.blend(red, (200, 0, 0)), (255, 0, 0));
+    }
+
+    #[test]
+    fn test_blend_backend() {
+        let mut drawn = vec![];
+        let mocked = MockedBackend::new(10, 10);
+        let mut backend = BlendBackend::new(mocked).blend_mode(BlendMode::Add);
+        let color = BackendColor {
+            alpha: 0.25,
+            rgb: (0, 200, 0),
+        };
+        for _ in 0..3 {
+            backend.draw_pixel((2, 3), color).unwrap();
+            drawn.push(backend.canvas[3 * 10 + 2]);
+        }
+        backend.draw_pixel((20, 3), color).unwrap();
+        assert_eq!(drawn, vec![(0, 50, 0), (0, 100, 0), (0, 150, 0)]);
+    }
+}
@@ -0,0 +1,192 @@
+use plotters_backend::text_anchor::{HPos, VPos};
+use plotters_backend::{
+    BackendColor, BackendCoord, BackendStyle, BackendTextStyle, DrawingBackend, DrawingErrorKind,
+};
+
+/// The drawing backend adapter used by the clipped text elements, see
+/// [Text::clip_to](../element/struct.Text.html#method.clip_to), which discards everything drawn
+/// out of the clip rectangle.
+///
+/// The text fully inside the clip rectangle is passed to the backend unchanged. The text crossing
+/// the edge and the shapes other than the filled rectangles are clipped pixel by pixel, so they
+/// are rasterized even on the vector backends.
+pub(crate) struct ClippedBackend<'b, DB: DrawingBackend> {
+    inner: &'b mut DB,
+    area: (BackendCoord, BackendCoord),
+}
+
+impl<'b, DB: DrawingBackend> ClippedBackend<'b, DB> {
+    /// Create the adapter clipping everything to the rectangle `[upper_left, bottom_right)`
+    pub(crate) fn new(inner: &'b mut DB, area: (BackendCoord, BackendCoord)) -> Self {
+        Self { inner, area }
+    }
+
+    fn contains(&self, (x, y): BackendCoord) -> bool {
+        let ((x0, y0), (x1, y1)) = self.area;
+        x >= x0 && x < x1 && y >= y0 && y < y1
+    }
+}
+
+impl<'b, DB: DrawingBackend> DrawingBackend for ClippedBackend<'b, DB> {
+    type ErrorType = DB::ErrorType;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.inner.get_size()
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.inner.ensure_prepared()
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.inner.present()
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: BackendColor,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if !self.contains(point) {
+            return Ok(());
+        }
+        self.inner.draw_pixel(point, color)
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if !fill {
+            // The border on the clip edge must not be drawn, so the outline is drawn as lines
+            let corners = [
+                upper_left,
+                (bottom_right.0, upper_left.1),
+                bottom_right,
+                (upper_left.0, bottom_right.1),
+            ];
+            for (from, to) in corners.iter().zip(corners.iter().cycle().skip(1)) {
+                self.draw_line(*from, *to, style)?;
+            }
+            return Ok(());
+        }
+        let ((x0, y0), (x1, y1)) = self.area;
+        let upper_left = (upper_left.0.max(x0), upper_left.1.max(y0));
+        let bottom_right = (bottom_right.0.min(x1 - 1), bottom_right.1.min(y1 - 1));
+        if upper_left.0 > bottom_right.0 || upper_left.1 > bottom_right.1 {
+            return Ok(());
+        }
+        self.inner.draw_rect(upper_left, bottom_right, style, true)
+    }
+
+    fn draw_text<TStyle: BackendTextStyle>(
+        &mut self,
+        text: &str,
+        style: &TStyle,
+        pos: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let color = style.color();
+        if color.alpha == 0.0 {
+            return Ok(());
+        }
+
+        let ((min_x, min_y), (max_x, max_y)) = style
+            .layout_box(text)
+            .map_err(|e| DrawingErrorKind::FontError(Box::new(e)))?;
+        let (width, height) = (max_x - min_x, max_y - min_y);
+        let dx = match style.anchor().h_pos {
+            HPos::Left => 0,
+            HPos::Right => -width,
+            HPos::Center => -width / 2,
+        };
+        let dy = match style.anchor().v_pos {
+            VPos::Top => 0,
+            VPos::Center => -height / 2,
+            VPos::Bottom => -height,
+        };
+        let trans = style.transform();
+
+        // The glyphs are rasterized in the boxes of the font size, which may overhang the layout
+        // box, but never by more than the font size
+        let overhang = style.size().ceil() as i32;
+        let (x0, y0) = trans.transform(dx - overhang, dy - overhang);
+        let (x1, y1) = trans.transform(dx + width + overhang, dy + height + overhang);
+        let (upper_left, bottom_right) = (
+            (pos.0 + x0.min(x1), pos.1 + y0.min(y1)),
+            (pos.0 + x0.max(x1), pos.1 + y0.max(y1)),
+        );
+        if self.contains(upper_left) && self.contains((bottom_right.0 - 1, bottom_right.1 - 1)) {
+            return self.inner.draw_text(text, style, pos);
+        }
+
+        let result = style.draw(text, (0, 0), |x, y, color| {
+            let (x, y) = trans.transform(x + dx - min_x, y + dy - min_y);
+            self.draw_pixel((pos.0 + x, pos.1 + y), color)
+        });
+        match result {
+            Ok(result) => result,
+            Err(e) => Err(DrawingErrorKind::FontError(Box::new(e))),
+        }
+    }
+
+    fn estimate_text_size<TStyle: BackendTextStyle>(
+        &self,
+        text: &str,
+        style: &TStyle,
+    ) -> Result<(u32, u32), DrawingErrorKind<DB::ErrorType>> {
+        self.inner.estimate_text_size(text, style)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[cfg(feature = "ttf")]
+    #[test]
+    fn test_clipped_text_background() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_rect(|c, _, fill, u, d| {
+                assert_eq!(c, RED.to_rgba());
+                assert!(fill);
+                assert_eq!(u.1, 10);
+                assert_eq!(d.0, 89);
+            });
+            m.check_draw_pixel(|_, (x, y)| {
+                assert!((10..90).contains(&x) && (10..90).contains(&y));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 1);
+                assert_eq!(b.num_draw_text_call, 0);
+                assert!(b.num_draw_pixel_call > 0);
+            });
+        });
+        let area = drawing_area.margin(10, 10, 10, 10);
+
+        let style = TextStyle::from(("sans-serif", 20).into_font())
+            .background(TextBackground::new(&RED).padding(5));
+        area.draw(&Text::new("Outlier", (70, 0), style).clip_to(&area))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_clipped_text_inside() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(pos, (60, 60));
+                assert_eq!(text, "a");
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_text_call, 1);
+                assert_eq!(b.num_draw_pixel_call, 0);
+            });
+        });
+        let area = drawing_area.margin(10, 10, 10, 10);
+
+        area.draw(&Text::new("a", (50, 50), ("sans-serif", 10)).clip_to(&area))
+            .unwrap();
+    }
+}
@@ -3,6 +3,21 @@ mod mocked;
 #[cfg(test)]
 pub use mocked::{check_color, create_mocked_drawing_area, MockedBackend};
 
+mod blend;
+pub use blend::{BlendBackend, BlendMode};
+
+mod clipped;
+pub(crate) use clipped::ClippedBackend;
+
+mod recording;
+pub use recording::{DrawCommand, RecordingBackend};
+
+mod scaled;
+pub use scaled::ScaledBackend;
+
+mod tee;
+pub use tee::{TeeBackend, TeeBackendError};
+
 /// This is the dummy backend placeholder for the backend that never fails
 #[derive(Debug)]
 pub struct DummyBackendError;
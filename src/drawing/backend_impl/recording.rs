@@ -0,0 +1,309 @@
+use super::DummyBackendError;
+use crate::style::RGBAColor;
+use plotters_backend::{
+    BackendColor, BackendCoord, BackendStyle, BackendTextStyle, DrawingBackend, DrawingErrorKind,
+};
+
+/// A drawing primitive recorded by the [RecordingBackend](struct.RecordingBackend.html)
+#[derive(Clone, Debug, PartialEq)]
+pub enum DrawCommand {
+    /// A single pixel
+    Pixel {
+        /// The position of the pixel
+        point: BackendCoord,
+        /// The color of the pixel
+        color: RGBAColor,
+    },
+    /// A straight line
+    Line {
+        /// The start point
+        from: BackendCoord,
+        /// The end point
+        to: BackendCoord,
+        /// The color of the line
+        color: RGBAColor,
+        /// The stroke width in pixels
+        stroke_width: u32,
+    },
+    /// A rectangle
+    Rect {
+        /// The upper left corner
+        upper_left: BackendCoord,
+        /// The bottom right corner
+        bottom_right: BackendCoord,
+        /// The color of the rectangle
+        color: RGBAColor,
+        /// The stroke width in pixels
+        stroke_width: u32,
+        /// If the rectangle is filled
+        fill: bool,
+    },
+    /// A polyline
+    Path {
+        /// The points of the polyline
+        path: Vec<BackendCoord>,
+        /// The color of the polyline
+        color: RGBAColor,
+        /// The stroke width in pixels
+        stroke_width: u32,
+    },
+    /// A circle
+    Circle {
+        /// The center of the circle
+        center: BackendCoord,
+        /// The radius in pixels
+        radius: u32,
+        /// The color of the circle
+        color: RGBAColor,
+        /// The stroke width in pixels
+        stroke_width: u32,
+        /// If the circle is filled
+        fill: bool,
+    },
+    /// A filled polygon
+    Polygon {
+        /// The vertices of the polygon
+        vert: Vec<BackendCoord>,
+        /// The fill color
+        color: RGBAColor,
+    },
+    /// A piece of text
+    Text {
+        /// The text content
+        text: String,
+        /// The font family name
+        family: String,
+        /// The font size
+        size: f64,
+        /// The color of the text
+        color: RGBAColor,
+        /// The anchor position of the text
+        pos: BackendCoord,
+    },
+    /// A bitmap blit
+    Bitmap {
+        /// The upper left corner of the bitmap
+        pos: BackendCoord,
+        /// The size of the bitmap in pixels
+        size: (u32, u32),
+    },
+}
+
+/// The drawing backend that records every drawing primitive instead of rendering it.
+///
+/// This is useful for testing the drawing code in a resolution independent way, since the
+/// recorded [DrawCommand](enum.DrawCommand.html)s can be compared directly. As the drawing area
+/// takes the ownership of the backend, share the backend with `Rc<RefCell<_>>` to read the
+/// commands back after drawing.
+///
+/// ```rust
+/// use plotters::prelude::*;
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+///
+/// let backend = Rc::new(RefCell::new(RecordingBackend::new(100, 100)));
+/// let root: DrawingArea<_, plotters::coord::Shift> = (&backend).into();
+/// root.draw(&PathElement::new(vec![(0, 0), (10, 10)], &RED)).unwrap();
+///
+/// assert_eq!(
+///     backend.borrow().commands(),
+///     &[DrawCommand::Path {
+///         path: vec![(0, 0), (10, 10)],
+///         color: RED.to_rgba(),
+///         stroke_width: 1,
+///     }]
+/// );
+/// ```
+pub struct RecordingBackend {
+    size: (u32, u32),
+    commands: Vec<DrawCommand>,
+}
+
+fn to_rgba(color: BackendColor) -> RGBAColor {
+    RGBAColor(color.rgb.0, color.rgb.1, color.rgb.2, color.alpha)
+}
+
+impl RecordingBackend {
+    /// Create a new recording backend with the given size
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            size: (width, height),
+            commands: vec![],
+        }
+    }
+
+    /// Get the commands recorded so far
+    pub fn commands(&self) -> &[DrawCommand] {
+        &self.commands
+    }
+
+    /// Take the commands recorded so far and clear the record
+    pub fn take_commands(&mut self) -> Vec<DrawCommand> {
+        std::mem::replace(&mut self.commands, vec![])
+    }
+}
+
+impl DrawingBackend for RecordingBackend {
+    type ErrorType = DummyBackendError;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.size
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        Ok(())
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        Ok(())
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: BackendColor,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        self.commands.push(DrawCommand::Pixel {
+            point,
+            color: to_rgba(color),
+        });
+        Ok(())
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        self.commands.push(DrawCommand::Line {
+            from,
+            to,
+            color: to_rgba(style.color()),
+            stroke_width: style.stroke_width(),
+        });
+        Ok(())
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        self.commands.push(DrawCommand::Rect {
+            upper_left,
+            bottom_right,
+            color: to_rgba(style.color()),
+            stroke_width: style.stroke_width(),
+            fill,
+        });
+        Ok(())
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        self.commands.push(DrawCommand::Path {
+            path: path.into_iter().collect(),
+            color: to_rgba(style.color()),
+            stroke_width: style.stroke_width(),
+        });
+        Ok(())
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        self.commands.push(DrawCommand::Circle {
+            center,
+            radius,
+            color: to_rgba(style.color()),
+            stroke_width: style.stroke_width(),
+            fill,
+        });
+        Ok(())
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        self.commands.push(DrawCommand::Polygon {
+            vert: vert.into_iter().collect(),
+            color: to_rgba(style.color()),
+        });
+        Ok(())
+    }
+
+    fn draw_text<TStyle: BackendTextStyle>(
+        &mut self,
+        text: &str,
+        style: &TStyle,
+        pos: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        self.commands.push(DrawCommand::Text {
+            text: text.to_string(),
+            family: style.family().as_str().to_string(),
+            size: style.size(),
+            color: to_rgba(style.color()),
+            pos,
+        });
+        Ok(())
+    }
+
+    fn blit_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        size: (u32, u32),
+        _src: &[u8],
+    ) -> Result<(), DrawingErrorKind<DummyBackendError>> {
+        self.commands.push(DrawCommand::Bitmap { pos, size });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_recording_backend() {
+        let mut backend = RecordingBackend::new(100, 100);
+        backend
+            .draw_line((0, 0), (10, 20), &ShapeStyle::from(&BLUE).stroke_width(3))
+            .unwrap();
+        backend
+            .draw_rect((1, 2), (3, 4), &RED.to_rgba(), true)
+            .unwrap();
+
+        assert_eq!(
+            backend.take_commands(),
+            vec![
+                DrawCommand::Line {
+                    from: (0, 0),
+                    to: (10, 20),
+                    color: BLUE.to_rgba(),
+                    stroke_width: 3,
+                },
+                DrawCommand::Rect {
+                    upper_left: (1, 2),
+                    bottom_right: (3, 4),
+                    color: RED.to_rgba(),
+                    stroke_width: 1,
+                    fill: true,
+                },
+            ]
+        );
+        assert!(backend.commands().is_empty());
+    }
+}
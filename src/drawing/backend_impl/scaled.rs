@@ -0,0 +1,283 @@
+use crate::style::{FontDesc, TextStyle};
+use plotters_backend::{
+    BackendColor, BackendCoord, BackendStyle, BackendTextStyle, DrawingBackend, DrawingErrorKind,
+};
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// The drawing backend that scales everything drawn on it by a constant factor.
+///
+/// The chart is authored in the logical pixels, and all the pixel quantities, including the
+/// coordinates, stroke widths, circle radius and font sizes are multiplied by the scale factor
+/// before they are passed to the underlying backend. This allows the same drawing code renders
+/// visually identical images in different resolutions, e.g. for HiDPI displays.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// fn draw<DB: DrawingBackend>(root: DrawingArea<DB, plotters::coord::Shift>) {
+///     root.fill(&WHITE).unwrap();
+///     root.draw(&Circle::new((50, 50), 10, &RED)).unwrap();
+/// }
+///
+/// let mut buffer = vec![0; 200 * 200 * 3];
+/// // The logical size of the drawing area is 100x100
+/// let root = ScaledBackend::new(BitMapBackend::with_buffer(&mut buffer, (200, 200)), 2.0)
+///     .into_drawing_area();
+/// draw(root);
+/// ```
+///
+/// To scale an existing drawing area, use
+/// [DrawingArea::with_scale](struct.DrawingArea.html#method.with_scale) instead.
+pub struct ScaledBackend<DB: DrawingBackend> {
+    inner: Rc<RefCell<DB>>,
+    scale: f64,
+}
+
+struct ScaledStyle {
+    color: BackendColor,
+    stroke_width: u32,
+}
+
+impl BackendStyle for ScaledStyle {
+    fn color(&self) -> BackendColor {
+        self.color
+    }
+    fn stroke_width(&self) -> u32 {
+        self.stroke_width
+    }
+}
+
+impl<DB: DrawingBackend> ScaledBackend<DB> {
+    /// Create a new scaled backend
+    /// - `inner`: The backend that actually renders the image
+    /// - `scale`: The scale factor, which is the number of physical pixels per logical pixel
+    pub fn new(inner: DB, scale: f64) -> Self {
+        Self::with_shared(Rc::new(RefCell::new(inner)), scale)
+    }
+
+    pub(crate) fn with_shared(inner: Rc<RefCell<DB>>, scale: f64) -> Self {
+        Self { inner, scale }
+    }
+
+    /// Get the scale factor
+    pub fn scale_factor(&self) -> f64 {
+        self.scale
+    }
+
+    /// Consume the scaled backend and get the underlying backend, returns `None` if the
+    /// underlying backend is still shared with other drawing areas
+    pub fn into_inner(self) -> Option<DB> {
+        Rc::try_unwrap(self.inner).ok().map(RefCell::into_inner)
+    }
+
+    fn scale_value(&self, value: i32) -> i32 {
+        (f64::from(value) * self.scale).round() as i32
+    }
+
+    fn scale_coord(&self, (x, y): BackendCoord) -> BackendCoord {
+        (self.scale_value(x), self.scale_value(y))
+    }
+
+    fn scale_size(&self, value: u32) -> u32 {
+        (f64::from(value) * self.scale).round() as u32
+    }
+
+    fn scale_style<S: BackendStyle>(&self, style: &S) -> ScaledStyle {
+        ScaledStyle {
+            color: style.color(),
+            stroke_width: self.scale_size(style.stroke_width()).max(1),
+        }
+    }
+
+    fn scale_text_style<'a, S: BackendTextStyle>(&self, style: &'a S) -> TextStyle<'a> {
+        let font = FontDesc::new(style.family(), style.size() * self.scale, style.style())
+            .transform(style.transform());
+        TextStyle {
+            font,
+            color: style.color(),
+            pos: style.anchor(),
+            background: None,
+        }
+    }
+}
+
+impl<DB: DrawingBackend> DrawingBackend for ScaledBackend<DB> {
+    type ErrorType = DB::ErrorType;
+
+    fn get_size(&self) -> (u32, u32) {
+        let (w, h) = self.inner.borrow().get_size();
+        (
+            (f64::from(w) / self.scale).round() as u32,
+            (f64::from(h) / self.scale).round() as u32,
+        )
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.inner.borrow_mut().ensure_prepared()
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.inner.borrow_mut().present()
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: BackendColor,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let upper_left = self.scale_coord(point);
+        let bottom_right = self.scale_coord((point.0 + 1, point.1 + 1));
+        if bottom_right.0 - upper_left.0 <= 1 && bottom_right.1 - upper_left.1 <= 1 {
+            return self.inner.borrow_mut().draw_pixel(upper_left, color);
+        }
+        self.inner.borrow_mut().draw_rect(
+            upper_left,
+            (bottom_right.0 - 1, bottom_right.1 - 1),
+            &color,
+            true,
+        )
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let style = self.scale_style(style);
+        let (from, to) = (self.scale_coord(from), self.scale_coord(to));
+        self.inner.borrow_mut().draw_line(from, to, &style)
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let style = self.scale_style(style);
+        let (upper_left, bottom_right) =
+            (self.scale_coord(upper_left), self.scale_coord(bottom_right));
+        self.inner
+            .borrow_mut()
+            .draw_rect(upper_left, bottom_right, &style, fill)
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let style = self.scale_style(style);
+        let path: Vec<_> = path.into_iter().map(|p| self.scale_coord(p)).collect();
+        self.inner.borrow_mut().draw_path(path, &style)
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let style = self.scale_style(style);
+        let (center, radius) = (self.scale_coord(center), self.scale_size(radius));
+        self.inner
+            .borrow_mut()
+            .draw_circle(center, radius, &style, fill)
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let style = self.scale_style(style);
+        let vert: Vec<_> = vert.into_iter().map(|p| self.scale_coord(p)).collect();
+        self.inner.borrow_mut().fill_polygon(vert, &style)
+    }
+
+    fn draw_text<TStyle: BackendTextStyle>(
+        &mut self,
+        text: &str,
+        style: &TStyle,
+        pos: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let style = self.scale_text_style(style);
+        let pos = self.scale_coord(pos);
+        self.inner.borrow_mut().draw_text(text, &style, pos)
+    }
+
+    fn estimate_text_size<TStyle: BackendTextStyle>(
+        &self,
+        text: &str,
+        style: &TStyle,
+    ) -> Result<(u32, u32), DrawingErrorKind<DB::ErrorType>> {
+        let (w, h) = self
+            .inner
+            .borrow()
+            .estimate_text_size(text, &self.scale_text_style(style))?;
+        Ok((
+            (f64::from(w) / self.scale).round() as u32,
+            (f64::from(h) / self.scale).round() as u32,
+        ))
+    }
+
+    fn blit_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        (iw, ih): (u32, u32),
+        src: &[u8],
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (sw, sh) = (self.scale_size(iw), self.scale_size(ih));
+        let mut buffer = vec![0; (sw * sh * 3) as usize];
+        for y in 0..sh {
+            let sy = ((f64::from(y) / self.scale) as u32).min(ih.max(1) - 1);
+            for x in 0..sw {
+                let sx = ((f64::from(x) / self.scale) as u32).min(iw.max(1) - 1);
+                let (src_idx, dst_idx) =
+                    (((sy * iw + sx) * 3) as usize, ((y * sw + x) * 3) as usize);
+                buffer[dst_idx..dst_idx + 3].copy_from_slice(&src[src_idx..src_idx + 3]);
+            }
+        }
+        let pos = self.scale_coord(pos);
+        self.inner.borrow_mut().blit_bitmap(pos, (sw, sh), &buffer)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::drawing::MockedBackend;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_scaled_backend() {
+        let mut mocked = MockedBackend::new(200, 100);
+        mocked.check_draw_circle(|_, s, f, c, r| {
+            assert_eq!(s, 4);
+            assert!(!f);
+            assert_eq!(c, (20, 40));
+            assert_eq!(r, 10);
+        });
+        mocked.check_draw_text(|_, _, size, pos, _| {
+            assert_eq!(size, 20.0);
+            assert_eq!(pos, (2, 4));
+        });
+        mocked.drop_check(|b| {
+            assert_eq!(b.num_draw_circle_call, 1);
+            assert_eq!(b.num_draw_text_call, 1);
+        });
+
+        let root = ScaledBackend::new(mocked, 2.0).into_drawing_area();
+        assert_eq!(root.dim_in_pixel(), (100, 50));
+
+        root.draw(&Circle::new((10, 20), 5, Color::stroke_width(&RED, 2)))
+            .unwrap();
+        root.draw_text("Test", &("sans-serif", 10).into_text_style(&root), (1, 2))
+            .unwrap();
+    }
+}
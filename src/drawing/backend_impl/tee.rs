@@ -0,0 +1,243 @@
+use plotters_backend::{
+    BackendColor, BackendCoord, BackendStyle, BackendTextStyle, DrawingBackend, DrawingErrorKind,
+};
+
+use std::error::Error;
+
+/// The drawing backend that forwards every drawing call to two underlying backends.
+///
+/// This allows rendering the same chart to multiple targets in a single pass, e.g. a bitmap and
+/// an SVG file, or a file and a live window. More than two backends can be combined by nesting
+/// the tee backends. The size and the text metrics are taken from the first backend.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut first = vec![0; 100 * 100 * 3];
+/// let mut second = vec![0; 100 * 100 * 3];
+/// let root = TeeBackend::new(
+///     BitMapBackend::with_buffer(&mut first, (100, 100)),
+///     BitMapBackend::with_buffer(&mut second, (100, 100)),
+/// )
+/// .into_drawing_area();
+/// root.fill(&WHITE).unwrap();
+/// root.draw(&Circle::new((50, 50), 10, &RED)).unwrap();
+/// ```
+pub struct TeeBackend<A: DrawingBackend, B: DrawingBackend> {
+    first: A,
+    second: B,
+}
+
+/// The error produced by a [TeeBackend](struct.TeeBackend.html), which tells which of the
+/// underlying backends has failed
+#[derive(Debug)]
+pub enum TeeBackendError<A: Error, B: Error> {
+    /// The error from the first backend
+    First(A),
+    /// The error from the second backend
+    Second(B),
+}
+
+impl<A: Error, B: Error> std::fmt::Display for TeeBackendError<A, B> {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            TeeBackendError::First(e) => write!(fmt, "First backend: {}", e),
+            TeeBackendError::Second(e) => write!(fmt, "Second backend: {}", e),
+        }
+    }
+}
+
+impl<A: Error, B: Error> Error for TeeBackendError<A, B> {}
+
+type TeeResult<A, B> = Result<
+    (),
+    DrawingErrorKind<
+        TeeBackendError<<A as DrawingBackend>::ErrorType, <B as DrawingBackend>::ErrorType>,
+    >,
+>;
+
+fn map_first<A: Error + Send + Sync, B: Error + Send + Sync>(
+    e: DrawingErrorKind<A>,
+) -> DrawingErrorKind<TeeBackendError<A, B>> {
+    match e {
+        DrawingErrorKind::DrawingError(e) => {
+            DrawingErrorKind::DrawingError(TeeBackendError::First(e))
+        }
+        DrawingErrorKind::FontError(e) => DrawingErrorKind::FontError(e),
+    }
+}
+
+fn map_second<A: Error + Send + Sync, B: Error + Send + Sync>(
+    e: DrawingErrorKind<B>,
+) -> DrawingErrorKind<TeeBackendError<A, B>> {
+    match e {
+        DrawingErrorKind::DrawingError(e) => {
+            DrawingErrorKind::DrawingError(TeeBackendError::Second(e))
+        }
+        DrawingErrorKind::FontError(e) => DrawingErrorKind::FontError(e),
+    }
+}
+
+impl<A: DrawingBackend, B: DrawingBackend> TeeBackend<A, B> {
+    /// Create a new tee backend
+    /// - `first`: The first backend, which also determines the size of the drawing area
+    /// - `second`: The second backend
+    pub fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+
+    /// Consume the tee backend and get the underlying backends
+    pub fn into_inner(self) -> (A, B) {
+        (self.first, self.second)
+    }
+
+    fn forward<F, G>(&mut self, first: F, second: G) -> TeeResult<A, B>
+    where
+        F: FnOnce(&mut A) -> Result<(), DrawingErrorKind<A::ErrorType>>,
+        G: FnOnce(&mut B) -> Result<(), DrawingErrorKind<B::ErrorType>>,
+    {
+        first(&mut self.first).map_err(map_first::<_, B::ErrorType>)?;
+        second(&mut self.second).map_err(map_second)
+    }
+}
+
+impl<A: DrawingBackend, B: DrawingBackend> DrawingBackend for TeeBackend<A, B> {
+    type ErrorType = TeeBackendError<A::ErrorType, B::ErrorType>;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.first.get_size()
+    }
+
+    fn ensure_prepared(&mut self) -> TeeResult<A, B> {
+        self.forward(|b| b.ensure_prepared(), |b| b.ensure_prepared())
+    }
+
+    fn present(&mut self) -> TeeResult<A, B> {
+        self.forward(|b| b.present(), |b| b.present())
+    }
+
+    fn draw_pixel(&mut self, point: BackendCoord, color: BackendColor) -> TeeResult<A, B> {
+        self.forward(
+            |b| b.draw_pixel(point, color),
+            |b| b.draw_pixel(point, color),
+        )
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> TeeResult<A, B> {
+        self.forward(
+            |b| b.draw_line(from, to, style),
+            |b| b.draw_line(from, to, style),
+        )
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> TeeResult<A, B> {
+        self.forward(
+            |b| b.draw_rect(upper_left, bottom_right, style, fill),
+            |b| b.draw_rect(upper_left, bottom_right, style, fill),
+        )
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> TeeResult<A, B> {
+        let path: Vec<_> = path.into_iter().collect();
+        self.forward(
+            |b| b.draw_path(path.iter().copied(), style),
+            |b| b.draw_path(path.iter().copied(), style),
+        )
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> TeeResult<A, B> {
+        self.forward(
+            |b| b.draw_circle(center, radius, style, fill),
+            |b| b.draw_circle(center, radius, style, fill),
+        )
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> TeeResult<A, B> {
+        let vert: Vec<_> = vert.into_iter().collect();
+        self.forward(
+            |b| b.fill_polygon(vert.iter().copied(), style),
+            |b| b.fill_polygon(vert.iter().copied(), style),
+        )
+    }
+
+    fn draw_text<TStyle: BackendTextStyle>(
+        &mut self,
+        text: &str,
+        style: &TStyle,
+        pos: BackendCoord,
+    ) -> TeeResult<A, B> {
+        self.forward(
+            |b| b.draw_text(text, style, pos),
+            |b| b.draw_text(text, style, pos),
+        )
+    }
+
+    fn estimate_text_size<TStyle: BackendTextStyle>(
+        &self,
+        text: &str,
+        style: &TStyle,
+    ) -> Result<(u32, u32), DrawingErrorKind<Self::ErrorType>> {
+        self.first
+            .estimate_text_size(text, style)
+            .map_err(map_first)
+    }
+
+    fn blit_bitmap(&mut self, pos: BackendCoord, size: (u32, u32), src: &[u8]) -> TeeResult<A, B> {
+        self.forward(
+            |b| b.blit_bitmap(pos, size, src),
+            |b| b.blit_bitmap(pos, size, src),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::drawing::MockedBackend;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_tee_backend() {
+        let make_mocked = || {
+            let mut mocked = MockedBackend::new(100, 100);
+            mocked.check_draw_circle(|_, _, f, c, r| {
+                assert!(f);
+                assert_eq!(c, (20, 30));
+                assert_eq!(r, 5);
+            });
+            mocked.drop_check(|b| {
+                assert_eq!(b.num_draw_circle_call, 1);
+            });
+            mocked
+        };
+
+        let root = TeeBackend::new(make_mocked(), make_mocked()).into_drawing_area();
+        assert_eq!(root.dim_in_pixel(), (100, 100));
+        root.draw(&Circle::new((20, 30), 5, RED.filled())).unwrap();
+    }
+}
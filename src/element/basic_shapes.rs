@@ -1,6 +1,7 @@
 use super::{Drawable, PointCollection};
-use crate::style::{Color, ShapeStyle, SizeDesc};
-use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use crate::style::{Color, RGBAColor, ShapeStyle, SizeDesc};
+use plotters_backend::{BackendColor, BackendCoord, DrawingBackend, DrawingErrorKind};
+use std::collections::HashMap;
 
 /// An element of a single pixel
 pub struct Pixel<Coord> {
@@ -95,7 +96,7 @@ impl<Coord, DB: DrawingBackend> Drawable<DB> for PathElement<Coord> {
         backend: &mut DB,
         _: (u32, u32),
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
-        backend.draw_path(points, &self.style)
+        self.style.draw_path(backend, points)
     }
 }
 
@@ -121,11 +122,146 @@ fn test_path_element() {
     .expect("Drawing Failure");
 }
 
+#[cfg(test)]
+#[test]
+fn test_dashed_outline() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_draw_path(|c, _, path| {
+            assert_eq!(c, RED.to_rgba());
+            assert_eq!(path, vec![(0, 0), (4, 0)]);
+        });
+        m.check_draw_path(|_, _, path| assert_eq!(path, vec![(6, 0), (10, 0)]));
+        m.check_draw_path(|_, _, _| {});
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_path_call, 7);
+            assert_eq!(b.num_draw_rect_call, 0);
+        });
+    });
+    da.draw(&Rectangle::new(
+        [(0, 0), (10, 10)],
+        ShapeStyle::from(&RED).dashed(4, 2),
+    ))
+    .expect("Drawing Failure");
+}
+
+/// An arrow element, which is a line segment with a filled arrowhead at its end. The
+/// arrowhead is sized in pixels, so it looks the same regardless of the coordinate
+pub struct Arrow<Coord> {
+    points: [Coord; 2],
+    style: ShapeStyle,
+    head_size: u32,
+}
+
+impl<Coord> Arrow<Coord> {
+    /// Create a new arrow
+    /// - `from`: The tail of the arrow
+    /// - `to`: The point the arrow points to
+    /// - `style`: The shape style, the arrowhead is filled with the same color
+    pub fn new<S: Into<ShapeStyle>>(from: Coord, to: Coord, style: S) -> Self {
+        Self {
+            points: [from, to],
+            style: style.into(),
+            head_size: 8,
+        }
+    }
+
+    /// Set the length of the arrowhead in pixels, the default is 8
+    pub fn head_size(mut self, size: u32) -> Self {
+        self.head_size = size;
+        self
+    }
+}
+
+/// Compute the triangle of an arrowhead at `to`, the arrowhead is shortened when the arrow is
+/// shorter than the arrowhead
+/// - **returns**: The base of the arrowhead on the shaft, and the three vertices of the head
+pub(crate) fn arrow_head(
+    from: BackendCoord,
+    to: BackendCoord,
+    size: u32,
+) -> Option<(BackendCoord, [BackendCoord; 3])> {
+    let (dx, dy) = (f64::from(to.0 - from.0), f64::from(to.1 - from.1));
+    let len = dx.hypot(dy);
+    if len < 1.0 {
+        return None;
+    }
+    let size = f64::from(size).min(len);
+    let (ux, uy) = (dx / len, dy / len);
+    let (bx, by) = (f64::from(to.0) - ux * size, f64::from(to.1) - uy * size);
+    // The half width of the arrowhead is 40% of its length
+    let (nx, ny) = (-uy * size * 0.4, ux * size * 0.4);
+    let base = (bx.round() as i32, by.round() as i32);
+    Some((
+        base,
+        [
+            to,
+            ((bx + nx).round() as i32, (by + ny).round() as i32),
+            ((bx - nx).round() as i32, (by - ny).round() as i32),
+        ],
+    ))
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a Arrow<Coord> {
+    type Point = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for Arrow<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let (Some(from), Some(to)) = (points.next(), points.next()) {
+            match arrow_head(from, to, self.head_size) {
+                Some((base, head)) => {
+                    self.style.draw_line(backend, from, base)?;
+                    backend.fill_polygon(head.iter().copied(), &self.style.color)?;
+                }
+                None => backend.draw_pixel(to, self.style.color.to_backend_color())?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_arrow_element() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_draw_line(|c, _, from, to| {
+            assert_eq!(c, RED.to_rgba());
+            assert_eq!(from, (100, 100));
+            assert_eq!(to, (190, 100));
+        });
+        m.check_fill_polygon(|c, path| {
+            assert_eq!(c, RED.to_rgba());
+            assert_eq!(path, vec![(200, 100), (190, 104), (190, 96)]);
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_line_call, 1);
+            assert_eq!(b.num_fill_polygon_call, 1);
+        });
+    });
+    da.draw(&Arrow::new((100, 100), (200, 100), &RED).head_size(10))
+        .expect("Drawing Failure");
+    assert_eq!(arrow_head((0, 0), (0, 0), 10), None);
+}
+
 /// A rectangle element
 pub struct Rectangle<Coord> {
     points: [Coord; 2],
     style: ShapeStyle,
     margin: (u32, u32, u32, u32),
+    corner_radius: u32,
+    arc_tolerance: f64,
+    gradient: Option<(Vec<RGBAColor>, bool)>,
 }
 
 impl<Coord> Rectangle<Coord> {
@@ -138,6 +274,9 @@ impl<Coord> Rectangle<Coord> {
             points,
             style: style.into(),
             margin: (0, 0, 0, 0),
+            corner_radius: 0,
+            arc_tolerance: DEFAULT_ARC_TOLERANCE,
+            gradient: None,
         }
     }
 
@@ -150,6 +289,192 @@ impl<Coord> Rectangle<Coord> {
         self.margin = (t, b, l, r);
         self
     }
+
+    /// Set the corner radius of the rectangle
+    /// - `radius`: The radius of the rounded corners in pixels, if it exceeds half of the
+    ///   smaller side of the rectangle, it will be clamped to that value
+    pub fn set_corner_radius(&mut self, radius: u32) -> &mut Self {
+        self.corner_radius = radius;
+        self
+    }
+
+    /// Set the tolerance of the arc approximation of the rounded corners
+    /// - `tolerance`: The maximum distance in pixels between the arc and the chords used to
+    ///   approximate it, smaller tolerance produces smoother corners with more vertices
+    pub fn set_arc_tolerance(&mut self, tolerance: f64) -> &mut Self {
+        self.arc_tolerance = tolerance;
+        self
+    }
+
+    /// Fill the rectangle with a linear gradient instead of the solid color of its style
+    /// - `stops`: The colors of the gradient, evenly distributed from the second corner
+    ///   of the rectangle to the first one
+    /// - `vertical`: If the gradient goes along the Y axis, otherwise it goes along the X axis
+    ///
+    /// The adjacent rows (or columns) of the same color are filled at once, so the number of
+    /// the drawn rectangles depends on the number of the distinct colors rather than the size
+    /// of the rectangle. The gradient fill is clipped to the rounded corners as well.
+    pub fn set_gradient(&mut self, stops: Vec<RGBAColor>, vertical: bool) -> &mut Self {
+        self.gradient = if stops.is_empty() {
+            None
+        } else {
+            Some((stops, vertical))
+        };
+        self
+    }
+}
+
+fn gradient_color(stops: &[RGBAColor], t: f64) -> RGBAColor {
+    if stops.len() == 1 {
+        return stops[0];
+    }
+    let pos = t.max(0.0).min(1.0) * (stops.len() - 1) as f64;
+    let idx = (pos.floor() as usize).min(stops.len() - 2);
+    let (c0, c1, t) = (stops[idx], stops[idx + 1], pos - idx as f64);
+    let lerp = |a: u8, b: u8| (f64::from(a) + (f64::from(b) - f64::from(a)) * t).round() as u8;
+    RGBAColor(
+        lerp(c0.0, c1.0),
+        lerp(c0.1, c1.1),
+        lerp(c0.2, c1.2),
+        c0.3 + (c1.3 - c0.3) * t,
+    )
+}
+
+/// The default maximum distance in pixels between an arc and the chords approximating it
+pub(crate) const DEFAULT_ARC_TOLERANCE: f64 = 0.25;
+
+/// Compute the number of the chords needed to approximate an arc, so that the distance between
+/// the arc and the chords never exceeds the tolerance. Thus the small arcs use few segments and
+/// the large arcs stay smooth.
+/// - `radius`: The radius of the arc in pixels
+/// - `sweep`: The angle of the arc in radians
+/// - `tolerance`: The maximum distance in pixels between the arc and the chords
+pub(crate) fn arc_segment_count(radius: f64, sweep: f64, tolerance: f64) -> usize {
+    const MAX_SEGMENTS: usize = 1024;
+    let sweep = sweep.abs();
+    if radius.is_nan() || radius <= 0.0 || sweep == 0.0 {
+        return 1;
+    }
+    let tolerance = tolerance.max(1e-3);
+    if tolerance >= radius {
+        return ((sweep / std::f64::consts::FRAC_PI_2).ceil() as usize).max(1);
+    }
+    // The chord of angle theta deviates from the arc by r * (1 - cos(theta / 2))
+    let max_angle = 2.0 * (1.0 - tolerance / radius).acos();
+    ((sweep / max_angle).ceil() as usize)
+        .max(1)
+        .min(MAX_SEGMENTS)
+}
+
+/// Compute the outline of a rectangle with rounded corners in backend coordinate.
+/// The radius is clamped to half of the smaller side of the rectangle.
+pub(crate) fn rounded_rect_outline(
+    upper_left: BackendCoord,
+    bottom_right: BackendCoord,
+    radius: u32,
+    tolerance: f64,
+) -> Vec<BackendCoord> {
+    let (x0, y0) = (
+        upper_left.0.min(bottom_right.0),
+        upper_left.1.min(bottom_right.1),
+    );
+    let (x1, y1) = (
+        upper_left.0.max(bottom_right.0),
+        upper_left.1.max(bottom_right.1),
+    );
+    let radius = (radius as i32).min((x1 - x0).min(y1 - y0) / 2).max(0);
+
+    if radius == 0 {
+        return vec![(x0, y0), (x1, y0), (x1, y1), (x0, y1), (x0, y0)];
+    }
+
+    let r = f64::from(radius);
+    let steps = arc_segment_count(r, std::f64::consts::FRAC_PI_2, tolerance);
+    let corners = [
+        (x1 - radius, y0 + radius, -std::f64::consts::FRAC_PI_2),
+        (x1 - radius, y1 - radius, 0.0),
+        (x0 + radius, y1 - radius, std::f64::consts::FRAC_PI_2),
+        (x0 + radius, y0 + radius, std::f64::consts::PI),
+    ];
+
+    let mut ret = Vec::with_capacity(4 * (steps + 1) + 1);
+    for &(cx, cy, start) in corners.iter() {
+        for i in 0..=steps {
+            let theta = start + std::f64::consts::FRAC_PI_2 * i as f64 / steps as f64;
+            ret.push((
+                cx + (r * theta.cos()).round() as i32,
+                cy + (r * theta.sin()).round() as i32,
+            ));
+        }
+    }
+    ret.push(ret[0]);
+    ret
+}
+
+impl<Coord> Rectangle<Coord> {
+    /// Fill the rectangle between the corners `a` and `b` with the gradient. The runs of the
+    /// rows (or columns) with the same color and the same inset of the rounded corners are
+    /// merged into a single rectangle.
+    fn fill_gradient<DB: DrawingBackend>(
+        &self,
+        backend: &mut DB,
+        a: BackendCoord,
+        b: BackendCoord,
+        stops: &[RGBAColor],
+        vertical: bool,
+        reversed: bool,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let ((begin, end), (low, high)) = if vertical {
+            ((a.1, b.1), (a.0, b.0))
+        } else {
+            ((a.0, b.0), (a.1, b.1))
+        };
+        let radius = (self.corner_radius as i32)
+            .min((b.0 - a.0).min(b.1 - a.1) / 2)
+            .max(0);
+        let inset = |pos: i32| {
+            let d = (begin + radius - pos).max(pos - end + radius);
+            if d <= 0 {
+                return 0;
+            }
+            let r = f64::from(radius);
+            (r - (r * r - f64::from(d * d)).sqrt()).round() as i32
+        };
+
+        let mut draw_band = |from: i32, to: i32, (color, inset): (RGBAColor, i32)| {
+            let (upper_left, bottom_right) = if vertical {
+                ((low + inset, from), (high - inset, to))
+            } else {
+                ((from, low + inset), (to, high - inset))
+            };
+            backend.draw_rect(upper_left, bottom_right, &color.filled(), true)
+        };
+
+        let mut band: Option<(i32, (RGBAColor, i32))> = None;
+        for pos in begin..=end {
+            let mut t = if end > begin {
+                f64::from(pos - begin) / f64::from(end - begin)
+            } else {
+                1.0
+            };
+            if reversed {
+                t = 1.0 - t;
+            }
+            let key = (gradient_color(stops, t), inset(pos));
+            match band {
+                Some((_, current)) if current == key => {}
+                Some((from, current)) => {
+                    draw_band(from, pos - 1, current)?;
+                    band = Some((pos, key));
+                }
+                None => band = Some((pos, key)),
+            }
+        }
+        if let Some((from, current)) = band {
+            draw_band(from, end, current)?;
+        }
+        Ok(())
+    }
 }
 
 impl<'a, Coord> PointCollection<'a, Coord> for &'a Rectangle<Coord> {
@@ -168,13 +493,32 @@ impl<Coord, DB: DrawingBackend> Drawable<DB> for Rectangle<Coord> {
         _: (u32, u32),
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
         match (points.next(), points.next()) {
-            (Some(a), Some(b)) => {
-                let (mut a, mut b) = ((a.0.min(b.0), a.1.min(b.1)), (a.0.max(b.0), a.1.max(b.1)));
+            (Some(first), Some(second)) => {
+                let (mut a, mut b) = (
+                    (first.0.min(second.0), first.1.min(second.1)),
+                    (first.0.max(second.0), first.1.max(second.1)),
+                );
                 a.1 += self.margin.0 as i32;
                 b.1 -= self.margin.1 as i32;
                 a.0 += self.margin.2 as i32;
                 b.0 -= self.margin.3 as i32;
-                backend.draw_rect(a, b, &self.style, self.style.filled)
+                if let Some((stops, vertical)) = self.gradient.as_ref() {
+                    let reversed = if *vertical {
+                        second.1 > first.1
+                    } else {
+                        second.0 > first.0
+                    };
+                    return self.fill_gradient(backend, a, b, stops, *vertical, reversed);
+                }
+                if self.corner_radius == 0 {
+                    return self.style.draw_rect(backend, a, b, self.style.filled);
+                }
+                let outline = rounded_rect_outline(a, b, self.corner_radius, self.arc_tolerance);
+                if self.style.filled {
+                    backend.fill_polygon(outline, &self.style)
+                } else {
+                    self.style.draw_path(backend, outline)
+                }
             }
             _ => Ok(()),
         }
@@ -222,6 +566,125 @@ fn test_rect_element() {
     }
 }
 
+#[cfg(test)]
+#[test]
+fn test_gradient_rect_element() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        for y in 100..=110 {
+            m.check_draw_rect(move |c, _, f, u, d| {
+                assert!(f);
+                assert_eq!([u, d], [(100, y), (105, y)]);
+                if y == 110 {
+                    assert_eq!(c, RED.to_rgba());
+                }
+                if y == 100 {
+                    assert_eq!(c, BLUE.to_rgba());
+                }
+            });
+        }
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_rect_call, 11);
+        });
+    });
+    let mut rect = Rectangle::new([(100, 100), (105, 110)], BLUE.filled());
+    rect.set_gradient(vec![RED.to_rgba(), BLUE.to_rgba()], true);
+    da.draw(&rect).expect("Drawing Failure");
+}
+
+#[cfg(test)]
+#[test]
+fn test_gradient_rect_bands() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_draw_rect(|c, _, f, u, d| {
+            assert!(f);
+            assert_eq!(c, RED.to_rgba());
+            assert!(u.0 >= 100 && d.0 <= 120 && u.1 >= 100 && d.1 <= 200);
+            if u.1 == 100 {
+                assert!(u.0 > 100 && d.0 < 120);
+            }
+        });
+        m.drop_check(|b| {
+            // The insets of the corner rows are 5, 2, 1, 0, 0, so three rows at each end are
+            // drawn one by one and the rest is a single band
+            assert_eq!(b.num_draw_rect_call, 2 * 3 + 1);
+        });
+    });
+    let mut rect = Rectangle::new([(100, 100), (120, 200)], BLUE.filled());
+    rect.set_gradient(vec![RED.to_rgba(), RED.to_rgba()], true)
+        .set_corner_radius(5);
+    da.draw(&rect).expect("Drawing Failure");
+}
+
+#[cfg(test)]
+#[test]
+fn test_rounded_rect_element() {
+    use crate::prelude::*;
+    {
+        let da = crate::create_mocked_drawing_area(300, 300, |m| {
+            m.check_fill_polygon(|c, path| {
+                assert_eq!(c, BLUE.to_rgba());
+                assert!(path
+                    .iter()
+                    .all(|&(x, y)| x >= 100 && x <= 150 && y >= 100 && y <= 120));
+                assert!(!path.contains(&(100, 100)));
+                assert!(path.contains(&(110, 100)));
+                assert!(path.contains(&(100, 110)));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 1);
+                assert_eq!(b.num_draw_rect_call, 0);
+            });
+        });
+        // The radius should be clamped to half of the height
+        let mut rect = Rectangle::new([(100, 100), (150, 120)], BLUE.filled());
+        rect.set_corner_radius(100);
+        da.draw(&rect).expect("Drawing Failure");
+    }
+
+    {
+        let da = crate::create_mocked_drawing_area(300, 300, |m| {
+            m.check_draw_path(|c, s, path| {
+                assert_eq!(c, BLUE.to_rgba());
+                assert_eq!(s, 1);
+                assert_eq!(path.first(), path.last());
+                assert!(path.contains(&(145, 100)));
+                assert!(path.contains(&(150, 105)));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 1);
+                assert_eq!(b.draw_count, 1);
+            });
+        });
+        let mut rect = Rectangle::new([(100, 100), (150, 120)], &BLUE);
+        rect.set_corner_radius(5);
+        da.draw(&rect).expect("Drawing Failure");
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_arc_segment_count() {
+    use std::f64::consts::{FRAC_PI_2, PI};
+    let small = arc_segment_count(3.0, 2.0 * PI, DEFAULT_ARC_TOLERANCE);
+    let large = arc_segment_count(300.0, 2.0 * PI, DEFAULT_ARC_TOLERANCE);
+    assert!(small < large);
+    assert!(small >= 4);
+
+    // The chord error should be within the tolerance
+    let theta = 2.0 * PI / large as f64;
+    assert!(300.0 * (1.0 - (theta / 2.0).cos()) <= DEFAULT_ARC_TOLERANCE);
+
+    assert!(arc_segment_count(300.0, FRAC_PI_2, 5.0) < arc_segment_count(300.0, FRAC_PI_2, 0.1));
+    assert_eq!(arc_segment_count(0.0, PI, 0.25), 1);
+    assert_eq!(arc_segment_count(1.0, FRAC_PI_2, 10.0), 1);
+
+    let coarse = rounded_rect_outline((0, 0), (200, 200), 100, 10.0);
+    let fine = rounded_rect_outline((0, 0), (200, 200), 100, 0.1);
+    assert!(coarse.len() < fine.len());
+}
+
 /// A circle element
 pub struct Circle<Coord, Size: SizeDesc> {
     center: Coord,
@@ -261,7 +724,9 @@ impl<Coord, DB: DrawingBackend, Size: SizeDesc> Drawable<DB> for Circle<Coord, S
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
         if let Some((x, y)) = points.next() {
             let size = self.size.in_pixels(&ps).max(0) as u32;
-            return backend.draw_circle((x, y), size, &self.style, self.style.filled);
+            return self
+                .style
+                .draw_circle(backend, (x, y), size, self.style.filled);
         }
         Ok(())
     }
@@ -290,8 +755,23 @@ fn test_circle_element() {
 /// An element of a filled polygon
 pub struct Polygon<Coord> {
     points: Vec<Coord>,
+    rings: Vec<usize>,
+    fill_rule: Option<FillRule>,
+    anti_aliasing: u32,
     style: ShapeStyle,
 }
+
+/// The rule that decides which part of a polygon with multiple rings is filled
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FillRule {
+    /// A point is inside the polygon if a ray from it crosses the rings odd number of times,
+    /// so the inner rings are always holes
+    EvenOdd,
+    /// A point is inside the polygon if the rings wind around it non-zero times, so the inner
+    /// rings are holes only if they have the opposite direction to the outer ring
+    NonZero,
+}
+
 impl<Coord> Polygon<Coord> {
     /// Create a new polygon
     /// - `points`: The iterator of the points
@@ -300,9 +780,364 @@ impl<Coord> Polygon<Coord> {
     pub fn new<P: Into<Vec<Coord>>, S: Into<ShapeStyle>>(points: P, style: S) -> Self {
         Self {
             points: points.into(),
+            rings: vec![],
+            fill_rule: None,
+            anti_aliasing: 0,
             style: style.into(),
         }
     }
+
+    /// Create a new polygon with multiple rings, such as a polygon with holes or a set of
+    /// disjoint regions. The area covered by the rings is decided by the fill rule, which is
+    /// `FillRule::EvenOdd` by default
+    /// - `rings`: The iterator of the rings, each ring is implicitly closed
+    /// - `style`: The shape style
+    /// - returns the created element
+    pub fn with_rings<P: Into<Vec<Coord>>, R: IntoIterator<Item = P>, S: Into<ShapeStyle>>(
+        rings: R,
+        style: S,
+    ) -> Self {
+        let mut points = vec![];
+        let mut ring_sizes = vec![];
+        for ring in rings {
+            let ring: Vec<Coord> = ring.into();
+            ring_sizes.push(ring.len());
+            points.extend(ring);
+        }
+        Self {
+            points,
+            rings: ring_sizes,
+            fill_rule: None,
+            anti_aliasing: 0,
+            style: style.into(),
+        }
+    }
+
+    /// Set the fill rule of the polygon. By default, a polygon with a single ring is filled with
+    /// the rule of the backend, which doesn't matter unless the ring intersects itself, and a
+    /// polygon with multiple rings is filled with `FillRule::EvenOdd`
+    pub fn set_fill_rule(&mut self, rule: FillRule) -> &mut Self {
+        self.fill_rule = Some(rule);
+        self
+    }
+
+    /// Set the anti-aliasing quality of the edges. Each pixel is sampled on a
+    /// `quality` x `quality` grid and the pixels on the edges are drawn with the alpha of the
+    /// covered fraction, so the edges look smooth. The polygon is drawn pixel by pixel in this
+    /// mode, even on the vector backends. By default the quality is 0, which disables the
+    /// anti-aliasing and fills the polygon exactly with the backend.
+    /// - `quality`: The number of samples along each axis of a pixel, at most 16, 0 or 1 for
+    ///   no anti-aliasing
+    pub fn set_anti_aliasing(&mut self, quality: u32) -> &mut Self {
+        self.anti_aliasing = quality.min(16);
+        self
+    }
+}
+
+/// Compute the horizontal spans covered by the rings under the fill rule, each span is
+/// `(y, x0, x1)` with both ends included. The pixels are sampled at their centers.
+pub(crate) fn polygon_spans(rings: &[&[BackendCoord]], rule: FillRule) -> Vec<(i32, i32, i32)> {
+    let points = || rings.iter().flat_map(|r| r.iter());
+    let (y_min, y_max) = match (points().map(|p| p.1).min(), points().map(|p| p.1).max()) {
+        (Some(min), Some(max)) => (min, max),
+        _ => return vec![],
+    };
+
+    let mut spans = vec![];
+    let mut crossings = vec![];
+    for y in y_min..y_max {
+        let sy = f64::from(y) + 0.5;
+        crossings.clear();
+        for ring in rings.iter().filter(|r| r.len() > 2) {
+            for (idx, p) in ring.iter().enumerate() {
+                let q = ring[(idx + 1) % ring.len()];
+                let (py, qy) = (f64::from(p.1), f64::from(q.1));
+                if (py <= sy) != (qy <= sy) {
+                    let x = f64::from(p.0) + (sy - py) * f64::from(q.0 - p.0) / (qy - py);
+                    crossings.push((x, if qy > py { 1 } else { -1 }));
+                }
+            }
+        }
+        crossings.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+        let mut winding = 0;
+        let mut start = None;
+        for &(x, dir) in crossings.iter() {
+            winding += dir;
+            let inside = match rule {
+                FillRule::EvenOdd => winding % 2 != 0,
+                FillRule::NonZero => winding != 0,
+            };
+            match (inside, start) {
+                (true, None) => start = Some(x),
+                (false, Some(x0)) => {
+                    let (x0, x1) = ((x0 - 0.5_f64).ceil() as i32, (x - 0.5_f64).floor() as i32);
+                    if x0 <= x1 {
+                        spans.push((y, x0, x1));
+                    }
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+    }
+    spans
+}
+
+/// Remove the repeated points from a ring, including the last point closing the ring
+fn simplify_ring(ring: &[BackendCoord]) -> Vec<BackendCoord> {
+    let mut ret: Vec<BackendCoord> = vec![];
+    for &p in ring {
+        if ret.last() != Some(&p) {
+            ret.push(p);
+        }
+    }
+    while ret.len() > 1 && ret.first() == ret.last() {
+        ret.pop();
+    }
+    ret
+}
+
+/// Twice the signed area of the ring, the sign is the direction of the ring
+fn ring_area(ring: &[BackendCoord]) -> i64 {
+    (0..ring.len())
+        .map(|idx| {
+            let (p, q) = (ring[idx], ring[(idx + 1) % ring.len()]);
+            i64::from(p.0) * i64::from(q.1) - i64::from(q.0) * i64::from(p.1)
+        })
+        .sum()
+}
+
+/// Check if the point is inside the ring, the point must not be on the ring
+fn ring_contains(ring: &[BackendCoord], (x, y): BackendCoord) -> bool {
+    let mut inside = false;
+    for idx in 0..ring.len() {
+        let (p, q) = (ring[idx], ring[(idx + 1) % ring.len()]);
+        if (p.1 > y) != (q.1 > y) {
+            let cross = i64::from(q.0 - p.0) * i64::from(y - p.1)
+                - i64::from(x - p.0) * i64::from(q.1 - p.1);
+            if (cross > 0) == (q.1 > p.1) {
+                inside = !inside;
+            }
+        }
+    }
+    inside
+}
+
+fn segments_intersect(a: (BackendCoord, BackendCoord), b: (BackendCoord, BackendCoord)) -> bool {
+    let side = |p: BackendCoord, q: BackendCoord, r: BackendCoord| {
+        let cross = i64::from(q.0 - p.0) * i64::from(r.1 - p.1)
+            - i64::from(q.1 - p.1) * i64::from(r.0 - p.0);
+        cross.signum()
+    };
+    let within = |p: BackendCoord, q: BackendCoord, r: BackendCoord| {
+        p.0.min(q.0) <= r.0 && r.0 <= p.0.max(q.0) && p.1.min(q.1) <= r.1 && r.1 <= p.1.max(q.1)
+    };
+    let (d1, d2) = (side(b.0, b.1, a.0), side(b.0, b.1, a.1));
+    let (d3, d4) = (side(a.0, a.1, b.0), side(a.0, a.1, b.1));
+    if d1 * d2 < 0 && d3 * d4 < 0 {
+        return true;
+    }
+    (d1 == 0 && within(b.0, b.1, a.0))
+        || (d2 == 0 && within(b.0, b.1, a.1))
+        || (d3 == 0 && within(a.0, a.1, b.0))
+        || (d4 == 0 && within(a.0, a.1, b.1))
+}
+
+/// Check if any two edges of the rings cross or touch, except the adjacent edges of a ring.
+/// The edges are put into a grid, so only the edges in the same cell are compared.
+fn rings_intersect(rings: &[Vec<BackendCoord>]) -> bool {
+    let edges: Vec<_> = rings
+        .iter()
+        .enumerate()
+        .flat_map(|(ring, points)| {
+            (0..points.len()).map(move |idx| {
+                let edge = (points[idx], points[(idx + 1) % points.len()]);
+                (ring, idx, edge)
+            })
+        })
+        .collect();
+    let points = || rings.iter().flat_map(|r| r.iter());
+    let (x0, y0) = match (points().map(|p| p.0).min(), points().map(|p| p.1).min()) {
+        (Some(x0), Some(y0)) => (x0, y0),
+        _ => return false,
+    };
+    let (x1, y1) = (
+        points().map(|p| p.0).max().unwrap_or(x0),
+        points().map(|p| p.1).max().unwrap_or(y0),
+    );
+    let cells = (edges.len() as f64).sqrt().ceil() as i32;
+    let cell_size = ((x1 - x0) / cells + 1, (y1 - y0) / cells + 1);
+    let cell_of = |p: BackendCoord| ((p.0 - x0) / cell_size.0, (p.1 - y0) / cell_size.1);
+
+    let adjacent = |a: usize, b: usize| {
+        let (ra, ia, _) = edges[a];
+        let (rb, ib, _) = edges[b];
+        let size = rings[ra].len();
+        ra == rb && ((ia + 1) % size == ib || (ib + 1) % size == ia)
+    };
+
+    let mut grid: HashMap<(i32, i32), Vec<usize>> = HashMap::new();
+    for (idx, &(_, _, (p, q))) in edges.iter().enumerate() {
+        let (c0, c1) = (cell_of(p), cell_of(q));
+        for cx in c0.0.min(c1.0)..=c0.0.max(c1.0) {
+            for cy in c0.1.min(c1.1)..=c0.1.max(c1.1) {
+                let cell = grid.entry((cx, cy)).or_default();
+                for &other in cell.iter() {
+                    if !adjacent(idx, other) && segments_intersect(edges[other].2, (p, q)) {
+                        return true;
+                    }
+                }
+                cell.push(idx);
+            }
+        }
+    }
+    false
+}
+
+/// Convert the rings into a single compound path, which is filled the same way under both fill
+/// rules: the rings that don't change the filled state under the fill rule are dropped, the
+/// remaining rings are directed so the holes run opposite to the filled regions around them,
+/// and each of them is joined to the first ring by a bridge travelled forth and back.
+/// Returns `None` if the rings intersect, since the filled regions can't be decided by nesting.
+pub(crate) fn compound_path(
+    rings: &[&[BackendCoord]],
+    rule: FillRule,
+) -> Option<Vec<BackendCoord>> {
+    let rings: Vec<_> = rings
+        .iter()
+        .map(|ring| simplify_ring(ring))
+        .filter(|ring| ring.len() > 2 && ring_area(ring) != 0)
+        .collect();
+    if rings_intersect(&rings) {
+        return None;
+    }
+
+    let areas: Vec<_> = rings.iter().map(|ring| ring_area(ring)).collect();
+    let parents: Vec<_> = (0..rings.len())
+        .map(|idx| {
+            (0..rings.len())
+                .filter(|&other| other != idx && ring_contains(&rings[other], rings[idx][0]))
+                .min_by_key(|&other| areas[other].abs())
+        })
+        .collect();
+
+    // Visit the rings from the outermost, so the winding number around the parent is known
+    let mut order: Vec<_> = (0..rings.len()).collect();
+    order.sort_by_key(|&idx| std::cmp::Reverse(areas[idx].abs()));
+    let mut winding = vec![0; rings.len()];
+    let mut boundaries = vec![];
+    for idx in order {
+        let outside = parents[idx].map_or(0, |parent| winding[parent]);
+        winding[idx] = outside + areas[idx].signum();
+        let filled = |winding: i64| match rule {
+            FillRule::EvenOdd => winding % 2 != 0,
+            FillRule::NonZero => winding != 0,
+        };
+        if filled(winding[idx]) == filled(outside) {
+            continue;
+        }
+        let mut ring = rings[idx].clone();
+        if filled(winding[idx]) != (areas[idx] > 0) {
+            ring.reverse();
+        }
+        boundaries.push(ring);
+    }
+
+    let mut path = vec![];
+    if let Some((first, rest)) = boundaries.split_first() {
+        path.extend_from_slice(first);
+        for ring in rest {
+            path.push(first[0]);
+            path.extend_from_slice(ring);
+            path.push(ring[0]);
+        }
+    }
+    Some(path)
+}
+
+/// Fill the rings with the fill rule. The rings are filled as one compound path with a single
+/// `fill_polygon` call, see [compound_path](fn.compound_path.html), so a vector backend emits a
+/// single shape. If the rings intersect, the rings are scan-converted into horizontal lines
+/// instead.
+pub(crate) fn fill_rings<DB: DrawingBackend>(
+    backend: &mut DB,
+    rings: &[&[BackendCoord]],
+    rule: FillRule,
+    color: &BackendColor,
+) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+    match compound_path(rings, rule) {
+        Some(path) if path.is_empty() => Ok(()),
+        Some(path) => backend.fill_polygon(path, color),
+        None => {
+            for (y, x0, x1) in polygon_spans(rings, rule) {
+                backend.draw_line((x0, y), (x1, y), color)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Compute the fraction of each pixel covered by the rings under the fill rule, by sampling
+/// every pixel on a `quality` x `quality` grid. The result is the horizontal runs of the pixels
+/// with the same coverage, each run is `(y, x0, x1, coverage)` with both ends included and the
+/// coverage in `(0, 1]`.
+pub(crate) fn polygon_coverage(
+    rings: &[&[BackendCoord]],
+    rule: FillRule,
+    quality: u32,
+) -> Vec<(i32, i32, i32, f64)> {
+    let q = quality.max(1) as i32;
+    let scaled: Vec<Vec<_>> = rings
+        .iter()
+        .map(|ring| ring.iter().map(|&(x, y)| (x * q, y * q)).collect())
+        .collect();
+    let scaled: Vec<&[BackendCoord]> = scaled.iter().map(Vec::as_slice).collect();
+    let spans = polygon_spans(&scaled, rule);
+    let samples = f64::from(q * q);
+
+    let mut runs = vec![];
+    let mut begin = 0;
+    while begin < spans.len() {
+        // The spans are in the order of the rows, collect the sub-rows of the pixel row
+        let y = spans[begin].0.div_euclid(q);
+        let end = begin
+            + spans[begin..]
+                .iter()
+                .take_while(|span| span.0.div_euclid(q) == y)
+                .count();
+        let row = &spans[begin..end];
+        begin = end;
+
+        let x_min = row
+            .iter()
+            .map(|span| span.1.div_euclid(q))
+            .min()
+            .unwrap_or(0);
+        let x_max = row
+            .iter()
+            .map(|span| span.2.div_euclid(q))
+            .max()
+            .unwrap_or(0);
+        let mut counts = vec![0; (x_max - x_min + 1) as usize];
+        for &(_, x0, x1) in row {
+            for x in x0.div_euclid(q)..=x1.div_euclid(q) {
+                counts[(x - x_min) as usize] += x1.min(x * q + q - 1) - x0.max(x * q) + 1;
+            }
+        }
+
+        let mut start = 0;
+        for idx in 1..=counts.len() {
+            if idx == counts.len() || counts[idx] != counts[start] {
+                if counts[start] > 0 {
+                    let coverage = (f64::from(counts[start]) / samples).min(1.0);
+                    runs.push((y, x_min + start as i32, x_min + idx as i32 - 1, coverage));
+                }
+                start = idx;
+            }
+        }
+    }
+    runs
 }
 
 impl<'a, Coord> PointCollection<'a, Coord> for &'a Polygon<Coord> {
@@ -320,7 +1155,38 @@ impl<Coord, DB: DrawingBackend> Drawable<DB> for Polygon<Coord> {
         backend: &mut DB,
         _: (u32, u32),
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
-        backend.fill_polygon(points, &self.style.color.to_backend_color())
+        let color = self.style.color.to_backend_color();
+        if self.rings.len() <= 1 && self.anti_aliasing <= 1 && self.fill_rule.is_none() {
+            return backend.fill_polygon(points, &color);
+        }
+        let fill_rule = self.fill_rule.unwrap_or(FillRule::EvenOdd);
+
+        let points: Vec<_> = points.collect();
+        let mut rings = vec![];
+        let mut begin = 0;
+        for size in self.rings.iter() {
+            let end = (begin + size).min(points.len());
+            rings.push(&points[begin..end]);
+            begin = end;
+        }
+        if self.rings.is_empty() {
+            rings.push(&points[..]);
+        }
+
+        if self.anti_aliasing > 1 {
+            for (y, x0, x1, coverage) in polygon_coverage(&rings, fill_rule, self.anti_aliasing) {
+                let mut color = color;
+                color.alpha *= coverage;
+                if x0 == x1 {
+                    backend.draw_pixel((x0, y), color)?;
+                } else {
+                    backend.draw_line((x0, y), (x1, y), &color)?;
+                }
+            }
+            return Ok(());
+        }
+
+        fill_rings(backend, &rings, fill_rule, &color)
     }
 }
 
@@ -346,3 +1212,145 @@ fn test_polygon_element() {
     da.draw(&Polygon::new(points.clone(), &BLUE))
         .expect("Drawing Failure");
 }
+
+#[cfg(test)]
+#[test]
+fn test_polygon_with_holes() {
+    use crate::prelude::*;
+    let outer = vec![(0, 0), (10, 0), (10, 10), (0, 10)];
+    let hole = vec![(3, 3), (7, 3), (7, 7), (3, 7)];
+    let reversed_hole: Vec<_> = hole.iter().rev().copied().collect();
+    let covers = |spans: &[(i32, i32, i32)], (x, y): (i32, i32)| {
+        spans
+            .iter()
+            .any(|&(sy, x0, x1)| sy == y && x0 <= x && x <= x1)
+    };
+
+    let spans = polygon_spans(&[&outer, &hole], FillRule::EvenOdd);
+    assert!(covers(&spans, (1, 1)));
+    assert!(covers(&spans, (9, 9)));
+    assert!(!covers(&spans, (5, 5)));
+    assert!(!covers(&spans, (10, 5)));
+
+    // With the nonzero rule, a hole in the same direction is filled
+    let spans = polygon_spans(&[&outer, &hole], FillRule::NonZero);
+    assert!(covers(&spans, (5, 5)));
+    let spans = polygon_spans(&[&outer, &reversed_hole], FillRule::NonZero);
+    assert!(!covers(&spans, (5, 5)));
+    assert!(covers(&spans, (1, 5)));
+
+    // The hole is directed opposite to the outer ring and bridged to it
+    let path = compound_path(&[&outer, &hole], FillRule::EvenOdd).unwrap();
+    assert_eq!(path.len(), 4 + 1 + 4 + 1);
+    assert_eq!(&path[..5], &[(0, 0), (10, 0), (10, 10), (0, 10), (0, 0)]);
+    assert_eq!(&path[5..], &[(3, 7), (7, 7), (7, 3), (3, 3), (3, 7)]);
+    // A hole in the same direction doesn't change the filled area under the nonzero rule
+    let path = compound_path(&[&outer, &hole], FillRule::NonZero).unwrap();
+    assert_eq!(path, outer);
+    // The rings crossing each other can't be converted
+    let crossing = vec![(5, 5), (15, 5), (15, 15), (5, 15)];
+    assert!(compound_path(&[&outer, &crossing], FillRule::EvenOdd).is_none());
+
+    let da = crate::create_mocked_drawing_area(100, 100, |m| {
+        m.check_fill_polygon(|c, path| {
+            assert_eq!(c, BLUE.to_rgba());
+            assert_eq!(path.len(), 10);
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_fill_polygon_call, 1);
+            assert_eq!(b.num_draw_line_call, 0);
+        });
+    });
+    da.draw(&Polygon::with_rings(vec![outer.clone(), hole], &BLUE))
+        .expect("Drawing Failure");
+
+    let da = crate::create_mocked_drawing_area(100, 100, |m| {
+        m.check_draw_line(|c, _, from, to| {
+            assert_eq!(c, BLUE.to_rgba());
+            assert_eq!(from.1, to.1);
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_fill_polygon_call, 0);
+            assert_eq!(b.num_draw_line_call, 5 + 5 * 2 + 5);
+        });
+    });
+    da.draw(&Polygon::with_rings(vec![outer, crossing], &BLUE))
+        .expect("Drawing Failure");
+}
+
+#[cfg(test)]
+#[test]
+fn test_polygon_single_ring_fill_rule() {
+    use crate::prelude::*;
+    let star = vec![(50, 0), (80, 90), (5, 35), (95, 35), (20, 90)];
+    let center = |spans: &[(i32, i32, i32)]| {
+        spans
+            .iter()
+            .any(|&(y, x0, x1)| y == 50 && x0 <= 50 && 50 <= x1)
+    };
+    assert!(!center(&polygon_spans(&[&star], FillRule::EvenOdd)));
+    assert!(center(&polygon_spans(&[&star], FillRule::NonZero)));
+
+    // The self-intersecting ring is scan-converted with the rule set on the polygon
+    let da = crate::create_mocked_drawing_area(100, 100, |m| {
+        m.check_draw_line(|_, _, from, to| {
+            assert!(from.1 != 50 || from.0 > 50 || to.0 < 50);
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_fill_polygon_call, 0);
+            assert!(b.num_draw_line_call > 0);
+        });
+    });
+    let mut polygon = Polygon::new(star, &BLUE);
+    polygon.set_fill_rule(FillRule::EvenOdd);
+    da.draw(&polygon).expect("Drawing Failure");
+
+    // A simple ring is still filled by the backend
+    let da = crate::create_mocked_drawing_area(100, 100, |m| {
+        m.drop_check(|b| {
+            assert_eq!(b.num_fill_polygon_call, 1);
+            assert_eq!(b.num_draw_line_call, 0);
+        });
+    });
+    let mut polygon = Polygon::new(vec![(10, 10), (60, 20), (30, 70)], &BLUE);
+    polygon.set_fill_rule(FillRule::NonZero);
+    da.draw(&polygon).expect("Drawing Failure");
+}
+
+#[cfg(test)]
+#[test]
+fn test_polygon_anti_aliasing() {
+    use crate::prelude::*;
+    let square = vec![(0, 0), (4, 0), (4, 4), (0, 4)];
+    let runs = polygon_coverage(&[&square], FillRule::EvenOdd, 4);
+    assert_eq!(runs, (0..4).map(|y| (y, 0, 3, 1.0)).collect::<Vec<_>>());
+
+    let triangle = vec![(0, 0), (2, 0), (0, 2)];
+    let runs = polygon_coverage(&[&triangle], FillRule::EvenOdd, 4);
+    let coverage = |x: i32, y: i32| {
+        runs.iter()
+            .find(|&&(ry, x0, x1, _)| ry == y && x0 <= x && x <= x1)
+            .map_or(0.0, |run| run.3)
+    };
+    assert!((coverage(0, 0) - 1.0).abs() < 1e-9);
+    assert!(coverage(1, 0) > 0.0 && coverage(1, 0) < 1.0);
+    assert!(coverage(0, 1) > 0.0 && coverage(0, 1) < 1.0);
+
+    let da = crate::create_mocked_drawing_area(100, 100, |m| {
+        m.check_draw_line(|c, _, from, to| {
+            assert_eq!((c.0, c.1, c.2), (0, 0, 255));
+            assert_eq!(from.1, to.1);
+        });
+        m.check_draw_pixel(|c, _| {
+            assert_eq!((c.0, c.1, c.2), (0, 0, 255));
+            assert!(c.3 > 0.0 && c.3 <= 1.0);
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_fill_polygon_call, 0);
+            assert!(b.num_draw_pixel_call > 0);
+        });
+    });
+    let mut polygon = Polygon::new(vec![(10, 10), (60, 20), (30, 70)], &BLUE);
+    polygon.set_anti_aliasing(4);
+    da.draw(&polygon).expect("Drawing Failure");
+}
@@ -70,11 +70,11 @@ impl<X, Y, Z, DB: DrawingBackend> Drawable<DB, BackendCoordAndZ> for Cubiod<X, Y
 
         for p in polygon {
             backend.fill_polygon(p.iter().map(|(coord, _)| *coord), &self.face_style)?;
-            backend.draw_path(
+            self.edge_style.draw_path(
+                backend,
                 p.iter()
                     .map(|(coord, _)| *coord)
                     .chain(std::iter::once(p[0].0)),
-                &self.edge_style,
             )?;
         }
 
@@ -0,0 +1,71 @@
+use super::{Drawable, DynElement, IntoDynElement, PointCollection};
+use crate::drawing::{BlendBackend, BlendMode};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// The element wrapper that draws an element with a blend mode, which overrides the blend mode
+/// of the [BlendBackend](../drawing/struct.BlendBackend.html) it's drawn on. The element can
+/// only be drawn on a `BlendBackend`, since the other backends don't support blending. Like
+/// [DynElement](struct.DynElement.html), the inner element is boxed.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut buffer = vec![0; 100 * 100 * 3];
+/// let root = BlendBackend::new(BitMapBackend::with_buffer(&mut buffer, (100, 100)))
+///     .into_drawing_area();
+/// root.fill(&WHITE).unwrap();
+/// root.draw(&Blended::new(
+///     Rectangle::new([(10, 10), (60, 60)], CYAN.mix(0.8).filled()),
+///     BlendMode::Multiply,
+/// ))
+/// .unwrap();
+/// root.draw(&Blended::new(
+///     Rectangle::new([(40, 40), (90, 90)], MAGENTA.mix(0.8).filled()),
+///     BlendMode::Multiply,
+/// ))
+/// .unwrap();
+/// ```
+pub struct Blended<'a, DB: DrawingBackend, Coord: Clone> {
+    inner: DynElement<'a, BlendBackend<DB>, Coord>,
+    mode: BlendMode,
+}
+
+impl<'a, DB: DrawingBackend, Coord: Clone> Blended<'a, DB, Coord> {
+    /// Create a new blended element
+    /// - `inner`: The element to draw
+    /// - `mode`: The blend mode used to draw the element
+    pub fn new<E>(inner: E, mode: BlendMode) -> Self
+    where
+        E: Drawable<BlendBackend<DB>> + 'a,
+        for<'b> &'b E: PointCollection<'b, Coord>,
+    {
+        Self {
+            inner: inner.into_dyn(),
+            mode,
+        }
+    }
+}
+
+impl<'a, 'b: 'a, DB: DrawingBackend, Coord: Clone> PointCollection<'a, Coord>
+    for &'a Blended<'b, DB, Coord>
+{
+    type Point = &'a Coord;
+    type IntoIter = &'a Vec<Coord>;
+    fn point_iter(self) -> Self::IntoIter {
+        self.inner.point_iter()
+    }
+}
+
+impl<'a, DB: DrawingBackend, Coord: Clone> Drawable<BlendBackend<DB>> for Blended<'a, DB, Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut BlendBackend<DB>,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let mode = backend.replace_blend_mode(self.mode);
+        let result = self.inner.draw(points, backend, parent_dim);
+        backend.replace_blend_mode(mode);
+        result
+    }
+}
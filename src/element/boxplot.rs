@@ -210,11 +210,8 @@ impl<K, DB: DrawingBackend, O: BoxplotOrient<K, f32>> Drawable<DB> for Boxplot<K
 
             // |---[   |  ]----|
             // ^________________
-            backend.draw_line(
-                start_whisker(points[0]),
-                end_whisker(points[0]),
-                &self.style,
-            )?;
+            self.style
+                .draw_line(backend, start_whisker(points[0]), end_whisker(points[0]))?;
 
             // |---[   |  ]----|
             // _^^^_____________
@@ -231,23 +228,23 @@ impl<K, DB: DrawingBackend, O: BoxplotOrient<K, f32>> Drawable<DB> for Boxplot<K
             let corner2 = end_bar(points[1]);
             let upper_left = (corner1.0.min(corner2.0), corner1.1.min(corner2.1));
             let bottom_right = (corner1.0.max(corner2.0), corner1.1.max(corner2.1));
-            backend.draw_rect(upper_left, bottom_right, &self.style, false)?;
+            self.style
+                .draw_rect(backend, upper_left, bottom_right, false)?;
 
             // |---[   |  ]----|
             // ________^________
-            backend.draw_line(start_bar(points[2]), end_bar(points[2]), &self.style)?;
+            self.style
+                .draw_line(backend, start_bar(points[2]), end_bar(points[2]))?;
 
             // |---[   |  ]----|
             // ____________^^^^_
-            backend.draw_line(moved(points[3]), moved(points[4]), &self.style)?;
+            self.style
+                .draw_line(backend, moved(points[3]), moved(points[4]))?;
 
             // |---[   |  ]----|
             // ________________^
-            backend.draw_line(
-                start_whisker(points[4]),
-                end_whisker(points[4]),
-                &self.style,
-            )?;
+            self.style
+                .draw_line(backend, start_whisker(points[4]), end_whisker(points[4]))?;
         }
         Ok(())
     }
@@ -1,5 +1,5 @@
 /*!
-  The candlestick element, which showing the high/low/open/close price
+  The candlestick and OHLC bar elements, which showing the high/low/open/close price
 */
 
 use std::cmp::Ordering;
@@ -46,21 +46,39 @@ impl<X: Clone, Y: PartialOrd> CandleStick<X, Y> {
         width: u32,
     ) -> Self {
         Self {
-            style: match open.partial_cmp(&close) {
-                Some(Ordering::Less) => gain_style.into(),
-                _ => loss_style.into(),
-            },
+            style: pick_style(&open, &close, gain_style, loss_style),
             width,
-            points: [
-                (x.clone(), open),
-                (x.clone(), high),
-                (x.clone(), low),
-                (x, close),
-            ],
+            points: ohlc_points(x, open, high, low, close),
         }
     }
 }
 
+fn pick_style<Y: PartialOrd, GS: Into<ShapeStyle>, LS: Into<ShapeStyle>>(
+    open: &Y,
+    close: &Y,
+    gain_style: GS,
+    loss_style: LS,
+) -> ShapeStyle {
+    match open.partial_cmp(close) {
+        Some(Ordering::Less) => gain_style.into(),
+        _ => loss_style.into(),
+    }
+}
+
+fn ohlc_points<X: Clone, Y>(x: X, open: Y, high: Y, low: Y, close: Y) -> [(X, Y); 4] {
+    [
+        (x.clone(), open),
+        (x.clone(), high),
+        (x.clone(), low),
+        (x, close),
+    ]
+}
+
+/// Split the width into the parts on the left and the right of the center
+fn half_widths(width: u32) -> (i32, i32) {
+    (width as i32 / 2, width as i32 - width as i32 / 2)
+}
+
 impl<'a, X: 'a, Y: PartialOrd + 'a> PointCollection<'a, (X, Y)> for &'a CandleStick<X, Y> {
     type Point = &'a (X, Y);
     type IntoIter = &'a [(X, Y)];
@@ -82,19 +100,118 @@ impl<X, Y: PartialOrd, DB: DrawingBackend> Drawable<DB> for CandleStick<X, Y> {
             if points[0].1 > points[3].1 {
                 points.swap(0, 3);
             }
-            let (l, r) = (
-                self.width as i32 / 2,
-                self.width as i32 - self.width as i32 / 2,
-            );
+            let (l, r) = half_widths(self.width);
 
-            backend.draw_line(points[0], points[1], &self.style)?;
-            backend.draw_line(points[2], points[3], &self.style)?;
+            self.style.draw_line(backend, points[0], points[1])?;
+            self.style.draw_line(backend, points[2], points[3])?;
 
             points[0].0 -= l;
             points[3].0 += r;
 
-            backend.draw_rect(points[0], points[3], &self.style, fill)?;
+            self.style.draw_rect(backend, points[0], points[3], fill)?;
         }
         Ok(())
     }
 }
+
+/// The OHLC bar element, which is a vertical line from the low price to the high price, with a
+/// tick on the left for the open price and a tick on the right for the close price
+pub struct OhlcBar<X, Y: PartialOrd> {
+    style: ShapeStyle,
+    width: u32,
+    points: [(X, Y); 4],
+}
+
+impl<X: Clone, Y: PartialOrd> OhlcBar<X, Y> {
+    /// Create a new OHLC bar element, the parameters are the same as
+    /// [CandleStick::new](struct.CandleStick.html#method.new)
+    ///
+    /// - `x`: The x coordinate
+    /// - `open`: The open value
+    /// - `high`: The high value
+    /// - `low`: The low value
+    /// - `close`: The close value
+    /// - `gain_style`: The style for gain
+    /// - `loss_style`: The style for loss
+    /// - `width`: The width, which is the distance between the ends of the two ticks
+    /// - **returns** The newly created OHLC bar element
+    ///
+    /// ```rust
+    /// use chrono::prelude::*;
+    /// use plotters::prelude::*;
+    ///
+    /// let bar = OhlcBar::new(Local::now(), 130.0600, 131.3700, 128.8300, 129.1500, &GREEN, &RED, 15);
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn new<GS: Into<ShapeStyle>, LS: Into<ShapeStyle>>(
+        x: X,
+        open: Y,
+        high: Y,
+        low: Y,
+        close: Y,
+        gain_style: GS,
+        loss_style: LS,
+        width: u32,
+    ) -> Self {
+        Self {
+            style: pick_style(&open, &close, gain_style, loss_style),
+            width,
+            points: ohlc_points(x, open, high, low, close),
+        }
+    }
+}
+
+impl<'a, X: 'a, Y: PartialOrd + 'a> PointCollection<'a, (X, Y)> for &'a OhlcBar<X, Y> {
+    type Point = &'a (X, Y);
+    type IntoIter = &'a [(X, Y)];
+    fn point_iter(self) -> &'a [(X, Y)] {
+        &self.points
+    }
+}
+
+impl<X, Y: PartialOrd, DB: DrawingBackend> Drawable<DB> for OhlcBar<X, Y> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = points.take(4).collect();
+        if points.len() == 4 {
+            let (l, r) = half_widths(self.width);
+            let (open, high, low, close) = (points[0], points[1], points[2], points[3]);
+            self.style.draw_line(backend, low, high)?;
+            self.style.draw_line(backend, (open.0 - l, open.1), open)?;
+            self.style
+                .draw_line(backend, close, (close.0 + r, close.1))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_ohlc_bar() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_line(|c, _, from, to| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!((from, to), ((50, 90), (50, 10)));
+            });
+            m.check_draw_line(|_, _, from, to| assert_eq!((from, to), ((45, 70), (50, 70))));
+            m.check_draw_line(|_, _, from, to| assert_eq!((from, to), ((50, 30), (55, 30))));
+            m.check_draw_line(|c, _, _, _| assert_eq!(c, GREEN.to_rgba()));
+            m.drop_check(|b| assert_eq!(b.num_draw_line_call, 6));
+        });
+
+        drawing_area
+            .draw(&OhlcBar::new(50, 70, 10, 90, 30, &GREEN, &RED, 10))
+            .expect("Drawing error");
+        drawing_area
+            .draw(&OhlcBar::new(50, 30, 10, 90, 70, &GREEN, &RED, 10))
+            .expect("Drawing error");
+    }
+}
@@ -0,0 +1,237 @@
+use crate::coord::ranged1d::{AsRangedCoord, Ranged, ValueFormatter};
+use crate::element::{Drawable, PointCollection};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{Color, IntoFont, RGBAColor, ShapeStyle, TextStyle, BLACK};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// The direction of a colorbar
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColorbarOrientation {
+    /// The minimum value is at the bottom and the tick labels are on the right
+    Vertical,
+    /// The minimum value is on the left and the tick labels are under the strip
+    Horizontal,
+}
+
+/// The legend of a continuous color scale, which is a gradient strip with tick labels.
+///
+/// The color map takes the normalized value in the range `[0, 1]`, where 0 maps to the beginning
+/// of the value range and 1 maps to the end of it. The tick labels are formatted with the same
+/// formatter as the axis of the same value type. The colorbar is defined in the pixel coordinate,
+/// so it's usually drawn onto the area beside the plotting area.
+pub struct Colorbar<'a, R: Ranged> {
+    pos: BackendCoord,
+    size: (u32, u32),
+    spec: R,
+    color_map: Box<dyn Fn(f64) -> RGBAColor + 'a>,
+    orientation: ColorbarOrientation,
+    border_style: ShapeStyle,
+    label_style: TextStyle<'a>,
+    n_ticks: usize,
+    tick_size: u32,
+    n_bands: usize,
+}
+
+impl<'a, R: Ranged> Colorbar<'a, R> {
+    /// Create a new vertical colorbar
+    /// - `pos`: The upper left corner of the gradient strip
+    /// - `size`: The width and height of the gradient strip, the tick labels are not included
+    /// - `range`: The value range the color map encodes
+    /// - `color_map`: The function maps the normalized value to the color
+    pub fn new<S: AsRangedCoord<CoordDescType = R>, C: Color>(
+        pos: BackendCoord,
+        size: (u32, u32),
+        range: S,
+        color_map: impl Fn(f64) -> C + 'a,
+    ) -> Self
+    where
+        R: From<S>,
+    {
+        Self {
+            pos,
+            size,
+            spec: range.into(),
+            color_map: Box::new(move |v| color_map(v).to_rgba()),
+            orientation: ColorbarOrientation::Vertical,
+            border_style: (&BLACK).into(),
+            label_style: ("sans-serif", 12).into_font().into(),
+            n_ticks: 5,
+            tick_size: 5,
+            n_bands: 64,
+        }
+    }
+
+    /// Set the orientation of the colorbar
+    pub fn orientation(mut self, orientation: ColorbarOrientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Set the style of the border around the gradient strip and the ticks
+    pub fn border_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.border_style = style.into();
+        self
+    }
+
+    /// Set the style of the tick labels
+    pub fn label_style<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        self.label_style = style.into();
+        self
+    }
+
+    /// Set the maximum number of tick labels
+    pub fn n_ticks(mut self, n: usize) -> Self {
+        self.n_ticks = n;
+        self
+    }
+
+    /// Set the length of the ticks in pixels
+    pub fn tick_size(mut self, size: u32) -> Self {
+        self.tick_size = size;
+        self
+    }
+
+    /// Set the number of the color bands the gradient strip is divided into, which is 64 by
+    /// default. Each band is drawn as a rectangle, the adjacent bands of the same color are
+    /// merged, and a strip shorter than the number of bands has a band per pixel. More bands
+    /// give a smoother gradient at the cost of a larger output of the vector backends.
+    pub fn n_bands(mut self, n: usize) -> Self {
+        self.n_bands = n.max(1);
+        self
+    }
+}
+
+impl<'b, 'a, R: Ranged> PointCollection<'b, BackendCoord> for &'b Colorbar<'a, R> {
+    type Point = &'b BackendCoord;
+    type IntoIter = std::iter::Once<&'b BackendCoord>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&self.pos)
+    }
+}
+
+impl<'a, R: Ranged + ValueFormatter<R::ValueType>, DB: DrawingBackend> Drawable<DB>
+    for Colorbar<'a, R>
+{
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (x0, y0) = match points.next() {
+            Some(pos) => pos,
+            None => return Ok(()),
+        };
+        let (w, h) = (self.size.0 as i32, self.size.1 as i32);
+        if w <= 0 || h <= 0 {
+            return Ok(());
+        }
+        let (x1, y1) = (x0 + w - 1, y0 + h - 1);
+        let vertical = self.orientation == ColorbarOrientation::Vertical;
+
+        let len = if vertical { h } else { w };
+        let n_bands = (self.n_bands as i32).min(len);
+        let mut bands: Vec<(i32, i32, RGBAColor)> = vec![];
+        for band in 0..n_bands {
+            let (start, end) = (band * len / n_bands, (band + 1) * len / n_bands);
+            let t = (f64::from(start + end) / 2.0) / f64::from(len);
+            let color = (self.color_map)(if vertical { 1.0 - t } else { t });
+            match bands.last_mut() {
+                Some(last) if last.2 == color => last.1 = end,
+                _ => bands.push((start, end, color)),
+            }
+        }
+        for (start, end, color) in bands {
+            let style = color.filled();
+            if vertical {
+                backend.draw_rect((x0, y0 + start), (x1, y0 + end - 1), &style, true)?;
+            } else {
+                backend.draw_rect((x0 + start, y0), (x0 + end - 1, y1), &style, true)?;
+            }
+        }
+        self.border_style
+            .draw_rect(backend, (x0, y0), (x1, y1), false)?;
+
+        let tick_size = self.tick_size as i32;
+        let label_style = if vertical {
+            self.label_style.pos(Pos::new(HPos::Left, VPos::Center))
+        } else {
+            self.label_style.pos(Pos::new(HPos::Center, VPos::Top))
+        };
+        for value in self.spec.key_points(self.n_ticks) {
+            let text = self.spec.format_ext(&value);
+            if vertical {
+                let y = self.spec.map(&value, (y1, y0));
+                self.border_style
+                    .draw_line(backend, (x1, y), (x1 + tick_size, y))?;
+                backend.draw_text(&text, &label_style, (x1 + tick_size + 2, y))?;
+            } else {
+                let x = self.spec.map(&value, (x0, x1));
+                self.border_style
+                    .draw_line(backend, (x, y1), (x, y1 + tick_size))?;
+                backend.draw_text(&text, &label_style, (x, y1 + tick_size + 2))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_vertical_colorbar() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(pos.0, 10 + 20 - 1 + 5 + 2);
+                let value: f64 = text.parse().unwrap();
+                assert!((0.0..=1.0).contains(&value));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 64 + 1);
+                assert_eq!(b.num_draw_line_call, b.num_draw_text_call);
+                assert!(b.num_draw_text_call > 1);
+            });
+        });
+
+        drawing_area
+            .draw(&Colorbar::new((10, 10), (20, 100), 0.0..1.0, |v| {
+                HSLColor(v, 1.0, 0.5)
+            }))
+            .expect("Drawing error");
+    }
+
+    #[test]
+    fn test_horizontal_colorbar() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_rect(|c, _, f, u, d| {
+                assert!(f);
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!((u, d), ((10, 10), (59, 29)));
+            });
+            m.check_draw_rect(|c, _, f, u, d| {
+                assert!(f);
+                assert_eq!(c, BLUE.to_rgba());
+                assert_eq!((u, d), ((60, 10), (109, 29)));
+            });
+            m.check_draw_rect(|_, _, f, _, _| assert!(!f));
+            m.check_draw_text(|_, _, _, pos, _| assert_eq!(pos.1, 10 + 20 - 1 + 5 + 2));
+            m.drop_check(|b| assert_eq!(b.num_draw_rect_call, 3));
+        });
+
+        drawing_area
+            .draw(
+                &Colorbar::new((10, 10), (100, 20), 0..100, |v| {
+                    if v < 0.5 {
+                        RED
+                    } else {
+                        BLUE
+                    }
+                })
+                .orientation(ColorbarOrientation::Horizontal),
+            )
+            .expect("Drawing error");
+    }
+}
@@ -117,14 +117,15 @@ impl<K, V, O: ErrorBarOrient<K, V>, DB: DrawingBackend> Drawable<DB> for ErrorBa
         let points: Vec<_> = points.take(3).collect();
 
         let (from, to) = O::ending_coord(points[0], self.width);
-        backend.draw_line(from, to, &self.style)?;
+        self.style.draw_line(backend, from, to)?;
 
         let (from, to) = O::ending_coord(points[2], self.width);
-        backend.draw_line(from, to, &self.style)?;
+        self.style.draw_line(backend, from, to)?;
 
-        backend.draw_line(points[0], points[2], &self.style)?;
+        self.style.draw_line(backend, points[0], points[2])?;
 
-        backend.draw_circle(points[1], self.width / 2, &self.style, self.style.filled)?;
+        self.style
+            .draw_circle(backend, points[1], self.width / 2, self.style.filled)?;
 
         Ok(())
     }
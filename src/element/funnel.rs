@@ -0,0 +1,220 @@
+use crate::element::{Drawable, PointCollection};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{Color, IntoFont, Palette, Palette99, RGBAColor, TextStyle};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+type LabelFormatter<'a> = dyn Fn(&str, f64) -> String + 'a;
+
+/// The funnel element, which draws the ordered stages of a process as centered trapezoids
+/// stacked from the top down, e.g. the visitors, the sign-ups and the purchases of a website.
+///
+/// The width of each stage is proportional to its value, the largest value takes the full
+/// width of the element. Each trapezoid narrows from the width of its stage to the width of the
+/// next stage, and the last one is a rectangle. Optionally, the value of each stage as the
+/// percentage of the previous stage is drawn below its label.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut buffer = vec![0; 300 * 200 * 3];
+/// let root = BitMapBackend::with_buffer(&mut buffer, (300, 200)).into_drawing_area();
+/// root.draw(
+///     &Funnel::new((10, 10), (280, 180))
+///         .stage("Visits", 1000.0)
+///         .stage("Sign-ups", 320.0)
+///         .stage("Purchases", 85.0)
+///         .conversion_rates(true),
+/// )
+/// .unwrap();
+/// ```
+pub struct Funnel<'a> {
+    pos: BackendCoord,
+    size: (u32, u32),
+    stages: Vec<(String, f64)>,
+    color_map: Box<dyn Fn(usize) -> RGBAColor + 'a>,
+    label_style: TextStyle<'a>,
+    label_formatter: Box<LabelFormatter<'a>>,
+    gap: u32,
+    conversion_rates: bool,
+}
+
+impl<'a> Funnel<'a> {
+    /// Create a new funnel element without any stage
+    /// - `pos`: The upper left corner of the funnel
+    /// - `size`: The size of the funnel in pixels
+    pub fn new(pos: BackendCoord, size: (u32, u32)) -> Self {
+        Self {
+            pos,
+            size,
+            stages: vec![],
+            color_map: Box::new(|idx| Palette99::pick(idx).to_rgba()),
+            label_style: ("sans-serif", 14).into_font().into(),
+            label_formatter: Box::new(|label, value| format!("{}: {}", label, value)),
+            gap: 2,
+            conversion_rates: false,
+        }
+    }
+
+    /// Append a stage to the bottom of the funnel, the negative and non-finite values are
+    /// treated as zero
+    /// - `label`: The label of the stage
+    /// - `value`: The value of the stage, which decides the width of the stage
+    pub fn stage<S: Into<String>>(mut self, label: S, value: f64) -> Self {
+        let value = if value.is_finite() {
+            value.max(0.0)
+        } else {
+            0.0
+        };
+        self.stages.push((label.into(), value));
+        self
+    }
+
+    /// Set the fill color of the stages
+    /// - `func`: The function takes the index of the stage
+    pub fn color_map<C: Color>(mut self, func: impl Fn(usize) -> C + 'a) -> Self {
+        self.color_map = Box::new(move |idx| func(idx).to_rgba());
+        self
+    }
+
+    /// Set the style of the labels
+    pub fn label_style<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        self.label_style = style.into();
+        self
+    }
+
+    /// Set the function which formats the label of a stage from its label and value
+    pub fn label_formatter(mut self, formatter: impl Fn(&str, f64) -> String + 'a) -> Self {
+        self.label_formatter = Box::new(formatter);
+        self
+    }
+
+    /// Set the vertical gap between the stages in pixels
+    pub fn gap(mut self, gap: u32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Draw the value of each stage as the percentage of the previous stage
+    pub fn conversion_rates(mut self, show: bool) -> Self {
+        self.conversion_rates = show;
+        self
+    }
+}
+
+impl<'b, 'a> PointCollection<'b, BackendCoord> for &'b Funnel<'a> {
+    type Point = &'b BackendCoord;
+    type IntoIter = std::iter::Once<&'b BackendCoord>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&self.pos)
+    }
+}
+
+impl<'a, DB: DrawingBackend> Drawable<DB> for Funnel<'a> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (x, y) = match points.next() {
+            Some(pos) => pos,
+            None => return Ok(()),
+        };
+        let n = self.stages.len();
+        let max = self.stages.iter().fold(0.0f64, |max, s| max.max(s.1));
+        if n == 0 || max <= 0.0 {
+            return Ok(());
+        }
+
+        let total_gap = self.gap as f64 * (n - 1) as f64;
+        let height = ((self.size.1 as f64 - total_gap) / n as f64).max(0.0);
+        let center = x as f64 + self.size.0 as f64 / 2.0;
+        let half_width = |value: f64| self.size.0 as f64 * value / max / 2.0;
+        let label_style = self.label_style.pos(Pos::new(HPos::Center, VPos::Center));
+        let line_height = self.label_style.font.get_size() as i32;
+
+        for (idx, (label, value)) in self.stages.iter().enumerate() {
+            let top = y as f64 + (height + self.gap as f64) * idx as f64;
+            let bottom = top + height;
+            let next = self.stages.get(idx + 1).map_or(*value, |s| s.1);
+            let (top_half, bottom_half) = (half_width(*value), half_width(next));
+            let corner = |dx: f64, y: f64| ((center + dx).round() as i32, y.round() as i32);
+            backend.fill_polygon(
+                vec![
+                    corner(-top_half, top),
+                    corner(top_half, top),
+                    corner(bottom_half, bottom),
+                    corner(-bottom_half, bottom),
+                ],
+                &(self.color_map)(idx),
+            )?;
+
+            let mid = corner(0.0, (top + bottom) / 2.0);
+            let text = (self.label_formatter)(label, *value);
+            let previous = idx.checked_sub(1).map(|i| self.stages[i].1);
+            match previous.filter(|_| self.conversion_rates) {
+                Some(previous) => {
+                    let rate = if previous > 0.0 {
+                        format!("{:.1}%", value / previous * 100.0)
+                    } else {
+                        "-".to_string()
+                    };
+                    backend.draw_text(&text, &label_style, (mid.0, mid.1 - line_height / 2))?;
+                    backend.draw_text(&rate, &label_style, (mid.0, mid.1 + line_height / 2))?;
+                }
+                None => backend.draw_text(&text, &label_style, mid)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_funnel_element() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let texts = Rc::new(RefCell::new(vec![]));
+        {
+            let texts = texts.clone();
+            let drawing_area = create_mocked_drawing_area(200, 100, move |m| {
+                m.check_fill_polygon(|c, vert| {
+                    assert_eq!(c, RED.to_rgba());
+                    assert_eq!(vert, vec![(0, 0), (200, 0), (150, 30), (50, 30)]);
+                });
+                m.check_fill_polygon(|_, vert| {
+                    assert_eq!((vert[0].1, vert[1].0 - vert[0].0), (35, 100));
+                });
+                m.check_fill_polygon(|_, vert| {
+                    assert_eq!((vert[0].1, vert[1].0 - vert[0].0), (70, 20));
+                });
+                m.check_draw_text(move |_, _, _, _, text| {
+                    texts.borrow_mut().push(text.to_string())
+                });
+                m.drop_check(|b| assert_eq!(b.num_fill_polygon_call, 3));
+            });
+
+            drawing_area
+                .draw(
+                    &Funnel::new((0, 0), (200, 100))
+                        .stage("Visits", 100.0)
+                        .stage("Sign-ups", 50.0)
+                        .stage("Buys", 10.0)
+                        .gap(5)
+                        .color_map(|idx| if idx == 0 { RED } else { BLUE })
+                        .conversion_rates(true),
+                )
+                .unwrap();
+        }
+
+        assert_eq!(
+            *texts.borrow(),
+            vec!["Visits: 100", "Sign-ups: 50", "50.0%", "Buys: 10", "20.0%"]
+        );
+    }
+}
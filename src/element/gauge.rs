@@ -0,0 +1,268 @@
+use std::cmp::Ordering;
+use std::f64::consts::{FRAC_PI_2, PI};
+use std::ops::Range;
+
+use super::basic_shapes::{arc_segment_count, DEFAULT_ARC_TOLERANCE};
+use super::{Drawable, PointCollection};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{Color, IntoFont, RGBAColor, RGBColor, ShapeStyle, TextStyle, BLACK};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// The gauge element, also known as dial chart, which shows a single value on an arc scale with
+/// a needle pointing at the value.
+///
+/// The arc is symmetric about the vertical line through the center, the minimum value is at
+/// the left end of the arc and the maximum value is at the right end. The arc can be divided
+/// into colored zones, e.g. the green, yellow and red bands of a dashboard.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut buffer = vec![0; 200 * 200 * 3];
+/// let root = BitMapBackend::with_buffer(&mut buffer, (200, 200)).into_drawing_area();
+/// root.draw(
+///     &Gauge::new((100, 110), 80, 72.0, 0.0..100.0)
+///         .zone(0.0..60.0, &GREEN)
+///         .zone(60.0..85.0, &YELLOW)
+///         .zone(85.0..100.0, &RED),
+/// )
+/// .unwrap();
+/// ```
+pub struct Gauge<'a> {
+    center: BackendCoord,
+    radius: u32,
+    value: f64,
+    range: Range<f64>,
+    sweep: f64,
+    arc_width: u32,
+    arc_color: RGBAColor,
+    zones: Vec<(Range<f64>, RGBAColor)>,
+    needle_style: ShapeStyle,
+    tick_style: ShapeStyle,
+    label_style: TextStyle<'a>,
+    label_formatter: Box<dyn Fn(f64) -> String + 'a>,
+    n_ticks: usize,
+    tick_size: u32,
+}
+
+impl<'a> Gauge<'a> {
+    /// Create a new gauge element
+    /// - `center`: The center of the arc
+    /// - `radius`: The outer radius of the arc in pixels
+    /// - `value`: The value the needle points at, it's clamped into the range
+    /// - `range`: The range of the scale
+    pub fn new(center: BackendCoord, radius: u32, value: f64, range: Range<f64>) -> Self {
+        Self {
+            center,
+            radius,
+            value,
+            range,
+            sweep: PI * 4.0 / 3.0,
+            arc_width: (radius / 5).max(1),
+            arc_color: RGBColor(220, 220, 220).to_rgba(),
+            zones: vec![],
+            needle_style: BLACK.stroke_width(2),
+            tick_style: (&BLACK).into(),
+            label_style: ("sans-serif", 12).into_font().into(),
+            label_formatter: Box::new(|v| format!("{}", v)),
+            n_ticks: 6,
+            tick_size: 5,
+        }
+    }
+
+    /// Set the angle the arc spans, in radians. The default is 240 degrees
+    pub fn sweep(mut self, angle: f64) -> Self {
+        self.sweep = angle.max(0.0).min(PI * 2.0);
+        self
+    }
+
+    /// Set the width of the arc in pixels
+    pub fn arc_width(mut self, width: u32) -> Self {
+        self.arc_width = width;
+        self
+    }
+
+    /// Set the color of the part of the arc which isn't covered by any zone
+    pub fn arc_color<C: Color>(mut self, color: &C) -> Self {
+        self.arc_color = color.to_rgba();
+        self
+    }
+
+    /// Add a colored zone to the arc, the zones added later are drawn on top of the earlier ones
+    /// - `range`: The value range the zone covers
+    /// - `color`: The color of the zone
+    pub fn zone<C: Color>(mut self, range: Range<f64>, color: &C) -> Self {
+        self.zones.push((range, color.to_rgba()));
+        self
+    }
+
+    /// Set the style of the needle, the stroke width is the width of the needle
+    pub fn needle_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.needle_style = style.into();
+        self
+    }
+
+    /// Set the style of the ticks
+    pub fn tick_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.tick_style = style.into();
+        self
+    }
+
+    /// Set the style of the tick labels
+    pub fn label_style<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        self.label_style = style.into();
+        self
+    }
+
+    /// Set the function which formats the tick labels
+    pub fn label_formatter(mut self, formatter: impl Fn(f64) -> String + 'a) -> Self {
+        self.label_formatter = Box::new(formatter);
+        self
+    }
+
+    /// Set the number of the ticks, including both ends of the scale. Zero disables the ticks
+    /// and the tick labels
+    pub fn n_ticks(mut self, n: usize) -> Self {
+        self.n_ticks = n;
+        self
+    }
+
+    /// Set the length of the ticks in pixels
+    pub fn tick_size(mut self, size: u32) -> Self {
+        self.tick_size = size;
+        self
+    }
+
+    /// Map a value to the angle on the arc, where the angle grows clockwise on the screen and
+    /// `-PI / 2` points upward
+    fn angle_of(&self, value: f64) -> f64 {
+        let t = (value - self.range.start) / (self.range.end - self.range.start);
+        -FRAC_PI_2 - self.sweep / 2.0 + self.sweep * t.max(0.0).min(1.0)
+    }
+}
+
+fn polar(center: BackendCoord, radius: f64, angle: f64) -> BackendCoord {
+    (
+        center.0 + (radius * angle.cos()).round() as i32,
+        center.1 + (radius * angle.sin()).round() as i32,
+    )
+}
+
+/// Compute the outline of the part of a ring between two angles
+fn ring_sector(
+    center: BackendCoord,
+    (inner, outer): (f64, f64),
+    (from, to): (f64, f64),
+) -> Vec<BackendCoord> {
+    let steps = arc_segment_count(outer, to - from, DEFAULT_ARC_TOLERANCE);
+    let angle = |i: usize| from + (to - from) * i as f64 / steps as f64;
+    (0..=steps)
+        .map(|i| polar(center, outer, angle(i)))
+        .chain((0..=steps).rev().map(|i| polar(center, inner, angle(i))))
+        .collect()
+}
+
+impl<'b, 'a> PointCollection<'b, BackendCoord> for &'b Gauge<'a> {
+    type Point = &'b BackendCoord;
+    type IntoIter = std::iter::Once<&'b BackendCoord>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&self.center)
+    }
+}
+
+impl<'a, DB: DrawingBackend> Drawable<DB> for Gauge<'a> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let center = match points.next() {
+            Some(pos) => pos,
+            None => return Ok(()),
+        };
+        if self.range.start.partial_cmp(&self.range.end) != Some(Ordering::Less) || self.radius == 0
+        {
+            return Ok(());
+        }
+
+        let outer = f64::from(self.radius);
+        let inner = (outer - f64::from(self.arc_width)).max(0.0);
+        let arc = (
+            self.angle_of(self.range.start),
+            self.angle_of(self.range.end),
+        );
+        backend.fill_polygon(ring_sector(center, (inner, outer), arc), &self.arc_color)?;
+        for (range, color) in self.zones.iter() {
+            let (from, to) = (self.angle_of(range.start), self.angle_of(range.end));
+            if from < to {
+                backend.fill_polygon(ring_sector(center, (inner, outer), (from, to)), color)?;
+            }
+        }
+
+        let tick_size = f64::from(self.tick_size);
+        let label_radius = inner - tick_size - self.label_style.font.get_size();
+        let label_style = self.label_style.pos(Pos::new(HPos::Center, VPos::Center));
+        for idx in 0..self.n_ticks {
+            let t = if self.n_ticks > 1 {
+                idx as f64 / (self.n_ticks - 1) as f64
+            } else {
+                0.0
+            };
+            let value = self.range.start + (self.range.end - self.range.start) * t;
+            let angle = self.angle_of(value);
+            self.tick_style.draw_line(
+                backend,
+                polar(center, inner, angle),
+                polar(center, inner - tick_size, angle),
+            )?;
+            backend.draw_text(
+                &(self.label_formatter)(value),
+                &label_style,
+                polar(center, label_radius, angle),
+            )?;
+        }
+
+        let tip = polar(center, (outer + inner) / 2.0, self.angle_of(self.value));
+        self.needle_style.draw_path(backend, vec![center, tip])?;
+        let hub = self.needle_style.stroke_width.max(1) * 2;
+        backend.draw_circle(center, hub, &self.needle_style.filled(), true)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_gauge_element() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_path(|c, s, path| {
+                assert_eq!(c, BLUE.to_rgba());
+                assert_eq!(s, 3);
+                assert_eq!(path[0], (100, 100));
+                assert_eq!(path[1].0, 100);
+                assert!(path[1].1 < 100);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 3);
+                assert_eq!(b.num_draw_line_call, 3);
+                assert_eq!(b.num_draw_text_call, 3);
+                assert_eq!(b.num_draw_path_call, 1);
+                assert_eq!(b.num_draw_circle_call, 1);
+            });
+        });
+
+        drawing_area
+            .draw(
+                &Gauge::new((100, 100), 80, 50.0, 0.0..100.0)
+                    .zone(0.0..70.0, &GREEN)
+                    .zone(70.0..100.0, &RED)
+                    .needle_style(BLUE.stroke_width(3))
+                    .n_ticks(3),
+            )
+            .unwrap();
+    }
+}
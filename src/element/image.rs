@@ -1,8 +1,9 @@
 #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
 use image::{DynamicImage, GenericImageView};
 
-use super::{Drawable, PointCollection};
-use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use super::{BackendCoordAndArea, Drawable, PointCollection};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use plotters_backend::{BackendColor, BackendCoord, DrawingBackend, DrawingErrorKind};
 
 use plotters_bitmap::bitmap_pixel::{PixelFormat, RGBPixel};
 
@@ -53,11 +54,72 @@ impl<'a> Buffer<'a> {
     }
 }
 
+/// The interpolation used when a bitmap element is drawn in a size different from its own,
+/// see [BitMapElement::scale_to](struct.BitMapElement.html#method.scale_to)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Interpolation {
+    /// Use the nearest source pixel, which keeps the hard edges, e.g. the pixel art
+    Nearest,
+    /// Blend the four nearest source pixels, which makes the scaled images look smooth
+    Bilinear,
+}
+
+/// Resample a bitmap to the given size, each channel of the pixels is interpolated separately
+/// - `pixel_size`: The number of bytes of each pixel
+fn resample(
+    src: &[u8],
+    pixel_size: usize,
+    (sw, sh): (u32, u32),
+    (dw, dh): (u32, u32),
+    mode: Interpolation,
+) -> Vec<u8> {
+    let mut dst = vec![0; (dw * dh) as usize * pixel_size];
+    if sw == 0 || sh == 0 {
+        return dst;
+    }
+    let pixel = |x: u32, y: u32| (y * sw + x) as usize * pixel_size;
+    let (scale_x, scale_y) = (sw as f64 / dw as f64, sh as f64 / dh as f64);
+
+    for y in 0..dh {
+        for x in 0..dw {
+            let base = (y * dw + x) as usize * pixel_size;
+            match mode {
+                Interpolation::Nearest => {
+                    let sx = (((x as f64 + 0.5) * scale_x) as u32).min(sw - 1);
+                    let sy = (((y as f64 + 0.5) * scale_y) as u32).min(sh - 1);
+                    let from = pixel(sx, sy);
+                    dst[base..base + pixel_size].copy_from_slice(&src[from..from + pixel_size]);
+                }
+                Interpolation::Bilinear => {
+                    let fx = ((x as f64 + 0.5) * scale_x - 0.5)
+                        .max(0.0)
+                        .min((sw - 1) as f64);
+                    let fy = ((y as f64 + 0.5) * scale_y - 0.5)
+                        .max(0.0)
+                        .min((sh - 1) as f64);
+                    let (x0, y0) = (fx.floor() as u32, fy.floor() as u32);
+                    let (x1, y1) = ((x0 + 1).min(sw - 1), (y0 + 1).min(sh - 1));
+                    let (tx, ty) = (fx - x0 as f64, fy - y0 as f64);
+                    for c in 0..pixel_size {
+                        let value = |x, y| src[pixel(x, y) + c] as f64;
+                        let top = value(x0, y0) * (1.0 - tx) + value(x1, y0) * tx;
+                        let bottom = value(x0, y1) * (1.0 - tx) + value(x1, y1) * tx;
+                        dst[base + c] = (top * (1.0 - ty) + bottom * ty).round() as u8;
+                    }
+                }
+            }
+        }
+    }
+    dst
+}
+
 /// The element that contains a bitmap on it
 pub struct BitMapElement<'a, Coord, P: PixelFormat = RGBPixel> {
     image: Buffer<'a>,
     size: (u32, u32),
     pos: Coord,
+    draw_size: Option<(u32, u32)>,
+    interpolation: Interpolation,
     phantom: PhantomData<P>,
 }
 
@@ -72,6 +134,8 @@ impl<'a, Coord, P: PixelFormat> BitMapElement<'a, Coord, P> {
             image: Buffer::Owned(vec![0; (size.0 * size.1) as usize * P::PIXEL_SIZE]),
             size,
             pos,
+            draw_size: None,
+            interpolation: Interpolation::Bilinear,
             phantom: PhantomData,
         }
     }
@@ -93,6 +157,8 @@ impl<'a, Coord, P: PixelFormat> BitMapElement<'a, Coord, P> {
             image: Buffer::Owned(buf),
             size,
             pos,
+            draw_size: None,
+            interpolation: Interpolation::Bilinear,
             phantom: PhantomData,
         })
     }
@@ -113,6 +179,8 @@ impl<'a, Coord, P: PixelFormat> BitMapElement<'a, Coord, P> {
             image: Buffer::BorrowedMut(buf),
             size,
             pos,
+            draw_size: None,
+            interpolation: Interpolation::Bilinear,
             phantom: PhantomData,
         })
     }
@@ -134,6 +202,8 @@ impl<'a, Coord, P: PixelFormat> BitMapElement<'a, Coord, P> {
             image: Buffer::Borrowed(buf),
             size,
             pos,
+            draw_size: None,
+            interpolation: Interpolation::Bilinear,
             phantom: PhantomData,
         })
     }
@@ -146,6 +216,8 @@ impl<'a, Coord, P: PixelFormat> BitMapElement<'a, Coord, P> {
             image: Buffer::Borrowed(self.image.borrow()),
             size: self.size,
             pos,
+            draw_size: self.draw_size,
+            interpolation: self.interpolation,
             phantom: PhantomData,
         }
     }
@@ -157,6 +229,22 @@ impl<'a, Coord, P: PixelFormat> BitMapElement<'a, Coord, P> {
         self.pos = pos;
     }
 
+    /// Draw the bitmap in the given size rather than its own size, e.g. to fit a logo or a
+    /// rendered sub-figure into the chart. The bitmap is resampled when it's drawn
+    ///
+    /// - `size`: The size of the bitmap on the drawing area in pixels
+    pub fn scale_to(mut self, size: (u32, u32)) -> Self {
+        self.draw_size = Some(size);
+        self
+    }
+
+    /// Set the interpolation used when the bitmap is scaled, the default is
+    /// `Interpolation::Bilinear`
+    pub fn interpolation(mut self, mode: Interpolation) -> Self {
+        self.interpolation = mode;
+        self
+    }
+
     /// Make the bitmap element as a bitmap backend, so that we can use
     /// plotters drawing functionality on the bitmap element
     pub fn as_bitmap_backend(&mut self) -> BitMapBackend<P> {
@@ -173,6 +261,8 @@ impl<'a, Coord> From<(Coord, DynamicImage)> for BitMapElement<'a, Coord, RGBPixe
             pos,
             image: Buffer::Owned(rgb_image),
             size: (w, h),
+            draw_size: None,
+            interpolation: Interpolation::Bilinear,
             phantom: PhantomData,
         }
     }
@@ -187,6 +277,8 @@ impl<'a, Coord> From<(Coord, DynamicImage)> for BitMapElement<'a, Coord, BGRXPix
             pos,
             image: Buffer::Owned(rgb_image),
             size: (w, h),
+            draw_size: None,
+            interpolation: Interpolation::Bilinear,
             phantom: PhantomData,
         }
     }
@@ -208,9 +300,258 @@ impl<'a, Coord, DB: DrawingBackend> Drawable<DB> for BitMapElement<'a, Coord> {
         _: (u32, u32),
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
         if let Some((x, y)) = points.next() {
+            if let Some(size) = self.draw_size.filter(|size| *size != self.size) {
+                let image = resample(self.image.as_ref(), 3, self.size, size, self.interpolation);
+                return backend.blit_bitmap((x, y), size, &image);
+            }
             // TODO: convert the pixel format when needed
             return backend.blit_bitmap((x, y), self.size, self.image.as_ref());
         }
         Ok(())
     }
 }
+
+/// The element of an RGBA raster image, which is placed at a coordinate and composited onto
+/// the drawing area with its alpha channel, e.g. a logo, a watermark or a pre-rendered heatmap.
+///
+/// By default, the image is drawn in its own size with the upper-left corner at the anchor
+/// coordinate. It can be scaled to a size in pixels with [scale_to](#method.scale_to), or
+/// stretched to another coordinate with [extend_to](#method.extend_to), so that it covers an
+/// area in the data units.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let logo = vec![255u8; 16 * 16 * 4];
+/// let root = BitMapBackend::new("plotters-doc-data/image.png", (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+///     .unwrap();
+///
+/// chart
+///     .draw_series(std::iter::once(
+///         Image::with_ref((2.0, 8.0), (16, 16), &logo)
+///             .unwrap()
+///             .extend_to((4.0, 6.0))
+///             .clip(true),
+///     ))
+///     .unwrap();
+/// ```
+pub struct Image<'a, Coord> {
+    image: Buffer<'a>,
+    size: (u32, u32),
+    pos: Coord,
+    corner: Option<Coord>,
+    draw_size: Option<(u32, u32)>,
+    anchor: Pos,
+    interpolation: Interpolation,
+    clip: bool,
+}
+
+impl<'a, Coord> Image<'a, Coord> {
+    const PIXEL_SIZE: usize = 4;
+
+    fn with_buffer(pos: Coord, size: (u32, u32), image: Buffer<'a>) -> Option<Self> {
+        if image.as_ref().len() < (size.0 * size.1) as usize * Self::PIXEL_SIZE {
+            return None;
+        }
+
+        Some(Self {
+            image,
+            size,
+            pos,
+            corner: None,
+            draw_size: None,
+            anchor: Pos::new(HPos::Left, VPos::Top),
+            interpolation: Interpolation::Bilinear,
+            clip: false,
+        })
+    }
+
+    /// Create a new image element which takes the ownership of the pixel buffer
+    ///
+    /// - `pos`: The anchor coordinate of the image
+    /// - `size`: The size of the image
+    /// - `buf`: The RGBA pixels of the image, row by row
+    /// - **returns**: The newly created image element, if the buffer isn't fit the image
+    ///   dimension, this will return `None`.
+    pub fn with_owned_buffer(pos: Coord, size: (u32, u32), buf: Vec<u8>) -> Option<Self> {
+        Self::with_buffer(pos, size, Buffer::Owned(buf))
+    }
+
+    /// Create a new image element with a shared borrowed pixel buffer
+    ///
+    /// - `pos`: The anchor coordinate of the image
+    /// - `size`: The size of the image
+    /// - `buf`: The RGBA pixels of the image, row by row
+    /// - **returns**: The newly created image element, if the buffer isn't fit the image
+    ///   dimension, this will return `None`.
+    pub fn with_ref(pos: Coord, size: (u32, u32), buf: &'a [u8]) -> Option<Self> {
+        Self::with_buffer(pos, size, Buffer::Borrowed(buf))
+    }
+
+    /// Set how the image is aligned to the anchor coordinate, the default is the upper-left
+    /// corner. This is ignored if the image is stretched with [extend_to](#method.extend_to)
+    pub fn anchor(mut self, anchor: Pos) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    /// Draw the image in the given size in pixels
+    pub fn scale_to(mut self, size: (u32, u32)) -> Self {
+        self.draw_size = Some(size);
+        self
+    }
+
+    /// Stretch the image so that it covers the area between the anchor coordinate and the
+    /// given coordinate, which sizes the image in the data units
+    pub fn extend_to(mut self, corner: Coord) -> Self {
+        self.corner = Some(corner);
+        self
+    }
+
+    /// Set the interpolation used when the image is scaled, the default is
+    /// `Interpolation::Bilinear`
+    pub fn interpolation(mut self, mode: Interpolation) -> Self {
+        self.interpolation = mode;
+        self
+    }
+
+    /// Clip the image to the drawing area, e.g. the plotting area of a chart. The image isn't
+    /// clipped by default
+    pub fn clip(mut self, clip: bool) -> Self {
+        self.clip = clip;
+        self
+    }
+}
+
+impl<'a, 'b, Coord> PointCollection<'a, Coord, BackendCoordAndArea> for &'a Image<'b, Coord> {
+    type Point = &'a Coord;
+    type IntoIter = std::iter::Chain<std::iter::Once<&'a Coord>, std::option::Iter<'a, Coord>>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&self.pos).chain(self.corner.iter())
+    }
+}
+
+impl<'a, Coord, DB: DrawingBackend> Drawable<DB, BackendCoordAndArea> for Image<'a, Coord> {
+    fn draw<I: Iterator<Item = (BackendCoord, (BackendCoord, BackendCoord))>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let ((x, y), (area_ul, area_br)) = match points.next() {
+            Some(point) => point,
+            None => return Ok(()),
+        };
+
+        let (left, top, size) = if let Some(((cx, cy), _)) = points.next() {
+            let size = ((cx - x).abs() as u32, (cy - y).abs() as u32);
+            (x.min(cx), y.min(cy), size)
+        } else {
+            let (w, h) = self.draw_size.unwrap_or(self.size);
+            let left = match self.anchor.h_pos {
+                HPos::Left => x,
+                HPos::Center => x - w as i32 / 2,
+                HPos::Right => x - w as i32,
+            };
+            let top = match self.anchor.v_pos {
+                VPos::Top => y,
+                VPos::Center => y - h as i32 / 2,
+                VPos::Bottom => y - h as i32,
+            };
+            (left, top, (w, h))
+        };
+
+        let resampled;
+        let pixels = if size != self.size {
+            resampled = resample(
+                self.image.as_ref(),
+                Self::PIXEL_SIZE,
+                self.size,
+                size,
+                self.interpolation,
+            );
+            &resampled[..]
+        } else {
+            self.image.as_ref()
+        };
+
+        for (idx, pixel) in pixels
+            .chunks(Self::PIXEL_SIZE)
+            .take((size.0 * size.1) as usize)
+            .enumerate()
+        {
+            let px = left + (idx as u32 % size.0) as i32;
+            let py = top + (idx as u32 / size.0) as i32;
+            let outside = px < area_ul.0 || px >= area_br.0 || py < area_ul.1 || py >= area_br.1;
+            if pixel[3] == 0 || (self.clip && outside) {
+                continue;
+            }
+            let color = BackendColor {
+                alpha: pixel[3] as f64 / 255.0,
+                rgb: (pixel[0], pixel[1], pixel[2]),
+            };
+            backend.draw_pixel((px, py), color)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_resample() {
+        let src = [0, 0, 0, 255, 255, 255];
+        let nearest = resample(&src, 3, (2, 1), (4, 1), Interpolation::Nearest);
+        assert_eq!(
+            nearest,
+            vec![0, 0, 0, 0, 0, 0, 255, 255, 255, 255, 255, 255]
+        );
+        let bilinear = resample(&src, 3, (2, 1), (4, 1), Interpolation::Bilinear);
+        assert_eq!(
+            bilinear,
+            vec![0, 0, 0, 64, 64, 64, 191, 191, 191, 255, 255, 255]
+        );
+        assert_eq!(
+            resample(&src, 3, (2, 1), (1, 1), Interpolation::Bilinear).len(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_image_clip() {
+        use crate::prelude::*;
+
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_pixel(|c, pos| {
+                assert_eq!((c.0, c.1, c.2), (255, 0, 0));
+                assert!((c.3 - 0.4).abs() < 1e-6);
+                // The top edge of the chart is the row -1, so the image covers the rows -3..=0
+                assert_eq!(pos.1, 0);
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_pixel_call, 3));
+        });
+
+        let mut pixels = vec![];
+        for idx in 0..16 {
+            let alpha = if idx % 4 == 3 { 0 } else { 102 };
+            pixels.extend_from_slice(&[255, 0, 0, alpha]);
+        }
+
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0..10)
+            .unwrap();
+        chart
+            .plotting_area()
+            .draw(
+                &Image::with_owned_buffer((5, 10), (4, 4), pixels)
+                    .unwrap()
+                    .anchor(Pos::new(HPos::Center, VPos::Center))
+                    .clip(true),
+            )
+            .unwrap();
+    }
+}
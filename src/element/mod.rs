@@ -169,13 +169,40 @@ pub use text::*;
 mod points;
 pub use points::*;
 
+mod size_legend;
+pub use size_legend::{sqrt_area_scale, SizeLegend, SizeLegendLayout};
+
+mod treemap;
+pub use treemap::{Treemap, TreemapItem};
+
+mod gauge;
+pub use gauge::Gauge;
+
+mod funnel;
+pub use funnel::Funnel;
+
+mod path_builder;
+pub use path_builder::{CustomShape, PathBuilder};
+
+mod word_cloud;
+pub use word_cloud::WordCloud;
+
+mod rotated;
+pub use rotated::{Rotated, RotatedBackend};
+
+mod shadow;
+pub use shadow::{ShadowBackend, Shadowed};
+
+mod blend;
+pub use blend::Blended;
+
 mod composable;
-pub use composable::{ComposedElement, EmptyElement};
+pub use composable::{BoxedElement, ComposedElement, EmptyElement};
 
 #[cfg(feature = "candlestick")]
 mod candlestick;
 #[cfg(feature = "candlestick")]
-pub use candlestick::CandleStick;
+pub use candlestick::{CandleStick, OhlcBar};
 
 #[cfg(feature = "errorbar")]
 mod errorbar;
@@ -187,10 +214,15 @@ mod boxplot;
 #[cfg(feature = "boxplot")]
 pub use boxplot::Boxplot;
 
+#[cfg(feature = "colorbar")]
+mod colorbar;
+#[cfg(feature = "colorbar")]
+pub use colorbar::{Colorbar, ColorbarOrientation};
+
 #[cfg(feature = "bitmap_backend")]
 mod image;
 #[cfg(feature = "bitmap_backend")]
-pub use self::image::BitMapElement;
+pub use self::image::{BitMapElement, Image, Interpolation};
 
 mod dynelem;
 pub use dynelem::{DynElement, IntoDynElement};
@@ -276,3 +308,19 @@ impl CoordMapper for BackendCoordAndZ {
         (coord, z)
     }
 }
+
+/// The coordinate mapper which keeps the coordinates out of the drawing area as they are, and
+/// provides the corners of the drawing area with each coordinate, so that the element is able
+/// to clip itself to the drawing area
+pub struct BackendCoordAndArea;
+
+impl CoordMapper for BackendCoordAndArea {
+    type Output = (BackendCoord, (BackendCoord, BackendCoord));
+    fn map<CT: CoordTranslate>(
+        coord_trans: &CT,
+        from: &CT::From,
+        rect: &Rect,
+    ) -> (BackendCoord, (BackendCoord, BackendCoord)) {
+        (coord_trans.translate(from), rect.corners())
+    }
+}
@@ -0,0 +1,320 @@
+use super::basic_shapes::fill_rings;
+use super::{Drawable, FillRule, PointCollection};
+use crate::style::{Color, ShapeStyle};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// The max length of the segments a cubic curve is flattened into, in pixels
+const CURVE_TOLERANCE: f64 = 2.0;
+/// The max number of the segments of a cubic curve
+const MAX_CURVE_SEGMENTS: usize = 128;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum PathCommand {
+    MoveTo,
+    LineTo,
+    CubicTo,
+    Close,
+}
+
+/// The builder of a custom shape, which takes the path commands in the guest coordinate and
+/// builds a [CustomShape](struct.CustomShape.html) element. This is the way to draw the shapes
+/// not covered by the other elements, e.g. a logo or a custom marker.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let root = BitMapBackend::new("plotters-doc-data/path.png", (400, 400)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+///     .unwrap();
+///
+/// let drop = PathBuilder::new()
+///     .move_to((5.0, 9.0))
+///     .cubic_to((6.0, 6.0), (8.0, 4.0), (5.0, 1.0))
+///     .cubic_to((2.0, 4.0), (4.0, 6.0), (5.0, 9.0))
+///     .close()
+///     .build()
+///     .fill(&BLUE.mix(0.5))
+///     .stroke(&BLUE);
+/// chart.plotting_area().draw(&drop).unwrap();
+/// ```
+pub struct PathBuilder<Coord> {
+    points: Vec<Coord>,
+    commands: Vec<PathCommand>,
+}
+
+impl<Coord> PathBuilder<Coord> {
+    /// Create an empty path builder
+    pub fn new() -> Self {
+        Self {
+            points: vec![],
+            commands: vec![],
+        }
+    }
+
+    /// Start a new subpath at the point
+    pub fn move_to(mut self, point: Coord) -> Self {
+        self.points.push(point);
+        self.commands.push(PathCommand::MoveTo);
+        self
+    }
+
+    /// Draw a straight line from the current point to the point, this starts a new subpath if
+    /// there's no current point
+    pub fn line_to(mut self, point: Coord) -> Self {
+        self.points.push(point);
+        self.commands.push(PathCommand::LineTo);
+        self
+    }
+
+    /// Draw a cubic Bézier curve from the current point to the point
+    /// - `control1`: The control point of the start of the curve
+    /// - `control2`: The control point of the end of the curve
+    /// - `point`: The end of the curve
+    pub fn cubic_to(mut self, control1: Coord, control2: Coord, point: Coord) -> Self {
+        self.points.extend(vec![control1, control2, point]);
+        self.commands.push(PathCommand::CubicTo);
+        self
+    }
+
+    /// Close the current subpath with a straight line back to its start
+    pub fn close(mut self) -> Self {
+        self.commands.push(PathCommand::Close);
+        self
+    }
+
+    /// Build the shape element, which is neither filled nor stroked until its styles are set
+    pub fn build(self) -> CustomShape<Coord> {
+        CustomShape {
+            points: self.points,
+            commands: self.commands,
+            fill: None,
+            stroke: None,
+            fill_rule: FillRule::NonZero,
+        }
+    }
+}
+
+impl<Coord> Default for PathBuilder<Coord> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The element of a custom shape built by [PathBuilder](struct.PathBuilder.html). The curves
+/// are flattened into line segments in the backend coordinate, so the shape is drawn the same
+/// way by all the backends.
+pub struct CustomShape<Coord> {
+    points: Vec<Coord>,
+    commands: Vec<PathCommand>,
+    fill: Option<ShapeStyle>,
+    stroke: Option<ShapeStyle>,
+    fill_rule: FillRule,
+}
+
+impl<Coord> CustomShape<Coord> {
+    /// Fill the shape, the open subpaths are filled as if they were closed
+    pub fn fill<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.fill = Some(style.into());
+        self
+    }
+
+    /// Stroke the outline of the shape
+    pub fn stroke<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.stroke = Some(style.into());
+        self
+    }
+
+    /// Set the fill rule of the shape, which decides the filled area where the subpaths overlap
+    /// or a subpath intersects itself, the default is `FillRule::NonZero`
+    pub fn fill_rule(mut self, rule: FillRule) -> Self {
+        self.fill_rule = rule;
+        self
+    }
+
+    /// Flatten the path in the backend coordinate into the subpaths, each of them is the list
+    /// of the points and if it's closed
+    fn flatten(&self, points: &[BackendCoord]) -> Vec<(Vec<BackendCoord>, bool)> {
+        let mut subpaths = vec![];
+        let mut current: Vec<BackendCoord> = vec![];
+        let mut points = points.iter().copied();
+        let mut finish = |current: &mut Vec<BackendCoord>, closed| {
+            if current.len() > 1 {
+                subpaths.push((std::mem::replace(current, vec![]), closed));
+            }
+            current.clear();
+        };
+
+        for command in self.commands.iter() {
+            match command {
+                PathCommand::MoveTo => {
+                    finish(&mut current, false);
+                    current.extend(points.next());
+                }
+                PathCommand::LineTo => current.extend(points.next()),
+                PathCommand::CubicTo => {
+                    let (c1, c2, end) = match (points.next(), points.next(), points.next()) {
+                        (Some(c1), Some(c2), Some(end)) => (c1, c2, end),
+                        _ => break,
+                    };
+                    let start = match current.last() {
+                        Some(&start) => start,
+                        None => {
+                            current.push(end);
+                            continue;
+                        }
+                    };
+                    current.extend(flatten_cubic([start, c1, c2, end]).into_iter().skip(1));
+                }
+                PathCommand::Close => {
+                    let start = current.first().copied();
+                    finish(&mut current, true);
+                    // The following commands continue from the start of the closed subpath
+                    current.extend(start);
+                }
+            }
+        }
+        finish(&mut current, false);
+        subpaths
+    }
+}
+
+/// Flatten a cubic Bézier curve into a polyline, including both ends
+fn flatten_cubic(p: [BackendCoord; 4]) -> Vec<BackendCoord> {
+    let dist = |a: BackendCoord, b: BackendCoord| {
+        (f64::from(a.0 - b.0).powi(2) + f64::from(a.1 - b.1).powi(2)).sqrt()
+    };
+    // The length of the control polygon is an upper bound of the length of the curve
+    let length = dist(p[0], p[1]) + dist(p[1], p[2]) + dist(p[2], p[3]);
+    let n = ((length / CURVE_TOLERANCE).ceil() as usize)
+        .max(1)
+        .min(MAX_CURVE_SEGMENTS);
+    (0..=n)
+        .map(|i| {
+            let t = i as f64 / n as f64;
+            let s = 1.0 - t;
+            let w = [s * s * s, 3.0 * s * s * t, 3.0 * s * t * t, t * t * t];
+            let (x, y) = p.iter().zip(w.iter()).fold((0.0, 0.0), |(x, y), (p, w)| {
+                (x + f64::from(p.0) * w, y + f64::from(p.1) * w)
+            });
+            (x.round() as i32, y.round() as i32)
+        })
+        .collect()
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a CustomShape<Coord> {
+    type Point = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for CustomShape<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = points.collect();
+        let subpaths = self.flatten(&points);
+
+        if let Some(fill) = self.fill.as_ref() {
+            let color = fill.color.to_backend_color();
+            let rings: Vec<_> = subpaths
+                .iter()
+                .map(|(ring, _)| &ring[..])
+                .filter(|ring| ring.len() > 2)
+                .collect();
+            fill_rings(backend, &rings, self.fill_rule, &color)?;
+        }
+
+        if let Some(stroke) = self.stroke.as_ref() {
+            for (path, closed) in subpaths {
+                let first = path[0];
+                let path = path.into_iter().chain(Some(first).filter(|_| closed));
+                stroke.draw_path(backend, path)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_flatten_cubic() {
+        let curve = flatten_cubic([(0, 0), (0, 20), (40, 20), (40, 0)]);
+        assert_eq!(curve.len(), 41);
+        assert_eq!(curve[0], (0, 0));
+        assert_eq!(curve[20], (20, 15));
+        assert_eq!(curve[40], (40, 0));
+    }
+
+    #[test]
+    fn test_custom_shape() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_fill_polygon(|c, vert| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(vert[0], (10, 10));
+                assert!(vert.len() > 4);
+            });
+            m.check_draw_path(|c, _, path| {
+                assert_eq!(c, BLACK.to_rgba());
+                assert_eq!(path.first(), path.last());
+            });
+            m.check_draw_path(|_, _, path| {
+                assert_eq!(path, vec![(60, 60), (90, 90)]);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 1);
+                assert_eq!(b.num_draw_path_call, 2);
+            });
+        });
+
+        let shape = PathBuilder::new()
+            .move_to((10, 10))
+            .line_to((50, 10))
+            .cubic_to((50, 30), (30, 50), (10, 50))
+            .close()
+            .move_to((60, 60))
+            .line_to((90, 90))
+            .build()
+            .fill(&RED)
+            .stroke(&BLACK);
+        drawing_area.draw(&shape).expect("Drawing error");
+    }
+
+    #[test]
+    fn test_custom_shape_with_hole() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_fill_polygon(|_, vert| {
+                assert_eq!(vert.len(), 4 + 1 + 4 + 1);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 1);
+                assert_eq!(b.num_draw_line_call, 0);
+            });
+        });
+
+        let shape = PathBuilder::new()
+            .move_to((10, 10))
+            .line_to((90, 10))
+            .line_to((90, 90))
+            .line_to((10, 90))
+            .close()
+            .move_to((30, 30))
+            .line_to((70, 30))
+            .line_to((70, 70))
+            .line_to((30, 70))
+            .close()
+            .build()
+            .fill(&RED)
+            .fill_rule(FillRule::EvenOdd);
+        drawing_area.draw(&shape).expect("Drawing error");
+    }
+}
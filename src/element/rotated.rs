@@ -0,0 +1,654 @@
+use std::borrow::Borrow;
+use std::f64::consts::FRAC_PI_2;
+
+use super::{Drawable, PointCollection};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{FontDesc, FontFamily, FontStyle, FontTransform};
+use plotters_backend::{
+    BackendColor, BackendCoord, BackendStyle, BackendTextStyle, DrawingBackend, DrawingErrorKind,
+};
+
+/// The element wrapper that rotates any element by an angle about a pivot point.
+///
+/// The rotation is applied to the pixel coordinates the inner element draws with, so the
+/// rectangles are drawn as rotated polygons. The text rotated by quarter turns is passed to the
+/// backend with the combined [FontTransform](../style/enum.FontTransform.html), otherwise the
+/// glyph outlines are rotated and filled as polygons. The bitmaps are resampled and blitted in
+/// rows. This makes it possible to draw rotated bars, tilted annotations or the needle of a
+/// gauge with the existing elements. Like [DynElement](struct.DynElement.html), the rotated
+/// element is boxed, so the rotated elements of different types can be drawn in one series.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut buffer = vec![0; 100 * 100 * 3];
+/// let root = BitMapBackend::with_buffer(&mut buffer, (100, 100)).into_drawing_area();
+/// root.draw(&Rotated::new(
+///     Rectangle::new([(40, 45), (90, 55)], RED.filled()),
+///     (50, 50),
+///     std::f64::consts::FRAC_PI_4,
+/// ))
+/// .unwrap();
+/// ```
+pub struct Rotated<'a, DB: DrawingBackend, Coord> {
+    points: Vec<Coord>,
+    inner: Box<dyn DrawRotated<DB> + 'a>,
+    angle: f64,
+}
+
+trait DrawRotated<DB: DrawingBackend> {
+    fn draw_rotated(
+        &self,
+        points: &mut dyn Iterator<Item = BackendCoord>,
+        backend: &mut RotatedBackend<DB>,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>>;
+}
+
+impl<DB: DrawingBackend, E> DrawRotated<DB> for E
+where
+    for<'b> E: Drawable<RotatedBackend<'b, DB>>,
+{
+    fn draw_rotated(
+        &self,
+        points: &mut dyn Iterator<Item = BackendCoord>,
+        backend: &mut RotatedBackend<DB>,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.draw(points, backend, parent_dim)
+    }
+}
+
+impl<'a, DB: DrawingBackend, Coord: Clone> Rotated<'a, DB, Coord> {
+    /// Create a new rotated element
+    /// - `inner`: The element to rotate
+    /// - `pivot`: The point the element is rotated about, in the same coordinate as the element
+    /// - `angle`: The angle of the rotation in radians, a positive angle rotates the element
+    ///   clockwise on the screen
+    pub fn new<E>(inner: E, pivot: Coord, angle: f64) -> Self
+    where
+        E: 'a,
+        for<'b> E: Drawable<RotatedBackend<'b, DB>>,
+        for<'b> &'b E: PointCollection<'b, Coord>,
+    {
+        let points = std::iter::once(pivot)
+            .chain(inner.point_iter().into_iter().map(|p| p.borrow().clone()))
+            .collect();
+        Self {
+            points,
+            inner: Box::new(inner),
+            angle,
+        }
+    }
+}
+
+impl<'a, 'b: 'a, DB: DrawingBackend, Coord> PointCollection<'a, Coord>
+    for &'a Rotated<'b, DB, Coord>
+{
+    type Point = &'a Coord;
+    type IntoIter = &'a Vec<Coord>;
+    fn point_iter(self) -> Self::IntoIter {
+        &self.points
+    }
+}
+
+impl<'a, DB: DrawingBackend, Coord> Drawable<DB> for Rotated<'a, DB, Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let Some(pivot) = points.next() {
+            let mut rotated = RotatedBackend {
+                inner: backend,
+                rotation: Rotation::new(pivot, self.angle),
+            };
+            self.inner
+                .draw_rotated(&mut points, &mut rotated, parent_dim)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Rotation {
+    pivot: (f64, f64),
+    angle: f64,
+    sin: f64,
+    cos: f64,
+}
+
+impl Rotation {
+    fn new(pivot: BackendCoord, angle: f64) -> Self {
+        Self {
+            pivot: (f64::from(pivot.0), f64::from(pivot.1)),
+            angle,
+            sin: angle.sin(),
+            cos: angle.cos(),
+        }
+    }
+
+    fn rotate(&self, (x, y): (f64, f64), sin: f64) -> (f64, f64) {
+        let dx = x - self.pivot.0;
+        let dy = y - self.pivot.1;
+        (
+            self.pivot.0 + dx * self.cos - dy * sin,
+            self.pivot.1 + dx * sin + dy * self.cos,
+        )
+    }
+
+    fn transform(&self, point: BackendCoord, sin: f64) -> BackendCoord {
+        let (x, y) = self.rotate((f64::from(point.0), f64::from(point.1)), sin);
+        (x.round() as i32, y.round() as i32)
+    }
+
+    fn apply(&self, point: BackendCoord) -> BackendCoord {
+        self.transform(point, self.sin)
+    }
+
+    fn apply_f64(&self, point: (f64, f64)) -> (f64, f64) {
+        self.rotate(point, self.sin)
+    }
+
+    fn revert(&self, point: BackendCoord) -> BackendCoord {
+        self.transform(point, -self.sin)
+    }
+
+    /// The number of the clockwise quarter turns closest to the rotation, in `0..4`
+    fn closest_quarter_turns(&self) -> u8 {
+        (self.angle / FRAC_PI_2).round().rem_euclid(4.0) as u8
+    }
+
+    /// The number of the clockwise quarter turns of the rotation, or `None` if the rotation
+    /// isn't a multiple of the quarter turn
+    fn quarter_turns(&self) -> Option<u8> {
+        let turns = self.angle / FRAC_PI_2;
+        if (turns - turns.round()).abs() < 1e-9 {
+            Some(self.closest_quarter_turns())
+        } else {
+            None
+        }
+    }
+}
+
+fn font_transform_turns(trans: &FontTransform) -> u8 {
+    match trans {
+        FontTransform::None => 0,
+        FontTransform::Rotate90 => 1,
+        FontTransform::Rotate180 => 2,
+        FontTransform::Rotate270 => 3,
+    }
+}
+
+fn font_transform_from_turns(turns: u8) -> FontTransform {
+    match turns % 4 {
+        0 => FontTransform::None,
+        1 => FontTransform::Rotate90,
+        2 => FontTransform::Rotate180,
+        _ => FontTransform::Rotate270,
+    }
+}
+
+/// The text style with the font transform replaced, which passes the quarter turns of the
+/// rotation to the backend
+struct TransformedTextStyle<'a, S> {
+    inner: &'a S,
+    transform: FontTransform,
+}
+
+impl<'a, S: BackendTextStyle> BackendTextStyle for TransformedTextStyle<'a, S> {
+    type FontError = S::FontError;
+
+    fn color(&self) -> BackendColor {
+        self.inner.color()
+    }
+
+    fn size(&self) -> f64 {
+        self.inner.size()
+    }
+
+    fn transform(&self) -> FontTransform {
+        self.transform.clone()
+    }
+
+    fn style(&self) -> FontStyle {
+        self.inner.style()
+    }
+
+    fn anchor(&self) -> Pos {
+        self.inner.anchor()
+    }
+
+    fn family(&self) -> FontFamily {
+        self.inner.family()
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn layout_box(&self, text: &str) -> Result<((i32, i32), (i32, i32)), Self::FontError> {
+        self.inner.layout_box(text)
+    }
+
+    fn draw<E, DrawFunc: FnMut(i32, i32, BackendColor) -> Result<(), E>>(
+        &self,
+        text: &str,
+        pos: BackendCoord,
+        draw: DrawFunc,
+    ) -> Result<Result<(), E>, Self::FontError> {
+        self.inner.draw(text, pos, draw)
+    }
+}
+
+/// The drawing backend adapter used by [Rotated](struct.Rotated.html), which rotates the
+/// coordinates of every drawing call before forwarding it to the underlying backend
+pub struct RotatedBackend<'b, DB: DrawingBackend> {
+    inner: &'b mut DB,
+    rotation: Rotation,
+}
+
+impl<'b, DB: DrawingBackend> RotatedBackend<'b, DB> {
+    /// Draw the raster image in the rectangle `[upper_left, bottom_right)` rotated, by sampling
+    /// the source pixel of each pixel covered by the rotated rectangle, so the rotated image
+    /// doesn't have holes. The covered pixels are blitted in horizontal runs.
+    fn draw_resampled<F: Fn(BackendCoord) -> Option<[u8; 3]>>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        sample: F,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let rotation = self.rotation;
+        let (min, max) = rotated_bounds(&rotation, upper_left, bottom_right);
+
+        let (w, h) = self.inner.get_size();
+        let mut run = vec![];
+        for y in min.1.max(0)..=max.1.min(h as i32 - 1) {
+            let mut run_start = 0;
+            // The extra column past the right edge ends the last run of the row
+            for x in min.0.max(0)..=max.0.min(w as i32 - 1) + 1 {
+                let src = rotation.revert((x, y));
+                let color = if x > max.0.min(w as i32 - 1)
+                    || src.0 < upper_left.0
+                    || src.1 < upper_left.1
+                    || src.0 >= bottom_right.0
+                    || src.1 >= bottom_right.1
+                {
+                    None
+                } else {
+                    sample(src)
+                };
+                match color {
+                    Some(rgb) => {
+                        if run.is_empty() {
+                            run_start = x;
+                        }
+                        run.extend_from_slice(&rgb);
+                    }
+                    None if !run.is_empty() => {
+                        let len = (run.len() / 3) as u32;
+                        self.inner.blit_bitmap((run_start, y), (len, 1), &run)?;
+                        run.clear();
+                    }
+                    None => {}
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The bounding box of the rectangle `[upper_left, bottom_right)` after the rotation
+fn rotated_bounds(
+    rotation: &Rotation,
+    upper_left: BackendCoord,
+    bottom_right: BackendCoord,
+) -> (BackendCoord, BackendCoord) {
+    let corners = [
+        upper_left,
+        (bottom_right.0, upper_left.1),
+        bottom_right,
+        (upper_left.0, bottom_right.1),
+    ];
+    let (mut min, mut max) = (
+        (std::i32::MAX, std::i32::MAX),
+        (std::i32::MIN, std::i32::MIN),
+    );
+    for (x, y) in corners.iter().map(|p| rotation.apply(*p)) {
+        min = (min.0.min(x), min.1.min(y));
+        max = (max.0.max(x), max.1.max(y));
+    }
+    (min, max)
+}
+
+impl<'b, DB: DrawingBackend> DrawingBackend for RotatedBackend<'b, DB> {
+    type ErrorType = DB::ErrorType;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.inner.get_size()
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.inner.ensure_prepared()
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.inner.present()
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: BackendColor,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.inner.draw_pixel(self.rotation.apply(point), color)
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let rotation = self.rotation;
+        self.inner
+            .draw_line(rotation.apply(from), rotation.apply(to), style)
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let rotation = self.rotation;
+        let corners = vec![
+            upper_left,
+            (bottom_right.0, upper_left.1),
+            bottom_right,
+            (upper_left.0, bottom_right.1),
+        ];
+        if fill {
+            self.inner
+                .fill_polygon(corners.into_iter().map(|p| rotation.apply(p)), style)
+        } else {
+            self.inner.draw_path(
+                corners
+                    .iter()
+                    .chain(std::iter::once(&upper_left))
+                    .map(|p| rotation.apply(*p)),
+                style,
+            )
+        }
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let rotation = self.rotation;
+        self.inner
+            .draw_path(path.into_iter().map(|p| rotation.apply(p)), style)
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.inner
+            .draw_circle(self.rotation.apply(center), radius, style, fill)
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let rotation = self.rotation;
+        self.inner
+            .fill_polygon(vert.into_iter().map(|p| rotation.apply(p)), style)
+    }
+
+    fn draw_text<TStyle: BackendTextStyle>(
+        &mut self,
+        text: &str,
+        style: &TStyle,
+        pos: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let color = style.color();
+        if color.alpha == 0.0 {
+            return Ok(());
+        }
+
+        let quarter_turns = self.rotation.quarter_turns();
+        if quarter_turns.is_none() {
+            let font = FontDesc::new(style.family(), style.size(), style.style());
+            let ((min_x, min_y), (max_x, max_y)) = font
+                .layout_box(text)
+                .map_err(|e| DrawingErrorKind::FontError(Box::new(e)))?;
+            let (width, height) = (max_x - min_x, max_y - min_y);
+            let anchor = style.anchor();
+            let dx = match anchor.h_pos {
+                HPos::Left => 0,
+                HPos::Right => -width,
+                HPos::Center => -width / 2,
+            };
+            let dy = match anchor.v_pos {
+                VPos::Top => 0,
+                VPos::Center => -height / 2,
+                VPos::Bottom => -height,
+            };
+            let glyphs = font
+                .outline(text, (dx - min_x, dy - min_y))
+                .map_err(|e| DrawingErrorKind::FontError(Box::new(e)))?;
+            if let Some(glyphs) = glyphs {
+                // The font transform turns the text about the text position before the rotation
+                let text_rotation = Rotation::new(
+                    pos,
+                    f64::from(font_transform_turns(&style.transform())) * FRAC_PI_2,
+                );
+                let rotation = self.rotation;
+                for glyph in glyphs {
+                    // The contours of a glyph are joined into one path by the bridges travelled
+                    // forth and back, the holes run opposite to the outer contours in the fonts
+                    let mut path: Vec<BackendCoord> = vec![];
+                    for contour in glyph {
+                        let mut ring: Vec<BackendCoord> = vec![];
+                        for (x, y) in contour {
+                            let point = (f64::from(pos.0) + x, f64::from(pos.1) + y);
+                            let (x, y) = rotation.apply_f64(text_rotation.apply_f64(point));
+                            let point = (x.round() as i32, y.round() as i32);
+                            if ring.last() != Some(&point) {
+                                ring.push(point);
+                            }
+                        }
+                        if ring.len() < 3 {
+                            continue;
+                        }
+                        if let Some(&start) = path.first() {
+                            path.push(start);
+                            path.extend_from_slice(&ring);
+                            path.push(ring[0]);
+                        } else {
+                            path = ring;
+                        }
+                    }
+                    if !path.is_empty() {
+                        self.inner.fill_polygon(path, &color)?;
+                    }
+                }
+                return Ok(());
+            }
+        }
+
+        // The quarter turns are folded into the font transform, so the backend draws the text
+        // itself. If the glyph outlines are not available, the text is drawn with the closest
+        // quarter turn.
+        let turns = quarter_turns.unwrap_or_else(|| self.rotation.closest_quarter_turns());
+        let style = TransformedTextStyle {
+            inner: style,
+            transform: font_transform_from_turns(font_transform_turns(&style.transform()) + turns),
+        };
+        self.inner.draw_text(text, &style, self.rotation.apply(pos))
+    }
+
+    fn estimate_text_size<TStyle: BackendTextStyle>(
+        &self,
+        text: &str,
+        style: &TStyle,
+    ) -> Result<(u32, u32), DrawingErrorKind<DB::ErrorType>> {
+        self.inner.estimate_text_size(text, style)
+    }
+
+    fn blit_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        (iw, ih): (u32, u32),
+        src: &[u8],
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let bottom_right = (pos.0 + iw as i32, pos.1 + ih as i32);
+        let pixel = |x: u32, y: u32| {
+            let idx = (y as usize * iw as usize + x as usize) * 3;
+            src.get(idx..idx + 3)
+        };
+
+        // The image rotated by quarter turns is still a raster image, which is blitted once
+        if let Some(turns) = self.rotation.quarter_turns() {
+            let (w, h) = if turns % 2 == 0 { (iw, ih) } else { (ih, iw) };
+            let mut rotated = Vec::with_capacity(src.len());
+            for j in 0..h {
+                for i in 0..w {
+                    let (x, y) = match turns {
+                        0 => (i, j),
+                        1 => (j, ih - 1 - i),
+                        2 => (iw - 1 - i, ih - 1 - j),
+                        _ => (iw - 1 - j, i),
+                    };
+                    rotated.extend_from_slice(pixel(x, y).unwrap_or(&[0, 0, 0]));
+                }
+            }
+            let (upper_left, _) = rotated_bounds(&self.rotation, pos, bottom_right);
+            return self.inner.blit_bitmap(upper_left, (w, h), &rotated);
+        }
+
+        self.draw_resampled(pos, bottom_right, |(x, y)| {
+            pixel((x - pos.0) as u32, (y - pos.1) as u32).map(|rgb| [rgb[0], rgb[1], rgb[2]])
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_rotated_rectangle() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_fill_polygon(|c, vert| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(vert, vec![(10, 10), (10, 30), (0, 30), (0, 10)]);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 1);
+                assert_eq!(b.num_draw_rect_call, 0);
+            });
+        });
+
+        drawing_area
+            .draw(&Rotated::new(
+                Rectangle::new([(10, 10), (30, 20)], RED.filled()),
+                (10, 10),
+                std::f64::consts::FRAC_PI_2,
+            ))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_rotated_text_quarter_turn() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(pos, (0, 30));
+                assert_eq!(text, "abc");
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_text_call, 1);
+                assert_eq!(b.num_draw_pixel_call, 0);
+            });
+        });
+
+        drawing_area
+            .draw(&Rotated::new(
+                Text::new("abc", (30, 20), ("sans-serif", 10)),
+                (10, 10),
+                std::f64::consts::FRAC_PI_2,
+            ))
+            .unwrap();
+    }
+
+    #[cfg(feature = "ttf")]
+    #[test]
+    fn test_rotated_text_outline() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_fill_polygon(|c, _| assert_eq!(c, RED.to_rgba()));
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 3);
+                assert_eq!(b.num_draw_text_call, 0);
+                assert_eq!(b.num_draw_pixel_call, 0);
+            });
+        });
+
+        drawing_area
+            .draw(&Rotated::new(
+                Text::new("abc", (30, 20), ("sans-serif", 20).into_font().color(&RED)),
+                (30, 20),
+                std::f64::consts::FRAC_PI_4,
+            ))
+            .unwrap();
+    }
+
+    #[cfg(feature = "bitmap_backend")]
+    #[test]
+    fn test_rotated_bitmap() {
+        let mut image = vec![];
+        for y in 0..2u8 {
+            for x in 0..3u8 {
+                image.extend_from_slice(&[100 + x * 10, 100 + y * 10, 50]);
+            }
+        }
+        let pixel = |buffer: &[u8], (x, y): (usize, usize)| {
+            let idx = (y * 20 + x) * 3;
+            [buffer[idx], buffer[idx + 1], buffer[idx + 2]]
+        };
+
+        let mut buffer = vec![0; 20 * 20 * 3];
+        {
+            let mut backend = BitMapBackend::with_buffer(&mut buffer, (20, 20));
+            let mut rotated = RotatedBackend {
+                inner: &mut backend,
+                rotation: Rotation::new((10, 10), std::f64::consts::FRAC_PI_2),
+            };
+            rotated.blit_bitmap((10, 10), (3, 2), &image).unwrap();
+        }
+        assert_eq!(pixel(&buffer, (9, 10)), [100, 100, 50]);
+        assert_eq!(pixel(&buffer, (8, 10)), [100, 110, 50]);
+        assert_eq!(pixel(&buffer, (9, 12)), [120, 100, 50]);
+        assert_eq!(buffer.iter().filter(|&&c| c == 50).count(), 6);
+
+        let mut buffer = vec![0; 20 * 20 * 3];
+        {
+            let mut backend = BitMapBackend::with_buffer(&mut buffer, (20, 20));
+            let mut rotated = RotatedBackend {
+                inner: &mut backend,
+                rotation: Rotation::new((10, 10), std::f64::consts::FRAC_PI_4),
+            };
+            rotated.blit_bitmap((10, 10), (3, 2), &image).unwrap();
+        }
+        let covered: Vec<_> = buffer.chunks(3).filter(|p| p != &[0, 0, 0]).collect();
+        assert!(covered.len() >= 6);
+        assert!(covered.iter().all(|p| image.chunks(3).any(|q| &q == p)));
+    }
+}
@@ -0,0 +1,358 @@
+use std::borrow::Borrow;
+
+use super::{Drawable, PointCollection};
+use crate::style::{Color, RGBAColor, BLACK};
+use plotters_backend::{
+    BackendColor, BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind,
+};
+
+/// The element wrapper that draws a drop shadow beneath any element.
+///
+/// The shadow is a copy of the element drawn in the shadow color and moved by the offset,
+/// before the element itself is drawn. When the blur radius is set, the shadow is drawn as
+/// several translucent copies spread around the offset within the radius, which fades the edges
+/// of the shadow out. The shadows are drawn only for the wrapped elements, so the cost is only
+/// paid where the effect is wanted. Like [DynElement](struct.DynElement.html), the element
+/// casting the shadow is boxed.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut buffer = vec![0; 100 * 100 * 3];
+/// let root = BitMapBackend::with_buffer(&mut buffer, (100, 100)).into_drawing_area();
+/// root.fill(&WHITE).unwrap();
+/// root.draw(
+///     &Shadowed::new(Rectangle::new([(20, 20), (70, 60)], BLUE.filled()))
+///         .offset((5, 5))
+///         .blur(4)
+///         .color(BLACK.mix(0.4)),
+/// )
+/// .unwrap();
+/// ```
+pub struct Shadowed<'a, DB: DrawingBackend, Coord> {
+    points: Vec<Coord>,
+    inner: Box<dyn DrawShadowed<DB> + 'a>,
+    offset: BackendCoord,
+    blur: u32,
+    color: RGBAColor,
+}
+
+trait DrawShadowed<DB: DrawingBackend> {
+    fn draw_shadow(
+        &self,
+        points: &mut dyn Iterator<Item = BackendCoord>,
+        backend: &mut ShadowBackend<DB>,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>>;
+
+    fn draw_element(
+        &self,
+        points: &mut dyn Iterator<Item = BackendCoord>,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>>;
+}
+
+impl<DB: DrawingBackend, E> DrawShadowed<DB> for E
+where
+    E: Drawable<DB>,
+    for<'b> E: Drawable<ShadowBackend<'b, DB>>,
+{
+    fn draw_shadow(
+        &self,
+        points: &mut dyn Iterator<Item = BackendCoord>,
+        backend: &mut ShadowBackend<DB>,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        Drawable::<ShadowBackend<DB>>::draw(self, points, backend, parent_dim)
+    }
+
+    fn draw_element(
+        &self,
+        points: &mut dyn Iterator<Item = BackendCoord>,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        Drawable::<DB>::draw(self, points, backend, parent_dim)
+    }
+}
+
+impl<'a, DB: DrawingBackend, Coord: Clone> Shadowed<'a, DB, Coord> {
+    /// Create a new element with the default shadow, which is moved by 3 pixels to the bottom
+    /// right without the blur
+    /// - `inner`: The element casts the shadow
+    pub fn new<E>(inner: E) -> Self
+    where
+        E: Drawable<DB> + 'a,
+        for<'b> E: Drawable<ShadowBackend<'b, DB>>,
+        for<'b> &'b E: PointCollection<'b, Coord>,
+    {
+        Self {
+            points: inner
+                .point_iter()
+                .into_iter()
+                .map(|p| p.borrow().clone())
+                .collect(),
+            inner: Box::new(inner),
+            offset: (3, 3),
+            blur: 0,
+            color: BLACK.mix(0.3),
+        }
+    }
+}
+
+impl<'a, DB: DrawingBackend, Coord> Shadowed<'a, DB, Coord> {
+    /// Set the offset of the shadow in pixels
+    pub fn offset(mut self, offset: BackendCoord) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Set the blur radius of the shadow in pixels, 0 draws a shadow with sharp edges
+    pub fn blur(mut self, radius: u32) -> Self {
+        self.blur = radius;
+        self
+    }
+
+    /// Set the color of the shadow, the alpha channel decides the darkness of the shadow
+    pub fn color<C: Color>(mut self, color: C) -> Self {
+        self.color = color.to_rgba();
+        self
+    }
+
+    /// Get the offsets of the copies the shadow is drawn with
+    fn passes(&self) -> Vec<BackendCoord> {
+        let mut passes = vec![self.offset];
+        if self.blur > 0 {
+            let radius = f64::from(self.blur);
+            for r in [radius / 2.0, radius].iter() {
+                for i in 0..8 {
+                    let angle = std::f64::consts::FRAC_PI_4 * f64::from(i);
+                    passes.push((
+                        self.offset.0 + (r * angle.cos()).round() as i32,
+                        self.offset.1 + (r * angle.sin()).round() as i32,
+                    ));
+                }
+            }
+            passes.sort_unstable();
+            passes.dedup();
+        }
+        passes
+    }
+}
+
+impl<'a, 'b: 'a, DB: DrawingBackend, Coord> PointCollection<'a, Coord>
+    for &'a Shadowed<'b, DB, Coord>
+{
+    type Point = &'a Coord;
+    type IntoIter = &'a Vec<Coord>;
+    fn point_iter(self) -> Self::IntoIter {
+        &self.points
+    }
+}
+
+impl<'a, DB: DrawingBackend, Coord> Drawable<DB> for Shadowed<'a, DB, Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = points.collect();
+        let passes = self.passes();
+        // The alpha of each copy makes the area covered by all of them as dark as the color
+        let alpha = 1.0 - (1.0 - self.color.3).powf(1.0 / passes.len() as f64);
+        let mut color = self.color.to_backend_color();
+        color.alpha = alpha;
+
+        for offset in passes {
+            let mut shadow = ShadowBackend {
+                inner: &mut *backend,
+                offset,
+                color,
+            };
+            self.inner
+                .draw_shadow(&mut points.iter().copied(), &mut shadow, parent_dim)?;
+        }
+        self.inner
+            .draw_element(&mut points.into_iter(), backend, parent_dim)
+    }
+}
+
+struct ShadowStyle {
+    color: BackendColor,
+    stroke_width: u32,
+}
+
+impl BackendStyle for ShadowStyle {
+    fn color(&self) -> BackendColor {
+        self.color
+    }
+    fn stroke_width(&self) -> u32 {
+        self.stroke_width
+    }
+}
+
+/// The drawing backend adapter used by [Shadowed](struct.Shadowed.html), which moves every
+/// drawing call by the offset of the shadow and draws it in the shadow color. The translucent
+/// parts of the element cast lighter shadows, and the invisible parts cast no shadow.
+///
+/// The text and the bitmaps are drawn pixel by pixel, so their shadows have the shape of the
+/// glyphs and the bounds of the images.
+pub struct ShadowBackend<'b, DB: DrawingBackend> {
+    inner: &'b mut DB,
+    offset: BackendCoord,
+    color: BackendColor,
+}
+
+impl<'b, DB: DrawingBackend> ShadowBackend<'b, DB> {
+    fn translate(&self, (x, y): BackendCoord) -> BackendCoord {
+        (x + self.offset.0, y + self.offset.1)
+    }
+
+    fn shadow_color(&self, color: BackendColor) -> BackendColor {
+        BackendColor {
+            rgb: self.color.rgb,
+            alpha: self.color.alpha * color.alpha,
+        }
+    }
+
+    fn shadow_style<S: BackendStyle>(&self, style: &S) -> ShadowStyle {
+        ShadowStyle {
+            color: self.shadow_color(style.color()),
+            stroke_width: style.stroke_width(),
+        }
+    }
+}
+
+impl<'b, DB: DrawingBackend> DrawingBackend for ShadowBackend<'b, DB> {
+    type ErrorType = DB::ErrorType;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.inner.get_size()
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.inner.ensure_prepared()
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.inner.present()
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: BackendColor,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if color.alpha == 0.0 {
+            return Ok(());
+        }
+        let (point, color) = (self.translate(point), self.shadow_color(color));
+        self.inner.draw_pixel(point, color)
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let style = self.shadow_style(style);
+        let (from, to) = (self.translate(from), self.translate(to));
+        self.inner.draw_line(from, to, &style)
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let style = self.shadow_style(style);
+        let (upper_left, bottom_right) = (self.translate(upper_left), self.translate(bottom_right));
+        self.inner.draw_rect(upper_left, bottom_right, &style, fill)
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let style = self.shadow_style(style);
+        let offset = self.offset;
+        self.inner.draw_path(
+            path.into_iter().map(|(x, y)| (x + offset.0, y + offset.1)),
+            &style,
+        )
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let style = self.shadow_style(style);
+        let center = self.translate(center);
+        self.inner.draw_circle(center, radius, &style, fill)
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let style = self.shadow_style(style);
+        let offset = self.offset;
+        self.inner.fill_polygon(
+            vert.into_iter().map(|(x, y)| (x + offset.0, y + offset.1)),
+            &style,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_shadowed_rectangle() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_rect(|c, _, fill, u, d| {
+                assert_eq!(c, BLACK.mix(0.5).to_rgba());
+                assert!(fill);
+                assert_eq!((u, d), ((14, 15), (34, 35)));
+            });
+            m.check_draw_rect(|c, _, _, u, d| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!((u, d), ((10, 10), (30, 30)));
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_rect_call, 2));
+        });
+
+        drawing_area
+            .draw(
+                &Shadowed::new(Rectangle::new([(10, 10), (30, 30)], RED.filled()))
+                    .offset((4, 5))
+                    .color(BLACK.mix(0.5)),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn test_blurred_shadow() {
+        let shadow = Shadowed::<MockedBackend, (i32, i32)>::new(Pixel::new((0, 0), &RED))
+            .offset((0, 0))
+            .blur(4);
+        let passes = shadow.passes();
+        assert_eq!(passes.len(), 17);
+        assert!(passes.contains(&(0, 0)));
+        assert!(passes.contains(&(-4, 0)));
+        assert!(passes.contains(&(0, 2)));
+        assert!(passes.iter().all(|(x, y)| x.abs() <= 4 && y.abs() <= 4));
+    }
+}
@@ -0,0 +1,205 @@
+use crate::element::{Drawable, PointCollection};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{IntoFont, ShapeStyle, TextStyle, BLACK};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// Create the size scaling function that maps a value to a marker radius so that the area of the
+/// marker is proportional to the value.
+/// - `max_value`: The value that maps to the largest marker
+/// - `max_radius`: The radius of the largest marker in pixels
+/// - **returns**: The function maps the value to the radius, negative values map to 0
+pub fn sqrt_area_scale(max_value: f64, max_radius: f64) -> impl Fn(f64) -> f64 {
+    move |value| {
+        if max_value <= 0.0 || value <= 0.0 {
+            return 0.0;
+        }
+        max_radius * (value / max_value).sqrt()
+    }
+}
+
+/// How the circles of a size legend are arranged
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SizeLegendLayout {
+    /// All the circles share the same bottom point, the largest circle encloses the others
+    Nested,
+    /// The circles are placed from top to bottom, one per row
+    Stacked,
+}
+
+/// The legend of the marker size in a bubble chart, which draws the circles of a few
+/// representative values with their labels.
+pub struct SizeLegend<'a> {
+    pos: BackendCoord,
+    values: Vec<f64>,
+    scale: Box<dyn Fn(f64) -> f64 + 'a>,
+    formatter: Box<dyn Fn(f64) -> String + 'a>,
+    layout: SizeLegendLayout,
+    style: ShapeStyle,
+    label_style: TextStyle<'a>,
+    spacing: u32,
+}
+
+impl<'a> SizeLegend<'a> {
+    /// Create a new size legend with the nested layout
+    /// - `pos`: The upper left corner of the legend
+    /// - `values`: The representative values to show
+    /// - `scale`: The function maps the value to the radius of the marker in pixels, this should
+    ///   be the same function used by the series, e.g. [sqrt_area_scale](fn.sqrt_area_scale.html)
+    pub fn new<I: IntoIterator<Item = f64>>(
+        pos: BackendCoord,
+        values: I,
+        scale: impl Fn(f64) -> f64 + 'a,
+    ) -> Self {
+        let mut values: Vec<_> = values.into_iter().filter(|v| v.is_finite()).collect();
+        values.sort_by(|a, b| b.partial_cmp(a).unwrap());
+        Self {
+            pos,
+            values,
+            scale: Box::new(scale),
+            formatter: Box::new(|v| format!("{}", v)),
+            layout: SizeLegendLayout::Nested,
+            style: (&BLACK).into(),
+            label_style: ("sans-serif", 12).into_font().into(),
+            spacing: 5,
+        }
+    }
+
+    /// Set the layout of the circles
+    pub fn layout(mut self, layout: SizeLegendLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Set the style of the circles and the leader lines
+    pub fn style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Set the style of the value labels
+    pub fn label_style<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        self.label_style = style.into();
+        self
+    }
+
+    /// Set the function that formats the value labels
+    pub fn label_formatter<F: Fn(f64) -> String + 'a>(mut self, formatter: F) -> Self {
+        self.formatter = Box::new(formatter);
+        self
+    }
+
+    /// Set the space between the circles and the labels in pixels
+    pub fn spacing(mut self, spacing: u32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    fn radius_of(&self, value: f64) -> i32 {
+        (self.scale)(value).max(0.0).round() as i32
+    }
+}
+
+impl<'b, 'a> PointCollection<'b, BackendCoord> for &'b SizeLegend<'a> {
+    type Point = &'b BackendCoord;
+    type IntoIter = std::iter::Once<&'b BackendCoord>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&self.pos)
+    }
+}
+
+impl<'a, DB: DrawingBackend> Drawable<DB> for SizeLegend<'a> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (x0, y0) = match points.next() {
+            Some(pos) => pos,
+            None => return Ok(()),
+        };
+        let max_radius = self
+            .values
+            .iter()
+            .map(|v| self.radius_of(*v))
+            .max()
+            .unwrap_or(0);
+        let spacing = self.spacing as i32;
+        let cx = x0 + max_radius;
+        let label_x = x0 + max_radius * 2 + spacing;
+        let label_style = self.label_style.pos(Pos::new(HPos::Left, VPos::Center));
+
+        let mut y = y0;
+        for value in self.values.iter() {
+            let radius = self.radius_of(*value);
+            let text = (self.formatter)(*value);
+            match self.layout {
+                SizeLegendLayout::Nested => {
+                    let bottom = y0 + max_radius * 2;
+                    let top = bottom - radius * 2;
+                    self.style.draw_circle(
+                        backend,
+                        (cx, bottom - radius),
+                        radius as u32,
+                        self.style.filled,
+                    )?;
+                    self.style
+                        .draw_line(backend, (cx, top), (label_x - spacing / 2, top))?;
+                    backend.draw_text(&text, &label_style, (label_x, top))?;
+                }
+                SizeLegendLayout::Stacked => {
+                    let center = y + radius;
+                    self.style.draw_circle(
+                        backend,
+                        (cx, center),
+                        radius as u32,
+                        self.style.filled,
+                    )?;
+                    backend.draw_text(&text, &label_style, (label_x, center))?;
+                    y += radius * 2 + spacing;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_sqrt_area_scale() {
+        let scale = sqrt_area_scale(100.0, 20.0);
+        assert_eq!(scale(100.0), 20.0);
+        assert_eq!(scale(25.0), 10.0);
+        assert_eq!(scale(-1.0), 0.0);
+    }
+
+    #[test]
+    fn test_nested_size_legend() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            let mut expected = vec![((20, 20), 20), ((20, 30), 10)].into_iter();
+            m.check_draw_circle(move |_, _, _, center, radius| {
+                assert_eq!(Some((center, radius)), expected.next());
+            });
+            let mut labels = vec![("100", (45, 0)), ("25", (45, 20))].into_iter();
+            m.check_draw_text(move |_, _, _, pos, text| {
+                assert_eq!(Some((text, pos)), labels.next());
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_circle_call, 2);
+                assert_eq!(b.num_draw_line_call, 2);
+            });
+        });
+
+        drawing_area
+            .draw(&SizeLegend::new(
+                (0, 0),
+                vec![25.0, 100.0],
+                sqrt_area_scale(100.0, 20.0),
+            ))
+            .expect("Drawing error");
+    }
+}
@@ -1,16 +1,75 @@
 use std::borrow::Borrow;
 use std::i32;
 
-use super::{Drawable, PointCollection};
-use crate::style::{FontDesc, FontResult, LayoutBox, TextStyle};
+use super::{Drawable, PointCollection, Rectangle};
+use crate::coord::CoordTranslate;
+use crate::drawing::{ClippedBackend, DrawingArea};
+use crate::style::text_anchor::{HPos, VPos};
+use crate::style::{Color, FontDesc, FontResult, LayoutBox, TextStyle};
 use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 
+/// Draw a text on the backend, the background box defined by the style is filled before
+/// the glyphs are drawn.
+pub(crate) fn draw_text_with_background<DB: DrawingBackend>(
+    backend: &mut DB,
+    text: &str,
+    style: &TextStyle,
+    pos: BackendCoord,
+) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+    if let Some(background) = style.background.as_ref() {
+        let ((min_x, min_y), (max_x, max_y)) = style
+            .font
+            .layout_box(text)
+            .map_err(|e| DrawingErrorKind::FontError(Box::new(e)))?;
+        let (width, height) = (max_x - min_x, max_y - min_y);
+        let dx = match style.pos.h_pos {
+            HPos::Left => 0,
+            HPos::Right => -width,
+            HPos::Center => -width / 2,
+        };
+        let dy = match style.pos.v_pos {
+            VPos::Top => 0,
+            VPos::Center => -height / 2,
+            VPos::Bottom => -height,
+        };
+        let padding = background.padding as i32;
+        let trans = style.font.get_transform();
+        let (x0, y0) = trans.transform(dx - padding, dy - padding);
+        let (x1, y1) = trans.transform(dx + width + padding, dy + height + padding);
+        let corners = [
+            (pos.0 + x0.min(x1), pos.1 + y0.min(y1)),
+            (pos.0 + x0.max(x1), pos.1 + y0.max(y1)),
+        ];
+
+        let size = backend.get_size();
+        let mut fill = Rectangle::new(corners, background.fill.filled());
+        fill.set_corner_radius(background.corner_radius);
+        fill.draw(corners.iter().copied(), backend, size)?;
+
+        if let Some(border) = background.border.as_ref() {
+            let mut border = Rectangle::new(corners, border.clone());
+            border.set_corner_radius(background.corner_radius);
+            border.draw(corners.iter().copied(), backend, size)?;
+        }
+    }
+    backend.draw_text(text, style, pos)
+}
+
+/// The rectangle `[upper_left, bottom_right)` of the drawing area in the backend coordinate
+fn clip_rect<DB: DrawingBackend, CT: CoordTranslate>(
+    area: &DrawingArea<DB, CT>,
+) -> (BackendCoord, BackendCoord) {
+    let (x, y) = area.get_pixel_range();
+    ((x.start, y.start), (x.end, y.end))
+}
+
 /// A single line text element. This can be owned or borrowed string, dependents on
 /// `String` or `str` moved into.
 pub struct Text<'a, Coord, T: Borrow<str>> {
     text: T,
     coord: Coord,
     style: TextStyle<'a>,
+    clip: Option<(BackendCoord, BackendCoord)>,
 }
 
 impl<'a, Coord, T: Borrow<str>> Text<'a, Coord, T> {
@@ -24,8 +83,39 @@ impl<'a, Coord, T: Borrow<str>> Text<'a, Coord, T> {
             text,
             coord: points,
             style: style.into(),
+            clip: None,
         }
     }
+
+    /// Clip the text to the drawing area, usually the plotting area of a chart, so the text
+    /// anchored near the edge of the area doesn't spill into the label areas and the margins.
+    /// The text fully inside the area is drawn as usual, and the text crossing the edge of the
+    /// area is drawn pixel by pixel with the pixels out of the area discarded.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut buffer = vec![0; 320 * 240 * 3];
+    /// let root = BitMapBackend::with_buffer(&mut buffer, (320, 240)).into_drawing_area();
+    /// let chart = ChartBuilder::on(&root)
+    ///     .margin(10)
+    ///     .x_label_area_size(30)
+    ///     .y_label_area_size(30)
+    ///     .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+    ///     .unwrap();
+    ///
+    /// let plotting_area = chart.plotting_area();
+    /// plotting_area
+    ///     .draw(&Text::new("Outlier", (9.0, 5.0), ("sans-serif", 20)).clip_to(plotting_area))
+    ///     .unwrap();
+    /// ```
+    pub fn clip_to<DB: DrawingBackend, CT: CoordTranslate>(
+        mut self,
+        area: &DrawingArea<DB, CT>,
+    ) -> Self {
+        self.clip = Some(clip_rect(area));
+        self
+    }
 }
 
 impl<'b, 'a, Coord: 'a, T: Borrow<str> + 'a> PointCollection<'a, Coord> for &'a Text<'b, Coord, T> {
@@ -44,7 +134,16 @@ impl<'a, Coord: 'a, DB: DrawingBackend, T: Borrow<str>> Drawable<DB> for Text<'a
         _: (u32, u32),
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
         if let Some(a) = points.next() {
-            return backend.draw_text(self.text.borrow(), &self.style, a);
+            let text = self.text.borrow();
+            return match self.clip {
+                Some(area) => draw_text_with_background(
+                    &mut ClippedBackend::new(backend, area),
+                    text,
+                    &self.style,
+                    a,
+                ),
+                None => draw_text_with_background(backend, text, &self.style, a),
+            };
         }
         Ok(())
     }
@@ -57,6 +156,7 @@ pub struct MultiLineText<'a, Coord, T: Borrow<str>> {
     coord: Coord,
     style: TextStyle<'a>,
     line_height: f64,
+    clip: Option<(BackendCoord, BackendCoord)>,
 }
 
 impl<'a, Coord, T: Borrow<str>> MultiLineText<'a, Coord, T> {
@@ -71,6 +171,7 @@ impl<'a, Coord, T: Borrow<str>> MultiLineText<'a, Coord, T> {
             coord: pos,
             style: style.into(),
             line_height: 1.25,
+            clip: None,
         }
     }
 
@@ -80,6 +181,15 @@ impl<'a, Coord, T: Borrow<str>> MultiLineText<'a, Coord, T> {
         self
     }
 
+    /// Clip the text to the drawing area, see [Text::clip_to](struct.Text.html#method.clip_to)
+    pub fn clip_to<DB: DrawingBackend, CT: CoordTranslate>(
+        mut self,
+        area: &DrawingArea<DB, CT>,
+    ) -> Self {
+        self.clip = Some(clip_rect(area));
+        self
+    }
+
     /// Push a new line into the given multi-line text
     /// `line`: The line to be pushed
     pub fn push_line<L: Into<T>>(&mut self, line: L) {
@@ -234,7 +344,15 @@ impl<'a, Coord: 'a, DB: DrawingBackend, T: Borrow<str>> Drawable<DB>
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
         if let Some(a) = points.next() {
             for (point, text) in self.layout_lines(a).zip(self.lines.iter()) {
-                backend.draw_text(text.borrow(), &self.style, point)?;
+                match self.clip {
+                    Some(area) => draw_text_with_background(
+                        &mut ClippedBackend::new(&mut *backend, area),
+                        text.borrow(),
+                        &self.style,
+                        point,
+                    )?,
+                    None => draw_text_with_background(backend, text.borrow(), &self.style, point)?,
+                }
             }
         }
         Ok(())
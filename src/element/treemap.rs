@@ -0,0 +1,351 @@
+use crate::element::{Drawable, PointCollection};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{
+    Color, FontDesc, IntoFont, Palette, Palette99, RGBAColor, ShapeStyle, TextStyle, BLACK, WHITE,
+};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// An item of a treemap, which is either a weighted leaf or a group of items
+#[derive(Clone, Debug)]
+pub struct TreemapItem {
+    label: String,
+    weight: f64,
+    children: Vec<TreemapItem>,
+}
+
+impl TreemapItem {
+    /// Create a leaf item
+    /// - `label`: The label of the cell
+    /// - `weight`: The weight of the item, which decides the area of the cell
+    pub fn new<S: Into<String>>(label: S, weight: f64) -> Self {
+        Self {
+            label: label.into(),
+            weight: if weight.is_finite() {
+                weight.max(0.0)
+            } else {
+                0.0
+            },
+            children: vec![],
+        }
+    }
+
+    /// Create a group item, the weight of the group is the sum of the weights of the children
+    /// and the children are laid out inside the cell of the group
+    pub fn group<S: Into<String>, I: IntoIterator<Item = TreemapItem>>(
+        label: S,
+        children: I,
+    ) -> Self {
+        let children: Vec<_> = children.into_iter().collect();
+        Self {
+            label: label.into(),
+            weight: children.iter().map(|c| c.weight).sum(),
+            children,
+        }
+    }
+
+    /// Get the label of the item
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Get the weight of the item
+    pub fn weight(&self) -> f64 {
+        self.weight
+    }
+}
+
+type Cell = (f64, f64, f64, f64);
+
+fn worst_ratio(row: &[f64], side: f64) -> f64 {
+    let sum: f64 = row.iter().sum();
+    let (max, min) = row
+        .iter()
+        .fold((0.0f64, std::f64::INFINITY), |(max, min), v| {
+            (max.max(*v), min.min(*v))
+        });
+    let (side2, sum2) = (side * side, sum * sum);
+    (side2 * max / sum2).max(sum2 / (side2 * min))
+}
+
+/// Lay out the weights into the cell with the squarified treemap algorithm, the result is in
+/// the same order as the weights
+fn squarify(weights: &[f64], cell: Cell) -> Vec<Cell> {
+    let mut result = vec![(cell.0, cell.1, 0.0, 0.0); weights.len()];
+    let total: f64 = weights.iter().sum();
+    if total <= 0.0 || cell.2 <= 0.0 || cell.3 <= 0.0 {
+        return result;
+    }
+
+    let scale = cell.2 * cell.3 / total;
+    let mut order: Vec<_> = (0..weights.len()).filter(|&i| weights[i] > 0.0).collect();
+    order.sort_by(|a, b| weights[*b].partial_cmp(&weights[*a]).unwrap());
+
+    let (mut x, mut y, mut w, mut h) = cell;
+    let mut row: Vec<usize> = vec![];
+    let mut idx = 0;
+    while idx <= order.len() {
+        let side = w.min(h);
+        let areas = |row: &[usize]| row.iter().map(|i| weights[*i] * scale).collect::<Vec<_>>();
+        if idx < order.len() {
+            let mut candidate = row.clone();
+            candidate.push(order[idx]);
+            if row.is_empty()
+                || worst_ratio(&areas(&candidate), side) <= worst_ratio(&areas(&row), side)
+            {
+                row = candidate;
+                idx += 1;
+                continue;
+            }
+        }
+
+        // Place the current row along the shorter side of the remaining cell
+        let row_area: f64 = areas(&row).iter().sum();
+        if w >= h {
+            let row_w = if h > 0.0 { row_area / h } else { 0.0 };
+            let mut offset = y;
+            for i in row.iter() {
+                let cell_h = weights[*i] * scale / row_w;
+                result[*i] = (x, offset, row_w, cell_h);
+                offset += cell_h;
+            }
+            x += row_w;
+            w = (w - row_w).max(0.0);
+        } else {
+            let row_h = if w > 0.0 { row_area / w } else { 0.0 };
+            let mut offset = x;
+            for i in row.iter() {
+                let cell_w = weights[*i] * scale / row_h;
+                result[*i] = (offset, y, cell_w, row_h);
+                offset += cell_w;
+            }
+            y += row_h;
+            h = (h - row_h).max(0.0);
+        }
+        row.clear();
+        if idx == order.len() {
+            break;
+        }
+    }
+    result
+}
+
+/// The treemap element, which lays out the weighted items into a rectangle with the squarified
+/// treemap algorithm, and draws each item as a filled cell with its label. The labels are
+/// scaled down to fit into the cells.
+pub struct Treemap<'a> {
+    pos: BackendCoord,
+    size: (u32, u32),
+    items: Vec<TreemapItem>,
+    color_map: Box<dyn Fn(usize) -> RGBAColor + 'a>,
+    border_style: ShapeStyle,
+    label_font: FontDesc<'a>,
+    label_color: RGBAColor,
+    padding: u32,
+    min_font_size: f64,
+}
+
+impl<'a> Treemap<'a> {
+    /// Create a new treemap element
+    /// - `pos`: The upper left corner of the treemap
+    /// - `size`: The size of the treemap in pixels
+    /// - `items`: The items to lay out
+    pub fn new<I: IntoIterator<Item = TreemapItem>>(
+        pos: BackendCoord,
+        size: (u32, u32),
+        items: I,
+    ) -> Self {
+        Self {
+            pos,
+            size,
+            items: items.into_iter().collect(),
+            color_map: Box::new(|idx| Palette99::pick(idx).to_rgba()),
+            border_style: (&WHITE).into(),
+            label_font: ("sans-serif", 20).into_font(),
+            label_color: BLACK.to_rgba(),
+            padding: 2,
+            min_font_size: 6.0,
+        }
+    }
+
+    /// Set the fill color of the cells
+    /// - `func`: The function takes the index of the top-level item the cell belongs to
+    pub fn color_map<C: Color>(mut self, func: impl Fn(usize) -> C + 'a) -> Self {
+        self.color_map = Box::new(move |idx| func(idx).to_rgba());
+        self
+    }
+
+    /// Set the style of the cell borders
+    pub fn border_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.border_style = style.into();
+        self
+    }
+
+    /// Set the label font, the font size is the largest size the labels can be drawn in
+    pub fn label_font<F: IntoFont<'a>>(mut self, font: F) -> Self {
+        self.label_font = font.into_font();
+        self
+    }
+
+    /// Set the color of the labels
+    pub fn label_color<C: Color>(mut self, color: &C) -> Self {
+        self.label_color = color.to_rgba();
+        self
+    }
+
+    /// Set the padding between the cell border and its content, in pixels
+    pub fn padding(mut self, padding: u32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Set the minimal font size, the labels that need a smaller font are not drawn
+    pub fn min_font_size(mut self, size: f64) -> Self {
+        self.min_font_size = size;
+        self
+    }
+
+    /// Compute the cells of all the leaf items
+    /// - `origin`: The upper left corner of the treemap in pixels
+    /// - **returns**: The list of the leaf items with its top-level index and the cell
+    pub(crate) fn layout(&self, origin: BackendCoord) -> Vec<(&TreemapItem, usize, Cell)> {
+        let mut result = vec![];
+        let root = (
+            f64::from(origin.0),
+            f64::from(origin.1),
+            f64::from(self.size.0),
+            f64::from(self.size.1),
+        );
+        let mut stack: Vec<_> = {
+            let weights: Vec<_> = self.items.iter().map(|i| i.weight).collect();
+            self.items
+                .iter()
+                .zip(squarify(&weights, root))
+                .enumerate()
+                .map(|(idx, (item, cell))| (item, idx, cell))
+                .rev()
+                .collect()
+        };
+        let padding = f64::from(self.padding);
+        while let Some((item, idx, cell)) = stack.pop() {
+            if item.children.is_empty() {
+                result.push((item, idx, cell));
+                continue;
+            }
+            let inner = (
+                cell.0 + padding,
+                cell.1 + padding,
+                (cell.2 - padding * 2.0).max(0.0),
+                (cell.3 - padding * 2.0).max(0.0),
+            );
+            let weights: Vec<_> = item.children.iter().map(|i| i.weight).collect();
+            for (child, cell) in item.children.iter().zip(squarify(&weights, inner)).rev() {
+                stack.push((child, idx, cell));
+            }
+        }
+        result
+    }
+}
+
+impl<'b, 'a> PointCollection<'b, BackendCoord> for &'b Treemap<'a> {
+    type Point = &'b BackendCoord;
+    type IntoIter = std::iter::Once<&'b BackendCoord>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&self.pos)
+    }
+}
+
+impl<'a, DB: DrawingBackend> Drawable<DB> for Treemap<'a> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let origin = match points.next() {
+            Some(pos) => pos,
+            None => return Ok(()),
+        };
+        let padding = self.padding * 2;
+        for (item, idx, (x, y, w, h)) in self.layout(origin) {
+            let (x0, y0) = (x.round() as i32, y.round() as i32);
+            let (x1, y1) = ((x + w).round() as i32 - 1, (y + h).round() as i32 - 1);
+            if x1 < x0 || y1 < y0 {
+                continue;
+            }
+            let style = (self.color_map)(idx).filled();
+            backend.draw_rect((x0, y0), (x1, y1), &style, true)?;
+            self.border_style
+                .draw_rect(backend, (x0, y0), (x1, y1), false)?;
+
+            let (cell_w, cell_h) = ((x1 - x0) as u32, (y1 - y0) as u32);
+            if item.label.is_empty() || cell_w <= padding || cell_h <= padding {
+                continue;
+            }
+            let size = self
+                .label_font
+                .fit_into_box(&item.label, cell_w - padding, cell_h - padding)
+                .map_err(|e| DrawingErrorKind::FontError(Box::new(e)))?
+                .min(self.label_font.get_size());
+            if size < self.min_font_size {
+                continue;
+            }
+            let style = TextStyle::from(self.label_font.resize(size))
+                .color(&self.label_color)
+                .pos(Pos::new(HPos::Center, VPos::Center));
+            backend.draw_text(&item.label, &style, ((x0 + x1) / 2, (y0 + y1) / 2))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_squarify() {
+        let cells = squarify(&[6.0, 6.0, 4.0, 3.0, 2.0, 2.0, 1.0], (0.0, 0.0, 6.0, 4.0));
+        let area: f64 = cells.iter().map(|c| c.2 * c.3).sum();
+        assert!((area - 24.0).abs() < 1e-6);
+        assert_eq!(cells[0], (0.0, 0.0, 3.0, 2.0));
+        assert_eq!(cells[1], (0.0, 2.0, 3.0, 2.0));
+        for (weight, cell) in [6.0, 6.0, 4.0, 3.0, 2.0, 2.0, 1.0].iter().zip(cells.iter()) {
+            assert!((cell.2 * cell.3 - weight).abs() < 1e-6);
+            assert!(cell.0 >= 0.0 && cell.0 + cell.2 <= 6.0 + 1e-6);
+            assert!(cell.1 >= 0.0 && cell.1 + cell.3 <= 4.0 + 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_treemap_element() {
+        let drawing_area = create_mocked_drawing_area(200, 100, |m| {
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 3 * 2);
+                assert_eq!(b.num_draw_text_call, 3);
+            });
+        });
+
+        let treemap = Treemap::new(
+            (0, 0),
+            (200, 100),
+            vec![
+                TreemapItem::new("a", 2.0),
+                TreemapItem::group(
+                    "g",
+                    vec![TreemapItem::new("b", 1.0), TreemapItem::new("c", 1.0)],
+                ),
+            ],
+        )
+        .padding(0);
+
+        let cells = treemap.layout((0, 0));
+        assert_eq!(cells.len(), 3);
+        assert_eq!(cells[0].0.label(), "a");
+        assert_eq!(cells[0].2, (0.0, 0.0, 100.0, 100.0));
+        assert_eq!(cells[1].1, 1);
+        assert_eq!(cells[2].1, 1);
+
+        drawing_area.draw(&treemap).expect("Drawing error");
+    }
+}
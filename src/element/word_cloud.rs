@@ -0,0 +1,266 @@
+use crate::element::{Drawable, PointCollection};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{
+    Color, FontDesc, FontResult, FontTransform, IntoFont, Palette, Palette99, RGBAColor, TextStyle,
+};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// The random number generator used to decide the rotation of the words, we don't need a
+/// good one, but it should produce the same sequence for the same seed on every platform
+struct XorShift(u64);
+
+impl XorShift {
+    fn new(seed: u64) -> Self {
+        Self(seed ^ 0x9E37_79B9_7F4A_7C15 | 1)
+    }
+
+    fn next_f64(&mut self) -> f64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// The word placed by the word cloud layout
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct PlacedWord {
+    pub(crate) index: usize,
+    pub(crate) center: BackendCoord,
+    pub(crate) font_size: f64,
+    pub(crate) rotated: bool,
+    pub(crate) rect: (BackendCoord, BackendCoord),
+}
+
+/// The word cloud element, which draws the words in the font size proportional to their weights.
+/// The words are placed from the heaviest to the lightest, each word is moved along a spiral
+/// starting from the center of the element until it doesn't overlap with the words already
+/// placed. The words that can't be placed are skipped.
+pub struct WordCloud<'a> {
+    pos: BackendCoord,
+    size: (u32, u32),
+    words: Vec<(String, f64)>,
+    font: FontDesc<'a>,
+    min_font_size: f64,
+    color_map: Box<dyn Fn(usize) -> RGBAColor + 'a>,
+    rotate_ratio: f64,
+    seed: u64,
+    spacing: i32,
+}
+
+impl<'a> WordCloud<'a> {
+    /// Create a new word cloud
+    /// - `pos`: The upper left corner of the word cloud
+    /// - `size`: The size of the word cloud in pixels
+    /// - `words`: The words and their weights
+    pub fn new<S: Into<String>, I: IntoIterator<Item = (S, f64)>>(
+        pos: BackendCoord,
+        size: (u32, u32),
+        words: I,
+    ) -> Self {
+        Self {
+            pos,
+            size,
+            words: words
+                .into_iter()
+                .map(|(w, v)| (w.into(), v))
+                .filter(|(_, v)| v.is_finite() && *v > 0.0)
+                .collect(),
+            font: ("sans-serif", 40).into_font(),
+            min_font_size: 8.0,
+            color_map: Box::new(|idx| Palette99::pick(idx).to_rgba()),
+            rotate_ratio: 0.0,
+            seed: 0,
+            spacing: 2,
+        }
+    }
+
+    /// Set the font of the words, the font size is used for the heaviest word
+    pub fn font<F: IntoFont<'a>>(mut self, font: F) -> Self {
+        self.font = font.into_font();
+        self
+    }
+
+    /// Set the smallest font size, the words need a smaller font are not drawn
+    pub fn min_font_size(mut self, size: f64) -> Self {
+        self.min_font_size = size;
+        self
+    }
+
+    /// Set the color of the words
+    /// - `func`: The function takes the index of the word in the input order
+    pub fn color_map<C: Color>(mut self, func: impl Fn(usize) -> C + 'a) -> Self {
+        self.color_map = Box::new(move |idx| func(idx).to_rgba());
+        self
+    }
+
+    /// Set the probability that a word is rotated by 90 degrees, and the seed of the random
+    /// number generator, the same seed always produces the same image
+    pub fn random_rotation(mut self, ratio: f64, seed: u64) -> Self {
+        self.rotate_ratio = ratio;
+        self.seed = seed;
+        self
+    }
+
+    /// Set the minimal space between two words in pixels
+    pub fn spacing(mut self, spacing: u32) -> Self {
+        self.spacing = spacing as i32;
+        self
+    }
+
+    /// Compute the placement of the words
+    /// - `origin`: The upper left corner of the word cloud in pixels
+    pub(crate) fn layout(&self, origin: BackendCoord) -> FontResult<Vec<PlacedWord>> {
+        let mut rng = XorShift::new(self.seed);
+        let max_weight = self.words.iter().map(|(_, v)| *v).fold(0.0, f64::max);
+        let mut order: Vec<_> = (0..self.words.len()).collect();
+        order.sort_by(|a, b| self.words[*b].1.partial_cmp(&self.words[*a].1).unwrap());
+
+        let (w, h) = (self.size.0 as i32, self.size.1 as i32);
+        let center = (origin.0 + w / 2, origin.1 + h / 2);
+        let max_radius = f64::from(w.max(h));
+        let aspect = if w > 0 {
+            f64::from(h) / f64::from(w)
+        } else {
+            1.0
+        };
+        let fits = |(ul, br): (BackendCoord, BackendCoord)| {
+            ul.0 >= origin.0 && ul.1 >= origin.1 && br.0 <= origin.0 + w && br.1 <= origin.1 + h
+        };
+
+        let mut placed: Vec<PlacedWord> = vec![];
+        for idx in order {
+            let (word, weight) = &self.words[idx];
+            let font_size = self.font.get_size() * weight / max_weight;
+            let rotated = rng.next_f64() < self.rotate_ratio;
+            if font_size < self.min_font_size {
+                continue;
+            }
+            let (mut bw, mut bh) = self.font.resize(font_size).box_size(word)?;
+            if rotated {
+                std::mem::swap(&mut bw, &mut bh);
+            }
+            let (bw, bh) = (bw as i32 + self.spacing, bh as i32 + self.spacing);
+
+            // Walk along the Archimedean spiral until the word has no overlap with others
+            let mut theta: f64 = 0.0;
+            while theta * 2.0 < max_radius {
+                let (dx, dy) = (theta * theta.cos(), theta * theta.sin() * aspect);
+                let c = (center.0 + dx.round() as i32, center.1 + dy.round() as i32);
+                let rect = (
+                    (c.0 - bw / 2, c.1 - bh / 2),
+                    (c.0 - bw / 2 + bw, c.1 - bh / 2 + bh),
+                );
+                let overlaps = placed.iter().any(|p| {
+                    rect.0 .0 < p.rect.1 .0
+                        && p.rect.0 .0 < rect.1 .0
+                        && rect.0 .1 < p.rect.1 .1
+                        && p.rect.0 .1 < rect.1 .1
+                });
+                if fits(rect) && !overlaps {
+                    placed.push(PlacedWord {
+                        index: idx,
+                        center: c,
+                        font_size,
+                        rotated,
+                        rect,
+                    });
+                    break;
+                }
+                theta += 0.1;
+            }
+        }
+        Ok(placed)
+    }
+}
+
+impl<'b, 'a> PointCollection<'b, BackendCoord> for &'b WordCloud<'a> {
+    type Point = &'b BackendCoord;
+    type IntoIter = std::iter::Once<&'b BackendCoord>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&self.pos)
+    }
+}
+
+impl<'a, DB: DrawingBackend> Drawable<DB> for WordCloud<'a> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let origin = match points.next() {
+            Some(pos) => pos,
+            None => return Ok(()),
+        };
+        let placed = self
+            .layout(origin)
+            .map_err(|e| DrawingErrorKind::FontError(Box::new(e)))?;
+        for word in placed {
+            let mut font = self.font.resize(word.font_size);
+            if word.rotated {
+                font = font.transform(FontTransform::Rotate270);
+            }
+            let color = (self.color_map)(word.index);
+            let style = TextStyle::from(font)
+                .color(&color)
+                .pos(Pos::new(HPos::Center, VPos::Center));
+            backend.draw_text(&self.words[word.index].0, &style, word.center)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    fn words() -> Vec<(&'static str, f64)> {
+        vec![
+            ("plotters", 10.0),
+            ("rust", 8.0),
+            ("chart", 6.0),
+            ("plot", 5.0),
+            ("data", 4.0),
+            ("axis", 3.0),
+            ("tiny", 0.1),
+        ]
+    }
+
+    #[test]
+    fn test_word_cloud_layout() {
+        let cloud = WordCloud::new((0, 0), (300, 200), words()).random_rotation(0.5, 42);
+        let placed = cloud.layout((0, 0)).unwrap();
+
+        assert_eq!(placed.len(), 6);
+        assert_eq!(placed[0].index, 0);
+        assert_eq!(placed[0].font_size, 40.0);
+        for (i, a) in placed.iter().enumerate() {
+            assert!(a.rect.0 .0 >= 0 && a.rect.0 .1 >= 0);
+            assert!(a.rect.1 .0 <= 300 && a.rect.1 .1 <= 200);
+            for b in placed[i + 1..].iter() {
+                let disjoint = a.rect.1 .0 <= b.rect.0 .0
+                    || b.rect.1 .0 <= a.rect.0 .0
+                    || a.rect.1 .1 <= b.rect.0 .1
+                    || b.rect.1 .1 <= a.rect.0 .1;
+                assert!(disjoint);
+            }
+        }
+
+        let again = WordCloud::new((0, 0), (300, 200), words()).random_rotation(0.5, 42);
+        assert_eq!(again.layout((0, 0)).unwrap(), placed);
+    }
+
+    #[test]
+    fn test_word_cloud_element() {
+        let drawing_area = create_mocked_drawing_area(300, 200, |m| {
+            m.check_draw_text(|c, _, _, _, _| assert_eq!(c, RED.to_rgba()));
+            m.drop_check(|b| assert_eq!(b.num_draw_text_call, 6));
+        });
+
+        drawing_area
+            .draw(&WordCloud::new((0, 0), (300, 200), words()).color_map(|_| RED))
+            .expect("Drawing error");
+    }
+}
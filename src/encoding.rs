@@ -0,0 +1,323 @@
+/*!
+  The image encoding options for saving the rendered bitmaps.
+
+  The file saved by `BitMapBackend::new` always uses the default encoding settings. To trade
+  the file size against the encoding speed, render the chart into an in-memory bitmap and save
+  it with an [ImageEncoding](struct.ImageEncoding.html), e.g. the fast PNG compression for
+  generating a large number of charts, or the best compression for the final output.
+
+  ```rust,no_run
+  use plotters::encoding::{ImageEncoding, PngCompression};
+  use plotters::prelude::*;
+
+  ImageEncoding::png()
+      .compression(PngCompression::Fast)
+      .render_to_file("plotters-doc-data/fast.png", (640, 480), |root| {
+          root.fill(&WHITE)?;
+          root.draw(&Circle::new((320, 240), 100, RED.filled()))?;
+          Ok(())
+      })
+      .unwrap();
+  ```
+
+  When generating many charts, the encoding can be moved to a background thread with
+  [render_to_file_in_background](struct.ImageEncoding.html#method.render_to_file_in_background),
+  so the next chart is rendered while the previous one is being encoded.
+
+  ```rust,no_run
+  use plotters::encoding::ImageEncoding;
+  use plotters::prelude::*;
+
+  let mut handles = vec![];
+  for idx in 0..10 {
+      let path = format!("plotters-doc-data/batch-{}.png", idx);
+      handles.push(
+          ImageEncoding::png()
+              .render_to_file_in_background(path, (640, 480), |root| {
+                  root.fill(&WHITE)?;
+                  root.draw(&Circle::new((320, 240), 10 * idx, RED.filled()))?;
+                  Ok(())
+              })
+              .unwrap(),
+      );
+  }
+  for handle in handles {
+      handle.join().unwrap().unwrap();
+  }
+  ```
+*/
+use crate::coord::Shift;
+use crate::drawing::{DrawingArea, IntoDrawingArea};
+use plotters_bitmap::BitMapBackend;
+
+use image::codecs::jpeg::JpegEncoder;
+use image::codecs::png::{CompressionType, FilterType, PngEncoder};
+use image::{ColorType, ImageResult};
+
+use std::error::Error;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::thread::JoinHandle;
+
+/// The compression level of the PNG encoder
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PngCompression {
+    /// The balanced compression, which is used by `BitMapBackend`
+    Default,
+    /// The fastest encoding with larger files
+    Fast,
+    /// The smallest files with slower encoding
+    Best,
+}
+
+/// The filter applied to the rows before the PNG compression
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PngFilter {
+    /// No filter
+    NoFilter,
+    /// The difference from the pixel on the left
+    Sub,
+    /// The difference from the pixel above
+    Up,
+    /// The difference from the average of the pixels on the left and above
+    Avg,
+    /// The Paeth predictor, which usually compresses the charts the best
+    Paeth,
+}
+
+/// The format and the options used to encode a bitmap
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ImageEncoding {
+    /// The lossless PNG format
+    Png {
+        /// The compression level
+        compression: PngCompression,
+        /// The row filter
+        filter: PngFilter,
+    },
+    /// The lossy JPEG format
+    Jpeg {
+        /// The quality from 1 to 100, the higher quality makes larger files
+        quality: u8,
+    },
+}
+
+impl ImageEncoding {
+    /// The PNG encoding with the default compression and the Paeth filter
+    pub fn png() -> Self {
+        ImageEncoding::Png {
+            compression: PngCompression::Default,
+            filter: PngFilter::Paeth,
+        }
+    }
+
+    /// The JPEG encoding
+    /// - `quality`: The quality from 1 to 100, the values out of the range are clamped
+    pub fn jpeg(quality: u8) -> Self {
+        ImageEncoding::Jpeg {
+            quality: quality.max(1).min(100),
+        }
+    }
+
+    /// Set the compression level of the PNG encoding, it has no effect on the other formats
+    pub fn compression(mut self, level: PngCompression) -> Self {
+        if let ImageEncoding::Png { compression, .. } = &mut self {
+            *compression = level;
+        }
+        self
+    }
+
+    /// Set the row filter of the PNG encoding, it has no effect on the other formats
+    pub fn filter(mut self, row_filter: PngFilter) -> Self {
+        if let ImageEncoding::Png { filter, .. } = &mut self {
+            *filter = row_filter;
+        }
+        self
+    }
+
+    /// Encode a RGB buffer
+    /// - `buffer`: The RGB pixels of the image, e.g. the buffer of `BitMapBackend::with_buffer`
+    /// - `size`: The size of the image
+    /// - `writer`: Where the encoded image is written to
+    pub fn encode<W: Write>(&self, buffer: &[u8], size: (u32, u32), writer: W) -> ImageResult<()> {
+        match *self {
+            ImageEncoding::Png {
+                compression,
+                filter,
+            } => {
+                let compression = match compression {
+                    PngCompression::Default => CompressionType::Default,
+                    PngCompression::Fast => CompressionType::Fast,
+                    PngCompression::Best => CompressionType::Best,
+                };
+                let filter = match filter {
+                    PngFilter::NoFilter => FilterType::NoFilter,
+                    PngFilter::Sub => FilterType::Sub,
+                    PngFilter::Up => FilterType::Up,
+                    PngFilter::Avg => FilterType::Avg,
+                    PngFilter::Paeth => FilterType::Paeth,
+                };
+                PngEncoder::new_with_quality(writer, compression, filter).encode(
+                    buffer,
+                    size.0,
+                    size.1,
+                    ColorType::Rgb8,
+                )
+            }
+            ImageEncoding::Jpeg { quality } => {
+                let mut writer = writer;
+                JpegEncoder::new_with_quality(&mut writer, quality).encode(
+                    buffer,
+                    size.0,
+                    size.1,
+                    ColorType::Rgb8,
+                )
+            }
+        }
+    }
+
+    /// Encode a RGB buffer and save it to a file
+    /// - `buffer`: The RGB pixels of the image
+    /// - `size`: The size of the image
+    /// - `path`: The path of the file
+    pub fn save<P: AsRef<Path>>(
+        &self,
+        buffer: &[u8],
+        size: (u32, u32),
+        path: P,
+    ) -> ImageResult<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.encode(buffer, size, &mut writer)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Encode a RGB buffer and save it to a file on a new thread
+    /// - `buffer`: The RGB pixels of the image
+    /// - `size`: The size of the image
+    /// - `path`: The path of the file
+    /// - **returns**: The handle of the thread, which returns the result of the saving
+    pub fn save_in_background<P: AsRef<Path>>(
+        &self,
+        buffer: Vec<u8>,
+        size: (u32, u32),
+        path: P,
+    ) -> JoinHandle<ImageResult<()>> {
+        let encoding = *self;
+        let path = path.as_ref().to_path_buf();
+        std::thread::spawn(move || encoding.save(&buffer, size, path))
+    }
+
+    /// Render the chart into a bitmap and save it to a file
+    /// - `path`: The path of the file
+    /// - `size`: The size of the bitmap
+    /// - `draw`: The drawing code
+    pub fn render_to_file<
+        P: AsRef<Path>,
+        Draw: FnOnce(DrawingArea<BitMapBackend, Shift>) -> Result<(), Box<dyn Error>>,
+    >(
+        &self,
+        path: P,
+        size: (u32, u32),
+        draw: Draw,
+    ) -> Result<(), Box<dyn Error>> {
+        let mut buffer = vec![0; (size.0 * size.1 * 3) as usize];
+        {
+            let root = BitMapBackend::with_buffer(&mut buffer, size).into_drawing_area();
+            draw(root)?;
+        }
+        self.save(&buffer, size, path)?;
+        Ok(())
+    }
+
+    /// Render the chart into a bitmap on the current thread, and encode and save it on a new
+    /// thread, see [save_in_background](#method.save_in_background)
+    /// - `path`: The path of the file
+    /// - `size`: The size of the bitmap
+    /// - `draw`: The drawing code
+    /// - **returns**: The handle of the thread saving the file, or the drawing error
+    pub fn render_to_file_in_background<
+        P: AsRef<Path>,
+        Draw: FnOnce(DrawingArea<BitMapBackend, Shift>) -> Result<(), Box<dyn Error>>,
+    >(
+        &self,
+        path: P,
+        size: (u32, u32),
+        draw: Draw,
+    ) -> Result<JoinHandle<ImageResult<()>>, Box<dyn Error>> {
+        let mut buffer = vec![0; (size.0 * size.1 * 3) as usize];
+        {
+            let root = BitMapBackend::with_buffer(&mut buffer, size).into_drawing_area();
+            draw(root)?;
+        }
+        Ok(self.save_in_background(buffer, size, path))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn test_image() -> Vec<u8> {
+        (0..64 * 64 * 3)
+            .map(|i: u32| (i.wrapping_mul(2_654_435_761) >> 24) as u8)
+            .collect()
+    }
+
+    #[test]
+    fn test_png_encoding() {
+        let buffer = test_image();
+        let mut fast = vec![];
+        let mut best = vec![];
+        ImageEncoding::png()
+            .compression(PngCompression::Fast)
+            .filter(PngFilter::NoFilter)
+            .encode(&buffer, (64, 64), &mut fast)
+            .unwrap();
+        ImageEncoding::png()
+            .compression(PngCompression::Best)
+            .encode(&buffer, (64, 64), &mut best)
+            .unwrap();
+
+        for encoded in [fast, best].iter() {
+            let decoded = image::load_from_memory(encoded).unwrap().to_rgb8();
+            assert_eq!(decoded.into_raw(), buffer);
+        }
+    }
+
+    #[test]
+    fn test_jpeg_quality() {
+        let buffer = test_image();
+        let mut low = vec![];
+        let mut high = vec![];
+        ImageEncoding::jpeg(10)
+            .encode(&buffer, (64, 64), &mut low)
+            .unwrap();
+        ImageEncoding::jpeg(95)
+            .encode(&buffer, (64, 64), &mut high)
+            .unwrap();
+        assert!(low.len() < high.len());
+        assert_eq!(ImageEncoding::jpeg(0), ImageEncoding::Jpeg { quality: 1 });
+        assert_eq!(
+            ImageEncoding::jpeg(10).compression(PngCompression::Best),
+            ImageEncoding::jpeg(10)
+        );
+    }
+
+    #[test]
+    fn test_save_in_background() {
+        let buffer = test_image();
+        let path =
+            std::env::temp_dir().join(format!("plotters-encoding-test-{}.png", std::process::id()));
+        ImageEncoding::png()
+            .save_in_background(buffer.clone(), (64, 64), &path)
+            .join()
+            .unwrap()
+            .unwrap();
+
+        let decoded = image::open(&path).unwrap().to_rgb8();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(decoded.into_raw(), buffer);
+    }
+}
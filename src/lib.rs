@@ -655,6 +655,7 @@ The following list is a complete list of features that can be opt in and out.
 | Name    |  Description | Additional Dependency |Default?|
 |---------|--------------|--------|------------|
 | ttf | Allows TrueType font support | rusttype, font-kit | Yes |
+| deterministic | Only use the fonts registered with `register_font` and the bundled DejaVu Sans, rasterize them with FreeType and snap the coordinate mapping to 1/1024 pixel, for byte-identical images on every platform | freetype | No |
 
 - Coordinate features
 
@@ -667,12 +668,20 @@ The following list is a complete list of features that can be opt in and out.
 | Name    |  Description | Additional Dependency |Default?|
 |---------|--------------|--------|------------|
 | errorbar | The errorbar element support | None | Yes |
-| candlestick | The candlestick element support | None | Yes |
+| candlestick | The candlestick and OHLC bar element support | None | Yes |
 | boxplot | The boxplot element support | None | Yes |
+| colorbar | The colorbar element support | None | Yes |
 | area\_series | The area series support | None | Yes |
 | line\_series | The line series support | None | Yes |
 | histogram | The histogram series support | None | Yes |
 | point\_series| The point series support | None | Yes |
+| gradient\_line\_series | The line series with a color gradient support | None | Yes |
+| hexbin\_series | The hexagonal binning series support | None | Yes |
+| quiver\_series | The quiver (vector field) series support | None | Yes |
+| ridgeline\_series | The ridgeline series support | None | Yes |
+| rug\_series | The rug series support | None | Yes |
+| waterfall\_series | The waterfall series support | None | Yes |
+| geojson | The GeoJSON parsing and series support | serde\_json | No |
 
 - Misc
 
@@ -680,40 +689,48 @@ The following list is a complete list of features that can be opt in and out.
 |---------|--------------|--------|------------|
 | deprecated\_items | This feature allows use of deprecated items which is going to be removed in the future | None | Yes |
 | debug | Enable the code used for debugging | None | No |
+| testing | The golden image comparison helpers for regression testing charts | image | No |
 
 
 ## FAQ List
 
 * Why does the WASM example break on my machine ?
 
-    The WASM example requires using `wasm32` target to build. Using `cargo build` is likely to use the default target
-    which in most of the case is any of the x86 target. Thus you need add `--target=wasm32-unknown-unknown` in the cargo
-    parameter list  to build it.
+  The WASM example requires using `wasm32` target to build. Using `cargo build` is likely to use the default target
+  which in most of the case is any of the x86 target. Thus you need add `--target=wasm32-unknown-unknown` in the cargo
+  parameter list  to build it.
 
 * How to draw text/circle/point/rectangle/... on the top of chart ?
 
-    As you may realized, Plotters is a drawing library rather than a traditional data plotting library,
-    you have the freedom to draw anything you want on the drawing area.
-    Use `DrawingArea::draw` to draw any element on the drawing area.
+  As you may realized, Plotters is a drawing library rather than a traditional data plotting library,
+  you have the freedom to draw anything you want on the drawing area.
+  Use `DrawingArea::draw` to draw any element on the drawing area.
 
 * Where can I find the backend code ?
 
-    Since Plotters 0.3, all drawing backends are independent crate from the main Plotters crate.
-    Use the following link to find the backend code:
+  Since Plotters 0.3, all drawing backends are independent crate from the main Plotters crate.
+  Use the following link to find the backend code:
 
-    - [Bitmap Backend](https://github.com/plotters-rs/plotters-bitmap.git)
-    - [SVG Backend](https://github.com/plotters-rs/plotters-svg.git)
-    - [HTML5 Canvas Backend](https://github.com/plotters-rs/plotters-canvas.git)
-    - [GTK/Cairo Backend](https://github.com/plotters-rs/plotters-cairo.git)
+  - [Bitmap Backend](https://github.com/plotters-rs/plotters-bitmap.git)
+  - [SVG Backend](https://github.com/plotters-rs/plotters-svg.git)
+  - [HTML5 Canvas Backend](https://github.com/plotters-rs/plotters-canvas.git)
+  - [GTK/Cairo Backend](https://github.com/plotters-rs/plotters-cairo.git)
+
+* How to get the SVG output as a `String` without writing a file ?
+
+  Use `SVGBackend::with_string(&mut buffer, (width, height))`, where `buffer` is a `String`. The SVG document is
+  written into the string when `present` is called or the backend gets dropped, so drop the drawing area (or call
+  `present`) before reading the buffer. This is handy for server side rendering, where the SVG is sent in a
+  response body directly.
 
 * How to check if a backend writes file successfully ?
 
-    The behavior of Plotters backend is consistent with stadnard library.
-    When the backend instance is being dropped, `DrawingArea::present` or `Backend::present` is called automatically
-    whenever is needed. When the `persent` method is called from `drop`, any error will be sliently ignored.
+  The behavior of Plotters backend is consistent with stadnard library.
+  When the backend instance is being dropped, `DrawingArea::present` or `Backend::present` is called automatically
+  whenever is needed. When the `persent` method is called from `drop`, any error will be sliently ignored.
 
-    In the case that error handling is important, you need manually call `present` method before the backend gets dropped.
-    For more information, please see the examples.
+  In the case that error handling is important, you need manually call `present` method before the backend gets dropped.
+  For more information, please see the examples.
 
 
 <style>
@@ -747,6 +764,16 @@ pub mod style;
 #[cfg(feature = "evcxr")]
 pub mod evcxr;
 
+#[cfg(all(feature = "testing", not(target_arch = "wasm32")))]
+pub mod testing;
+
+#[cfg(all(
+    feature = "bitmap_backend",
+    feature = "image",
+    not(target_arch = "wasm32")
+))]
+pub mod encoding;
+
 #[cfg(test)]
 pub use crate::drawing::{check_color, create_mocked_drawing_area};
 
@@ -756,15 +783,20 @@ pub use palette;
 /// The module imports the most commonly used types and modules in Plotters
 pub mod prelude {
     // Chart related types
-    pub use crate::chart::{ChartBuilder, ChartContext, LabelAreaPosition, SeriesLabelPosition};
+    #[cfg(feature = "line_series")]
+    pub use crate::chart::{sparkline, ChartTemplate, Sparkline};
+    pub use crate::chart::{
+        ChartBuilder, ChartContext, LabelAreaPosition, LabelAreaSize, LegendMarker,
+        PriceVolumeChart, ScatterMatrix, SeriesLabelPosition, Theme, TickStyle,
+    };
 
     // Coordinates
     pub use crate::coord::{
         cartesian::Cartesian2d,
         combinators::{
             make_partial_axis, BindKeyPointMethod, BindKeyPoints, BuildNestedCoord, GroupBy,
-            IntoLinspace, IntoLogRange, IntoPartialAxis, Linspace, LogCoord, LogScalable,
-            NestedRange, NestedValue, ToGroupByRange,
+            IntoLinspace, IntoLogRange, IntoPartialAxis, IntoSymLogRange, Linspace, LogCoord,
+            LogScalable, NestedRange, NestedValue, RangedBuilder, SymLogCoord, ToGroupByRange,
         },
         ranged1d::{DiscreteRanged, IntoSegmentedCoord, Ranged, SegmentValue},
         CoordTranslate,
@@ -784,16 +816,32 @@ pub mod prelude {
     pub use crate::drawing::*;
 
     // Series helpers
-    #[cfg(feature = "area_series")]
-    pub use crate::series::AreaSeries;
-    #[cfg(feature = "histogram")]
-    pub use crate::series::Histogram;
+    #[cfg(feature = "geojson")]
+    pub use crate::series::GeoJsonSeries;
+    #[cfg(feature = "gradient_line_series")]
+    pub use crate::series::GradientLineSeries;
+    #[cfg(feature = "hexbin_series")]
+    pub use crate::series::HexbinSeries;
+    #[cfg(any(feature = "histogram", feature = "point_series"))]
+    pub use crate::series::LabelSide;
     #[cfg(feature = "line_series")]
     pub use crate::series::LineSeries;
-    #[cfg(feature = "point_series")]
-    pub use crate::series::PointSeries;
+    #[cfg(feature = "quiver_series")]
+    pub use crate::series::QuiverSeries;
+    #[cfg(feature = "ridgeline_series")]
+    pub use crate::series::RidgelineSeries;
+    #[cfg(feature = "rug_series")]
+    pub use crate::series::RugSeries;
     #[cfg(feature = "surface_series")]
     pub use crate::series::SurfaceSeries;
+    #[cfg(feature = "area_series")]
+    pub use crate::series::{AreaBorder, AreaSeries};
+    #[cfg(feature = "histogram")]
+    pub use crate::series::{BarFillMode, Histogram};
+    #[cfg(feature = "point_series")]
+    pub use crate::series::{OutOfRange, PointSeries};
+    #[cfg(feature = "waterfall_series")]
+    pub use crate::series::{WaterfallSeries, WaterfallStep};
 
     // Styles
     pub use crate::style::{BLACK, BLUE, CYAN, GREEN, MAGENTA, RED, TRANSPARENT, WHITE, YELLOW};
@@ -802,26 +850,29 @@ pub mod prelude {
     pub use crate::style::full_palette;
 
     pub use crate::style::{
-        AsRelative, Color, FontDesc, FontFamily, FontStyle, FontTransform, HSLColor, IntoFont,
-        IntoTextStyle, Palette, Palette100, Palette99, Palette9999, PaletteColor, RGBColor,
-        ShapeStyle, TextStyle,
+        AsRelative, CategoryColors, Color, DataStrokeWidth, FontDesc, FontFamily, FontStyle,
+        FontTransform, HSLColor, IntoFont, IntoTextStyle, Palette, Palette100, Palette99,
+        Palette9999, PaletteColor, RGBColor, ShapeStyle, TextBackground, TextStyle,
     };
 
     // Elements
     pub use crate::element::{
-        Circle, Cross, Cubiod, DynElement, EmptyElement, IntoDynElement, MultiLineText,
-        PathElement, Pixel, Polygon, Rectangle, Text, TriangleMarker,
+        Arrow, Blended, Circle, Cross, Cubiod, CustomShape, DynElement, EmptyElement, FillRule,
+        Funnel, Gauge, IntoDynElement, MultiLineText, PathBuilder, PathElement, Pixel, Polygon,
+        Rectangle, Rotated, Shadowed, SizeLegend, SizeLegendLayout, Text, TriangleMarker,
     };
 
     #[cfg(feature = "boxplot")]
     pub use crate::element::Boxplot;
-    #[cfg(feature = "candlestick")]
-    pub use crate::element::CandleStick;
     #[cfg(feature = "errorbar")]
     pub use crate::element::ErrorBar;
+    #[cfg(feature = "candlestick")]
+    pub use crate::element::{CandleStick, OhlcBar};
+    #[cfg(feature = "colorbar")]
+    pub use crate::element::{Colorbar, ColorbarOrientation};
 
     #[cfg(feature = "bitmap_backend")]
-    pub use crate::element::BitMapElement;
+    pub use crate::element::{BitMapElement, Image, Interpolation};
 
     // Data
     pub use crate::data::Quartiles;
@@ -3,10 +3,24 @@ use crate::style::colors::TRANSPARENT;
 use crate::style::ShapeStyle;
 use plotters_backend::DrawingBackend;
 
+/// The part of the filled area outlined by the border of an [AreaSeries](struct.AreaSeries.html)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AreaBorder {
+    /// Only the top edge of the area, i.e. the line through the data points
+    Top,
+    /// The whole outline of the area, including the sides and the edge on the baseline
+    Outline,
+}
+
 /// An area series is similar to a line series but use a filled polygon
+///
+/// The border is drawn through exactly the same vertices as the edge of the filled polygon, so
+/// the border and the fill always meet at the endpoints of the series.
 pub struct AreaSeries<DB: DrawingBackend, X: Clone, Y: Clone> {
     area_style: ShapeStyle,
     border_style: ShapeStyle,
+    border: AreaBorder,
+    anti_aliasing: u32,
     baseline: Y,
     data: Vec<(X, Y)>,
     state: u32,
@@ -25,6 +39,8 @@ impl<DB: DrawingBackend, X: Clone, Y: Clone> AreaSeries<DB, X, Y> {
             data: iter.into_iter().collect(),
             state: 0,
             border_style: (&TRANSPARENT).into(),
+            border: AreaBorder::Top,
+            anti_aliasing: 0,
             _p: std::marker::PhantomData,
         }
     }
@@ -33,24 +49,54 @@ impl<DB: DrawingBackend, X: Clone, Y: Clone> AreaSeries<DB, X, Y> {
         self.border_style = style.into();
         self
     }
+
+    /// Set which part of the area is outlined by the border, by default only the top edge is
+    /// outlined
+    pub fn border(mut self, border: AreaBorder) -> Self {
+        self.border = border;
+        self
+    }
+
+    /// Smooth the edges of the filled area, see
+    /// [Polygon::set_anti_aliasing](../element/struct.Polygon.html#method.set_anti_aliasing)
+    /// - `quality`: The number of samples along each axis of a pixel, 0 or 1 for no anti-aliasing
+    pub fn anti_aliasing(mut self, quality: u32) -> Self {
+        self.anti_aliasing = quality;
+        self
+    }
+
+    /// Get the outline of the filled area, which is the data points followed by their
+    /// projections on the baseline
+    fn outline(&self) -> Vec<(X, Y)> {
+        let mut outline = self.data.clone();
+        if let (Some(first), Some(last)) = (self.data.first(), self.data.last()) {
+            outline.push((last.0.clone(), self.baseline.clone()));
+            outline.push((first.0.clone(), self.baseline.clone()));
+        }
+        outline
+    }
 }
 
 impl<DB: DrawingBackend, X: Clone + 'static, Y: Clone + 'static> Iterator for AreaSeries<DB, X, Y> {
     type Item = DynElement<'static, DB, (X, Y)>;
     fn next(&mut self) -> Option<Self::Item> {
         if self.state == 0 {
-            let mut data: Vec<_> = self.data.clone();
-
-            if !data.is_empty() {
-                data.push((data[data.len() - 1].0.clone(), self.baseline.clone()));
-                data.push((data[0].0.clone(), self.baseline.clone()));
-            }
-
             self.state = 1;
 
-            Some(Polygon::new(data, self.area_style.clone()).into_dyn())
+            let mut polygon = Polygon::new(self.outline(), self.area_style.clone());
+            polygon.set_anti_aliasing(self.anti_aliasing);
+            Some(polygon.into_dyn())
         } else if self.state == 1 {
-            let data: Vec<_> = self.data.clone();
+            // The border shares the vertices of the fill, so there's no gap between them
+            let mut data = self.outline();
+            match self.border {
+                AreaBorder::Top => data.truncate(self.data.len()),
+                AreaBorder::Outline => {
+                    if let Some(first) = data.first().cloned() {
+                        data.push(first);
+                    }
+                }
+            }
 
             self.state = 2;
 
@@ -60,3 +106,67 @@ impl<DB: DrawingBackend, X: Clone + 'static, Y: Clone + 'static> Iterator for Ar
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_border_traces_fill() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            let fill = std::rc::Rc::new(std::cell::RefCell::new(vec![]));
+            let fill_ref = fill.clone();
+            m.check_fill_polygon(move |_, vert| {
+                assert_eq!(vert.len(), 5);
+                *fill_ref.borrow_mut() = vert;
+            });
+            m.check_draw_path(move |c, _, path| {
+                let fill = fill.borrow();
+                assert_eq!(c, BLUE.to_rgba());
+                assert_eq!(path.len(), 3);
+                assert_eq!(path[..], fill[..3]);
+                // The border ends exactly at the corners where the fill turns to the baseline
+                assert_eq!(path[0].0, fill[4].0);
+                assert_eq!(path[2].0, fill[3].0);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 1);
+                assert_eq!(b.num_draw_path_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+        chart
+            .draw_series(
+                AreaSeries::new(vec![(0, 3), (5, 8), (10, 5)], 0, RED.mix(0.2)).border_style(&BLUE),
+            )
+            .expect("Drawing error");
+    }
+
+    #[test]
+    fn test_outline_border() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_path(|_, _, path| {
+                assert_eq!(
+                    path,
+                    vec![(0, 139), (200, 99), (200, 199), (0, 199), (0, 139)]
+                );
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_path_call, 1));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+        chart
+            .draw_series(
+                AreaSeries::new(vec![(0, 3), (10, 5)], 0, RED.mix(0.2))
+                    .border_style(&BLUE)
+                    .border(AreaBorder::Outline),
+            )
+            .expect("Drawing error");
+    }
+}
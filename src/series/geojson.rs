@@ -0,0 +1,280 @@
+use std::collections::VecDeque;
+use std::fmt;
+
+use serde_json::Value;
+
+use crate::element::{DynElement, IntoDynElement, PathElement, Polygon};
+use crate::style::ShapeStyle;
+use plotters_backend::DrawingBackend;
+
+/// The error when parsing a GeoJSON document
+#[derive(Debug)]
+pub enum GeoJsonError {
+    /// The document isn't valid JSON
+    Json(serde_json::Error),
+    /// The document is valid JSON but not valid GeoJSON
+    Format(String),
+}
+
+impl fmt::Display for GeoJsonError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GeoJsonError::Json(e) => write!(f, "Invalid JSON: {}", e),
+            GeoJsonError::Format(msg) => write!(f, "Invalid GeoJSON: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for GeoJsonError {}
+
+/// A ring or a line string in the `(longitude, latitude)` coordinate
+pub type GeoLine = Vec<(f64, f64)>;
+
+/// The supported GeoJSON geometries
+#[derive(Clone, Debug, PartialEq)]
+pub enum GeoGeometry {
+    /// A polygon, the first ring is the exterior ring and the others are holes
+    Polygon(Vec<GeoLine>),
+    /// Multiple polygons
+    MultiPolygon(Vec<Vec<GeoLine>>),
+    /// A line string
+    LineString(GeoLine),
+    /// Multiple line strings
+    MultiLineString(Vec<GeoLine>),
+}
+
+/// A GeoJSON feature, which is a geometry with its properties
+#[derive(Clone, Debug)]
+pub struct GeoFeature {
+    /// The geometry of the feature
+    pub geometry: GeoGeometry,
+    /// The properties of the feature, this is `Value::Null` if the feature has no properties
+    pub properties: Value,
+}
+
+fn format_error<T>(msg: &str) -> Result<T, GeoJsonError> {
+    Err(GeoJsonError::Format(msg.to_string()))
+}
+
+fn parse_position(value: &Value) -> Result<(f64, f64), GeoJsonError> {
+    match value.as_array().map(|a| a.as_slice()) {
+        Some([lon, lat, ..]) => match (lon.as_f64(), lat.as_f64()) {
+            (Some(lon), Some(lat)) => Ok((lon, lat)),
+            _ => format_error("position is not a number"),
+        },
+        _ => format_error("position should have at least two elements"),
+    }
+}
+
+fn parse_array<T>(
+    value: &Value,
+    parse: impl Fn(&Value) -> Result<T, GeoJsonError>,
+) -> Result<Vec<T>, GeoJsonError> {
+    match value.as_array() {
+        Some(items) => items.iter().map(parse).collect(),
+        None => format_error("coordinates should be an array"),
+    }
+}
+
+fn parse_line(value: &Value) -> Result<GeoLine, GeoJsonError> {
+    parse_array(value, parse_position)
+}
+
+fn parse_polygon(value: &Value) -> Result<Vec<GeoLine>, GeoJsonError> {
+    parse_array(value, parse_line)
+}
+
+fn parse_geometry(
+    value: &Value,
+    properties: &Value,
+    out: &mut Vec<GeoFeature>,
+) -> Result<(), GeoJsonError> {
+    let coords = &value["coordinates"];
+    let geometry = match value["type"].as_str() {
+        Some("Polygon") => GeoGeometry::Polygon(parse_polygon(coords)?),
+        Some("MultiPolygon") => GeoGeometry::MultiPolygon(parse_array(coords, parse_polygon)?),
+        Some("LineString") => GeoGeometry::LineString(parse_line(coords)?),
+        Some("MultiLineString") => GeoGeometry::MultiLineString(parse_polygon(coords)?),
+        Some("GeometryCollection") => {
+            for geometry in value["geometries"].as_array().into_iter().flatten() {
+                parse_geometry(geometry, properties, out)?;
+            }
+            return Ok(());
+        }
+        // Points and other geometries can't be drawn as polygons or paths
+        Some(_) => return Ok(()),
+        None => return format_error("geometry type is missing"),
+    };
+    out.push(GeoFeature {
+        geometry,
+        properties: properties.clone(),
+    });
+    Ok(())
+}
+
+/// Parse a GeoJSON document into features. The document can be a feature collection, a single
+/// feature or a bare geometry. The geometries other than `Polygon`, `MultiPolygon`,
+/// `LineString`, `MultiLineString` and `GeometryCollection` are ignored.
+pub fn parse_geojson(text: &str) -> Result<Vec<GeoFeature>, GeoJsonError> {
+    let doc: Value = serde_json::from_str(text).map_err(GeoJsonError::Json)?;
+    let mut features = vec![];
+    match doc["type"].as_str() {
+        Some("FeatureCollection") => {
+            for feature in doc["features"].as_array().into_iter().flatten() {
+                if !feature["geometry"].is_null() {
+                    parse_geometry(&feature["geometry"], &feature["properties"], &mut features)?;
+                }
+            }
+        }
+        Some("Feature") => {
+            if !doc["geometry"].is_null() {
+                parse_geometry(&doc["geometry"], &doc["properties"], &mut features)?;
+            }
+        }
+        Some(_) => parse_geometry(&doc, &Value::Null, &mut features)?,
+        None => return format_error("type is missing"),
+    }
+    Ok(features)
+}
+
+/// The series that draws the GeoJSON features. Each polygon is drawn as a multi-ring
+/// `Polygon`, so the holes are respected, and each line string is drawn as a `PathElement`.
+/// The positions are mapped to the chart coordinate through the projection function.
+pub struct GeoJsonSeries<DB: DrawingBackend, Coord: Clone> {
+    elements: VecDeque<DynElement<'static, DB, Coord>>,
+}
+
+impl<DB: DrawingBackend, Coord: Clone + 'static> GeoJsonSeries<DB, Coord> {
+    /// Create a new GeoJSON series
+    /// - `features`: The features to draw
+    /// - `projection`: The function maps the `(longitude, latitude)` to the chart coordinate
+    /// - `style`: The function decides the style of a feature, for example, by its properties.
+    ///   Polygons are always filled, and line strings are always stroked.
+    pub fn new<'a, I: IntoIterator<Item = &'a GeoFeature>, P: Fn((f64, f64)) -> Coord>(
+        features: I,
+        projection: P,
+        style: impl Fn(&GeoFeature) -> ShapeStyle,
+    ) -> Self {
+        let project = |line: &GeoLine| line.iter().map(|p| projection(*p)).collect::<Vec<_>>();
+        let mut elements = VecDeque::new();
+        for feature in features {
+            let style = style(feature);
+            let mut add_polygon = |rings: &Vec<GeoLine>| {
+                let rings: Vec<_> = rings.iter().map(&project).collect();
+                elements.push_back(Polygon::with_rings(rings, style.filled()).into_dyn());
+            };
+            match &feature.geometry {
+                GeoGeometry::Polygon(rings) => add_polygon(rings),
+                GeoGeometry::MultiPolygon(polygons) => polygons.iter().for_each(add_polygon),
+                GeoGeometry::LineString(line) => {
+                    elements.push_back(PathElement::new(project(line), style.clone()).into_dyn())
+                }
+                GeoGeometry::MultiLineString(lines) => {
+                    for line in lines {
+                        elements
+                            .push_back(PathElement::new(project(line), style.clone()).into_dyn());
+                    }
+                }
+            }
+        }
+        Self { elements }
+    }
+}
+
+impl<DB: DrawingBackend, Coord: Clone> Iterator for GeoJsonSeries<DB, Coord> {
+    type Item = DynElement<'static, DB, Coord>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.elements.pop_front()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    const DOC: &str = r#"{
+        "type": "FeatureCollection",
+        "features": [
+            {
+                "type": "Feature",
+                "properties": { "name": "ring", "value": 1 },
+                "geometry": {
+                    "type": "Polygon",
+                    "coordinates": [
+                        [[0, 0], [10, 0], [10, 10], [0, 10], [0, 0]],
+                        [[3, 3], [7, 3], [7, 7], [3, 7], [3, 3]]
+                    ]
+                }
+            },
+            {
+                "type": "Feature",
+                "properties": null,
+                "geometry": { "type": "LineString", "coordinates": [[0, 0], [5, 5.5, 100]] }
+            },
+            {
+                "type": "Feature",
+                "properties": {},
+                "geometry": { "type": "Point", "coordinates": [1, 1] }
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn test_parse_geojson() {
+        let features = parse_geojson(DOC).unwrap();
+        assert_eq!(features.len(), 2);
+        assert_eq!(features[0].properties["name"], "ring");
+        match &features[0].geometry {
+            GeoGeometry::Polygon(rings) => {
+                assert_eq!(rings.len(), 2);
+                assert_eq!(rings[1][0], (3.0, 3.0));
+            }
+            _ => panic!("Expect a polygon"),
+        }
+        assert_eq!(
+            features[1].geometry,
+            GeoGeometry::LineString(vec![(0.0, 0.0), (5.0, 5.5)])
+        );
+
+        match parse_geojson("{") {
+            Err(GeoJsonError::Json(_)) => {}
+            _ => panic!("Expect a JSON error"),
+        }
+        match parse_geojson(r#"{"type": "Polygon", "coordinates": [[[0]]]}"#) {
+            Err(GeoJsonError::Format(_)) => {}
+            _ => panic!("Expect a format error"),
+        }
+    }
+
+    #[test]
+    fn test_geojson_series() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_fill_polygon(|c, _| assert_eq!(c, RED.to_rgba()));
+            m.check_draw_path(|c, _, _| assert_eq!(c, BLUE.to_rgba()));
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 1);
+                assert_eq!(b.num_draw_path_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Create chart");
+
+        let features = parse_geojson(DOC).unwrap();
+        chart
+            .draw_series(GeoJsonSeries::new(
+                &features,
+                |(lon, lat)| (lon, lat),
+                |f| {
+                    if f.properties.is_null() {
+                        BLUE.into()
+                    } else {
+                        RED.into()
+                    }
+                },
+            ))
+            .expect("Drawing error");
+    }
+}
@@ -0,0 +1,205 @@
+use crate::element::{Drawable, PointCollection};
+use crate::style::{Color, RGBAColor, ShapeStyle, BLACK};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// The length in pixels of the pieces the segments are split into, each piece is drawn with
+/// the color of the value interpolated at its middle
+const PIECE_LENGTH: f64 = 4.0;
+
+/// The line series whose color changes along the line, e.g. to encode the time or the speed of
+/// a trajectory. Each point comes with a scalar value, and the value is interpolated linearly
+/// along each segment, so the color changes smoothly along the line and across the joints.
+/// The segments next to a point with a non-finite value aren't drawn.
+pub struct GradientLineSeries<'a, Coord> {
+    points: Vec<(Coord, f64)>,
+    stroke_width: u32,
+    value_range: Option<(f64, f64)>,
+    color_map: Box<dyn Fn(f64) -> RGBAColor + 'a>,
+    done: bool,
+}
+
+impl<'a, Coord> GradientLineSeries<'a, Coord> {
+    /// Create a new gradient line series
+    /// - `points`: The iterator of the points and the values attached to them
+    /// - `stroke_width`: The stroke width of the line in pixels
+    pub fn new<I: IntoIterator<Item = (Coord, f64)>>(points: I, stroke_width: u32) -> Self {
+        Self {
+            points: points.into_iter().collect(),
+            stroke_width,
+            value_range: None,
+            color_map: Box::new(|_| BLACK.to_rgba()),
+            done: false,
+        }
+    }
+
+    /// Set the range of the values mapped to `0.0..1.0` before the color map is applied. By
+    /// default, the range of the finite values of the points is used
+    pub fn value_range(mut self, min: f64, max: f64) -> Self {
+        self.value_range = Some((min, max));
+        self
+    }
+
+    /// Set the color of the line
+    /// - `func`: The function that maps the normalized value to the color
+    pub fn color_map<C: Color>(mut self, func: impl Fn(f64) -> C + 'a) -> Self {
+        self.color_map = Box::new(move |v| func(v).to_rgba());
+        self
+    }
+}
+
+impl<'a, Coord> Iterator for GradientLineSeries<'a, Coord> {
+    type Item = GradientLine<'a, Coord>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        self.done = true;
+
+        let (min, max) = self.value_range.unwrap_or_else(|| {
+            self.points
+                .iter()
+                .map(|(_, v)| *v)
+                .filter(|v| v.is_finite())
+                .fold(
+                    (std::f64::INFINITY, std::f64::NEG_INFINITY),
+                    |(lo, hi), v| (lo.min(v), hi.max(v)),
+                )
+        });
+        let normalize = |v: f64| {
+            if !v.is_finite() {
+                v
+            } else if max > min {
+                ((v - min) / (max - min)).max(0.0).min(1.0)
+            } else {
+                0.0
+            }
+        };
+
+        let (points, values) = std::mem::replace(&mut self.points, vec![])
+            .into_iter()
+            .map(|(coord, v)| (coord, normalize(v)))
+            .unzip();
+        Some(GradientLine {
+            points,
+            values,
+            stroke_width: self.stroke_width,
+            color_map: std::mem::replace(&mut self.color_map, Box::new(|_| BLACK.to_rgba())),
+        })
+    }
+}
+
+/// The line drawn by [GradientLineSeries](struct.GradientLineSeries.html). The segments are
+/// split into short pieces in the pixel space, and each piece is colored by the value
+/// interpolated at its middle.
+pub struct GradientLine<'a, Coord> {
+    points: Vec<Coord>,
+    values: Vec<f64>,
+    stroke_width: u32,
+    color_map: Box<dyn Fn(f64) -> RGBAColor + 'a>,
+}
+
+impl<'a, 'b, Coord> PointCollection<'b, Coord> for &'b GradientLine<'a, Coord> {
+    type Point = &'b Coord;
+    type IntoIter = &'b [Coord];
+    fn point_iter(self) -> &'b [Coord] {
+        &self.points
+    }
+}
+
+impl<'a, Coord, DB: DrawingBackend> Drawable<DB> for GradientLine<'a, Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = points.zip(self.values.iter().copied()).collect();
+        for segment in points.windows(2) {
+            let ((from, from_value), (to, to_value)) = (segment[0], segment[1]);
+            if !from_value.is_finite() || !to_value.is_finite() {
+                continue;
+            }
+            let (dx, dy) = (f64::from(to.0 - from.0), f64::from(to.1 - from.1));
+            let pieces = ((dx * dx + dy * dy).sqrt() / PIECE_LENGTH).ceil().max(1.0) as usize;
+            let at = |t: f64| {
+                (
+                    from.0 + (dx * t).round() as i32,
+                    from.1 + (dy * t).round() as i32,
+                )
+            };
+            for idx in 0..pieces {
+                let (t0, t1) = (idx as f64 / pieces as f64, (idx + 1) as f64 / pieces as f64);
+                let value = from_value + (to_value - from_value) * (t0 + t1) / 2.0;
+                let style =
+                    ShapeStyle::from((self.color_map)(value)).stroke_width(self.stroke_width);
+                backend.draw_path(vec![at(t0), at(t1)], &style)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_gradient_line_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            // The first segment is split into 20 pieces of the same color
+            for _ in 0..20 {
+                m.check_draw_path(|c, s, path| {
+                    assert_eq!(c, BLUE.to_rgba());
+                    assert_eq!(s, 3);
+                    assert_eq!(path.len(), 2);
+                });
+            }
+            // The color changes in the middle of the second segment
+            for _ in 0..10 {
+                m.check_draw_path(|c, _, _| assert_eq!(c, BLUE.to_rgba()));
+            }
+            m.check_draw_path(|c, _, _| assert_eq!(c, RED.to_rgba()));
+            m.drop_check(|b| assert_eq!(b.num_draw_path_call, 40));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Create chart");
+
+        chart
+            .draw_series(
+                GradientLineSeries::new(
+                    vec![((0.0, 0.0), 0.0), ((4.0, 0.0), 1.0), ((8.0, 0.0), 3.0)],
+                    3,
+                )
+                .value_range(0.0, 4.0)
+                .color_map(|v| if v < 0.5 { BLUE } else { RED }),
+            )
+            .expect("Drawing error");
+    }
+
+    #[test]
+    fn test_gradient_line_skips_non_finite_values() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_path(|_, _, path| assert!(path.iter().all(|p| p.0 >= 120)));
+            m.drop_check(|b| assert_eq!(b.num_draw_path_call, 10));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Create chart");
+
+        chart
+            .draw_series(GradientLineSeries::new(
+                vec![
+                    ((0.0, 0.0), 0.0),
+                    ((4.0, 0.0), std::f64::NAN),
+                    ((6.0, 0.0), 1.0),
+                    ((8.0, 0.0), 2.0),
+                ],
+                1,
+            ))
+            .expect("Drawing error");
+    }
+}
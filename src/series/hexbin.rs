@@ -0,0 +1,128 @@
+use std::collections::{btree_map::IntoIter as BTreeMapIter, BTreeMap};
+
+use crate::element::Polygon;
+use crate::style::{Color, HSLColor, RGBAColor, ShapeStyle};
+
+const SQRT_3: f64 = 1.732_050_807_568_877_2;
+
+/// The series that aggregates points into hexagonal bins, and fills each hexagon with the color
+/// mapped from the number of points it contains.
+pub struct HexbinSeries<'a> {
+    bins: BTreeMapIter<(i64, i64), usize>,
+    max_count: usize,
+    size: (f64, f64),
+    color_map: Box<dyn Fn(f64) -> RGBAColor + 'a>,
+}
+
+impl<'a> HexbinSeries<'a> {
+    /// Create a new hexbin series
+    /// - `iter`: The iterator of points
+    /// - `size`: The radius of the hexagon along the X and Y axis in guest coordinate
+    pub fn new<I: IntoIterator<Item = (f64, f64)>>(iter: I, size: (f64, f64)) -> Self {
+        let mut bins = BTreeMap::new();
+        for (x, y) in iter {
+            if !x.is_finite() || !y.is_finite() {
+                continue;
+            }
+            *bins.entry(Self::bin_of((x, y), size)).or_insert(0) += 1;
+        }
+        let max_count = bins.values().copied().max().unwrap_or(0);
+        Self {
+            bins: bins.into_iter(),
+            max_count,
+            size,
+            color_map: Box::new(|v| HSLColor((1.0 - v) * 2.0 / 3.0, 1.0, 0.5).to_rgba()),
+        }
+    }
+
+    /// Set the color map of the series
+    /// - `func`: The function that maps the normalized count, which is the number of points in
+    ///   the bin divided by the number of points in the fullest bin, to the fill color
+    pub fn color_map<C: Color>(mut self, func: impl Fn(f64) -> C + 'a) -> Self {
+        self.color_map = Box::new(move |v| func(v).to_rgba());
+        self
+    }
+
+    /// Get the number of points in the fullest bin
+    pub fn max_count(&self) -> usize {
+        self.max_count
+    }
+
+    fn bin_of((x, y): (f64, f64), (rx, ry): (f64, f64)) -> (i64, i64) {
+        let (u, v) = (x / rx, y / ry);
+        let q = u * SQRT_3 / 3.0 - v / 3.0;
+        let r = v * 2.0 / 3.0;
+        let s = -q - r;
+
+        let (mut rq, mut rr, rs) = (q.round(), r.round(), s.round());
+        let (dq, dr, ds) = ((rq - q).abs(), (rr - r).abs(), (rs - s).abs());
+        if dq > dr && dq > ds {
+            rq = -rr - rs;
+        } else if dr > ds {
+            rr = -rq - rs;
+        }
+        (rq as i64, rr as i64)
+    }
+
+    fn hexagon(&self, (q, r): (i64, i64)) -> Vec<(f64, f64)> {
+        let (rx, ry) = self.size;
+        let cx = rx * SQRT_3 * (q as f64 + r as f64 / 2.0);
+        let cy = ry * 1.5 * r as f64;
+        (0..6)
+            .map(|idx| {
+                let theta = std::f64::consts::PI / 3.0 * idx as f64 + std::f64::consts::PI / 6.0;
+                (cx + rx * theta.cos(), cy + ry * theta.sin())
+            })
+            .collect()
+    }
+}
+
+impl<'a> Iterator for HexbinSeries<'a> {
+    type Item = Polygon<(f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (bin, count) = self.bins.next()?;
+        let value = count as f64 / self.max_count.max(1) as f64;
+        let style: ShapeStyle = (self.color_map)(value).filled();
+        Some(Polygon::new(self.hexagon(bin), style))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_hexbin_binning() {
+        let series = HexbinSeries::new(vec![(0.0, 0.0), (0.1, 0.1), (5.0, 5.0)], (1.0, 1.0));
+        assert_eq!(series.max_count(), 2);
+        assert_eq!(series.count(), 2);
+    }
+
+    #[test]
+    fn test_hexbin_color_map() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_fill_polygon(|c, path| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(path.len(), 6);
+            });
+            m.drop_check(|b| assert_eq!(b.num_fill_polygon_call, 1));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(-10.0..10.0, -10.0..10.0)
+            .expect("Create chart");
+
+        chart
+            .draw_series(
+                HexbinSeries::new(vec![(0.0, 0.0), (0.2, -0.1)], (2.0, 2.0)).color_map(|v| {
+                    if v == 1.0 {
+                        RED
+                    } else {
+                        BLUE
+                    }
+                }),
+            )
+            .expect("Drawing error");
+    }
+}
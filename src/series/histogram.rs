@@ -2,11 +2,12 @@ use std::collections::{hash_map::IntoIter as HashMapIter, HashMap};
 use std::marker::PhantomData;
 use std::ops::AddAssign;
 
+use super::value_labels::{LabelSide, ValueLabels};
 use crate::chart::ChartContext;
 use crate::coord::cartesian::Cartesian2d;
 use crate::coord::ranged1d::{DiscreteRanged, Ranged};
 use crate::element::Rectangle;
-use crate::style::{Color, ShapeStyle, GREEN};
+use crate::style::{Color, RGBAColor, ShapeStyle, GREEN};
 use plotters_backend::DrawingBackend;
 
 pub trait HistogramType {}
@@ -16,6 +17,22 @@ pub struct Horizontal;
 impl HistogramType for Vertical {}
 impl HistogramType for Horizontal {}
 
+/// The number of color stops sampled for a gradient filled bar
+const GRADIENT_STOPS: usize = 16;
+
+type BarFillFunc<'a, A> = dyn Fn(&A, &A) -> Vec<RGBAColor> + 'a;
+type LabeledBar<Coord> = (Rectangle<Coord>, String, LabelSide);
+
+/// Describes how the bars are filled when the fill color is computed from the bar value
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BarFillMode {
+    /// Each bar is filled with the single color mapped from its value
+    Solid,
+    /// Each bar is filled with a gradient, which maps every value between the baseline
+    /// and the bar value to a color
+    Gradient,
+}
+
 /// The series that aggregate data into a histogram
 pub struct Histogram<'a, BR, A, Tag = Vertical>
 where
@@ -26,7 +43,9 @@ where
     style: Box<dyn Fn(&BR::ValueType, &A) -> ShapeStyle + 'a>,
     margin: u32,
     iter: HashMapIter<usize, A>,
-    baseline: Box<dyn Fn(&BR::ValueType) -> A + 'a>,
+    baseline: Option<Box<dyn Fn(&BR::ValueType) -> A + 'a>>,
+    fill: Option<Box<BarFillFunc<'a, A>>>,
+    floor: Option<A>,
     br: BR,
     _p: PhantomData<Tag>,
 }
@@ -42,7 +61,9 @@ where
             style: Box::new(|_, _| GREEN.filled()),
             margin: 5,
             iter: HashMap::new().into_iter(),
-            baseline: Box::new(|_| A::default()),
+            baseline: None,
+            fill: None,
+            floor: None,
             br: br.clone(),
             _p: PhantomData,
         }
@@ -63,18 +84,48 @@ where
         self
     }
 
-    /// Set the baseline of the histogram
+    /// Fill the bars with the colors mapped from the bar values, this overrides the
+    /// color defined by the style of the histogram
+    /// - `mode`: Fill each bar with a solid color or with a gradient
+    /// - `value`: The function that projects a bar value to the number passed to `func`
+    /// - `func`: The function that maps the projected value to the fill color
+    pub fn fill_func<C: Color>(
+        mut self,
+        mode: BarFillMode,
+        value: impl Fn(&A) -> f64 + 'a,
+        func: impl Fn(f64) -> C + 'a,
+    ) -> Self {
+        self.fill = Some(match mode {
+            BarFillMode::Solid => Box::new(move |_, y: &A| vec![func(value(y)).to_rgba()]),
+            BarFillMode::Gradient => Box::new(move |base: &A, y: &A| {
+                let (base, y) = (value(base), value(y));
+                (0..GRADIENT_STOPS)
+                    .map(|idx| {
+                        let t = idx as f64 / (GRADIENT_STOPS - 1) as f64;
+                        func(base + (y - base) * t).to_rgba()
+                    })
+                    .collect()
+            }),
+        });
+        self
+    }
+
+    /// Set the baseline of the histogram, which is where the bars start. The bars of the values
+    /// above the baseline extend up (or right for a horizontal histogram) and the ones below it
+    /// extend down, e.g. the deviation from a target value. The range of the axis isn't
+    /// changed by the baseline
     pub fn baseline(mut self, baseline: A) -> Self
     where
         A: Clone,
     {
-        self.baseline = Box::new(move |_| baseline.clone());
+        self.baseline = Some(Box::new(move |_| baseline.clone()));
         self
     }
 
-    /// Set a function that defines variant baseline
+    /// Set a function that defines variant baseline, e.g. the running total of a waterfall
+    /// chart
     pub fn baseline_func(mut self, func: impl Fn(&BR::ValueType) -> A + 'a) -> Self {
-        self.baseline = Box::new(func);
+        self.baseline = Some(Box::new(func));
         self
     }
 
@@ -100,11 +151,31 @@ where
     }
 }
 
+impl<'a, BR, A, Tag> Histogram<'a, BR, A, Tag>
+where
+    BR: DiscreteRanged,
+    A: AddAssign<A> + Default + Clone,
+    Tag: HistogramType,
+{
+    /// Get the baseline of the bar, which is the baseline set by the user, or the floor of the
+    /// value axis if it can't map zero, otherwise zero
+    fn base(&self, pos: &BR::ValueType) -> A {
+        match (self.baseline.as_ref(), self.floor.as_ref()) {
+            (Some(baseline), _) => baseline(pos),
+            (None, Some(floor)) => floor.clone(),
+            (None, None) => A::default(),
+        }
+    }
+}
+
 impl<'a, BR, A> Histogram<'a, BR, A, Vertical>
 where
     BR: DiscreteRanged + Clone,
     A: AddAssign<A> + Default + 'a,
 {
+    /// Create a vertical histogram on the chart. When the Y axis can't map zero, e.g. a log
+    /// scale axis, the bars start from the bottom of the axis by default and the bars not
+    /// above it are skipped.
     pub fn vertical<ACoord, DB: DrawingBackend + 'a>(
         parent: &ChartContext<DB, Cartesian2d<BR, ACoord>>,
     ) -> Self
@@ -113,7 +184,10 @@ where
     {
         let dp = parent.as_coord_spec().x_spec();
 
-        Self::empty(dp)
+        Self {
+            floor: parent.as_coord_spec().y_spec().value_floor(),
+            ..Self::empty(dp)
+        }
     }
 }
 
@@ -122,6 +196,9 @@ where
     BR: DiscreteRanged + Clone,
     A: AddAssign<A> + Default + 'a,
 {
+    /// Create a horizontal histogram on the chart. When the X axis can't map zero, e.g. a log
+    /// scale axis, the bars start from the left of the axis by default and the bars not right
+    /// of it are skipped.
     pub fn horizontal<ACoord, DB: DrawingBackend>(
         parent: &ChartContext<DB, Cartesian2d<ACoord, BR>>,
     ) -> Self
@@ -129,54 +206,346 @@ where
         ACoord: Ranged<ValueType = A>,
     {
         let dp = parent.as_coord_spec().y_spec();
-        Self::empty(dp)
+        Self {
+            floor: parent.as_coord_spec().x_spec().value_floor(),
+            ..Self::empty(dp)
+        }
     }
 }
 
-impl<'a, BR, A> Iterator for Histogram<'a, BR, A, Vertical>
+impl<'a, BR, A> Histogram<'a, BR, A, Vertical>
 where
     BR: DiscreteRanged,
-    A: AddAssign<A> + Default,
+    A: AddAssign<A> + Default + PartialOrd + Clone,
 {
-    type Item = Rectangle<(BR::ValueType, A)>;
-    fn next(&mut self) -> Option<Self::Item> {
+    /// Draw the formatted value of each bar on the end of the bar, see
+    /// [ValueLabels](struct.ValueLabels.html)
+    /// - `formatter`: The function that formats the value of a bar
+    pub fn with_value_labels<DB, F>(
+        mut self,
+        formatter: F,
+    ) -> ValueLabels<'a, DB, (BR::ValueType, A)>
+    where
+        DB: DrawingBackend,
+        F: Fn(&A) -> String + 'a,
+        BR: 'a,
+        BR::ValueType: Clone,
+        A: 'a,
+    {
+        ValueLabels::new(std::iter::from_fn(move || self.next_bar(Some(&formatter))))
+    }
+
+    fn next_bar(
+        &mut self,
+        formatter: Option<&dyn Fn(&A) -> String>,
+    ) -> Option<LabeledBar<(BR::ValueType, A)>> {
         while let Some((x, y)) = self.iter.next() {
+            if self.floor.as_ref().map_or(false, |floor| &y <= floor) {
+                continue;
+            }
             if let Some((x, Some(nx))) = self
                 .br
                 .from_index(x)
                 .map(|v| (v, self.br.from_index(x + 1)))
             {
-                let base = (self.baseline)(&x);
-                let style = (self.style)(&x, &y);
+                let base = self.base(&x);
+                let mut style = (self.style)(&x, &y);
+                let label = formatter.map_or_else(String::new, |f| f(&y));
+                let side = if y >= base {
+                    LabelSide::Above
+                } else {
+                    LabelSide::Below
+                };
+                let stops = self.fill.as_ref().map(|fill| fill(&base, &y));
+                if let Some(color) = stops.as_ref().and_then(|s| s.first()) {
+                    style.color = *color;
+                    style = style.filled();
+                }
                 let mut rect = Rectangle::new([(x, y), (nx, base)], style);
                 rect.set_margin(0, 0, self.margin, self.margin);
-                return Some(rect);
+                if let Some(stops) = stops.filter(|s| s.len() > 1) {
+                    rect.set_gradient(stops, true);
+                }
+                return Some((rect, label, side));
             }
         }
         None
     }
 }
 
-impl<'a, BR, A> Iterator for Histogram<'a, BR, A, Horizontal>
+impl<'a, BR, A> Iterator for Histogram<'a, BR, A, Vertical>
 where
     BR: DiscreteRanged,
-    A: AddAssign<A> + Default,
+    A: AddAssign<A> + Default + PartialOrd + Clone,
 {
-    type Item = Rectangle<(A, BR::ValueType)>;
+    type Item = Rectangle<(BR::ValueType, A)>;
     fn next(&mut self) -> Option<Self::Item> {
+        self.next_bar(None).map(|(rect, _, _)| rect)
+    }
+}
+
+impl<'a, BR, A> Histogram<'a, BR, A, Horizontal>
+where
+    BR: DiscreteRanged,
+    A: AddAssign<A> + Default + PartialOrd + Clone,
+{
+    /// Draw the formatted value of each bar on the end of the bar, see
+    /// [ValueLabels](struct.ValueLabels.html)
+    /// - `formatter`: The function that formats the value of a bar
+    pub fn with_value_labels<DB, F>(
+        mut self,
+        formatter: F,
+    ) -> ValueLabels<'a, DB, (A, BR::ValueType)>
+    where
+        DB: DrawingBackend,
+        F: Fn(&A) -> String + 'a,
+        BR: 'a,
+        BR::ValueType: Clone,
+        A: 'a,
+    {
+        ValueLabels::new(std::iter::from_fn(move || self.next_bar(Some(&formatter))))
+    }
+
+    fn next_bar(
+        &mut self,
+        formatter: Option<&dyn Fn(&A) -> String>,
+    ) -> Option<LabeledBar<(A, BR::ValueType)>> {
         while let Some((y, x)) = self.iter.next() {
+            if self.floor.as_ref().map_or(false, |floor| &x <= floor) {
+                continue;
+            }
             if let Some((y, Some(ny))) = self
                 .br
                 .from_index(y)
                 .map(|v| (v, self.br.from_index(y + 1)))
             {
-                let base = (self.baseline)(&y);
-                let style = (self.style)(&y, &x);
+                let base = self.base(&y);
+                let mut style = (self.style)(&y, &x);
+                let label = formatter.map_or_else(String::new, |f| f(&x));
+                let side = if x >= base {
+                    LabelSide::Right
+                } else {
+                    LabelSide::Left
+                };
+                let stops = self.fill.as_ref().map(|fill| fill(&base, &x));
+                if let Some(color) = stops.as_ref().and_then(|s| s.first()) {
+                    style.color = *color;
+                    style = style.filled();
+                }
                 let mut rect = Rectangle::new([(x, y), (base, ny)], style);
                 rect.set_margin(0, 0, self.margin, self.margin);
-                return Some(rect);
+                if let Some(stops) = stops.filter(|s| s.len() > 1) {
+                    rect.set_gradient(stops, false);
+                }
+                return Some((rect, label, side));
             }
         }
         None
     }
 }
+
+impl<'a, BR, A> Iterator for Histogram<'a, BR, A, Horizontal>
+where
+    BR: DiscreteRanged,
+    A: AddAssign<A> + Default + PartialOrd + Clone,
+{
+    type Item = Rectangle<(A, BR::ValueType)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_bar(None).map(|(rect, _, _)| rect)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_histogram_fill_func() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_rect(|c, _, f, _, _| {
+                assert!(f);
+                assert_eq!(c, RED.to_rgba());
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d((0..10).into_segmented(), 0..10)
+            .unwrap();
+
+        let to_color = |v: f64| if v > 5.0 { RED } else { BLUE };
+        chart
+            .draw_series(
+                Histogram::vertical(&chart)
+                    .style(BLUE.filled())
+                    .fill_func(BarFillMode::Solid, |&v| f64::from(v), to_color)
+                    .data(vec![(3, 8)]),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn test_histogram_gradient_fill() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.drop_check(|b| {
+                assert!(b.num_draw_rect_call > 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d((0..10).into_segmented(), 0..10)
+            .unwrap();
+
+        chart
+            .draw_series(
+                Histogram::vertical(&chart)
+                    .fill_func(
+                        BarFillMode::Gradient,
+                        |&v| f64::from(v),
+                        |v| RED.mix(v / 10.0),
+                    )
+                    .data(vec![(3, 8)]),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn test_histogram_value_labels() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_rect(|_, _, _, _, _| {});
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "8");
+                assert!(pos.1 < 200 - 160);
+            });
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "-4");
+                assert!(pos.1 > 100);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 2);
+                assert_eq!(b.num_draw_text_call, 2);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d((0..2).into_segmented(), -10..10)
+            .unwrap();
+
+        chart
+            .draw_series(
+                Histogram::vertical(&chart)
+                    .data(vec![(0, 8)])
+                    .with_value_labels(|v| v.to_string()),
+            )
+            .unwrap();
+        chart
+            .draw_series(
+                Histogram::vertical(&chart)
+                    .data(vec![(1, -4)])
+                    .with_value_labels(|v| v.to_string()),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn test_histogram_baseline() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_rect(|_, _, _, u, d| {
+                assert_eq!((u.1, d.1), (39, 99));
+            });
+            m.check_draw_rect(|_, _, _, u, d| {
+                assert_eq!((u.1, d.1), (99, 159));
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_rect_call, 2));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d((0..2).into_segmented(), 0..20)
+            .unwrap();
+
+        chart
+            .draw_series(Histogram::vertical(&chart).baseline(10).data(vec![(0, 16)]))
+            .unwrap();
+        chart
+            .draw_series(Histogram::vertical(&chart).baseline(10).data(vec![(1, 4)]))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_histogram_log_scale_empty_bins() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_rect(|_, _, _, u, d| {
+                assert!(u.1 < d.1);
+                assert!(u.1 >= 0 && d.1 <= 200);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 2);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d((0..5).into_segmented(), (1.0..1000.0).log_scale())
+            .unwrap();
+
+        chart
+            .draw_series(
+                Histogram::vertical(&chart)
+                    .style(BLUE.filled())
+                    .margin(0)
+                    .data(vec![(0, 0.0), (1, 10.0), (2, 0.0), (3, 100.0), (4, 0.0)]),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn test_histogram_linear_scale_without_zero() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_rect(|_, _, _, _, d| {
+                assert!(d.1 >= 200);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 2);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d((0..2).into_segmented(), 5.0..100.0)
+            .unwrap();
+
+        chart
+            .draw_series(
+                Histogram::vertical(&chart)
+                    .style(BLUE.filled())
+                    .data(vec![(0, 0.0), (1, 50.0)]),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn test_histogram_even_bar_widths() {
+        let widths = std::rc::Rc::new(std::cell::RefCell::new(vec![]));
+        let widths_ref = widths.clone();
+        let drawing_area = create_mocked_drawing_area(500, 200, move |m| {
+            m.check_draw_rect(move |_, _, _, u, d| widths_ref.borrow_mut().push(d.0 - u.0));
+            m.drop_check(|b| assert_eq!(b.num_draw_rect_call, 37));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d((0..36).into_segmented(), 0..10)
+            .unwrap();
+
+        chart
+            .draw_series(Histogram::vertical(&chart).data((0..37).map(|x| (x, 5))))
+            .unwrap();
+        drop(chart);
+        drop(drawing_area);
+
+        let widths = widths.borrow();
+        assert_eq!(widths.len(), 37);
+        let (min, max) = (widths.iter().min().unwrap(), widths.iter().max().unwrap());
+        assert!(max - min <= 1);
+    }
+}
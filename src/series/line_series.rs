@@ -1,36 +1,99 @@
-use crate::element::{Circle, DynElement, IntoDynElement, PathElement};
+use crate::chart::LegendMarker;
+use crate::element::{
+    Cross, Drawable, DynElement, IntoDynElement, PathElement, PointCollection, Polygon,
+    TriangleMarker,
+};
 use crate::style::ShapeStyle;
-use plotters_backend::DrawingBackend;
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use std::collections::VecDeque;
 use std::marker::PhantomData;
+use std::ops::Sub;
+
+/// The value that may not be plottable, such as NaN or infinity
+pub trait FiniteValue {
+    /// Check if the value is neither NaN nor infinity
+    fn is_finite_value(&self) -> bool;
+}
+
+macro_rules! impl_finite_value {
+    (float: $($t:ty),*) => {
+        $(impl FiniteValue for $t {
+            fn is_finite_value(&self) -> bool {
+                self.is_finite()
+            }
+        })*
+    };
+    (int: $($t:ty),*) => {
+        $(impl FiniteValue for $t {
+            fn is_finite_value(&self) -> bool {
+                true
+            }
+        })*
+    };
+}
+
+impl_finite_value!(float: f32, f64);
+impl_finite_value!(int: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl<X: FiniteValue, Y: FiniteValue> FiniteValue for (X, Y) {
+    fn is_finite_value(&self) -> bool {
+        self.0.is_finite_value() && self.1.is_finite_value()
+    }
+}
+
+impl<X: FiniteValue, Y: FiniteValue, Z: FiniteValue> FiniteValue for (X, Y, Z) {
+    fn is_finite_value(&self) -> bool {
+        self.0.is_finite_value() && self.1.is_finite_value() && self.2.is_finite_value()
+    }
+}
+
+type FillOutlineFunc<Coord> = dyn Fn(&[Coord]) -> Vec<Coord>;
 
 /// The line series object, which takes an iterator of points in guest coordinate system
 /// and creates the element rendering the line plot
 pub struct LineSeries<DB: DrawingBackend, Coord> {
     style: ShapeStyle,
-    data: Vec<Coord>,
+    segments: VecDeque<Vec<Coord>>,
+    segment_idx: usize,
     point_idx: usize,
     point_size: u32,
+    point_style: Option<ShapeStyle>,
+    marker: LegendMarker,
+    fill: Option<(Box<FillOutlineFunc<Coord>>, ShapeStyle)>,
+    fill_idx: usize,
     phantom: PhantomData<DB>,
 }
 
 impl<DB: DrawingBackend, Coord: Clone + 'static> Iterator for LineSeries<DB, Coord> {
     type Item = DynElement<'static, DB, Coord>;
     fn next(&mut self) -> Option<Self::Item> {
-        if !self.data.is_empty() {
-            if self.point_size > 0 && self.point_idx < self.data.len() {
-                let idx = self.point_idx;
-                self.point_idx += 1;
-                return Some(
-                    Circle::new(self.data[idx].clone(), self.point_size, self.style.clone())
-                        .into_dyn(),
-                );
+        if let Some((outline, style)) = self.fill.as_ref() {
+            while self.fill_idx < self.segments.len() {
+                let segment = &self.segments[self.fill_idx];
+                self.fill_idx += 1;
+                if !segment.is_empty() {
+                    return Some(Polygon::new(outline(segment), style.clone()).into_dyn());
+                }
             }
-            let mut data = vec![];
-            std::mem::swap(&mut self.data, &mut data);
-            Some(PathElement::new(data, self.style.clone()).into_dyn())
-        } else {
-            None
         }
+        if self.point_size > 0 {
+            while self.segment_idx < self.segments.len() {
+                let segment = &self.segments[self.segment_idx];
+                if self.point_idx < segment.len() {
+                    let pos = segment[self.point_idx].clone();
+                    self.point_idx += 1;
+                    return Some(self.vertex_marker(pos).into_dyn());
+                }
+                self.segment_idx += 1;
+                self.point_idx = 0;
+            }
+        }
+        while let Some(segment) = self.segments.pop_front() {
+            if !segment.is_empty() {
+                return Some(PathElement::new(segment, self.style.clone()).into_dyn());
+            }
+        }
+        None
     }
 }
 
@@ -38,23 +101,426 @@ impl<DB: DrawingBackend, Coord> LineSeries<DB, Coord> {
     pub fn new<I: IntoIterator<Item = Coord>, S: Into<ShapeStyle>>(iter: I, style: S) -> Self {
         Self {
             style: style.into(),
-            data: iter.into_iter().collect(),
+            segments: std::iter::once(iter.into_iter().collect()).collect(),
+            segment_idx: 0,
             point_size: 0,
             point_idx: 0,
+            point_style: None,
+            marker: LegendMarker::Circle,
+            fill: None,
+            fill_idx: 0,
             phantom: PhantomData,
         }
     }
 
+    /// Draw a marker at each vertex of the line in addition to the line itself. By default the
+    /// marker is a circle with the style of the line.
+    /// - `size`: The size of the markers, `0` means no markers
     pub fn point_size(mut self, size: u32) -> Self {
         self.point_size = size;
         self
     }
+
+    /// Set the style of the markers drawn at the vertices, see [point_size](#method.point_size)
+    pub fn point_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.point_style = Some(style.into());
+        self
+    }
+
+    /// Set the shape of the markers drawn at the vertices, see [point_size](#method.point_size)
+    pub fn point_marker(mut self, marker: LegendMarker) -> Self {
+        self.marker = marker;
+        self
+    }
+
+    /// Get the legend element creator of the series, which draws a short segment of the line
+    /// with the marker in the middle, so the legend entry shows both. The returned function is
+    /// meant to be passed to [SeriesAnno::legend](../chart/struct.SeriesAnno.html#method.legend):
+    ///
+    /// ```ignore
+    /// let series = LineSeries::new(data, &RED).point_size(3);
+    /// let legend = series.legend_element();
+    /// chart.draw_series(series)?.label("data").legend(legend);
+    /// ```
+    pub fn legend_element(&self) -> impl Fn(BackendCoord) -> DynElement<'static, DB, BackendCoord> {
+        let style = self.style.clone();
+        let marker = if self.point_size > 0 {
+            let marker = self.vertex_marker((0, 0));
+            Some(VertexMarker {
+                size: marker.size.min(5),
+                ..marker
+            })
+        } else {
+            None
+        };
+        move |pos| {
+            LineLegend {
+                pos,
+                style: style.clone(),
+                marker: marker.clone(),
+            }
+            .into_dyn()
+        }
+    }
+
+    fn vertex_marker<C>(&self, pos: C) -> VertexMarker<C> {
+        VertexMarker {
+            pos,
+            shape: self.marker,
+            size: self.point_size,
+            style: self.point_style.as_ref().unwrap_or(&self.style).clone(),
+        }
+    }
+
+    /// Break the line at the points that contains NaN or infinity, so that the line isn't
+    /// connected across the gaps. Those points are skipped and each run of the valid points
+    /// is drawn as a separated path.
+    pub fn skip_nan(mut self) -> Self
+    where
+        Coord: FiniteValue,
+    {
+        let mut segments = VecDeque::new();
+        for segment in std::mem::replace(&mut self.segments, VecDeque::new()) {
+            let mut current = vec![];
+            for point in segment {
+                if point.is_finite_value() {
+                    current.push(point);
+                } else if !current.is_empty() {
+                    segments.push_back(std::mem::replace(&mut current, vec![]));
+                }
+            }
+            if !current.is_empty() {
+                segments.push_back(current);
+            }
+        }
+        self.segments = segments;
+        self
+    }
+}
+
+impl<DB: DrawingBackend, X: Clone, Y: Clone + 'static> LineSeries<DB, (X, Y)> {
+    /// Fill the area between the line and the horizontal baseline, the line is still drawn on
+    /// top of the filled area. The parts of the line under the baseline are filled downwards to
+    /// the baseline as well. If the line is broken into multiple segments, e.g. by
+    /// [skip_nan](#method.skip_nan), each segment is filled separately.
+    /// - `baseline`: The Y value the area is filled to
+    /// - `style`: The style of the filled area
+    pub fn fill_to<S: Into<ShapeStyle>>(mut self, baseline: Y, style: S) -> Self {
+        let outline = move |segment: &[(X, Y)]| {
+            let mut outline = segment.to_vec();
+            if let (Some(first), Some(last)) = (segment.first(), segment.last()) {
+                outline.push((last.0.clone(), baseline.clone()));
+                outline.push((first.0.clone(), baseline.clone()));
+            }
+            outline
+        };
+        self.fill = Some((Box::new(outline), style.into().filled()));
+        self
+    }
+
+    /// Break the line where two adjacent points are more than `max_gap` apart along the X axis,
+    /// which usually indicates missing data in an irregularly sampled series. Each run of the
+    /// points is drawn as a separated path, like [skip_nan](#method.skip_nan).
+    /// - `max_gap`: The largest distance along the X axis that is still connected
+    pub fn max_gap<D: PartialOrd>(mut self, max_gap: D) -> Self
+    where
+        X: Sub<X, Output = D>,
+    {
+        let mut segments = VecDeque::new();
+        for segment in std::mem::replace(&mut self.segments, VecDeque::new()) {
+            let mut current: Vec<(X, Y)> = vec![];
+            for point in segment {
+                if let Some(last) = current.last() {
+                    if point.0.clone() - last.0.clone() > max_gap {
+                        segments.push_back(std::mem::replace(&mut current, vec![]));
+                    }
+                }
+                current.push(point);
+            }
+            if !current.is_empty() {
+                segments.push_back(current);
+            }
+        }
+        self.segments = segments;
+        self
+    }
+}
+
+/// The marker drawn at a vertex of the line
+#[derive(Clone)]
+struct VertexMarker<Coord> {
+    pos: Coord,
+    shape: LegendMarker,
+    size: u32,
+    style: ShapeStyle,
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a VertexMarker<Coord> {
+    type Point = &'a Coord;
+    type IntoIter = std::iter::Once<&'a Coord>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&self.pos)
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for VertexMarker<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let Some((x, y)) = points.next() {
+            let (size, s, style) = (self.size, self.size as i32, &self.style);
+            let center = std::iter::once((x, y));
+            match self.shape {
+                LegendMarker::Line => style.draw_line(backend, (x - s, y), (x + s, y))?,
+                LegendMarker::Rectangle => {
+                    style.draw_rect(backend, (x - s, y - s), (x + s, y + s), style.filled)?
+                }
+                LegendMarker::Circle => style.draw_circle(backend, (x, y), size, style.filled)?,
+                LegendMarker::Cross => {
+                    Cross::new((x, y), size, style.clone()).draw(center, backend, parent_dim)?
+                }
+                LegendMarker::Triangle => TriangleMarker::new((x, y), size, style.clone())
+                    .draw(center, backend, parent_dim)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The legend element of a line series, a short segment of the line with the vertex marker in
+/// the middle
+struct LineLegend {
+    pos: BackendCoord,
+    style: ShapeStyle,
+    marker: Option<VertexMarker<BackendCoord>>,
+}
+
+impl<'a> PointCollection<'a, BackendCoord> for &'a LineLegend {
+    type Point = &'a BackendCoord;
+    type IntoIter = std::iter::Once<&'a BackendCoord>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&self.pos)
+    }
+}
+
+impl<DB: DrawingBackend> Drawable<DB> for LineLegend {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let Some((x, y)) = points.next() {
+            self.style.draw_line(backend, (x, y), (x + 20, y))?;
+            if let Some(marker) = self.marker.as_ref() {
+                marker.draw(std::iter::once((x + 10, y)), backend, parent_dim)?;
+            }
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod test {
     use crate::prelude::*;
 
+    fn collect_paths(data: Vec<(f64, f64)>) -> Vec<Vec<(i32, i32)>> {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let paths = Rc::new(RefCell::new(vec![]));
+        {
+            let paths = paths.clone();
+            let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+                m.check_draw_path(move |_, _, path| paths.borrow_mut().push(path));
+            });
+
+            let mut chart = ChartBuilder::on(&drawing_area)
+                .build_cartesian_2d(0.0..100.0, 0.0..100.0)
+                .expect("Build chart error");
+
+            chart
+                .draw_series(LineSeries::new(data, &RED).skip_nan())
+                .expect("Drawing Error");
+        }
+        let ret = paths.borrow().clone();
+        ret
+    }
+
+    #[test]
+    fn test_line_series_skip_nan() {
+        let nan = std::f64::NAN;
+        let inf = std::f64::INFINITY;
+
+        // Leading NaN run
+        let paths = collect_paths(vec![(0.0, nan), (10.0, nan), (20.0, 20.0), (30.0, 30.0)]);
+        assert_eq!(paths.len(), 1);
+        assert_eq!(paths[0].len(), 2);
+
+        // Trailing NaN run
+        let paths = collect_paths(vec![(0.0, 0.0), (10.0, 10.0), (20.0, nan), (nan, 30.0)]);
+        assert_eq!(paths.len(), 1);
+        assert_eq!(paths[0].len(), 2);
+
+        // Interior NaN run and infinity
+        let paths = collect_paths(vec![
+            (0.0, 0.0),
+            (10.0, 10.0),
+            (20.0, nan),
+            (30.0, inf),
+            (40.0, 40.0),
+            (50.0, 50.0),
+            (60.0, 60.0),
+        ]);
+        assert_eq!(paths.len(), 2);
+        assert_eq!(paths[0], vec![(0, 199), (20, 179)]);
+        assert_eq!(paths[1].len(), 3);
+        assert_eq!(paths[1][0], (80, 119));
+
+        // Nothing but NaN
+        let paths = collect_paths(vec![(nan, nan), (nan, nan)]);
+        assert!(paths.is_empty());
+    }
+
+    #[test]
+    fn test_line_series_empty() {
+        assert!(collect_paths(vec![]).is_empty());
+        assert_eq!(collect_paths(vec![(50.0, 50.0)]), vec![vec![(100, 99)]]);
+    }
+
+    #[test]
+    fn test_line_series_max_gap() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let paths = Rc::new(RefCell::new(vec![]));
+        {
+            let paths = paths.clone();
+            let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+                m.check_draw_path(move |_, _, path| paths.borrow_mut().push(path));
+            });
+
+            let mut chart = ChartBuilder::on(&drawing_area)
+                .build_cartesian_2d(0.0..100.0, 0.0..100.0)
+                .expect("Build chart error");
+
+            let data = vec![
+                (0.0, 0.0),
+                (1.0, 1.0),
+                (10.0, 2.0),
+                (11.0, 3.0),
+                (12.0, 4.0),
+            ];
+            chart
+                .draw_series(LineSeries::new(data, &RED).max_gap(5.0))
+                .expect("Drawing Error");
+        }
+        let paths = paths.borrow();
+        assert_eq!(paths.len(), 2);
+        assert_eq!(paths[0].len(), 2);
+        assert_eq!(paths[1].len(), 3);
+    }
+
+    #[test]
+    fn test_line_series_fill_to() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_fill_polygon(|c, path| {
+                assert_eq!(c, BLUE.to_rgba());
+                assert_eq!(
+                    path,
+                    vec![(0, 0), (100, 199), (200, 99), (200, 99), (0, 99)]
+                );
+            });
+            m.check_draw_path(|c, _, path| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(path.len(), 3);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 1);
+                assert_eq!(b.num_draw_path_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..2.0, -1.0..1.0)
+            .expect("Build chart error");
+
+        chart
+            .draw_series(
+                LineSeries::new(vec![(0.0, 1.0), (1.0, -1.0), (2.0, 0.0)], &RED)
+                    .fill_to(0.0, &BLUE),
+            )
+            .expect("Drawing Error");
+    }
+
+    #[test]
+    fn test_line_series_markers() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_line(|c, _, _, _| {
+                assert_eq!(c, BLUE.to_rgba());
+            });
+            m.check_draw_path(|c, _, path| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(path.len(), 3);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_line_call, 6);
+                assert_eq!(b.num_draw_path_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..2.0, 0.0..2.0)
+            .expect("Build chart error");
+
+        chart
+            .draw_series(
+                LineSeries::new(vec![(0.0, 1.0), (1.0, 2.0), (2.0, 0.0)], &RED)
+                    .point_size(3)
+                    .point_style(&BLUE)
+                    .point_marker(LegendMarker::Cross),
+            )
+            .expect("Drawing Error");
+    }
+
+    #[test]
+    fn test_line_series_marker_legend() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_circle(|c, _, f, _, r| {
+                assert_eq!(c, BLUE.to_rgba());
+                assert!(f);
+                assert_eq!(r, 3);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_circle_call, 4);
+                assert_eq!(b.num_draw_line_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..2.0, 0.0..2.0)
+            .expect("Build chart error");
+
+        let series = LineSeries::new(vec![(0.0, 1.0), (1.0, 2.0), (2.0, 0.0)], &RED)
+            .point_size(3)
+            .point_style(BLUE.filled());
+        let legend = series.legend_element();
+        chart
+            .draw_series(series)
+            .expect("Drawing Error")
+            .label("line")
+            .legend(legend);
+        chart
+            .configure_series_labels()
+            .border_style(&TRANSPARENT)
+            .background_style(&TRANSPARENT)
+            .draw()
+            .expect("Drawing Error");
+    }
+
     #[test]
     fn test_line_series() {
         let drawing_area = create_mocked_drawing_area(200, 200, |m| {
@@ -12,22 +12,54 @@
 
 #[cfg(feature = "area_series")]
 mod area_series;
+#[cfg(feature = "geojson")]
+mod geojson;
+#[cfg(feature = "gradient_line_series")]
+mod gradient_line;
+#[cfg(feature = "hexbin_series")]
+mod hexbin;
 #[cfg(feature = "histogram")]
 mod histogram;
 #[cfg(feature = "line_series")]
 mod line_series;
 #[cfg(feature = "point_series")]
 mod point_series;
+#[cfg(feature = "quiver_series")]
+mod quiver;
+#[cfg(feature = "ridgeline_series")]
+mod ridgeline;
+#[cfg(feature = "rug_series")]
+mod rug;
 #[cfg(feature = "surface_series")]
 mod surface;
+#[cfg(any(feature = "histogram", feature = "point_series"))]
+mod value_labels;
+#[cfg(feature = "waterfall_series")]
+mod waterfall;
 
 #[cfg(feature = "area_series")]
-pub use area_series::AreaSeries;
+pub use area_series::{AreaBorder, AreaSeries};
+#[cfg(feature = "geojson")]
+pub use geojson::{parse_geojson, GeoFeature, GeoGeometry, GeoJsonError, GeoJsonSeries, GeoLine};
+#[cfg(feature = "gradient_line_series")]
+pub use gradient_line::{GradientLine, GradientLineSeries};
+#[cfg(feature = "hexbin_series")]
+pub use hexbin::HexbinSeries;
 #[cfg(feature = "histogram")]
-pub use histogram::Histogram;
+pub use histogram::{BarFillMode, Histogram};
 #[cfg(feature = "line_series")]
-pub use line_series::LineSeries;
+pub use line_series::{FiniteValue, LineSeries};
 #[cfg(feature = "point_series")]
-pub use point_series::PointSeries;
+pub use point_series::{OutOfRange, PointSeries};
+#[cfg(feature = "quiver_series")]
+pub use quiver::QuiverSeries;
+#[cfg(feature = "ridgeline_series")]
+pub use ridgeline::RidgelineSeries;
+#[cfg(feature = "rug_series")]
+pub use rug::RugSeries;
 #[cfg(feature = "surface_series")]
 pub use surface::SurfaceSeries;
+#[cfg(any(feature = "histogram", feature = "point_series"))]
+pub use value_labels::{LabelSide, ValueLabel, ValueLabels};
+#[cfg(feature = "waterfall_series")]
+pub use waterfall::{WaterfallSeries, WaterfallStep};
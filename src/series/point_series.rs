@@ -1,5 +1,47 @@
-use crate::element::PointElement;
+use std::cmp::Ordering;
+use std::ops::Range;
+
+use super::value_labels::{LabelSide, ValueLabels};
+use crate::chart::ChartContext;
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::Ranged;
+use crate::element::{Drawable, PointCollection, PointElement};
 use crate::style::{ShapeStyle, SizeDesc};
+use plotters_backend::DrawingBackend;
+
+/// The policy of the points which are out of the range of the axes, see
+/// [PointSeries::out_of_range](struct.PointSeries.html#method.out_of_range)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutOfRange {
+    /// Drop the points out of the range
+    Drop,
+    /// Move the points out of the range to the nearest edge of the plotting area, so the
+    /// off-scale points are still visible as the markers on the edge
+    Clamp,
+    /// Draw the points as they are and rely on the clipping of the plotting area
+    Allow,
+}
+
+/// Clamp the value into the range, returns the clamped value and if the value is changed, or
+/// `None` if the value can't be compared with the range, e.g. NaN
+fn clamp_value<T: PartialOrd + Clone>(value: T, range: &Range<T>) -> Option<(T, bool)> {
+    let (low, high) = if range.start <= range.end {
+        (&range.start, &range.end)
+    } else {
+        (&range.end, &range.start)
+    };
+    if value < *low {
+        Some((low.clone(), true))
+    } else if value > *high {
+        Some((high.clone(), true))
+    } else if value >= *low && value <= *high {
+        Some((value, false))
+    } else {
+        None
+    }
+}
+
+type RangePolicyFn<'a, Coord> = dyn Fn(Coord) -> Option<(Coord, bool)> + 'a;
 
 /// The point plot object, which takes an iterator of points in guest coordinate system
 /// and create an element for each point
@@ -8,6 +50,9 @@ pub struct PointSeries<'a, Coord, I: IntoIterator<Item = Coord>, E, Size: SizeDe
     size: Size,
     data_iter: I::IntoIter,
     make_point: &'a dyn Fn(Coord, Size, ShapeStyle) -> E,
+    range_policy: Option<Box<RangePolicyFn<'a, Coord>>>,
+    clamped_style: Option<ShapeStyle>,
+    sorted: Option<std::vec::IntoIter<Coord>>,
 }
 
 impl<'a, Coord, I: IntoIterator<Item = Coord>, E, Size: SizeDesc + Clone> Iterator
@@ -15,9 +60,119 @@ impl<'a, Coord, I: IntoIterator<Item = Coord>, E, Size: SizeDesc + Clone> Iterat
 {
     type Item = E;
     fn next(&mut self) -> Option<Self::Item> {
-        self.data_iter
-            .next()
-            .map(|x| (self.make_point)(x, self.size.clone(), self.style.clone()))
+        let (point, style) = self.next_point()?;
+        Some((self.make_point)(point, self.size.clone(), style))
+    }
+}
+
+impl<'a, Coord, I: IntoIterator<Item = Coord>, E, Size: SizeDesc + Clone>
+    PointSeries<'a, Coord, I, E, Size>
+{
+    fn next_point(&mut self) -> Option<(Coord, ShapeStyle)> {
+        let data: &mut dyn Iterator<Item = Coord> = match self.sorted {
+            Some(ref mut sorted) => sorted,
+            None => &mut self.data_iter,
+        };
+        for point in data {
+            let (point, clamped) = match self.range_policy {
+                Some(ref policy) => match policy(point) {
+                    Some(adjusted) => adjusted,
+                    None => continue,
+                },
+                None => (point, false),
+            };
+            let style = match self.clamped_style {
+                Some(ref style) if clamped => style.clone(),
+                _ => self.style.clone(),
+            };
+            return Some((point, style));
+        }
+        None
+    }
+
+    /// Draw the points in the ascending order of the key rather than the order of the data,
+    /// so the points with the largest key are drawn on top, e.g. use the negative size of the
+    /// bubbles as the key to draw the small bubbles on top of the large ones. The points with
+    /// the same key keep their order
+    /// - `key`: The function that computes the key of a point
+    pub fn draw_order_by<K: PartialOrd, F: Fn(&Coord) -> K>(mut self, key: F) -> Self {
+        let mut points: Vec<_> = match self.sorted.take() {
+            Some(sorted) => sorted.collect(),
+            None => self.data_iter.by_ref().collect(),
+        };
+        points.sort_by(|a, b| key(a).partial_cmp(&key(b)).unwrap_or(Ordering::Equal));
+        self.sorted = Some(points.into_iter());
+        self
+    }
+
+    /// Draw the formatted coordinate of each point on the right of the point, see
+    /// [ValueLabels](struct.ValueLabels.html). The label offset should be larger than the
+    /// point size, so the labels don't overlap the points
+    /// - `formatter`: The function that formats the coordinate of a point
+    pub fn with_value_labels<DB, F>(mut self, formatter: F) -> ValueLabels<'a, DB, Coord>
+    where
+        DB: DrawingBackend,
+        F: Fn(&Coord) -> String + 'a,
+        Coord: Clone + 'a,
+        I: 'a,
+        I::IntoIter: 'a,
+        E: Drawable<DB> + 'a,
+        for<'b> &'b E: PointCollection<'b, Coord>,
+        Size: 'a,
+    {
+        ValueLabels::new(std::iter::from_fn(move || {
+            let (point, style) = self.next_point()?;
+            let label = formatter(&point);
+            let element = (self.make_point)(point, self.size.clone(), style);
+            Some((element, label, LabelSide::Right))
+        }))
+    }
+}
+
+impl<'a, X, Y, I, E, Size> PointSeries<'a, (X, Y), I, E, Size>
+where
+    X: PartialOrd + Clone + 'a,
+    Y: PartialOrd + Clone + 'a,
+    I: IntoIterator<Item = (X, Y)>,
+    Size: SizeDesc + Clone,
+{
+    /// Set how the points out of the range of the axes are handled. By default, all the points
+    /// are drawn and the plotting area clips the parts out of the range, which is the same as
+    /// `OutOfRange::Allow`
+    /// - `policy`: The policy of the points out of the range
+    /// - `parent`: The chart the series is drawn on, which defines the range of the axes
+    pub fn out_of_range<DB, XR, YR>(
+        mut self,
+        policy: OutOfRange,
+        parent: &ChartContext<DB, Cartesian2d<XR, YR>>,
+    ) -> Self
+    where
+        DB: DrawingBackend,
+        XR: Ranged<ValueType = X>,
+        YR: Ranged<ValueType = Y>,
+    {
+        let coord = parent.as_coord_spec();
+        let (x_range, y_range) = (coord.x_spec().range(), coord.y_spec().range());
+        self.range_policy = match policy {
+            OutOfRange::Allow => None,
+            OutOfRange::Drop | OutOfRange::Clamp => Some(Box::new(move |(x, y): (X, Y)| {
+                let (x, x_clamped) = clamp_value(x, &x_range)?;
+                let (y, y_clamped) = clamp_value(y, &y_range)?;
+                let clamped = x_clamped || y_clamped;
+                if clamped && policy == OutOfRange::Drop {
+                    return None;
+                }
+                Some(((x, y), clamped))
+            })),
+        };
+        self
+    }
+
+    /// Set the style of the points moved to the edge by `OutOfRange::Clamp`, so they can be
+    /// told from the points in the range. The series style is used if it's not set
+    pub fn clamped_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.clamped_style = Some(style.into());
+        self
     }
 }
 
@@ -35,6 +190,9 @@ where
             size,
             style: style.into(),
             make_point: &|a, b, c| E::make_point(a, b, c),
+            range_policy: None,
+            clamped_style: None,
+            sorted: None,
         }
     }
 }
@@ -56,6 +214,113 @@ impl<'a, Coord, I: IntoIterator<Item = Coord>, E, Size: SizeDesc + Clone>
             size,
             style: style.into(),
             make_point: cons,
+            range_policy: None,
+            clamped_style: None,
+            sorted: None,
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_point_series_out_of_range() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            // Clamped: the points out of the range are moved to the edges, the NaN is skipped
+            m.check_draw_circle(|c, _, _, pos, _| {
+                assert_eq!(c, BLUE.to_rgba());
+                assert_eq!(pos, (50, 49));
+            });
+            m.check_draw_circle(|c, _, _, pos, _| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(pos, (100, 49));
+            });
+            m.check_draw_circle(|c, _, _, pos, _| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(pos, (50, 99));
+            });
+            // Dropped: only the point in the range is drawn
+            m.check_draw_circle(|c, _, _, pos, _| {
+                assert_eq!(c, BLUE.to_rgba());
+                assert_eq!(pos, (50, 49));
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_circle_call, 4));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Create chart");
+        let data = vec![(5.0, 5.0), (15.0, 5.0), (5.0, -3.0), (std::f64::NAN, 5.0)];
+
+        chart
+            .draw_series(
+                PointSeries::<_, _, Circle<_, _>, _>::new(data.clone(), 3, &BLUE)
+                    .out_of_range(OutOfRange::Clamp, &chart)
+                    .clamped_style(&RED),
+            )
+            .expect("Drawing error");
+        chart
+            .draw_series(
+                PointSeries::<_, _, Circle<_, _>, _>::new(data, 3, &BLUE)
+                    .out_of_range(OutOfRange::Drop, &chart),
+            )
+            .expect("Drawing error");
+    }
+
+    #[test]
+    fn test_point_series_draw_order() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_circle(|_, _, _, _, r| assert_eq!(r, 9));
+            m.check_draw_circle(|_, _, _, _, r| assert_eq!(r, 6));
+            m.check_draw_circle(|_, _, _, _, r| assert_eq!(r, 3));
+            m.drop_check(|b| assert_eq!(b.num_draw_circle_call, 3));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+
+        let bubbles = [(5, 5, 6), (5, 5, 3), (5, 5, 9)];
+        chart
+            .draw_series(
+                PointSeries::of_element(bubbles.iter().copied(), 0, &BLUE, &|(x, y, r), _, s| {
+                    Circle::new((x, y), r, s)
+                })
+                .draw_order_by(|&(_, _, r)| -r),
+            )
+            .expect("Drawing error");
+    }
+
+    #[test]
+    fn test_point_series_value_labels() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "1");
+                assert_eq!(pos, (18, 89));
+            });
+            m.check_draw_text(|_, _, _, _, text| {
+                assert_eq!(text, "5");
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_circle_call, 3);
+                assert_eq!(b.num_draw_text_call, 2);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+
+        chart
+            .draw_series(
+                PointSeries::<_, _, Circle<_, _>, _>::new(vec![(1, 1), (1, 1), (5, 5)], 3, &BLUE)
+                    .with_value_labels(|(_, y)| y.to_string())
+                    .label_offset(8)
+                    .hide_overlapping(true),
+            )
+            .expect("Drawing error");
+    }
+}
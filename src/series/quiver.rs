@@ -0,0 +1,197 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::element::Arrow;
+use crate::style::{Color, RGBAColor, BLACK};
+
+/// The quiver series, which draws a vector field as arrows. Each vector `(x, y, u, v)` is drawn
+/// as an arrow from `(x, y)` to `(x + u * scale, y + v * scale)`, thus the length of the arrow
+/// is proportional to the magnitude of the vector.
+pub struct QuiverSeries<'a> {
+    vectors: Vec<Vector>,
+    scale: Option<f64>,
+    head_size: u32,
+    max_arrows: Option<usize>,
+    color_map: Box<dyn Fn(f64) -> RGBAColor + 'a>,
+    arrows: Option<VecDeque<Arrow<(f64, f64)>>>,
+}
+
+type Vector = (f64, f64, f64, f64);
+
+/// Get the bounding box of the positions in `(x_min, x_max, y_min, y_max)`
+fn bounding_box(vectors: &[Vector]) -> (f64, f64, f64, f64) {
+    vectors.iter().fold(
+        (
+            std::f64::INFINITY,
+            std::f64::NEG_INFINITY,
+            std::f64::INFINITY,
+            std::f64::NEG_INFINITY,
+        ),
+        |(x0, x1, y0, y1), (x, y, _, _)| (x0.min(*x), x1.max(*x), y0.min(*y), y1.max(*y)),
+    )
+}
+
+impl<'a> QuiverSeries<'a> {
+    /// Create a new quiver series
+    /// - `vectors`: The iterator of the `(x, y, u, v)` tuples, where `(x, y)` is the position
+    ///   of the vector and `(u, v)` is the vector
+    pub fn new<I: IntoIterator<Item = (f64, f64, f64, f64)>>(vectors: I) -> Self {
+        Self {
+            vectors: vectors
+                .into_iter()
+                .filter(|(x, y, u, v)| {
+                    x.is_finite() && y.is_finite() && u.is_finite() && v.is_finite()
+                })
+                .collect(),
+            scale: None,
+            head_size: 6,
+            max_arrows: None,
+            color_map: Box::new(|_| BLACK.to_rgba()),
+            arrows: None,
+        }
+    }
+
+    /// Set the length of the arrow of the unit vector in guest coordinate. By default, the
+    /// longest arrow is about the average distance between two adjacent vectors
+    pub fn scale(mut self, scale: f64) -> Self {
+        self.scale = Some(scale);
+        self
+    }
+
+    /// Set the length of the arrowheads in pixels
+    pub fn head_size(mut self, size: u32) -> Self {
+        self.head_size = size;
+        self
+    }
+
+    /// Limit the number of the arrows for the dense fields. The bounding box of the positions
+    /// is split into a grid with at most `n` cells, and only the first vector in each cell is
+    /// drawn
+    pub fn max_arrows(mut self, n: usize) -> Self {
+        self.max_arrows = Some(n.max(1));
+        self
+    }
+
+    /// Set the color of the arrows
+    /// - `func`: The function that maps the normalized magnitude, which is the magnitude of the
+    ///   vector divided by the largest magnitude, to the color of the arrow
+    pub fn color_map<C: Color>(mut self, func: impl Fn(f64) -> C + 'a) -> Self {
+        self.color_map = Box::new(move |v| func(v).to_rgba());
+        self
+    }
+
+    fn decimate(&self) -> Vec<Vector> {
+        let n = match self.max_arrows {
+            Some(n) if n < self.vectors.len() => n,
+            _ => return self.vectors.clone(),
+        };
+        let (x0, x1, y0, y1) = bounding_box(&self.vectors);
+        let cells = (n as f64).sqrt().floor().max(1.0);
+        let cell_of = |v: f64, lo: f64, hi: f64| {
+            if hi > lo {
+                (((v - lo) / (hi - lo) * cells) as usize).min(cells as usize - 1)
+            } else {
+                0
+            }
+        };
+        let mut used = HashSet::new();
+        self.vectors
+            .iter()
+            .filter(|(x, y, _, _)| used.insert((cell_of(*x, x0, x1), cell_of(*y, y0, y1))))
+            .copied()
+            .collect()
+    }
+
+    fn compute_arrows(&self) -> VecDeque<Arrow<(f64, f64)>> {
+        let vectors = self.decimate();
+        let max_magnitude = vectors
+            .iter()
+            .map(|(_, _, u, v)| u.hypot(*v))
+            .fold(0.0, f64::max);
+        let scale = self.scale.unwrap_or_else(|| {
+            let (x0, x1, y0, y1) = bounding_box(&vectors);
+            let area = (x1 - x0).max(0.0) * (y1 - y0).max(0.0);
+            let spacing = if area > 0.0 {
+                (area / vectors.len() as f64).sqrt()
+            } else {
+                (x1 - x0).max(y1 - y0).max(0.0) / vectors.len() as f64
+            };
+            if max_magnitude > 0.0 && spacing > 0.0 {
+                spacing * 0.9 / max_magnitude
+            } else {
+                1.0
+            }
+        });
+        vectors
+            .into_iter()
+            .map(|(x, y, u, v)| {
+                let value = if max_magnitude > 0.0 {
+                    u.hypot(v) / max_magnitude
+                } else {
+                    0.0
+                };
+                Arrow::new(
+                    (x, y),
+                    (x + u * scale, y + v * scale),
+                    (self.color_map)(value),
+                )
+                .head_size(self.head_size)
+            })
+            .collect()
+    }
+}
+
+impl<'a> Iterator for QuiverSeries<'a> {
+    type Item = Arrow<(f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.arrows.is_none() {
+            self.arrows = Some(self.compute_arrows());
+        }
+        self.arrows.as_mut().and_then(|a| a.pop_front())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_quiver_decimation() {
+        let grid: Vec<_> = (0..10)
+            .flat_map(|x| (0..10).map(move |y| (x as f64, y as f64, 1.0, 0.0)))
+            .collect();
+        assert_eq!(QuiverSeries::new(grid.clone()).count(), 100);
+        assert_eq!(QuiverSeries::new(grid).max_arrows(25).count(), 25);
+    }
+
+    #[test]
+    fn test_quiver_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_line(|c, _, from, to| {
+                assert_eq!(c, BLUE.to_rgba());
+                assert_eq!(from, (0, 199));
+                assert_eq!(to.1, 199);
+            });
+            m.check_draw_line(|c, _, from, to| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(from.0, to.0);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_line_call, 2);
+                assert_eq!(b.num_fill_polygon_call, 2);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Create chart");
+
+        chart
+            .draw_series(
+                QuiverSeries::new(vec![(0.0, 0.0, 1.0, 0.0), (5.0, 5.0, 0.0, 2.0)])
+                    .scale(2.0)
+                    .color_map(|v| if v < 1.0 { BLUE } else { RED }),
+            )
+            .expect("Drawing error");
+    }
+}
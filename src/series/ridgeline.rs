@@ -0,0 +1,230 @@
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+use std::ops::Range;
+
+use crate::element::Polygon;
+use crate::style::{Color, Palette, Palette99, RGBAColor};
+
+/// The ridgeline series, also known as joyplot, which draws the kernel density estimation of
+/// each category as a filled curve. The curve of the `i`-th category sits on the baseline
+/// `y = i * offset`, so the categories are stacked vertically and can slightly overlap.
+///
+/// The curves are drawn from the top-most category to the bottom-most one, so that the lower
+/// curves are drawn in front of the higher ones.
+pub struct RidgelineSeries<'a> {
+    data: Vec<Vec<f64>>,
+    offset: f64,
+    overlap: f64,
+    bandwidth: Option<f64>,
+    samples: usize,
+    x_range: Option<Range<f64>>,
+    color_map: Box<dyn Fn(usize) -> RGBAColor + 'a>,
+    polygons: Option<VecDeque<Polygon<(f64, f64)>>>,
+}
+
+fn silverman_bandwidth(data: &[f64]) -> f64 {
+    let n = data.len() as f64;
+    let mean = data.iter().sum::<f64>() / n;
+    let var = data.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / (n - 1.0).max(1.0);
+    let bw = 1.06 * var.sqrt() * n.powf(-0.2);
+    if bw > 0.0 {
+        bw
+    } else {
+        1.0
+    }
+}
+
+fn gaussian_kde(data: &[f64], bandwidth: f64, x: f64) -> f64 {
+    let norm = 1.0 / (data.len() as f64 * bandwidth * (2.0 * std::f64::consts::PI).sqrt());
+    data.iter()
+        .map(|v| {
+            let u = (x - v) / bandwidth;
+            (-0.5 * u * u).exp()
+        })
+        .sum::<f64>()
+        * norm
+}
+
+impl<'a> RidgelineSeries<'a> {
+    /// Create a new ridgeline series
+    /// - `data`: The sample sets, one for each category
+    pub fn new<I: IntoIterator<Item = S>, S: IntoIterator<Item = f64>>(data: I) -> Self {
+        Self {
+            data: data
+                .into_iter()
+                .map(|s| s.into_iter().filter(|v| v.is_finite()).collect())
+                .collect(),
+            offset: 1.0,
+            overlap: 0.5,
+            bandwidth: None,
+            samples: 100,
+            x_range: None,
+            color_map: Box::new(|idx| Palette99::pick(idx).to_rgba()),
+            polygons: None,
+        }
+    }
+
+    /// Set the vertical distance between the baselines of two adjacent categories
+    pub fn offset(mut self, offset: f64) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Set how much the curves overlap. The highest peak of all the curves is
+    /// `offset * (1 + overlap)` above its baseline, thus 0 means the curves never overlap
+    pub fn overlap(mut self, overlap: f64) -> Self {
+        self.overlap = overlap;
+        self
+    }
+
+    /// Set the bandwidth of the Gaussian kernel. By default the bandwidth of each category
+    /// is selected by Silverman's rule of thumb, which is also used if the given bandwidth
+    /// isn't a positive finite number
+    pub fn bandwidth(mut self, bandwidth: f64) -> Self {
+        self.bandwidth = if bandwidth > 0.0 && bandwidth.is_finite() {
+            Some(bandwidth)
+        } else {
+            None
+        };
+        self
+    }
+
+    /// Set the number of the points each curve is sampled at
+    pub fn samples(mut self, samples: usize) -> Self {
+        self.samples = samples.max(2);
+        self
+    }
+
+    /// Set the range of the X axis the curves are evaluated in. By default, the range covers
+    /// all the samples and extends by three bandwidths on both sides
+    pub fn x_range(mut self, range: Range<f64>) -> Self {
+        self.x_range = Some(range);
+        self
+    }
+
+    /// Set the fill color of each category
+    /// - `func`: The function maps the index of the category to its color
+    pub fn color_map<C: Color>(mut self, func: impl Fn(usize) -> C + 'a) -> Self {
+        self.color_map = Box::new(move |idx| func(idx).to_rgba());
+        self
+    }
+
+    fn compute_polygons(&self) -> VecDeque<Polygon<(f64, f64)>> {
+        let bandwidths: Vec<_> = self
+            .data
+            .iter()
+            .map(|d| self.bandwidth.unwrap_or_else(|| silverman_bandwidth(d)))
+            .collect();
+
+        let range = self.x_range.clone().unwrap_or_else(|| {
+            let mut range = std::f64::INFINITY..std::f64::NEG_INFINITY;
+            for (data, bw) in self.data.iter().zip(bandwidths.iter()) {
+                for v in data {
+                    range.start = range.start.min(v - bw * 3.0);
+                    range.end = range.end.max(v + bw * 3.0);
+                }
+            }
+            range
+        });
+        if range.start.partial_cmp(&range.end) != Some(Ordering::Less) {
+            return VecDeque::new();
+        }
+
+        let step = (range.end - range.start) / (self.samples - 1) as f64;
+        let curves: Vec<Vec<(f64, f64)>> = self
+            .data
+            .iter()
+            .zip(bandwidths.iter())
+            .map(|(data, bw)| {
+                if data.is_empty() {
+                    return vec![];
+                }
+                (0..self.samples)
+                    .map(|i| {
+                        let x = range.start + step * i as f64;
+                        (x, gaussian_kde(data, *bw, x))
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let max_density = curves.iter().flatten().map(|(_, d)| *d).fold(0.0, f64::max);
+        let scale = if max_density > 0.0 {
+            self.offset * (1.0 + self.overlap) / max_density
+        } else {
+            0.0
+        };
+
+        let mut polygons = VecDeque::new();
+        for (idx, curve) in curves.into_iter().enumerate().rev() {
+            if curve.is_empty() {
+                continue;
+            }
+            let baseline = idx as f64 * self.offset;
+            let mut outline: Vec<_> = curve
+                .into_iter()
+                .map(|(x, d)| (x, baseline + d * scale))
+                .collect();
+            outline.push((range.end, baseline));
+            outline.push((range.start, baseline));
+            polygons.push_back(Polygon::new(outline, (self.color_map)(idx).filled()));
+        }
+        polygons
+    }
+}
+
+impl<'a> Iterator for RidgelineSeries<'a> {
+    type Item = Polygon<(f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.polygons.is_none() {
+            self.polygons = Some(self.compute_polygons());
+        }
+        self.polygons.as_mut().and_then(|p| p.pop_front())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_gaussian_kde() {
+        let density = gaussian_kde(&[0.0], 1.0, 0.0);
+        assert!((density - 1.0 / (2.0 * std::f64::consts::PI).sqrt()).abs() < 1e-10);
+
+        let series = RidgelineSeries::new(vec![vec![0.0, 1.0]]).bandwidth(0.0);
+        assert_eq!(series.bandwidth, None);
+    }
+
+    #[test]
+    fn test_ridgeline_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_fill_polygon(|c, path| {
+                assert_eq!(c, BLUE.to_rgba());
+                assert_eq!(path.len(), 12);
+                assert!(path.iter().all(|p| p.1 < 100));
+            });
+            m.check_fill_polygon(|c, path| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(path.len(), 12);
+                assert!(path.iter().all(|p| p.1 >= 50));
+            });
+            m.drop_check(|b| assert_eq!(b.num_fill_polygon_call, 2));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(-5.0..5.0, 0.0..4.0)
+            .expect("Create chart");
+
+        chart
+            .draw_series(
+                RidgelineSeries::new(vec![vec![0.0, 1.0, -1.0], vec![0.0, 0.5, 2.0]])
+                    .offset(2.0)
+                    .overlap(0.0)
+                    .samples(10)
+                    .color_map(|idx| if idx == 0 { RED } else { BLUE }),
+            )
+            .expect("Drawing error");
+    }
+}
@@ -0,0 +1,135 @@
+use std::marker::PhantomData;
+
+use crate::chart::ChartContext;
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::Ranged;
+use crate::element::{BoxedElement, EmptyElement, PathElement};
+use crate::style::{ShapeStyle, BLACK};
+use plotters_backend::{BackendCoord, DrawingBackend};
+
+/// The rug plot series, which draws a short tick for each value along the bottom or the left
+/// edge of the plotting area, showing the 1D distribution of a variable. It's usually drawn
+/// alongside a scatter plot, a histogram or a density plot.
+///
+/// The ticks are perpendicular to the axis and their length is defined in pixels, so they keep
+/// the same size whatever the range of the other axis is. The values out of the range of the
+/// axis are clipped by the plotting area.
+pub struct RugSeries<DB: DrawingBackend, X, Y> {
+    points: std::vec::IntoIter<(X, Y)>,
+    vertical: bool,
+    length: u32,
+    style: ShapeStyle,
+    _p: PhantomData<DB>,
+}
+
+impl<DB: DrawingBackend, X, Y> RugSeries<DB, X, Y> {
+    fn new(points: Vec<(X, Y)>, vertical: bool) -> Self {
+        Self {
+            points: points.into_iter(),
+            vertical,
+            length: 5,
+            style: BLACK.into(),
+            _p: PhantomData,
+        }
+    }
+
+    /// Create a rug series along the X axis, which draws the ticks at the bottom of the
+    /// plotting area
+    /// - `parent`: The chart the series is drawn on
+    /// - `data`: The values on the X axis
+    pub fn along_x<XR, YR, I>(parent: &ChartContext<DB, Cartesian2d<XR, YR>>, data: I) -> Self
+    where
+        XR: Ranged<ValueType = X>,
+        YR: Ranged<ValueType = Y>,
+        Y: Clone,
+        I: IntoIterator<Item = X>,
+    {
+        let floor = parent.as_coord_spec().y_spec().range().start;
+        Self::new(data.into_iter().map(|x| (x, floor.clone())).collect(), true)
+    }
+
+    /// Create a rug series along the Y axis, which draws the ticks at the left of the
+    /// plotting area
+    /// - `parent`: The chart the series is drawn on
+    /// - `data`: The values on the Y axis
+    pub fn along_y<XR, YR, I>(parent: &ChartContext<DB, Cartesian2d<XR, YR>>, data: I) -> Self
+    where
+        XR: Ranged<ValueType = X>,
+        YR: Ranged<ValueType = Y>,
+        X: Clone,
+        I: IntoIterator<Item = Y>,
+    {
+        let floor = parent.as_coord_spec().x_spec().range().start;
+        Self::new(
+            data.into_iter().map(|y| (floor.clone(), y)).collect(),
+            false,
+        )
+    }
+
+    /// Set the length of the ticks in pixels
+    pub fn length(mut self, length: u32) -> Self {
+        self.length = length;
+        self
+    }
+
+    /// Set the style of the ticks
+    pub fn style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<DB: DrawingBackend, X, Y> Iterator for RugSeries<DB, X, Y> {
+    type Item = BoxedElement<(X, Y), DB, PathElement<BackendCoord>>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let length = self.length as i32;
+        let end = if self.vertical {
+            (0, -length)
+        } else {
+            (length, 0)
+        };
+        self.points.next().map(|point| {
+            EmptyElement::at(point) + PathElement::new(vec![(0, 0), end], self.style.clone())
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_rug_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_path(|c, s, path| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(s, 2);
+                assert_eq!(path[0].0, path[1].0);
+                assert_eq!(path[0].1 - path[1].1, 8);
+            });
+            m.check_draw_path(|_, _, path| {
+                assert_eq!(path[0].1, path[1].1);
+                assert_eq!(path[1].0 - path[0].0, 5);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 2);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Create chart");
+
+        chart
+            .draw_series(
+                RugSeries::along_x(&chart, vec![3.0])
+                    .length(8)
+                    .style(RED.stroke_width(2)),
+            )
+            .expect("Drawing error");
+        chart
+            .draw_series(RugSeries::along_y(&chart, vec![4.0]))
+            .expect("Drawing error");
+    }
+}
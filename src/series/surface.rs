@@ -43,6 +43,7 @@ define_panel_descriptor!(YOZ, Y, Z, X, (y, z) -> x = (x,y,z));
 enum StyleConfig<'a, T> {
     Fixed(ShapeStyle),
     Function(&'a dyn Fn(&T) -> ShapeStyle),
+    ColorMap(Box<dyn Fn(&T) -> ShapeStyle + 'a>),
 }
 
 impl<T> StyleConfig<'_, T> {
@@ -50,6 +51,7 @@ impl<T> StyleConfig<'_, T> {
         match self {
             StyleConfig::Fixed(s) => s.clone(),
             StyleConfig::Function(f) => f(v),
+            StyleConfig::ColorMap(f) => f(v),
         }
     }
 }
@@ -59,6 +61,11 @@ impl<T> StyleConfig<'_, T> {
 /// Currently the surface is representing any surface represented by a
 /// function of two variables
 ///
+/// The faces are produced in the order of the free variables, which isn't the order they
+/// should be painted in once the chart is rotated. Draw the series with
+/// [ChartContext::draw_series_depth_sorted](../chart/struct.ChartContext.html#method.draw_series_depth_sorted)
+/// to paint the farther faces first.
+///
 pub struct SurfaceSeries<'a, X, Y, Z, D, SurfaceFunc>
 where
     D: Direction<X, Y, Z>,
@@ -104,6 +111,14 @@ where
         self.style = StyleConfig::Fixed(s.into());
         self
     }
+
+    /// Fill each face with the color of its value, which is the output of the surface function
+    /// at the first corner of the face. For a XOY surface, this is the height of the face.
+    /// - `f`: The function maps the value of the face to its fill color
+    pub fn color_map<C: Color, F: Fn(&D::OutputType) -> C + 'a>(mut self, f: F) -> Self {
+        self.style = StyleConfig::ColorMap(Box::new(move |v| f(v).filled()));
+        self
+    }
 }
 
 macro_rules! impl_constructor {
@@ -0,0 +1,311 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::element::{Drawable, DynElement, IntoDynElement, PointCollection};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{FontDesc, FontFamily, FontStyle, ShapeStyle, TextStyle};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// The side of the element where its value label is placed
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LabelSide {
+    /// Above the element, centered horizontally
+    Above,
+    /// Below the element, centered horizontally
+    Below,
+    /// On the left of the element, centered vertically
+    Left,
+    /// On the right of the element, centered vertically
+    Right,
+}
+
+type PlacedLabels = Rc<RefCell<Vec<(BackendCoord, BackendCoord)>>>;
+type LabeledElement<'a, DB, Coord> = (DynElement<'a, DB, Coord>, String, LabelSide);
+
+/// The series adaptor which draws the formatted value of each element next to it, e.g. the
+/// value on top of each bar. This is created by the `with_value_labels` method of
+/// [Histogram](struct.Histogram.html#method.with_value_labels) and
+/// [PointSeries](struct.PointSeries.html#method.with_value_labels).
+///
+/// The bars are labeled on the end of the value, so the label of a negative bar is below it,
+/// and the points are labeled on the right.
+///
+/// The labels may overlap when the elements are dense, e.g. a labeled scatter plot. In this
+/// case, the overlapping labels can be either hidden with
+/// [hide_overlapping](#method.hide_overlapping), or nudged apart with
+/// [avoid_collisions](#method.avoid_collisions). The nudging is greedy: each label is placed on
+/// the first free position next to its preferred position, in the order the elements are drawn.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let root = BitMapBackend::new("plotters-doc-data/value-labels.png", (640, 480))
+///     .into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d((0..5).into_segmented(), 0..20)
+///     .unwrap();
+///
+/// chart
+///     .draw_series(
+///         Histogram::vertical(&chart)
+///             .data(vec![(0, 5), (1, 12), (2, 18)])
+///             .with_value_labels(|v| v.to_string())
+///             .label_offset(3)
+///             .avoid_collisions(10)
+///             .leader_lines(&BLACK),
+///     )
+///     .unwrap();
+/// ```
+pub struct ValueLabels<'a, DB: DrawingBackend, Coord: Clone> {
+    iter: Box<dyn Iterator<Item = LabeledElement<'a, DB, Coord>> + 'a>,
+    style: TextStyle<'a>,
+    side: Option<LabelSide>,
+    layout: LabelLayout,
+}
+
+/// The options of placing the labels, shared by all the labels of a series
+#[derive(Clone)]
+struct LabelLayout {
+    offset: i32,
+    hide_overlapping: bool,
+    attempts: usize,
+    leader_style: Option<ShapeStyle>,
+    placed: PlacedLabels,
+}
+
+impl<'a, DB: DrawingBackend, Coord: Clone> ValueLabels<'a, DB, Coord> {
+    pub(super) fn new<E, I>(iter: I) -> Self
+    where
+        E: Drawable<DB> + 'a,
+        for<'b> &'b E: PointCollection<'b, Coord>,
+        I: Iterator<Item = (E, String, LabelSide)> + 'a,
+    {
+        Self {
+            iter: Box::new(iter.map(|(element, label, side)| (element.into_dyn(), label, side))),
+            style: FontDesc::new(FontFamily::SansSerif, 12.0, FontStyle::Normal).into(),
+            side: None,
+            layout: LabelLayout {
+                offset: 5,
+                hide_overlapping: false,
+                attempts: 0,
+                leader_style: None,
+                placed: Rc::default(),
+            },
+        }
+    }
+
+    /// Set the style of the labels
+    pub fn label_style<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Set the distance between the element and its label in pixels
+    pub fn label_offset(mut self, offset: i32) -> Self {
+        self.layout.offset = offset;
+        self
+    }
+
+    /// Place all the labels on the given side of their elements, rather than the side picked
+    /// by the series
+    pub fn label_side(mut self, side: LabelSide) -> Self {
+        self.side = Some(side);
+        self
+    }
+
+    /// Skip the labels which overlap the labels drawn before them. When the collision
+    /// avoidance is enabled, only the labels without any free position are skipped
+    pub fn hide_overlapping(mut self, hide: bool) -> Self {
+        self.layout.hide_overlapping = hide;
+        self
+    }
+
+    /// Nudge the labels apart when they overlap the labels drawn before them. The labels on
+    /// the left or the right are moved up and down alternately, and the labels above or below
+    /// are moved further away from their elements, one label size at a time.
+    /// - `max_attempts`: The maximum number of the positions tried for each label, which
+    ///   bounds the cost of the placement. The label stays on its preferred position if none
+    ///   of them is free, unless the overlapping labels are hidden
+    pub fn avoid_collisions(mut self, max_attempts: usize) -> Self {
+        self.layout.attempts = max_attempts;
+        self
+    }
+
+    /// Draw a line from each nudged label back to its element
+    pub fn leader_lines<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.layout.leader_style = Some(style.into());
+        self
+    }
+}
+
+impl<'a, DB: DrawingBackend, Coord: Clone> Iterator for ValueLabels<'a, DB, Coord> {
+    type Item = ValueLabel<'a, DB, Coord>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(element, label, side)| ValueLabel {
+            element,
+            label,
+            side: self.side.unwrap_or(side),
+            style: self.style.clone(),
+            layout: self.layout.clone(),
+        })
+    }
+}
+
+/// An element with its value label, which is produced by
+/// [ValueLabels](struct.ValueLabels.html). The labeled element is boxed as a
+/// [DynElement](../element/struct.DynElement.html)
+pub struct ValueLabel<'a, DB: DrawingBackend, Coord: Clone> {
+    element: DynElement<'a, DB, Coord>,
+    label: String,
+    side: LabelSide,
+    style: TextStyle<'a>,
+    layout: LabelLayout,
+}
+
+impl<'a, 'b: 'a, DB: DrawingBackend, Coord: Clone> PointCollection<'a, Coord>
+    for &'a ValueLabel<'b, DB, Coord>
+{
+    type Point = &'a Coord;
+    type IntoIter = &'a Vec<Coord>;
+    fn point_iter(self) -> Self::IntoIter {
+        self.element.point_iter()
+    }
+}
+
+impl<'a, DB: DrawingBackend, Coord: Clone> ValueLabel<'a, DB, Coord> {
+    /// Find the position of the label, returns the anchor of the label, or `None` if the label
+    /// should be hidden
+    /// - `anchor`: The preferred anchor of the label
+    /// - `size`: The size of the label
+    /// - `pos`: The alignment of the label to its anchor
+    fn place(
+        &self,
+        anchor: BackendCoord,
+        (w, h): (i32, i32),
+        pos: (HPos, VPos),
+    ) -> Option<BackendCoord> {
+        let left = match pos.0 {
+            HPos::Left => anchor.0,
+            HPos::Center => anchor.0 - w / 2,
+            HPos::Right => anchor.0 - w,
+        };
+        let top = match pos.1 {
+            VPos::Top => anchor.1,
+            VPos::Center => anchor.1 - h / 2,
+            VPos::Bottom => anchor.1 - h,
+        };
+
+        let mut placed = self.layout.placed.borrow_mut();
+        for attempt in 0..=self.layout.attempts {
+            let step = (attempt as i32 + 1) / 2;
+            let (dx, dy) = match self.side {
+                LabelSide::Above => (0, -(attempt as i32) * h),
+                LabelSide::Below => (0, attempt as i32 * h),
+                LabelSide::Left | LabelSide::Right if attempt % 2 == 1 => (0, -step * h),
+                LabelSide::Left | LabelSide::Right => (0, step * h),
+            };
+            let (l, t) = (left + dx, top + dy);
+            let (r, b) = (l + w, t + h);
+            let overlapped = placed
+                .iter()
+                .any(|&((pl, pt), (pr, pb))| l < pr && pl < r && t < pb && pt < b);
+            if !overlapped {
+                placed.push(((l, t), (r, b)));
+                return Some((anchor.0 + dx, anchor.1 + dy));
+            }
+        }
+
+        if self.layout.hide_overlapping {
+            None
+        } else {
+            Some(anchor)
+        }
+    }
+}
+
+impl<'a, DB: DrawingBackend, Coord: Clone> Drawable<DB> for ValueLabel<'a, DB, Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = points.collect();
+        self.element
+            .draw(points.iter().copied(), backend, parent_dim)?;
+
+        let (x0, y0, x1, y1) = match points.first() {
+            Some(&(x, y)) => points
+                .iter()
+                .fold((x, y, x, y), |(x0, y0, x1, y1), &(x, y)| {
+                    (x0.min(x), y0.min(y), x1.max(x), y1.max(y))
+                }),
+            None => return Ok(()),
+        };
+        let (cx, cy) = ((x0 + x1) / 2, (y0 + y1) / 2);
+        let offset = self.layout.offset;
+        let (pos, from, anchor) = match self.side {
+            LabelSide::Above => ((HPos::Center, VPos::Bottom), (cx, y0), (cx, y0 - offset)),
+            LabelSide::Below => ((HPos::Center, VPos::Top), (cx, y1), (cx, y1 + offset)),
+            LabelSide::Left => ((HPos::Right, VPos::Center), (x0, cy), (x0 - offset, cy)),
+            LabelSide::Right => ((HPos::Left, VPos::Center), (x1, cy), (x1 + offset, cy)),
+        };
+
+        let mut label_pos = anchor;
+        if self.layout.hide_overlapping || self.layout.attempts > 0 {
+            let ((min_x, min_y), (max_x, max_y)) = self
+                .style
+                .font
+                .layout_box(&self.label)
+                .map_err(|e| DrawingErrorKind::FontError(Box::new(e)))?;
+            label_pos = match self.place(anchor, (max_x - min_x, max_y - min_y), pos) {
+                Some(label_pos) => label_pos,
+                None => return Ok(()),
+            };
+        }
+
+        if let Some(style) = self.layout.leader_style.as_ref() {
+            if label_pos != anchor {
+                style.draw_line(backend, from, label_pos)?;
+            }
+        }
+
+        let style = self.style.pos(Pos::new(pos.0, pos.1));
+        backend.draw_text(&self.label, &style, label_pos)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_value_labels_avoid_collisions() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_text(|_, _, _, pos, _| assert_eq!(pos, (15, 89)));
+            m.check_draw_text(|_, _, _, pos, _| assert!(pos.0 == 15 && pos.1 < 89));
+            m.check_draw_line(|c, _, from, to| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(from, (10, 89));
+                assert!(to.0 == 15 && to.1 < 89);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_text_call, 2);
+                assert_eq!(b.num_draw_line_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+
+        chart
+            .draw_series(
+                PointSeries::<_, _, Pixel<_>, _>::new(vec![(1, 1), (1, 1)], 0, &BLUE)
+                    .with_value_labels(|(x, y)| format!("({}, {})", x, y))
+                    .avoid_collisions(4)
+                    .leader_lines(&RED),
+            )
+            .expect("Drawing error");
+    }
+}
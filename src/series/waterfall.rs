@@ -0,0 +1,268 @@
+use std::iter::{once, Once};
+use std::ops::Add;
+
+use crate::chart::ChartContext;
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::{DiscreteRanged, Ranged};
+use crate::element::{Drawable, DynElement, IntoDynElement, PointCollection, Rectangle};
+use crate::style::{Color, ShapeStyle, BLUE, GREEN, RED};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// A step of the waterfall chart
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WaterfallStep<T> {
+    /// Change the running total by the signed value, which is drawn as a floating bar from the
+    /// previous total to the new total
+    Delta(T),
+    /// Draw the running total as a bar from the baseline, e.g. the subtotal of a period
+    Total,
+}
+
+struct WaterfallBar<X, Y> {
+    left: X,
+    right: X,
+    from: Y,
+    to: Y,
+    total: bool,
+}
+
+/// The line from the end of a bar to the next bar, which crosses the gap between them
+struct Connector<X, Y> {
+    at: (X, Y),
+    gap: i32,
+    style: ShapeStyle,
+}
+
+impl<'a, X, Y> PointCollection<'a, (X, Y)> for &'a Connector<X, Y> {
+    type Point = &'a (X, Y);
+    type IntoIter = Once<&'a (X, Y)>;
+    fn point_iter(self) -> Self::IntoIter {
+        once(&self.at)
+    }
+}
+
+impl<X, Y, DB: DrawingBackend> Drawable<DB> for Connector<X, Y> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        match points.next() {
+            Some((x, y)) => self
+                .style
+                .draw_path(backend, vec![(x - self.gap, y), (x + self.gap, y)]),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The waterfall chart series, which shows how a value is built up by a sequence of increases
+/// and decreases. Each delta is drawn as a floating bar starting at the running total of the
+/// previous steps, and the totals are drawn as bars from the baseline.
+///
+/// The bars are placed on a discrete X axis like [Histogram](struct.Histogram.html), in the
+/// order of the steps.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let root = BitMapBackend::new("plotters-doc-data/waterfall.png", (640, 480))
+///     .into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d((0..5).into_segmented(), 0.0..150.0)
+///     .unwrap();
+///
+/// let steps = vec![
+///     (0, WaterfallStep::Delta(100.0)),
+///     (1, WaterfallStep::Delta(30.0)),
+///     (2, WaterfallStep::Delta(-45.0)),
+///     (3, WaterfallStep::Total),
+/// ];
+/// chart
+///     .draw_series(WaterfallSeries::new(&chart, steps).connectors(&BLACK))
+///     .unwrap();
+/// ```
+pub struct WaterfallSeries<DB: DrawingBackend, X, Y> {
+    bars: std::vec::IntoIter<WaterfallBar<X, Y>>,
+    connector: Option<Connector<X, Y>>,
+    increase_style: ShapeStyle,
+    decrease_style: ShapeStyle,
+    total_style: ShapeStyle,
+    connector_style: Option<ShapeStyle>,
+    margin: u32,
+    _p: std::marker::PhantomData<DB>,
+}
+
+impl<DB: DrawingBackend, X, Y> WaterfallSeries<DB, X, Y>
+where
+    Y: Add<Output = Y> + Default + Clone,
+{
+    /// Create a waterfall series on the chart, the steps whose X values aren't on the axis are
+    /// ignored
+    /// - `parent`: The chart the series is drawn on
+    /// - `data`: The X values and the steps, in order
+    pub fn new<BR, YR, TB, I>(parent: &ChartContext<DB, Cartesian2d<BR, YR>>, data: I) -> Self
+    where
+        BR: DiscreteRanged<ValueType = X>,
+        YR: Ranged<ValueType = Y>,
+        TB: Into<X>,
+        I: IntoIterator<Item = (TB, WaterfallStep<Y>)>,
+    {
+        let br = parent.as_coord_spec().x_spec();
+        let mut total = Y::default();
+        let mut bars = vec![];
+        for (x, step) in data {
+            let idx = match br.index_of(&x.into()) {
+                Some(idx) => idx,
+                None => continue,
+            };
+            let (left, right) = match (br.from_index(idx), br.from_index(idx + 1)) {
+                (Some(left), Some(right)) => (left, right),
+                _ => continue,
+            };
+            let (from, to, is_total) = match step {
+                WaterfallStep::Delta(delta) => {
+                    let from = total.clone();
+                    total = from.clone() + delta;
+                    (from, total.clone(), false)
+                }
+                WaterfallStep::Total => (Y::default(), total.clone(), true),
+            };
+            bars.push(WaterfallBar {
+                left,
+                right,
+                from,
+                to,
+                total: is_total,
+            });
+        }
+
+        Self {
+            bars: bars.into_iter(),
+            connector: None,
+            increase_style: GREEN.filled(),
+            decrease_style: RED.filled(),
+            total_style: BLUE.filled(),
+            connector_style: None,
+            margin: 5,
+            _p: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<DB: DrawingBackend, X, Y> WaterfallSeries<DB, X, Y> {
+    /// Set the style of the bars of the increases
+    pub fn increase_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.increase_style = style.into();
+        self
+    }
+
+    /// Set the style of the bars of the decreases
+    pub fn decrease_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.decrease_style = style.into();
+        self
+    }
+
+    /// Set the style of the bars of the totals
+    pub fn total_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.total_style = style.into();
+        self
+    }
+
+    /// Draw a line from the end of each bar to the next bar, at the level of the running total
+    pub fn connectors<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.connector_style = Some(style.into());
+        self
+    }
+
+    /// Set the margin for each bar
+    pub fn margin(mut self, value: u32) -> Self {
+        self.margin = value;
+        self
+    }
+}
+
+impl<DB, X, Y> Iterator for WaterfallSeries<DB, X, Y>
+where
+    DB: DrawingBackend,
+    X: Clone + 'static,
+    Y: PartialOrd + Clone + 'static,
+{
+    type Item = DynElement<'static, DB, (X, Y)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(connector) = self.connector.take() {
+            return Some(connector.into_dyn());
+        }
+
+        let bar = self.bars.next()?;
+        let style = if bar.total {
+            self.total_style.clone()
+        } else if bar.to >= bar.from {
+            self.increase_style.clone()
+        } else {
+            self.decrease_style.clone()
+        };
+
+        // The connector spans the gap between the bars, which is the margins of both of them
+        let is_last = self.bars.as_slice().is_empty();
+        if let Some(style) = self.connector_style.as_ref().filter(|_| !is_last) {
+            self.connector = Some(Connector {
+                at: (bar.right.clone(), bar.to.clone()),
+                gap: self.margin as i32,
+                style: style.clone(),
+            });
+        }
+
+        let mut rect = Rectangle::new([(bar.left, bar.to), (bar.right, bar.from)], style);
+        rect.set_margin(0, 0, self.margin, self.margin);
+        Some(rect.into_dyn())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_waterfall_series() {
+        let drawing_area = create_mocked_drawing_area(400, 200, |m| {
+            m.check_draw_rect(|c, _, _, u, d| {
+                assert_eq!(c, GREEN.to_rgba());
+                assert_eq!((u.1, d.1), (99, 199));
+            });
+            m.check_draw_rect(|c, _, _, u, d| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!((u.1, d.1), (99, 149));
+            });
+            m.check_draw_rect(|c, _, _, u, d| {
+                assert_eq!(c, BLUE.to_rgba());
+                assert_eq!((u.1, d.1), (149, 199));
+            });
+            m.check_draw_path(|c, _, path| {
+                assert_eq!(c, BLACK.to_rgba());
+                assert_eq!(path[0].1, path[1].1);
+                assert_eq!(path[1].0 - path[0].0, 10);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 3);
+                assert_eq!(b.num_draw_path_call, 2);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d((0..4).into_segmented(), 0..20)
+            .expect("Create chart");
+
+        let steps = vec![
+            (0, WaterfallStep::Delta(10)),
+            (1, WaterfallStep::Delta(-5)),
+            (2, WaterfallStep::Total),
+            (9, WaterfallStep::Delta(100)),
+        ];
+        chart
+            .draw_series(WaterfallSeries::new(&chart, steps).connectors(&BLACK))
+            .expect("Drawing error");
+    }
+}
@@ -0,0 +1,103 @@
+use super::color::{Color, RGBAColor};
+use super::palette::Palette;
+
+/// The mapping from the categories to their colors, which is used to color a categorical series,
+/// such as a choropleth or a scatter plot colored by class, and to build the legend of the
+/// coloring with
+/// [ChartContext::add_category_legend](../chart/struct.ChartContext.html#method.add_category_legend),
+/// so the legend always matches the colors of the data.
+///
+/// The categories keep the order they are added in, which is also the order of the legend.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let species = CategoryColors::from_palette::<Palette99, _>(vec!["setosa", "virginica"]);
+/// assert_eq!(species.color_of(&"virginica"), Some(Palette99::pick(1).to_rgba()));
+/// assert_eq!(species.color_of(&"versicolor"), None);
+/// ```
+#[derive(Clone, Debug)]
+pub struct CategoryColors<T> {
+    entries: Vec<(T, RGBAColor)>,
+}
+
+impl<T: PartialEq> Default for CategoryColors<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: PartialEq> CategoryColors<T> {
+    /// Create an empty mapping
+    pub fn new() -> Self {
+        Self { entries: vec![] }
+    }
+
+    /// Create the mapping which assigns the colors of the palette to the categories in order
+    /// - `categories`: The categories, the duplicated ones are ignored
+    pub fn from_palette<P: Palette, I: IntoIterator<Item = T>>(categories: I) -> Self {
+        let mut colors = Self::new();
+        for category in categories {
+            if colors.color_of(&category).is_none() {
+                let color = P::pick(colors.entries.len()).to_rgba();
+                colors.entries.push((category, color));
+            }
+        }
+        colors
+    }
+
+    /// Set the color of a category, the category is appended if it's not in the mapping yet
+    /// - `category`: The category
+    /// - `color`: The color of the category
+    pub fn set_color<C: Color>(&mut self, category: T, color: C) -> &mut Self {
+        let color = color.to_rgba();
+        match self.entries.iter_mut().find(|(c, _)| *c == category) {
+            Some(entry) => entry.1 = color,
+            None => self.entries.push((category, color)),
+        }
+        self
+    }
+
+    /// Get the color of a category
+    /// - **returns**: The color, or `None` if the category isn't in the mapping
+    pub fn color_of(&self, category: &T) -> Option<RGBAColor> {
+        self.entries
+            .iter()
+            .find(|(c, _)| c == category)
+            .map(|(_, color)| *color)
+    }
+
+    /// Iterate over the categories and their colors in order
+    pub fn iter(&self) -> impl Iterator<Item = (&T, RGBAColor)> {
+        self.entries.iter().map(|(c, color)| (c, *color))
+    }
+
+    /// Get the number of categories
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check if there's no category in the mapping
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::style::{Palette99, RED};
+
+    #[test]
+    fn test_category_colors() {
+        let mut colors = CategoryColors::from_palette::<Palette99, _>(vec!["a", "b", "a", "c"]);
+        assert_eq!(colors.len(), 3);
+        assert_eq!(colors.color_of(&"c"), Some(Palette99::pick(2).to_rgba()));
+
+        colors.set_color("b", RED).set_color("d", RED.mix(0.5));
+        let order: Vec<_> = colors.iter().map(|(c, _)| *c).collect();
+        assert_eq!(order, vec!["a", "b", "c", "d"]);
+        assert_eq!(colors.color_of(&"b"), Some(RED.to_rgba()));
+        assert_eq!(colors.color_of(&"e"), None);
+    }
+}
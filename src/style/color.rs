@@ -98,6 +98,35 @@ impl<P: Palette> Color for PaletteColor<P> {
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
 pub struct RGBColor(pub u8, pub u8, pub u8);
 
+impl RGBColor {
+    /// Get the relative luminance of the color, as defined by WCAG 2.0, which is 0 for black
+    /// and 1 for white
+    pub fn relative_luminance(&self) -> f64 {
+        let linear = |c: u8| {
+            let c = f64::from(c) / 255.0;
+            if c <= 0.03928 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        0.2126 * linear(self.0) + 0.7152 * linear(self.1) + 0.0722 * linear(self.2)
+    }
+
+    /// Pick the text color, either black or white, which has the higher contrast ratio when
+    /// the text is drawn on a background of this color. This is useful for labeling filled
+    /// regions, e.g. the cells of a treemap or a heatmap.
+    pub fn best_contrast_text(&self) -> RGBColor {
+        // Black and white have the same contrast ratio against the background with the
+        // luminance of sqrt(1.05 * 0.05) - 0.05
+        if self.relative_luminance() > 0.179 {
+            RGBColor(0, 0, 0)
+        } else {
+            RGBColor(255, 255, 255)
+        }
+    }
+}
+
 impl BackendStyle for RGBAColor {
     fn color(&self) -> BackendColor {
         self.to_backend_color()
@@ -172,3 +201,18 @@ impl Color for HSLColor {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::style::colors::{BLACK, WHITE};
+
+    #[test]
+    fn test_best_contrast_text() {
+        assert_eq!(RGBColor(255, 255, 0).best_contrast_text(), BLACK);
+        assert_eq!(RGBColor(0, 0, 128).best_contrast_text(), WHITE);
+        assert_eq!(RGBColor(128, 128, 128).best_contrast_text(), BLACK);
+        assert_eq!(RGBColor(100, 100, 100).best_contrast_text(), WHITE);
+        assert!((WHITE.relative_luminance() - 1.0).abs() < 1e-9);
+    }
+}
@@ -1,4 +1,4 @@
-use super::{FontData, FontDataInternal};
+use super::{FontData, FontDataInternal, GlyphOutline};
 use crate::style::text_anchor::Pos;
 use crate::style::{Color, TextStyle};
 
@@ -92,6 +92,7 @@ impl<'a> FontDesc<'a> {
             font: self.clone(),
             color: color.to_backend_color(),
             pos: Pos::default(),
+            background: None,
         }
     }
 
@@ -134,6 +135,36 @@ impl<'a> FontDesc<'a> {
         Ok((w.abs() as u32, h.abs() as u32))
     }
 
+    /// Find the largest font size that the text fits into the box, this is useful when labeling
+    /// the regions with variable size, such as treemap cells.
+    /// - `text`: The text to fit
+    /// - `w`, `h`: The size of the box in pixels
+    /// - **returns**: The largest font size whose `box_size` of the text is within the box, which
+    ///   is close to 0 if the box is too small for the text
+    pub fn fit_into_box(&self, text: &str, w: u32, h: u32) -> FontResult<f64> {
+        const ITERATIONS: usize = 20;
+        let fits = |size: f64| -> FontResult<bool> {
+            let (tw, th) = self.resize(size).box_size(text)?;
+            Ok(tw <= w && th <= h)
+        };
+
+        // The glyphs are rarely taller than twice of the font size, so the text height
+        // bounds the search range
+        let (mut lo, mut hi) = (0.0, f64::from(w.max(h).max(1)) * 2.0);
+        if fits(hi)? {
+            return Ok(hi);
+        }
+        for _ in 0..ITERATIONS {
+            let mid = (lo + hi) / 2.0;
+            if fits(mid)? {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        Ok(lo)
+    }
+
     /// Actually draws a font with a drawing function
     pub fn draw<E, DrawFunc: FnMut(i32, i32, f32) -> Result<(), E>>(
         &self,
@@ -146,6 +177,19 @@ impl<'a> FontDesc<'a> {
             Err(e) => Err(e.clone()),
         }
     }
+
+    /// Get the outlines of the glyphs of the text placed at `(x, y)`, or `None` if the font
+    /// implementation can't provide the outlines
+    pub(crate) fn outline(
+        &self,
+        text: &str,
+        (x, y): (i32, i32),
+    ) -> FontResult<Option<Vec<GlyphOutline>>> {
+        match &self.data {
+            Ok(ref font) => font.outline((x, y), self.size, text),
+            Err(e) => Err(e.clone()),
+        }
+    }
 }
 
 impl<'a> From<&'a str> for FontDesc<'a> {
@@ -195,3 +239,23 @@ impl<'a, T: Into<FontDesc<'a>>> IntoFont<'a> for T {
         self.into()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_fit_into_box() {
+        let font: FontDesc = ("sans-serif", 12).into();
+        let size = font.fit_into_box("Hello", 100, 50).unwrap();
+        assert!(size > 0.0);
+
+        let (w, h) = font.resize(size).box_size("Hello").unwrap();
+        assert!(w <= 100 && h <= 50);
+        let (w, h) = font.resize(size + 1.0).box_size("Hello").unwrap();
+        assert!(w > 100 || h > 50);
+
+        assert!(font.fit_into_box("Hello", 10, 50).unwrap() < size);
+        assert!(font.fit_into_box("Hello", 0, 0).unwrap() < 1.0);
+    }
+}
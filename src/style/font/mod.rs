@@ -10,6 +10,8 @@
 mod ttf;
 #[cfg(all(not(target_arch = "wasm32"), feature = "ttf"))]
 use ttf::FontDataInternal;
+#[cfg(all(not(target_arch = "wasm32"), feature = "ttf"))]
+pub use ttf::{font_cache_stats, register_font, FontCacheStats};
 
 #[cfg(all(not(target_arch = "wasm32"), not(feature = "ttf")))]
 mod naive;
@@ -26,6 +28,9 @@ pub use font_desc::*;
 
 pub type LayoutBox = ((i32, i32), (i32, i32));
 
+/// The closed contours of a single glyph in pixels
+pub(crate) type GlyphOutline = Vec<Vec<(f64, f64)>>;
+
 pub trait FontData: Clone {
     type ErrorType: Sized + std::error::Error + Clone;
     fn new(family: FontFamily, style: FontStyle) -> Result<Self, Self::ErrorType>;
@@ -39,4 +44,15 @@ pub trait FontData: Clone {
     ) -> Result<Result<(), E>, Self::ErrorType> {
         panic!("The font implementation is unable to draw text");
     }
+
+    /// Get the outlines of the glyphs, placed the same way as `draw` places the glyphs. Returns
+    /// `None` if the font implementation has no access to the glyph outlines.
+    fn outline(
+        &self,
+        _pos: (i32, i32),
+        _size: f64,
+        _text: &str,
+    ) -> Result<Option<Vec<GlyphOutline>>, Self::ErrorType> {
+        Ok(None)
+    }
 }
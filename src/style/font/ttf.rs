@@ -2,6 +2,7 @@ use std::borrow::{Borrow, Cow};
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::i32;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, RwLock};
 
 use lazy_static::lazy_static;
@@ -13,16 +14,18 @@ use font_kit::{
     font::Font,
     handle::Handle,
     hinting::HintingOptions,
+    outline::OutlineSink,
     properties::{Properties, Style, Weight},
     source::SystemSource,
 };
 
 use ttf_parser::{Face, GlyphId};
 
+use pathfinder_geometry::line_segment::LineSegment2F;
 use pathfinder_geometry::transform2d::Transform2F;
 use pathfinder_geometry::vector::{Vector2F, Vector2I};
 
-use super::{FontData, FontFamily, FontStyle, LayoutBox};
+use super::{FontData, FontFamily, FontStyle, GlyphOutline, LayoutBox};
 
 type FontResult<T> = Result<T, FontError>;
 
@@ -61,6 +64,53 @@ thread_local! {
 
 const PLACEHOLDER_CHAR: char = '�';
 
+static FONT_LOADS: AtomicUsize = AtomicUsize::new(0);
+static FONT_CACHE_HITS: AtomicUsize = AtomicUsize::new(0);
+static GLYPH_RASTERIZATIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// The statistics of the font loading and the text rendering, which are counted over all the
+/// threads since the program starts. See [font_cache_stats](fn.font_cache_stats.html)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FontCacheStats {
+    /// The number of the fonts loaded from the font data, either from the system fonts or the
+    /// registered fonts
+    pub font_loads: usize,
+    /// The number of the font lookups served by the cache of the loaded fonts
+    pub cache_hits: usize,
+    /// The number of the glyphs rasterized when drawing text
+    pub glyph_rasterizations: usize,
+}
+
+impl FontCacheStats {
+    /// The ratio of the font lookups served by the cache, which is 0 if there's no lookup yet
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.font_loads + self.cache_hits;
+        if lookups == 0 {
+            return 0.0;
+        }
+        self.cache_hits as f64 / lookups as f64
+    }
+}
+
+/// Get the statistics of the font cache. A low hit rate usually means the fonts are loaded
+/// repeatedly by short-lived threads, since the loaded fonts are cached per thread.
+pub fn font_cache_stats() -> FontCacheStats {
+    FontCacheStats {
+        font_loads: FONT_LOADS.load(Ordering::Relaxed),
+        cache_hits: FONT_CACHE_HITS.load(Ordering::Relaxed),
+        glyph_rasterizations: GLYPH_RASTERIZATIONS.load(Ordering::Relaxed),
+    }
+}
+
+fn load_font_handle(handle: &Handle) -> FontResult<FontExt> {
+    let font = handle
+        .load()
+        .map(FontExt::new)
+        .map_err(|e| FontError::FontLoadError(Arc::new(e)))?;
+    FONT_LOADS.fetch_add(1, Ordering::Relaxed);
+    Ok(font)
+}
+
 #[derive(Clone)]
 struct FontExt {
     inner: Font,
@@ -110,13 +160,60 @@ impl std::ops::Deref for FontExt {
     }
 }
 
+/// If the fonts are looked up from the system. In the deterministic mode, only the fonts
+/// registered with `register_font` and the bundled font are used, so that the text is rendered
+/// identically on every platform.
+const USE_SYSTEM_FONTS: bool = !cfg!(feature = "deterministic");
+
+/// The sans-serif font used in the deterministic mode unless another one is registered, which
+/// is DejaVu Sans, see `bundled/LICENSE` for its license
+#[cfg(feature = "deterministic")]
+fn bundled_font() -> Option<&'static [u8]> {
+    Some(include_bytes!("bundled/DejaVuSans.ttf"))
+}
+
+#[cfg(not(feature = "deterministic"))]
+fn bundled_font() -> Option<&'static [u8]> {
+    None
+}
+
+fn font_key<'a>(face: &'a str, style: FontStyle) -> Cow<'a, str> {
+    match style {
+        FontStyle::Normal => Cow::Borrowed(face),
+        _ => Cow::Owned(format!("{}, {}", face, style.as_str())),
+    }
+}
+
+/// Register a font from the TrueType or OpenType font data in memory. The registered font takes
+/// precedence over the system font with the same family name and style.
+///
+/// The fonts should be registered before any text is drawn, since the fonts that have already
+/// been loaded by other threads are not affected.
+///
+/// - `family`: The font family name, e.g. `"sans-serif"`
+/// - `style`: The font style
+/// - `data`: The font file content
+pub fn register_font(family: &str, style: FontStyle, data: &[u8]) -> FontResult<()> {
+    let handle = Handle::from_memory(Arc::new(data.to_vec()), 0);
+    handle
+        .load()
+        .map_err(|e| FontError::FontLoadError(Arc::new(e)))?;
+
+    let key = font_key(family, style).into_owned();
+    FONT_OBJECT_CACHE.with(|font_object_cache| {
+        font_object_cache.borrow_mut().remove(&key);
+    });
+    DATA_CACHE
+        .write()
+        .map_err(|_| FontError::LockError)?
+        .insert(key, Ok(handle));
+    Ok(())
+}
+
 /// Lazily load font data. Font type doesn't own actual data, which
 /// lives in the cache.
 fn load_font_data(face: FontFamily, style: FontStyle) -> FontResult<FontExt> {
-    let key = match style {
-        FontStyle::Normal => Cow::Borrowed(face.as_str()),
-        _ => Cow::Owned(format!("{}, {}", face.as_str(), style.as_str())),
-    };
+    let key = font_key(face.as_str(), style);
 
     // First, we try to find the font object for current thread
     if let Some(font_object) = FONT_OBJECT_CACHE.with(|font_object_cache| {
@@ -125,21 +222,55 @@ fn load_font_data(face: FontFamily, style: FontStyle) -> FontResult<FontExt> {
             .get(Borrow::<str>::borrow(&key))
             .map(Clone::clone)
     }) {
+        FONT_CACHE_HITS.fetch_add(1, Ordering::Relaxed);
         return Ok(font_object);
     }
 
     // Then we need to check if the data cache contains the font data
     let cache = DATA_CACHE.read().unwrap();
     if let Some(data) = cache.get(Borrow::<str>::borrow(&key)) {
-        return data.clone().map(|handle| {
-            handle
-                .load()
-                .map(FontExt::new)
-                .map_err(|e| FontError::FontLoadError(Arc::new(e)))
-        })?;
+        let font = data
+            .as_ref()
+            .map_err(Clone::clone)
+            .and_then(load_font_handle)?;
+        FONT_OBJECT_CACHE.with(|font_object_cache| {
+            font_object_cache
+                .borrow_mut()
+                .insert(key.into_owned(), font.clone());
+        });
+        return Ok(font);
     }
     drop(cache);
 
+    if !USE_SYSTEM_FONTS {
+        // Fall back to the registered sans-serif font, then to its normal style, and finally
+        // to the bundled font
+        let font = match (&face, &style) {
+            (FontFamily::SansSerif, FontStyle::Normal) => {
+                let data = bundled_font().ok_or_else(|| {
+                    FontError::NoSuchFont(face.as_str().to_owned(), style.as_str().to_owned())
+                })?;
+                register_font(face.as_str(), style, data)?;
+                return load_font_data(face, style);
+            }
+            (FontFamily::SansSerif, _) => load_font_data(FontFamily::SansSerif, FontStyle::Normal)?,
+            _ => load_font_data(FontFamily::SansSerif, style)?,
+        };
+        // Cache the fallback under the requested font, so it's only resolved once
+        if let Some(handle) = font.handle() {
+            DATA_CACHE
+                .write()
+                .map_err(|_| FontError::LockError)?
+                .insert(key.clone().into_owned(), Ok(handle));
+        }
+        FONT_OBJECT_CACHE.with(|font_object_cache| {
+            font_object_cache
+                .borrow_mut()
+                .insert(key.into_owned(), font.clone());
+        });
+        return Ok(font);
+    }
+
     // Otherwise we should load from system
     let mut properties = Properties::new();
     match style {
@@ -162,10 +293,7 @@ fn load_font_data(face: FontFamily, style: FontStyle) -> FontResult<FontExt> {
     if let Ok(handle) = FONT_SOURCE
         .with(|source| source.select_best_match(&[family, FamilyName::SansSerif], &properties))
     {
-        let font = handle
-            .load()
-            .map(FontExt::new)
-            .map_err(|e| FontError::FontLoadError(Arc::new(e)));
+        let font = load_font_handle(&handle);
         let (should_cache, data) = match font.as_ref().map(|f| f.handle()) {
             Ok(None) => (false, Err(FontError::LockError)),
             Ok(Some(handle)) => (true, Ok(handle)),
@@ -195,6 +323,66 @@ fn load_font_data(face: FontFamily, style: FontStyle) -> FontResult<FontExt> {
 #[derive(Clone)]
 pub struct FontDataInternal(FontExt);
 
+/// The number of the segments a Bezier curve of the glyph outline is flattened into
+const CURVE_SEGMENTS: usize = 8;
+
+/// Collects the glyph outline as the flattened contours in pixels. The outline is in the font
+/// unit with the y axis pointing up, so it's scaled and flipped around the baseline.
+struct ContourSink {
+    origin: (f64, f64),
+    scale: f64,
+    contours: GlyphOutline,
+    last: Vector2F,
+}
+
+impl ContourSink {
+    fn push(&mut self, to: Vector2F) {
+        let point = (
+            self.origin.0 + f64::from(to.x()) * self.scale,
+            self.origin.1 - f64::from(to.y()) * self.scale,
+        );
+        if let Some(contour) = self.contours.last_mut() {
+            contour.push(point);
+        }
+        self.last = to;
+    }
+
+    fn push_curve<F: Fn(f32) -> Vector2F>(&mut self, curve: F) {
+        for i in 1..=CURVE_SEGMENTS {
+            self.push(curve(i as f32 / CURVE_SEGMENTS as f32));
+        }
+    }
+}
+
+impl OutlineSink for ContourSink {
+    fn move_to(&mut self, to: Vector2F) {
+        self.contours.push(vec![]);
+        self.push(to);
+    }
+
+    fn line_to(&mut self, to: Vector2F) {
+        self.push(to);
+    }
+
+    fn quadratic_curve_to(&mut self, ctrl: Vector2F, to: Vector2F) {
+        let from = self.last;
+        self.push_curve(|t| {
+            from * ((1.0 - t) * (1.0 - t)) + ctrl * (2.0 * t * (1.0 - t)) + to * (t * t)
+        });
+    }
+
+    fn cubic_curve_to(&mut self, ctrl: LineSegment2F, to: Vector2F) {
+        let from = self.last;
+        let (c0, c1) = (ctrl.from(), ctrl.to());
+        self.push_curve(|t| {
+            let s = 1.0 - t;
+            from * (s * s * s) + c0 * (3.0 * s * s * t) + c1 * (3.0 * s * t * t) + to * (t * t * t)
+        });
+    }
+
+    fn close(&mut self) {}
+}
+
 impl FontData for FontDataInternal {
     type ErrorType = FontError;
 
@@ -209,7 +397,7 @@ impl FontData for FontDataInternal {
 
         let font = &self.0;
 
-        let mut x_in_unit = 0f32;
+        let mut x_in_unit = 0f64;
 
         let mut prev = None;
         let place_holder = font.glyph_for_char(PLACEHOLDER_CHAR);
@@ -217,16 +405,16 @@ impl FontData for FontDataInternal {
         for c in text.chars() {
             if let Some(glyph_id) = font.glyph_for_char(c).or(place_holder) {
                 if let Ok(size) = font.advance(glyph_id) {
-                    x_in_unit += size.x();
+                    x_in_unit += f64::from(size.x());
                 }
                 if let Some(pc) = prev {
-                    x_in_unit += font.query_kerning_table(pc, glyph_id);
+                    x_in_unit += f64::from(font.query_kerning_table(pc, glyph_id));
                 }
                 prev = Some(glyph_id);
             }
         }
 
-        let x_pixels = x_in_unit * pixel_per_em as f32 / metrics.units_per_em as f32;
+        let x_pixels = x_in_unit * pixel_per_em / f64::from(metrics.units_per_em);
 
         Ok(((0, 0), (x_pixels as i32, pixel_per_em as i32)))
     }
@@ -238,9 +426,11 @@ impl FontData for FontDataInternal {
         text: &str,
         mut draw: DrawFunc,
     ) -> Result<Result<(), E>, Self::ErrorType> {
-        let em = (size / 1.24) as f32;
+        // The glyphs are rasterized with the f32 scale required by the font library, but the pen
+        // position is accumulated in f64, so long labels don't drift
+        let em = size / 1.24;
 
-        let mut x = base_x as f32;
+        let mut x = f64::from(base_x);
         let font = &self.0;
         let metrics = font.metrics();
 
@@ -256,10 +446,12 @@ impl FontData for FontDataInternal {
         for c in text.chars() {
             if let Some(glyph_id) = font.glyph_for_char(c).or(place_holder) {
                 if let Some(pc) = prev {
-                    x += font.query_kerning_table(pc, glyph_id) * em / metrics.units_per_em as f32;
+                    x += f64::from(font.query_kerning_table(pc, glyph_id)) * em
+                        / f64::from(metrics.units_per_em);
                 }
 
                 let mut canvas = Canvas::new(Vector2I::splat(canvas_size as i32), Format::A8);
+                GLYPH_RASTERIZATIONS.fetch_add(1, Ordering::Relaxed);
 
                 result = font
                     .rasterize_glyph(
@@ -284,8 +476,8 @@ impl FontData for FontDataInternal {
                     }
                 }
 
-                x += font.advance(glyph_id).map(|size| size.x()).unwrap_or(0.0) * em
-                    / metrics.units_per_em as f32;
+                x += f64::from(font.advance(glyph_id).map(|size| size.x()).unwrap_or(0.0)) * em
+                    / f64::from(metrics.units_per_em);
 
                 prev = Some(glyph_id);
             }
@@ -293,6 +485,52 @@ impl FontData for FontDataInternal {
         result?;
         Ok(Ok(()))
     }
+
+    fn outline(
+        &self,
+        (base_x, base_y): (i32, i32),
+        size: f64,
+        text: &str,
+    ) -> Result<Option<Vec<GlyphOutline>>, Self::ErrorType> {
+        let em = size / 1.24;
+
+        let mut x = f64::from(base_x);
+        let font = &self.0;
+        let metrics = font.metrics();
+        let scale = em / f64::from(metrics.units_per_em);
+
+        // The same baseline as the rasterized glyphs in `draw`
+        let baseline = f64::from(base_y - (0.24 * em) as i32) + em;
+
+        let mut prev = None;
+        let place_holder = font.glyph_for_char(PLACEHOLDER_CHAR);
+
+        let mut glyphs = vec![];
+
+        for c in text.chars() {
+            if let Some(glyph_id) = font.glyph_for_char(c).or(place_holder) {
+                if let Some(pc) = prev {
+                    x += f64::from(font.query_kerning_table(pc, glyph_id)) * scale;
+                }
+
+                let mut sink = ContourSink {
+                    origin: (x, baseline),
+                    scale,
+                    contours: vec![],
+                    last: Vector2F::zero(),
+                };
+                font.outline(glyph_id, HintingOptions::None, &mut sink)
+                    .map_err(|e| FontError::GlyphError(Arc::new(e)))?;
+                glyphs.push(sink.contours);
+
+                let advance = font.advance(glyph_id).map(|size| size.x()).unwrap_or(0.0);
+                x += f64::from(advance) * scale;
+
+                prev = Some(glyph_id);
+            }
+        }
+        Ok(Some(glyphs))
+    }
 }
 
 #[cfg(test)]
@@ -315,4 +553,62 @@ mod test {
 
         return Ok(());
     }
+
+    #[test]
+    fn test_font_cache_stats() -> FontResult<()> {
+        // The counters are shared by the tests running in parallel, so only the lower bounds
+        // of the changes are checked
+        let _a = load_font_data(FontFamily::Monospace, FontStyle::Normal)?;
+        let before = font_cache_stats();
+        let font = FontDataInternal(load_font_data(FontFamily::Monospace, FontStyle::Normal)?);
+        font.draw((0, 0), 12.0, "ab", |_, _, _| Ok::<(), ()>(()))?
+            .unwrap();
+        let after = font_cache_stats();
+
+        assert!(after.cache_hits > before.cache_hits);
+        assert!(after.glyph_rasterizations >= before.glyph_rasterizations + 2);
+        assert!(after.hit_rate() > 0.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_outline() -> FontResult<()> {
+        let font = FontDataInternal(load_font_data(FontFamily::SansSerif, FontStyle::Normal)?);
+        let glyphs = font.outline((10, 20), 20.0, "Ho")?.unwrap();
+        assert_eq!(glyphs.len(), 2);
+
+        let ((_, top), (width, bottom)) = font.estimate_layout(20.0, "Ho")?;
+        for contour in glyphs.iter().flatten() {
+            assert!(contour.len() >= 3);
+            for &(x, y) in contour {
+                assert!(x >= 9.0 && x <= f64::from(11 + width));
+                assert!(y >= f64::from(19 + top) && y <= f64::from(21 + bottom));
+            }
+        }
+        // The ring of the letter "o" has the outer and the inner contours
+        assert_eq!(glyphs[1].len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_register_font() -> FontResult<()> {
+        let font = load_font_data(FontFamily::Serif, FontStyle::Normal)?;
+        let data = match font.handle() {
+            Some(Handle::Memory { bytes, .. }) => bytes,
+            _ => unreachable!(),
+        };
+
+        register_font("plotters-registered", FontStyle::Bold, &data)?;
+        assert!(DATA_CACHE
+            .read()
+            .unwrap()
+            .contains_key("plotters-registered, bold"));
+        let _font = load_font_data(FontFamily::Name("plotters-registered"), FontStyle::Bold)?;
+
+        assert!(register_font("plotters-invalid", FontStyle::Normal, &[0, 1, 2]).is_err());
+
+        Ok(())
+    }
 }
@@ -1,6 +1,7 @@
 /*!
   The style for shapes and text, font, color, etc.
 */
+mod category;
 mod color;
 pub mod colors;
 mod font;
@@ -11,16 +12,19 @@ mod text;
 
 /// Definitions of palettes of accessibility
 pub use self::palette::*;
+pub use category::CategoryColors;
 pub use color::{Color, HSLColor, PaletteColor, RGBAColor, RGBColor};
 pub use colors::{BLACK, BLUE, CYAN, GREEN, MAGENTA, RED, TRANSPARENT, WHITE, YELLOW};
 
 #[cfg(feature = "full_palette")]
 pub use colors::full_palette;
 
+#[cfg(all(not(target_arch = "wasm32"), feature = "ttf"))]
+pub use font::{font_cache_stats, register_font, FontCacheStats};
 pub use font::{
     FontDesc, FontError, FontFamily, FontResult, FontStyle, FontTransform, IntoFont, LayoutBox,
 };
-pub use shape::ShapeStyle;
+pub use shape::{DataStrokeWidth, ShapeStyle};
 pub use size::{AsRelative, RelativeSize, SizeDesc};
 pub use text::text_anchor;
-pub use text::{IntoTextStyle, TextStyle};
+pub use text::{IntoTextStyle, TextBackground, TextStyle};
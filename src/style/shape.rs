@@ -1,31 +1,230 @@
 use super::color::{Color, RGBAColor};
-use plotters_backend::{BackendColor, BackendStyle};
+use plotters_backend::{
+    BackendColor, BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind,
+};
+
+/// The stroke width measured in the data units along one of the axes, so that the stroke
+/// scales with the axis range, e.g. for schematic diagrams where the lines have a physical
+/// extent. See
+/// [ChartContext::pixel_stroke_width](../chart/struct.ChartContext.html#method.pixel_stroke_width)
+/// for the conversion to the pixel width of a style.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DataStrokeWidth {
+    /// The width in the units of the X axis
+    X(f64),
+    /// The width in the units of the Y axis
+    Y(f64),
+}
+
+impl DataStrokeWidth {
+    /// Convert the stroke width in data units to pixels
+    /// - `pixels_per_unit`: The number of pixels per data unit along the X and Y axes, see
+    ///   [Cartesian2d::pixels_per_unit](../coord/cartesian/struct.Cartesian2d.html#method.pixels_per_unit)
+    /// - **returns**: The stroke width in pixels, which is at least 1
+    pub fn to_pixels(&self, (x_scale, y_scale): (f64, f64)) -> u32 {
+        let pixels = match *self {
+            DataStrokeWidth::X(width) => width * x_scale,
+            DataStrokeWidth::Y(width) => width * y_scale,
+        };
+        pixels.abs().round().max(1.0) as u32
+    }
+}
 
 /// Style for any of shape
-#[derive(Clone)]
+///
+/// The style can be built fluently from a color, for example
+/// `ShapeStyle::from(&RED).stroke_width(2).dashed(4, 2)`.
+#[derive(Clone, Debug)]
 pub struct ShapeStyle {
     pub color: RGBAColor,
     pub filled: bool,
     pub stroke_width: u32,
+    dash: Option<(u32, u32)>,
 }
 
 impl ShapeStyle {
     /// Make a filled shape style
     pub fn filled(&self) -> Self {
         Self {
-            color: self.color.to_rgba(),
             filled: true,
-            stroke_width: self.stroke_width,
+            ..self.clone()
         }
     }
 
+    /// Make a shape style that only draws the outline
+    pub fn outlined(&self) -> Self {
+        Self {
+            filled: false,
+            ..self.clone()
+        }
+    }
+
+    /// Change the stroke width in pixels
     pub fn stroke_width(&self, width: u32) -> Self {
         Self {
-            color: self.color.to_rgba(),
-            filled: self.filled,
             stroke_width: width,
+            ..self.clone()
+        }
+    }
+
+    /// Change the color
+    pub fn color<C: Color>(&self, color: &C) -> Self {
+        Self {
+            color: color.to_rgba(),
+            ..self.clone()
+        }
+    }
+
+    /// Make the stroke dashed. The dash pattern is honored by all the elements drawing the
+    /// outlines and the lines, the filled parts are unaffected.
+    /// - `dash`: The length of the dashes in pixels
+    /// - `gap`: The length of the gaps between the dashes in pixels
+    pub fn dashed(&self, dash: u32, gap: u32) -> Self {
+        Self {
+            dash: Some((dash, gap)),
+            ..self.clone()
+        }
+    }
+
+    /// Get the dash pattern of the stroke, which is the lengths of the dashes and the gaps in
+    /// pixels, or `None` for a solid stroke
+    pub fn dash_pattern(&self) -> Option<(u32, u32)> {
+        self.dash
+    }
+
+    /// Make the stroke solid
+    pub fn solid(&self) -> Self {
+        Self {
+            dash: None,
+            ..self.clone()
+        }
+    }
+}
+
+/// The stroking of the shapes with the dash pattern applied. The filled shapes and the solid
+/// strokes are drawn by the backend directly.
+impl ShapeStyle {
+    pub(crate) fn draw_line<DB: DrawingBackend>(
+        &self,
+        backend: &mut DB,
+        from: BackendCoord,
+        to: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        match self.dash {
+            Some(_) => self.draw_path(backend, vec![from, to]),
+            None => backend.draw_line(from, to, self),
         }
     }
+
+    pub(crate) fn draw_path<DB: DrawingBackend, I: IntoIterator<Item = BackendCoord>>(
+        &self,
+        backend: &mut DB,
+        path: I,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        match self.dash {
+            Some((dash, gap)) => {
+                let points: Vec<_> = path.into_iter().collect();
+                for dash in split_dashes(&points, &[f64::from(dash), f64::from(gap)]) {
+                    backend.draw_path(dash, self)?;
+                }
+                Ok(())
+            }
+            None => backend.draw_path(path, self),
+        }
+    }
+
+    pub(crate) fn draw_rect<DB: DrawingBackend>(
+        &self,
+        backend: &mut DB,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if fill || self.dash.is_none() {
+            return backend.draw_rect(upper_left, bottom_right, self, fill);
+        }
+        let ((x0, y0), (x1, y1)) = (upper_left, bottom_right);
+        self.draw_path(
+            backend,
+            vec![(x0, y0), (x1, y0), (x1, y1), (x0, y1), (x0, y0)],
+        )
+    }
+
+    pub(crate) fn draw_circle<DB: DrawingBackend>(
+        &self,
+        backend: &mut DB,
+        center: BackendCoord,
+        radius: u32,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if fill || self.dash.is_none() || radius == 0 {
+            return backend.draw_circle(center, radius, self, fill);
+        }
+        // Approximate the circle with a polygon whose edges are about 2 pixels long
+        let radius = f64::from(radius);
+        let n = ((radius * std::f64::consts::PI).ceil() as usize).max(8);
+        let outline = (0..=n).map(|idx| {
+            let angle = 2.0 * std::f64::consts::PI * idx as f64 / n as f64;
+            (
+                center.0 + (radius * angle.cos()).round() as i32,
+                center.1 + (radius * angle.sin()).round() as i32,
+            )
+        });
+        self.draw_path(backend, outline)
+    }
+}
+
+/// Split a polyline into the dashes described by the dash pattern
+fn split_dashes(points: &[BackendCoord], pattern: &[f64]) -> Vec<Vec<BackendCoord>> {
+    if pattern.iter().any(|len| *len < 0.0) || pattern.iter().sum::<f64>() <= 0.0 {
+        return vec![points.to_vec()];
+    }
+
+    let mut dashes = vec![];
+    let mut current = vec![];
+    let (mut idx, mut remaining, mut drawing) = (0, pattern[0], true);
+
+    for segment in points.windows(2) {
+        let (from, to) = (segment[0], segment[1]);
+        let (dx, dy) = (f64::from(to.0 - from.0), f64::from(to.1 - from.1));
+        let len = (dx * dx + dy * dy).sqrt();
+        if len == 0.0 {
+            continue;
+        }
+        if drawing && current.is_empty() {
+            current.push(from);
+        }
+
+        let mut pos = 0.0;
+        while len - pos > remaining {
+            pos += remaining;
+            let point = (
+                (f64::from(from.0) + dx * pos / len).round() as i32,
+                (f64::from(from.1) + dy * pos / len).round() as i32,
+            );
+            if current.last() != Some(&point) {
+                current.push(point);
+            }
+            if drawing && current.len() > 1 {
+                dashes.push(std::mem::replace(&mut current, vec![]));
+            } else if drawing {
+                current.clear();
+            }
+            drawing = !drawing;
+            idx = (idx + 1) % pattern.len();
+            remaining = pattern[idx];
+        }
+        remaining -= len - pos;
+
+        if drawing {
+            current.push(to);
+        }
+    }
+
+    if current.len() > 1 {
+        dashes.push(current);
+    }
+    dashes
 }
 
 impl<T: Color> From<T> for ShapeStyle {
@@ -34,6 +233,7 @@ impl<T: Color> From<T> for ShapeStyle {
             color: f.to_rgba(),
             filled: false,
             stroke_width: 1,
+            dash: None,
         }
     }
 }
@@ -46,3 +246,32 @@ impl BackendStyle for ShapeStyle {
         self.stroke_width
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_split_dashes() {
+        assert_eq!(
+            split_dashes(&[(0, 0), (10, 0), (10, 10)], &[4.0, 2.0]),
+            vec![
+                vec![(0, 0), (4, 0)],
+                vec![(6, 0), (10, 0)],
+                vec![(10, 2), (10, 6)],
+                vec![(10, 8), (10, 10)],
+            ]
+        );
+        assert_eq!(
+            split_dashes(&[(0, 0), (10, 0)], &[0.0, 0.0]),
+            vec![vec![(0, 0), (10, 0)]]
+        );
+    }
+
+    #[test]
+    fn test_data_stroke_width() {
+        assert_eq!(DataStrokeWidth::X(0.5).to_pixels((10.0, 20.0)), 5);
+        assert_eq!(DataStrokeWidth::Y(0.5).to_pixels((10.0, 20.0)), 10);
+        assert_eq!(DataStrokeWidth::Y(0.01).to_pixels((10.0, 20.0)), 1);
+    }
+}
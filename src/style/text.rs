@@ -1,5 +1,6 @@
-use super::color::Color;
+use super::color::{Color, RGBAColor};
 use super::font::{FontDesc, FontError, FontFamily, FontStyle, FontTransform};
+use super::shape::ShapeStyle;
 use super::size::{HasDimension, SizeDesc};
 use super::BLACK;
 pub use plotters_backend::text_anchor;
@@ -14,6 +15,52 @@ pub struct TextStyle<'a> {
     pub color: BackendColor,
     /// The anchor point position
     pub pos: text_anchor::Pos,
+    /// The background box drawn behind the text, set with [background](#method.background)
+    pub(crate) background: Option<TextBackground>,
+}
+
+/// The background box of a text, which is sized to the bounding box of the text
+#[derive(Clone, Debug)]
+pub struct TextBackground {
+    /// The fill color of the box
+    pub fill: RGBAColor,
+    /// The style of the border, no border is drawn if this is `None`
+    pub border: Option<ShapeStyle>,
+    /// The space between the text and the border of the box in pixels
+    pub padding: u32,
+    /// The radius of the rounded corners in pixels
+    pub corner_radius: u32,
+}
+
+impl TextBackground {
+    /// Create a new text background box without border, padding and rounded corners
+    /// - `fill`: The fill color of the box
+    pub fn new<C: Color>(fill: &C) -> Self {
+        Self {
+            fill: fill.to_rgba(),
+            border: None,
+            padding: 0,
+            corner_radius: 0,
+        }
+    }
+
+    /// Set the padding between the text and the border of the box
+    pub fn padding(mut self, padding: u32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Set the border style of the box
+    pub fn border<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.border = Some(style.into());
+        self
+    }
+
+    /// Set the corner radius of the box
+    pub fn corner_radius(mut self, radius: u32) -> Self {
+        self.corner_radius = radius;
+        self
+    }
 }
 pub trait IntoTextStyle<'a> {
     fn into_text_style<P: HasDimension>(self, parent: &P) -> TextStyle<'a>;
@@ -79,6 +126,7 @@ impl<'a> TextStyle<'a> {
             font: self.font.clone(),
             color: color.to_backend_color(),
             pos: self.pos,
+            background: self.background.clone(),
         }
     }
 
@@ -97,6 +145,7 @@ impl<'a> TextStyle<'a> {
             font: self.font.clone().transform(trans),
             color: self.color,
             pos: self.pos,
+            background: self.background.clone(),
         }
     }
 
@@ -117,6 +166,27 @@ impl<'a> TextStyle<'a> {
             font: self.font.clone(),
             color: self.color,
             pos,
+            background: self.background.clone(),
+        }
+    }
+
+    /// Sets the background box drawn behind the text.
+    ///
+    /// - `background`: The description of the background box
+    /// - **returns** The up-to-dated text style
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let style = TextStyle::from(("sans-serif", 20).into_font())
+    ///     .background(TextBackground::new(&WHITE).padding(3).border(&BLACK));
+    /// ```
+    pub fn background(&self, background: TextBackground) -> Self {
+        Self {
+            font: self.font.clone(),
+            color: self.color,
+            pos: self.pos,
+            background: Some(background),
         }
     }
 }
@@ -202,6 +272,7 @@ impl<'a, T: Into<FontDesc<'a>>> From<T> for TextStyle<'a> {
             font: font.into(),
             color: BLACK.to_backend_color(),
             pos: text_anchor::Pos::default(),
+            background: None,
         }
     }
 }
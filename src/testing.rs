@@ -0,0 +1,260 @@
+/*!
+  The helpers for regression testing the rendered charts against reference images.
+
+  The chart is rendered into an in-memory bitmap and compared with a stored PNG file pixel by
+  pixel. Small rendering differences, e.g. from the anti-aliasing, can be accepted with a
+  per-channel tolerance.
+
+  ```rust,no_run
+  use plotters::prelude::*;
+  use plotters::testing::GoldenImage;
+
+  GoldenImage::new("tests/golden/circle.png")
+      .tolerance(2)
+      .diff_output("target/circle-diff.png")
+      .assert_matches((100, 100), |root| {
+          root.fill(&WHITE)?;
+          root.draw(&Circle::new((50, 50), 20, RED.filled()))?;
+          Ok(())
+      });
+  ```
+*/
+use crate::coord::Shift;
+use crate::drawing::{DrawingArea, IntoDrawingArea};
+use plotters_bitmap::BitMapBackend;
+
+use image::{ImageError, RgbImage};
+
+use std::error::Error;
+use std::path::{Path, PathBuf};
+
+/// The error that occurs when comparing the rendered image with the reference image
+#[derive(Debug)]
+pub enum GoldenImageError {
+    /// The reference image can not be loaded or the diff image can not be written
+    Image(ImageError),
+    /// The rendered image has a different size from the reference image
+    SizeMismatch {
+        /// The size of the reference image
+        expected: (u32, u32),
+        /// The size of the rendered image
+        actual: (u32, u32),
+    },
+    /// The drawing code has failed
+    Drawing(Box<dyn Error>),
+}
+
+impl std::fmt::Display for GoldenImageError {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            GoldenImageError::Image(e) => write!(fmt, "Image error: {}", e),
+            GoldenImageError::SizeMismatch { expected, actual } => write!(
+                fmt,
+                "Size mismatch: expected {:?}, got {:?}",
+                expected, actual
+            ),
+            GoldenImageError::Drawing(e) => write!(fmt, "Drawing error: {}", e),
+        }
+    }
+}
+
+impl Error for GoldenImageError {}
+
+impl From<ImageError> for GoldenImageError {
+    fn from(e: ImageError) -> Self {
+        GoldenImageError::Image(e)
+    }
+}
+
+/// The result of comparing the rendered image with the reference image
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImageDiff {
+    /// The maximum difference of any color channel
+    pub max_diff: u8,
+    /// The mean difference over all the color channels
+    pub mean_diff: f64,
+    /// The number of pixels with any channel differs more than the tolerance
+    pub mismatched_pixels: usize,
+}
+
+impl ImageDiff {
+    /// Check if the rendered image matches the reference image within the tolerance
+    pub fn is_match(&self) -> bool {
+        self.mismatched_pixels == 0
+    }
+}
+
+/// A reference image that the rendered charts are compared against
+pub struct GoldenImage {
+    path: PathBuf,
+    tolerance: u8,
+    diff_path: Option<PathBuf>,
+}
+
+impl GoldenImage {
+    /// Create a new golden image comparison
+    /// - `path`: The path to the reference PNG file
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            tolerance: 0,
+            diff_path: None,
+        }
+    }
+
+    /// Set the maximum difference allowed for each color channel of a pixel
+    pub fn tolerance(mut self, tolerance: u8) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Write an image highlighting the mismatched pixels to the given path when the comparison
+    /// fails
+    pub fn diff_output<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.diff_path = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Render the chart into a bitmap and compare it with the reference image
+    /// - `size`: The size of the bitmap
+    /// - `draw`: The drawing code
+    pub fn compare<
+        Draw: FnOnce(DrawingArea<BitMapBackend, Shift>) -> Result<(), Box<dyn Error>>,
+    >(
+        &self,
+        size: (u32, u32),
+        draw: Draw,
+    ) -> Result<ImageDiff, GoldenImageError> {
+        let mut buffer = vec![0; (size.0 * size.1 * 3) as usize];
+        {
+            let root = BitMapBackend::with_buffer(&mut buffer, size).into_drawing_area();
+            draw(root).map_err(GoldenImageError::Drawing)?;
+        }
+        self.compare_buffer(&buffer, size)
+    }
+
+    /// Compare a RGB buffer with the reference image
+    /// - `buffer`: The RGB pixels of the rendered image
+    /// - `size`: The size of the rendered image
+    pub fn compare_buffer(
+        &self,
+        buffer: &[u8],
+        size: (u32, u32),
+    ) -> Result<ImageDiff, GoldenImageError> {
+        let golden = image::open(&self.path)?.to_rgb8();
+        if golden.dimensions() != size {
+            return Err(GoldenImageError::SizeMismatch {
+                expected: golden.dimensions(),
+                actual: size,
+            });
+        }
+        let golden = golden.into_raw();
+
+        let mut diff = ImageDiff {
+            max_diff: 0,
+            mean_diff: 0.0,
+            mismatched_pixels: 0,
+        };
+        let mut diff_image = vec![0; buffer.len()];
+        let mut total = 0u64;
+
+        for (idx, (actual, expected)) in buffer.chunks(3).zip(golden.chunks(3)).enumerate() {
+            let pixel_diff = actual
+                .iter()
+                .zip(expected)
+                .map(|(a, e)| (i32::from(*a) - i32::from(*e)).abs() as u8)
+                .fold(0, |acc, d| {
+                    total += u64::from(d);
+                    acc.max(d)
+                });
+            diff.max_diff = diff.max_diff.max(pixel_diff);
+
+            let out = &mut diff_image[idx * 3..idx * 3 + 3];
+            if pixel_diff > self.tolerance {
+                diff.mismatched_pixels += 1;
+                out.copy_from_slice(&[255, 0, 0]);
+            } else {
+                let gray = (expected.iter().map(|&c| u32::from(c)).sum::<u32>() / 9) as u8;
+                out.copy_from_slice(&[gray, gray, gray]);
+            }
+        }
+
+        if !buffer.is_empty() {
+            diff.mean_diff = total as f64 / buffer.len() as f64;
+        }
+
+        if let Some(diff_path) = self.diff_path.as_ref() {
+            if !diff.is_match() {
+                if let Some(diff_image) = RgbImage::from_raw(size.0, size.1, diff_image) {
+                    diff_image.save(diff_path)?;
+                }
+            }
+        }
+
+        Ok(diff)
+    }
+
+    /// Render the chart and panic if it doesn't match the reference image
+    /// - `size`: The size of the bitmap
+    /// - `draw`: The drawing code
+    pub fn assert_matches<
+        Draw: FnOnce(DrawingArea<BitMapBackend, Shift>) -> Result<(), Box<dyn Error>>,
+    >(
+        &self,
+        size: (u32, u32),
+        draw: Draw,
+    ) {
+        match self.compare(size, draw) {
+            Ok(diff) if diff.is_match() => {}
+            Ok(diff) => panic!(
+                "Image mismatch with {}: {} pixels differ, max diff {}, mean diff {:.4}",
+                self.path.display(),
+                diff.mismatched_pixels,
+                diff.max_diff,
+                diff.mean_diff
+            ),
+            Err(e) => panic!(
+                "Image comparison with {} failed: {}",
+                self.path.display(),
+                e
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_golden_image() {
+        let path = std::env::temp_dir().join("plotters-golden-image-test.png");
+        let size = (20, 10);
+        let mut buffer = vec![0; 20 * 10 * 3];
+        {
+            let root = BitMapBackend::with_buffer(&mut buffer, size).into_drawing_area();
+            root.fill(&WHITE).unwrap();
+            root.draw_pixel((3, 4), &RED).unwrap();
+        }
+        RgbImage::from_raw(size.0, size.1, buffer.clone())
+            .unwrap()
+            .save(&path)
+            .unwrap();
+
+        let golden = GoldenImage::new(&path);
+        assert!(golden.compare_buffer(&buffer, size).unwrap().is_match());
+
+        buffer[0] = 250;
+        let diff = golden.compare_buffer(&buffer, size).unwrap();
+        assert_eq!(diff.max_diff, 5);
+        assert_eq!(diff.mismatched_pixels, 1);
+        assert!(GoldenImage::new(&path)
+            .tolerance(5)
+            .compare_buffer(&buffer, size)
+            .unwrap()
+            .is_match());
+
+        assert!(golden.compare_buffer(&buffer[..30], (5, 2)).is_err());
+    }
+}